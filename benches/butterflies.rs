@@ -0,0 +1,72 @@
+// benches/butterflies.rs
+//
+// Throughput of the float transforms and of the Q15 fixed-point complex
+// transform, in nanoseconds per radix-2 butterfly. The float butterflies
+// use fused multiply-adds only when the target has FMA, so compare a
+// default build against one with FMA enabled:
+//
+//     cargo bench --bench butterflies
+//     RUSTFLAGS="-C target-cpu=native" cargo bench --bench butterflies
+//
+// A plain harness keeps the benchmark free of extra dependencies.
+
+use rs_simple_fft::float::Complex32;
+use rs_simple_fft::{ComplexFixed, CplxFft, Fixed, RealFft};
+use std::hint::black_box;
+use std::time::Instant;
+
+const SIZES: [usize; 4] = [256, 1024, 4096, 16384];
+/// Transforms per measurement, scaled so every size runs ~2^24 butterflies.
+const WORK: usize = 1 << 24;
+
+fn report(name: &str, n: usize, butterflies: usize, mut run: impl FnMut()) {
+    let reps = (WORK / butterflies).max(1);
+    // Warm up caches and branch predictors
+    for _ in 0..reps / 10 + 1 {
+        run();
+    }
+    let start = Instant::now();
+    for _ in 0..reps {
+        run();
+    }
+    let ns = start.elapsed().as_nanos() as f64 / (reps * butterflies) as f64;
+    println!("{name:>8} N={n:<6} {ns:.3} ns/butterfly");
+}
+
+fn main() {
+    for n in SIZES {
+        let stages = n.trailing_zeros() as usize;
+
+        let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+        let mut bitrev = vec![0; n];
+        let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+        let mut buffer: Vec<Complex32> = (0..n)
+            .map(|i| Complex32::new((i % 7) as f32, (i % 3) as f32))
+            .collect();
+        report("complex", n, n / 2 * stages, || {
+            fft.process(black_box(&mut buffer), false).unwrap();
+            fft.process(black_box(&mut buffer), true).unwrap();
+        });
+
+        let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+        let mut bitrev = vec![0; n / 2];
+        let rfft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+        let mut buffer: Vec<f32> = (0..n).map(|i| (i % 5) as f32).collect();
+        report("real", n, n / 4 * (stages - 1), || {
+            rfft.process(black_box(&mut buffer), false).unwrap();
+            rfft.process(black_box(&mut buffer), true).unwrap();
+        });
+
+        // Q31 twiddles over Q15 data, as on an MCU without an FPU
+        let mut twiddles = vec![ComplexFixed::<31>::ZERO; n / 2];
+        let mut bitrev = vec![0; n];
+        let qfft = CplxFft::<ComplexFixed<31>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+        let mut buffer: Vec<ComplexFixed<15>> = (0..n)
+            .map(|i| ComplexFixed::new(Fixed::from_bits((i % 7) as i32), Fixed::ZERO))
+            .collect();
+        report("fixed", n, n / 2 * stages, || {
+            qfft.process(black_box(&mut buffer), false).unwrap();
+            qfft.process(black_box(&mut buffer), true).unwrap();
+        });
+    }
+}
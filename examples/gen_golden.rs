@@ -0,0 +1,102 @@
+//! Regenerates `src/validation/golden_vectors.rs`, the golden-vector corpus
+//! used by `validation::golden`: a grid of sizes and signal classes, with
+//! reference spectra computed by a plain f64 DFT on the host. The result is
+//! checked in, so builds do no generation work and the validation suite
+//! stays independent of the target's own floating-point math.
+//!
+//! Run with `cargo run --example gen_golden` after changing the grid.
+
+use std::f64::consts::PI;
+use std::fmt::Write as _;
+use std::fs;
+
+/// Sizes covered by the corpus. Keep in sync with `GOLDEN_MAX_N`.
+const SIZES: [usize; 5] = [2, 8, 32, 128, 512];
+
+/// Signal classes, as named by `validation::SignalClass`.
+const CLASSES: [&str; 5] = ["Impulse", "Dc", "Sine", "Chirp", "Noise"];
+
+fn signal(class: &str, n: usize) -> Vec<f32> {
+    let nf = n as f64;
+    // Fixed-seed LCG, so every build embeds the same noise
+    let mut state: u32 = 0x1234_5678;
+    (0..n)
+        .map(|t| {
+            let tf = t as f64;
+            let x = match class {
+                "Impulse" => {
+                    if t == 1 % n {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                }
+                "Dc" => 0.5,
+                // Off-bin tone, so leakage reaches every bin
+                "Sine" => 0.9 * (2.0 * PI * 3.3 * tf / nf + 0.25).sin(),
+                "Chirp" => 0.8 * (PI * tf * tf / (2.0 * nf)).cos(),
+                "Noise" => {
+                    state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                    (state >> 8) as f64 / (1u32 << 24) as f64 * 1.8 - 0.9
+                }
+                _ => unreachable!(),
+            };
+            x as f32
+        })
+        .collect()
+}
+
+/// Reference one-sided spectrum (N/2 + 1 bins) of the f32-rounded input.
+fn reference_dft(input: &[f32]) -> Vec<(f64, f64)> {
+    let n = input.len();
+    (0..=n / 2)
+        .map(|k| {
+            input
+                .iter()
+                .enumerate()
+                .fold((0.0, 0.0), |(re, im), (t, &x)| {
+                    // Reduce k * t modulo N first to keep the angle exact
+                    let angle = -2.0 * PI * ((k * t) % n) as f64 / n as f64;
+                    (re + x as f64 * angle.cos(), im + x as f64 * angle.sin())
+                })
+        })
+        .collect()
+}
+
+fn main() {
+    let mut out = String::new();
+    out.push_str("// Generated by examples/gen_golden.rs. Do not edit.\n\n");
+    writeln!(
+        out,
+        "pub(super) static GOLDEN: [GoldenVector; {}] = [",
+        SIZES.len() * CLASSES.len()
+    )
+    .unwrap();
+
+    for &n in &SIZES {
+        for class in CLASSES {
+            let input = signal(class, n);
+            let spectrum = reference_dft(&input);
+
+            writeln!(out, "    GoldenVector {{").unwrap();
+            writeln!(out, "        class: SignalClass::{},", class).unwrap();
+            writeln!(out, "        n: {},", n).unwrap();
+            out.push_str("        input: &[");
+            for x in &input {
+                write!(out, "{:?}, ", x).unwrap();
+            }
+            out.push_str("],\n        spectrum: &[");
+            for &(re, im) in &spectrum {
+                write!(out, "[{:?}, {:?}], ", re as f32, im as f32).unwrap();
+            }
+            out.push_str("],\n    },\n");
+        }
+    }
+    out.push_str("];\n");
+
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/src/validation/golden_vectors.rs"
+    );
+    fs::write(path, out).unwrap();
+}
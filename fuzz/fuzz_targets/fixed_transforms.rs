@@ -0,0 +1,63 @@
+#![no_main]
+
+// Runs the fixed-point transform paths on arbitrary Q15 input and checks
+// that nothing panics and the round trip stays within a few LSBs.
+
+use libfuzzer_sys::fuzz_target;
+use rs_simple_fft::{Algorithm, ComplexFixed, CplxFft, Fixed, RealFft};
+
+type Twiddle = ComplexFixed<31>;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&header, body)) = data.split_first() else {
+        return;
+    };
+    // Q15 samples in [-1, 1), stored in Fixed<15> with 16 bits of headroom
+    let samples: Vec<Fixed<15>> = body
+        .chunks_exact(2)
+        .map(|b| Fixed::from_bits(i16::from_le_bytes([b[0], b[1]]) as i32))
+        .collect();
+
+    let n = match samples.len() {
+        0 | 1 => return,
+        len => 1usize << (usize::BITS - 1 - len.leading_zeros()).min(12),
+    };
+    let algorithm = if header & 1 == 0 { Algorithm::Dit } else { Algorithm::Dif };
+    let zero = Twiddle::ZERO;
+    let tol = 4 * (n.trailing_zeros() as i32 + 1);
+
+    // Real path
+    let mut twiddles = vec![zero; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let rfft = RealFft::<Twiddle>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_algorithm(algorithm);
+    let mut buffer = samples[..n].to_vec();
+    rfft.process(&mut buffer, false).unwrap();
+    rfft.process(&mut buffer, true).unwrap();
+    for (a, b) in buffer.iter().zip(&samples[..n]) {
+        assert!((a.to_bits() - b.to_bits()).abs() <= tol, "real round trip");
+    }
+
+    // Complex path
+    let half = n / 2;
+    if half < 2 {
+        return;
+    }
+    let input: Vec<ComplexFixed<15>> = samples[..n]
+        .chunks_exact(2)
+        .map(|c| ComplexFixed::new(c[0], c[1]))
+        .collect();
+    let mut twiddles = vec![zero; half / 2];
+    let mut bitrev = vec![0; half];
+    let fft = CplxFft::<Twiddle>::new(&mut twiddles, &mut bitrev, half)
+        .unwrap()
+        .with_algorithm(algorithm);
+    let mut buffer = input.clone();
+    fft.process(&mut buffer, false).unwrap();
+    fft.process(&mut buffer, true).unwrap();
+    for (a, b) in buffer.iter().zip(&input) {
+        assert!((a.re.to_bits() - b.re.to_bits()).abs() <= tol, "complex round trip");
+        assert!((a.im.to_bits() - b.im.to_bits()).abs() <= tol, "complex round trip");
+    }
+});
@@ -0,0 +1,68 @@
+#![no_main]
+
+// Runs every float transform path on arbitrary input and checks that the
+// round trip recovers the signal. Non-finite samples are mapped to zero.
+
+use libfuzzer_sys::fuzz_target;
+use rs_simple_fft::float::Complex32;
+use rs_simple_fft::{Algorithm, CplxFft, RealFft};
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&header, body)) = data.split_first() else {
+        return;
+    };
+    let samples: Vec<f32> = body
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .map(|x| if x.is_finite() { x.clamp(-1e3, 1e3) } else { 0.0 })
+        .collect();
+
+    // Largest power of two that fits, capped at 2^12
+    let n = match samples.len() {
+        0 | 1 => return,
+        len => 1usize << (usize::BITS - 1 - len.leading_zeros()).min(12),
+    };
+    let algorithm = if header & 1 == 0 { Algorithm::Dit } else { Algorithm::Dif };
+    let peak = samples[..n].iter().fold(0.0f32, |m, x| m.max(x.abs()));
+    let tol = 1e-3 * (1.0 + peak) * (n.trailing_zeros() as f32 + 1.0);
+
+    // Real path
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let rfft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_algorithm(algorithm);
+    let mut buffer = samples[..n].to_vec();
+    rfft.process(&mut buffer, false).unwrap();
+    rfft.process(&mut buffer, true).unwrap();
+    for (a, b) in buffer.iter().zip(&samples[..n]) {
+        assert!((a - b).abs() <= tol, "real round trip: {} vs {}", a, b);
+    }
+
+    // Complex path, interleaved and planar
+    let half = n / 2;
+    if half < 2 {
+        return;
+    }
+    let input: Vec<Complex32> = samples[..n]
+        .chunks_exact(2)
+        .map(|c| Complex32::new(c[0], c[1]))
+        .collect();
+    let mut twiddles = vec![Complex32::new(0., 0.); half / 2];
+    let mut bitrev = vec![0; half];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, half)
+        .unwrap()
+        .with_algorithm(algorithm);
+
+    let mut buffer = input.clone();
+    fft.process(&mut buffer, false).unwrap();
+    let (mut re, mut im): (Vec<f32>, Vec<f32>) = input.iter().map(|c| (c.re, c.im)).unzip();
+    fft.process_split(&mut re, &mut im, false).unwrap();
+    fft.process(&mut buffer, true).unwrap();
+    fft.process_split(&mut re, &mut im, true).unwrap();
+
+    for ((a, b), (r, i)) in buffer.iter().zip(&input).zip(re.iter().zip(&im)) {
+        assert!((*a - *b).l1_norm() <= 2.0 * tol, "complex round trip");
+        assert!((r - b.re).abs() + (i - b.im).abs() <= 2.0 * tol, "split round trip");
+    }
+});
@@ -0,0 +1,53 @@
+// src/analysis/detrend.rs
+
+use super::window::remove_mean;
+
+/// Trend removed by [`detrend`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    /// Subtract the mean (`scipy.signal.detrend(type="constant")`).
+    Constant,
+    /// Subtract the least-squares straight line
+    /// (`scipy.signal.detrend(type="linear")`).
+    #[default]
+    Linear,
+}
+
+/// Removes a constant or linear trend from `buffer` in place, as done per
+/// segment by `scipy.signal.welch(detrend=...)` before windowing.
+///
+/// Sums are accumulated in f64 so that long records with a large offset
+/// give the same result as the NumPy reference.
+pub fn detrend(buffer: &mut [f32], order: Order) {
+    match order {
+        Order::Constant => {
+            remove_mean(buffer);
+        }
+        Order::Linear => {
+            let n = buffer.len();
+            if n < 2 {
+                buffer.fill(0.0);
+                return;
+            }
+            // Fit against a centred time axis, which decouples slope and mean
+            let centre = (n - 1) as f64 / 2.0;
+            let (mut sum, mut sum_tx, mut sum_tt) = (0.0, 0.0, 0.0);
+            for (i, &x) in buffer.iter().enumerate() {
+                let t = i as f64 - centre;
+                sum += x as f64;
+                sum_tx += t * x as f64;
+                sum_tt += t * t;
+            }
+            let mean = sum / n as f64;
+            let slope = sum_tx / sum_tt;
+            for (i, x) in buffer.iter_mut().enumerate() {
+                let t = i as f64 - centre;
+                *x = (*x as f64 - mean - slope * t) as f32;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "detrend_tests.rs"]
+mod tests;
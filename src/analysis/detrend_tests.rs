@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn test_constant() {
+    let mut x = [1.0, 2.0, 6.0];
+    detrend(&mut x, Order::Constant);
+    assert_eq!(x, [-2.0, -1.0, 3.0]);
+}
+
+#[test]
+fn test_linear_removes_ramp() {
+    // Offset and ramp on top of an alternating pattern, which has no trend
+    let mut x: Vec<f32> = (0..64)
+        .map(|i| 1000.0 + 0.25 * i as f32 + if i % 2 == 0 { 1.0 } else { -1.0 })
+        .collect();
+    detrend(&mut x, Order::Linear);
+
+    // The pattern itself has a tiny slope over an even length
+    let slope = -32.0 / (64.0 * (64.0 * 64.0 - 1.0) / 12.0);
+    for (i, &v) in x.iter().enumerate() {
+        let pattern = if i % 2 == 0 { 1.0 } else { -1.0 };
+        let expected = pattern - slope * (i as f32 - 31.5);
+        assert!((v - expected).abs() < 1e-3, "i = {i}: {v}");
+    }
+}
+
+#[test]
+fn test_short_buffers() {
+    let mut one = [5.0];
+    detrend(&mut one, Order::Linear);
+    assert_eq!(one, [0.0]);
+    let mut two = [1.0, 3.0];
+    detrend(&mut two, Order::Linear);
+    assert_eq!(two, [0.0, 0.0]);
+    detrend(&mut [], Order::Linear);
+}
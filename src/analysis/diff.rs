@@ -0,0 +1,99 @@
+// src/analysis/diff.rs
+
+use super::spectrum::Spectrum;
+use crate::common::{FftError, FftNum};
+
+/// Summary of how far a spectrum is from a reference, see
+/// [`compare_spectra`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpectrumDiff {
+    /// RMS over bins `0..=N/2` of the complex difference `a - b`.
+    pub rms_error: f32,
+    /// `rms_error` relative to the RMS of the reference, in dB. Negative
+    /// infinity for identical spectra.
+    pub relative_db: f32,
+    /// Bin whose level differs the most.
+    pub worst_bin: usize,
+    /// Level difference of that bin, `20 log10(|a| / |b|)`.
+    pub worst_db: f32,
+}
+
+/// Value of bin `k` as `f32`, whatever the sample type.
+fn value<T: FftNum>(s: &Spectrum<'_, T>, k: usize) -> (f32, f32) {
+    let (re, im) = s.bin(k).unwrap();
+    (re.to_f64() as f32, im.to_f64() as f32)
+}
+
+fn check<A: FftNum, B: FftNum>(a: &Spectrum<'_, A>, b: &Spectrum<'_, B>) -> Result<(), FftError> {
+    if a.n() != b.n() {
+        return Err(FftError::SizeMismatch);
+    }
+    Ok(())
+}
+
+/// Level difference in dB of bin `k`; bins that are both empty match.
+fn level_db(a: f32, b: f32) -> f32 {
+    if a == b {
+        0.0
+    } else {
+        20.0 * libm::log10f(a / b)
+    }
+}
+
+/// Writes the per-bin level difference `20 log10(|a| / |b|)` of bins
+/// `0..=N/2` into `out`.
+///
+/// The spectra may differ in packing and sample type (e.g. a fixed-point
+/// build against its float reference) but must come from the same N.
+pub fn level_difference<A: FftNum, B: FftNum>(
+    a: &Spectrum<'_, A>,
+    b: &Spectrum<'_, B>,
+    out: &mut [f32],
+) -> Result<(), FftError> {
+    check(a, b)?;
+    if out.len() != a.num_bins() {
+        return Err(FftError::SizeMismatch);
+    }
+    for (k, o) in out.iter_mut().enumerate() {
+        *o = level_db(a.magnitude(k).unwrap(), b.magnitude(k).unwrap());
+    }
+    Ok(())
+}
+
+/// Compares `a` against the reference `b`: RMS error of the complex
+/// difference and the bin with the largest level deviation.
+///
+/// Meant for null tests: a regression check can bound `relative_db` for
+/// the overall match and `worst_db` for any single bin.
+pub fn compare_spectra<A: FftNum, B: FftNum>(
+    a: &Spectrum<'_, A>,
+    b: &Spectrum<'_, B>,
+) -> Result<SpectrumDiff, FftError> {
+    check(a, b)?;
+    let (mut err, mut reference) = (0.0f32, 0.0f32);
+    let (mut worst_bin, mut worst_db) = (0, 0.0f32);
+    for k in 0..a.num_bins() {
+        let (ar, ai) = value(a, k);
+        let (br, bi) = value(b, k);
+        err += (ar - br) * (ar - br) + (ai - bi) * (ai - bi);
+        reference += br * br + bi * bi;
+
+        let db = level_db(libm::hypotf(ar, ai), libm::hypotf(br, bi));
+        if libm::fabsf(db) > libm::fabsf(worst_db) {
+            worst_bin = k;
+            worst_db = db;
+        }
+    }
+
+    let bins = a.num_bins() as f32;
+    Ok(SpectrumDiff {
+        rms_error: libm::sqrtf(err / bins),
+        relative_db: 10.0 * libm::log10f(err / reference),
+        worst_bin,
+        worst_db,
+    })
+}
+
+#[cfg(test)]
+#[path = "diff_tests.rs"]
+mod tests;
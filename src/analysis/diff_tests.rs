@@ -0,0 +1,83 @@
+use super::*;
+use crate::common::RealFft;
+use crate::fixed::Fixed;
+use crate::float::types::Complex32;
+
+const N: usize = 64;
+const FS: f32 = 8000.0;
+
+fn spectrum_of(x: &[f32]) -> Vec<f32> {
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut buf = x.to_vec();
+    fft.process(&mut buf, false).unwrap();
+    buf
+}
+
+fn signal() -> Vec<f32> {
+    (0..N).map(|i| libm::sinf(i as f32 * 0.7) + 0.25).collect()
+}
+
+#[test]
+fn test_identical_spectra_null() {
+    let s = spectrum_of(&signal());
+    let a = Spectrum::packed(&s, FS).unwrap();
+    let diff = compare_spectra(&a, &a).unwrap();
+    assert_eq!(diff.rms_error, 0.0);
+    assert_eq!(diff.relative_db, f32::NEG_INFINITY);
+    assert_eq!(diff.worst_db, 0.0);
+
+    let mut levels = [1.0f32; N / 2 + 1];
+    level_difference(&a, &a, &mut levels).unwrap();
+    assert!(levels.iter().all(|&d| d == 0.0));
+}
+
+#[test]
+fn test_gain_change_in_one_bin() {
+    let reference = spectrum_of(&signal());
+    let mut changed = reference.clone();
+    // Bin 5 up by 6 dB
+    changed[10] *= 2.0;
+    changed[11] *= 2.0;
+    let a = Spectrum::packed(&changed, FS).unwrap();
+    let b = Spectrum::packed(&reference, FS).unwrap();
+
+    let diff = compare_spectra(&a, &b).unwrap();
+    assert_eq!(diff.worst_bin, 5);
+    assert!((diff.worst_db - 6.0206).abs() < 1e-3);
+    let bin5 = libm::hypotf(reference[10], reference[11]);
+    let expected_rms = bin5 / libm::sqrtf((N / 2 + 1) as f32);
+    assert!((diff.rms_error - expected_rms).abs() < 1e-4 * bin5);
+
+    let mut levels = [0.0f32; N / 2 + 1];
+    level_difference(&a, &b, &mut levels).unwrap();
+    assert!((levels[5] - 6.0206).abs() < 1e-3);
+    assert!(levels[4].abs() < 1e-6);
+    assert_eq!(
+        level_difference(&a, &b, &mut levels[..N / 2]),
+        Err(FftError::SizeMismatch)
+    );
+}
+
+#[test]
+fn test_fixed_against_float_reference() {
+    let reference = spectrum_of(&signal());
+    // The same spectrum rounded to Q12
+    let fixed: Vec<Fixed<12>> = reference
+        .iter()
+        .map(|&v| Fixed::from_f64(v as f64))
+        .collect();
+    let a = Spectrum::packed(&fixed, FS).unwrap();
+    let b = Spectrum::packed(&reference, FS).unwrap();
+    let diff = compare_spectra(&a, &b).unwrap();
+    // Rounding error of at most half an LSB per component
+    assert!(diff.rms_error <= 0.5 / 4096.0 * libm::sqrtf(2.0));
+    assert!(diff.relative_db < -60.0);
+
+    let short = [0.0f32; N / 2];
+    assert_eq!(
+        compare_spectra(&a, &Spectrum::packed(&short, FS).unwrap()),
+        Err(FftError::SizeMismatch)
+    );
+}
@@ -0,0 +1,78 @@
+// src/analysis/exact_bin.rs
+
+use crate::common::FftError;
+use crate::fixed::Fixed;
+use core::f32::consts::PI;
+
+/// Angular step per sample, checked against the sample rate.
+fn step(freq_hz: f32, sample_rate: f32) -> Result<f32, FftError> {
+    let valid = sample_rate > 0.0 && freq_hz.abs() <= sample_rate / 2.0;
+    if !valid {
+        return Err(FftError::OutOfRange);
+    }
+    Ok(2.0 * PI * freq_hz / sample_rate)
+}
+
+/// DTFT of `buffer` at `freq_hz`, `X(f) = sum x[n] e^(-j 2 pi f n / fs)`,
+/// as `(re, im)`.
+///
+/// Unlike an FFT bin, `freq_hz` can be anywhere in `[-fs/2, fs/2]`, so a
+/// calibration tone is measured at its own frequency instead of being
+/// read through the scalloping of the nearest bin. The scale is that of an
+/// unscaled FFT: a sinusoid of amplitude A reads about `A * N / 2`.
+///
+/// The complex exponential comes from a rotating phasor (NCO) that is
+/// renormalized every sample, so the cost is a complex multiply per sample
+/// with no trigonometric calls in the loop.
+pub fn exact_bin(buffer: &[f32], freq_hz: f32, sample_rate: f32) -> Result<(f32, f32), FftError> {
+    let w = step(freq_hz, sample_rate)?;
+    let (dc, ds) = (libm::cosf(w), -libm::sinf(w));
+
+    let (mut c, mut s) = (1.0f32, 0.0f32);
+    let (mut re, mut im) = (0.0f32, 0.0f32);
+    for &x in buffer {
+        re += x * c;
+        im += x * s;
+        let next = (c * dc - s * ds, c * ds + s * dc);
+        // First-order correction back onto the unit circle
+        let g = 1.5 - 0.5 * (next.0 * next.0 + next.1 * next.1);
+        (c, s) = (next.0 * g, next.1 * g);
+    }
+    Ok((re, im))
+}
+
+/// Integer version of [`exact_bin`] for Q15 data.
+///
+/// The phasor runs in Q30 integer arithmetic; only its step is computed in
+/// floating point, once per call. Returns the raw sums in Q15 LSBs (i.e.
+/// `re / 2^15` is the value), in `i64` so any length fits.
+pub fn exact_bin_q15(
+    buffer: &[Fixed<15>],
+    freq_hz: f32,
+    sample_rate: f32,
+) -> Result<(i64, i64), FftError> {
+    const ONE: i64 = 1 << 30;
+    let w = step(freq_hz, sample_rate)?;
+    let q30 = |v: f32| libm::round(v as f64 * ONE as f64) as i64;
+    let (dc, ds) = (q30(libm::cosf(w)), q30(-libm::sinf(w)));
+
+    let (mut c, mut s) = (ONE, 0i64);
+    let (mut re, mut im) = (0i64, 0i64);
+    let round = 1i64 << 29;
+    for x in buffer {
+        let x = x.to_bits() as i64;
+        re += (x * c + round) >> 30;
+        im += (x * s + round) >> 30;
+        let nc = (c * dc - s * ds + round) >> 30;
+        let ns = (c * ds + s * dc + round) >> 30;
+        // (3 - |p|^2) / 2 in Q30
+        let g = (3 * (ONE << 30) - (nc * nc + ns * ns) + (1 << 30)) >> 31;
+        c = (nc * g + round) >> 30;
+        s = (ns * g + round) >> 30;
+    }
+    Ok((re, im))
+}
+
+#[cfg(test)]
+#[path = "exact_bin_tests.rs"]
+mod tests;
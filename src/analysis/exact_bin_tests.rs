@@ -0,0 +1,57 @@
+use super::*;
+use crate::common::RealFft;
+use crate::float::types::Complex32;
+
+const N: usize = 4096;
+const FS: f32 = 48000.0;
+
+fn tone(freq: f32, amplitude: f32, phase: f32) -> Vec<f32> {
+    (0..N)
+        .map(|i| amplitude * libm::cosf(2.0 * PI * freq * i as f32 / FS + phase))
+        .collect()
+}
+
+#[test]
+fn test_matches_fft_on_a_bin() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let x: Vec<f32> = (0..N).map(|i| libm::sinf(i as f32 * 0.37) + 0.1).collect();
+    let mut spectrum = x.clone();
+    fft.process(&mut spectrum, false).unwrap();
+
+    let k = 123;
+    let (re, im) = exact_bin(&x, k as f32 * FS / N as f32, FS).unwrap();
+    assert!(
+        (re - spectrum[2 * k]).abs() < 0.05,
+        "{re} vs {}",
+        spectrum[2 * k]
+    );
+    assert!(
+        (im - spectrum[2 * k + 1]).abs() < 0.05,
+        "{im} vs {}",
+        spectrum[2 * k + 1]
+    );
+}
+
+#[test]
+fn test_reads_off_bin_tone_exactly() {
+    // 1000.3 Hz falls 0.34 bins away from a bin centre
+    let x = tone(1000.3, 0.5, 0.4);
+    let (re, im) = exact_bin(&x, 1000.3, FS).unwrap();
+    let amplitude = 2.0 * libm::hypotf(re, im) / N as f32;
+    assert!((amplitude - 0.5).abs() < 1e-3, "{amplitude}");
+    assert!((libm::atan2f(im, re) - 0.4).abs() < 1e-2);
+
+    let q: Vec<Fixed<15>> = x.iter().map(|&v| Fixed::from_f64(v as f64)).collect();
+    let (qre, qim) = exact_bin_q15(&q, 1000.3, FS).unwrap();
+    let scale = 1.0 / 32768.0;
+    // Within quantization noise of the float result (about 1024 tall)
+    assert!((qre as f32 * scale - re).abs() < 0.05, "{qre}");
+    assert!((qim as f32 * scale - im).abs() < 0.05, "{qim}");
+    let q_amplitude = 2.0 * libm::hypotf(qre as f32, qim as f32) * scale / N as f32;
+    assert!((q_amplitude - 0.5).abs() < 1e-3, "{q_amplitude}");
+
+    assert_eq!(exact_bin(&x, 30000.0, FS), Err(FftError::OutOfRange));
+    assert_eq!(exact_bin_q15(&q, 1000.0, 0.0), Err(FftError::OutOfRange));
+}
@@ -0,0 +1,100 @@
+// src/analysis/features.rs
+
+use super::spectrum::Spectrum;
+use crate::common::{FftError, FftNum};
+
+/// Element type a feature extractor can write. Implemented for `f32` and,
+/// with the `half` feature, for `bf16`, so features for an NN accelerator
+/// are narrowed as they are produced rather than in a second pass.
+pub trait FeatureValue: Copy {
+    fn from_f32(value: f32) -> Self;
+}
+
+impl FeatureValue for f32 {
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+}
+
+#[cfg(feature = "half")]
+impl FeatureValue for half::bf16 {
+    fn from_f32(value: f32) -> Self {
+        half::bf16::from_f32(value)
+    }
+}
+
+/// Writes the magnitude of bins `0..=N/2` into `out`.
+pub fn magnitudes<T: FftNum, O: FeatureValue>(
+    spectrum: &Spectrum<'_, T>,
+    out: &mut [O],
+) -> Result<(), FftError> {
+    if out.len() != spectrum.num_bins() {
+        return Err(FftError::SizeMismatch);
+    }
+    for (k, o) in out.iter_mut().enumerate() {
+        *o = O::from_f32(spectrum.magnitude(k).unwrap_or(0.0));
+    }
+    Ok(())
+}
+
+/// HTK mel scale.
+fn hz_to_mel(hz: f32) -> f32 {
+    2595.0 * libm::log10f(1.0 + hz / 700.0)
+}
+
+fn mel_to_hz(mel: f32) -> f32 {
+    700.0 * (libm::powf(10.0, mel / 2595.0) - 1.0)
+}
+
+/// Mel filterbank energies: `out.len()` triangular bands, evenly spaced on
+/// the HTK mel scale between `f_min` and `f_max`, applied to the power
+/// `|X[k]|²` of each bin.
+///
+/// Band `m` rises from the centre of band `m - 1` to its own centre and
+/// falls to the centre of band `m + 1`, with unit peak weight. The weights
+/// are computed on the fly, so no filterbank table is needed. `f_max` must
+/// not exceed Nyquist.
+pub fn mel_energies<T: FftNum, O: FeatureValue>(
+    spectrum: &Spectrum<'_, T>,
+    f_min: f32,
+    f_max: f32,
+    out: &mut [O],
+) -> Result<(), FftError> {
+    let bands = out.len();
+    if bands == 0 {
+        return Err(FftError::SizeMismatch);
+    }
+    let nyquist = spectrum.sample_rate() / 2.0;
+    if f_min.is_nan() || f_min < 0.0 || f_max.is_nan() || f_max <= f_min || f_max > nyquist {
+        return Err(FftError::OutOfRange);
+    }
+
+    let (mel_lo, mel_hi) = (hz_to_mel(f_min), hz_to_mel(f_max));
+    let step = (mel_hi - mel_lo) / (bands + 1) as f32;
+    let edge = |i: usize| mel_to_hz(mel_lo + step * i as f32);
+
+    for (m, o) in out.iter_mut().enumerate() {
+        let (left, centre, right) = (edge(m), edge(m + 1), edge(m + 2));
+        let first = libm::ceilf(spectrum.hz_to_bin(left)) as usize;
+        let last = (libm::floorf(spectrum.hz_to_bin(right)) as usize).min(spectrum.num_bins() - 1);
+        let mut energy = 0.0;
+        for k in first..=last {
+            let f = spectrum.bin_to_hz(k as f32);
+            let weight = if f <= centre {
+                (f - left) / (centre - left)
+            } else {
+                (right - f) / (right - centre)
+            };
+            if weight > 0.0 {
+                let mag = spectrum.magnitude(k).unwrap_or(0.0);
+                energy += weight * mag * mag;
+            }
+        }
+        *o = O::from_f32(energy);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "features_tests.rs"]
+mod tests;
@@ -0,0 +1,64 @@
+use super::*;
+
+const N: usize = 64;
+const FS: f32 = 6400.0;
+
+/// Packed spectrum with a single bin of the given magnitude.
+fn one_bin(k: usize, magnitude: f32) -> Vec<f32> {
+    let mut packed = vec![0.0; N];
+    packed[2 * k] = magnitude;
+    packed
+}
+
+#[test]
+fn test_magnitudes() {
+    let mut packed = one_bin(3, 0.0);
+    packed[6] = 3.0;
+    packed[7] = -4.0;
+    packed[1] = -2.0;
+    let spectrum = Spectrum::packed(&packed, FS).unwrap();
+    let mut out = vec![0.0f32; N / 2 + 1];
+    magnitudes(&spectrum, &mut out).unwrap();
+    assert_eq!(out[3], 5.0);
+    assert_eq!(out[N / 2], 2.0);
+    assert_eq!(
+        magnitudes(&spectrum, &mut out[1..]),
+        Err(FftError::SizeMismatch)
+    );
+}
+
+#[test]
+fn test_mel_bands_pick_up_their_tone() {
+    // 100 Hz bins; a tone at bin 10 (1 kHz)
+    let packed = one_bin(10, 2.0);
+    let spectrum = Spectrum::packed(&packed, FS).unwrap();
+    let mut bands = [0.0f32; 8];
+    mel_energies(&spectrum, 0.0, FS / 2.0, &mut bands).unwrap();
+
+    // 1 kHz sits between two band centres and is shared by them
+    let active = bands.iter().filter(|&&b| b > 0.0).count();
+    assert_eq!(active, 2);
+    let total: f32 = bands.iter().sum();
+    // Adjacent triangles overlap with weights summing to 1
+    assert!((total - 4.0).abs() < 1e-4);
+
+    assert_eq!(
+        mel_energies(&spectrum, 0.0, FS, &mut bands),
+        Err(FftError::OutOfRange)
+    );
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_bf16_output() {
+    use half::bf16;
+    let packed = one_bin(10, 2.0);
+    let spectrum = Spectrum::packed(&packed, FS).unwrap();
+    let mut wide = [0.0f32; 8];
+    let mut narrow = [bf16::ZERO; 8];
+    mel_energies(&spectrum, 50.0, 3000.0, &mut wide).unwrap();
+    mel_energies(&spectrum, 50.0, 3000.0, &mut narrow).unwrap();
+    for (w, n) in wide.iter().zip(&narrow) {
+        assert_eq!(*n, bf16::from_f32(*w));
+    }
+}
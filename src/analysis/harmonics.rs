@@ -0,0 +1,120 @@
+// src/analysis/harmonics.rs
+
+use super::spectrum::Spectrum;
+use crate::common::{FftError, FftNum};
+use core::f32::consts::PI;
+
+/// One measured harmonic of a periodic signal.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Harmonic {
+    /// Harmonic number, 1 for the fundamental.
+    pub order: usize,
+    /// Interpolated frequency in Hz.
+    pub frequency: f32,
+    /// Peak amplitude of the sinusoid in signal units.
+    pub amplitude: f32,
+    /// Phase in radians relative to a cosine starting at sample 0, in
+    /// (-pi, pi].
+    pub phase: f32,
+}
+
+/// Measures the fundamental and its harmonics from a real spectrum.
+///
+/// Harmonic `h` is searched within one bin of `h * fundamental_hz`; the
+/// peak is refined by quadratic interpolation of the log-magnitude (a few
+/// percent of amplitude error at worst with a Hann window) and the phase is
+/// corrected for the fractional bin offset. `coherent_gain` is the
+/// window's mean value (1.0 for no window, 0.5 for Hann) and is needed to
+/// turn bin magnitudes into amplitudes.
+///
+/// Fills `out[0]` with the fundamental, `out[1]` with the second harmonic,
+/// and so on; harmonics past Nyquist are not measured. Returns the number
+/// of entries written.
+pub fn harmonics<T: FftNum>(
+    spectrum: &Spectrum<'_, T>,
+    fundamental_hz: f32,
+    coherent_gain: f32,
+    out: &mut [Harmonic],
+) -> Result<usize, FftError> {
+    if fundamental_hz <= 0.0 || coherent_gain <= 0.0 {
+        return Err(FftError::OutOfRange);
+    }
+    let n = spectrum.n();
+    let last = n / 2;
+    let f0_bin = spectrum.hz_to_bin(fundamental_hz);
+    let mag = |k: usize| spectrum.magnitude(k).unwrap();
+
+    let mut written = 0;
+    for (i, slot) in out.iter_mut().enumerate() {
+        let order = i + 1;
+        let expected = libm::roundf(f0_bin * order as f32) as usize;
+        if expected > last {
+            break;
+        }
+
+        // Largest bin within one bin of the expected position
+        let k = (expected.saturating_sub(1)..=(expected + 1).min(last))
+            .max_by(|&a, &b| mag(a).total_cmp(&mag(b)))
+            .unwrap();
+
+        let (delta, peak) = if k > 0 && k < last {
+            interpolate(mag(k - 1), mag(k), mag(k + 1))
+        } else {
+            (0.0, mag(k))
+        };
+
+        // A tone at k + delta shows up in bin k rotated by pi*delta*(N-1)/N
+        let phase = spectrum.phase(k).unwrap() - PI * delta * (n - 1) as f32 / n as f32;
+
+        *slot = Harmonic {
+            order,
+            frequency: spectrum.bin_to_hz(k as f32 + delta),
+            amplitude: 2.0 * peak / (n as f32 * coherent_gain),
+            phase: wrap_phase(phase),
+        };
+        written += 1;
+    }
+    Ok(written)
+}
+
+/// Total harmonic distortion of a measurement, as the ratio of the RMS sum
+/// of the harmonics to the fundamental. Returns 0.0 with fewer than two
+/// entries.
+pub fn thd(harmonics: &[Harmonic]) -> f32 {
+    match harmonics {
+        [fundamental, rest @ ..] if !rest.is_empty() && fundamental.amplitude > 0.0 => {
+            let sum: f32 = rest.iter().map(|h| h.amplitude * h.amplitude).sum();
+            libm::sqrtf(sum) / fundamental.amplitude
+        }
+        _ => 0.0,
+    }
+}
+
+/// Quadratic fit through three log-magnitudes: returns the peak offset in
+/// bins and the peak magnitude.
+fn interpolate(a: f32, b: f32, c: f32) -> (f32, f32) {
+    if a <= 0.0 || b <= 0.0 || c <= 0.0 {
+        return (0.0, b);
+    }
+    let (la, lb, lc) = (libm::logf(a), libm::logf(b), libm::logf(c));
+    let denom = la - 2.0 * lb + lc;
+    if denom >= 0.0 {
+        return (0.0, b);
+    }
+    let delta = (0.5 * (la - lc) / denom).clamp(-0.5, 0.5);
+    (delta, libm::expf(lb - 0.25 * (la - lc) * delta))
+}
+
+/// Wraps an angle into (-pi, pi].
+pub(crate) fn wrap_phase(phase: f32) -> f32 {
+    let wrapped = libm::remainderf(phase, 2.0 * PI);
+    if wrapped <= -PI {
+        wrapped + 2.0 * PI
+    } else {
+        wrapped
+    }
+}
+
+#[cfg(test)]
+#[path = "harmonics_tests.rs"]
+mod tests;
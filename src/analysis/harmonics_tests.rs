@@ -0,0 +1,65 @@
+use super::*;
+use crate::common::RealFft;
+use crate::float::types::Complex32;
+
+const N: usize = 256;
+const FS: f32 = 6400.0;
+
+/// Hann-windowed packed spectrum of a sum of `(order, amplitude, phase)`
+/// harmonics of `f0`.
+fn windowed_spectrum(f0: f32, parts: &[(usize, f32, f32)]) -> Vec<f32> {
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut buf: Vec<f32> = (0..N)
+        .map(|i| {
+            let t = i as f32 / FS;
+            let x: f32 = parts
+                .iter()
+                .map(|&(h, a, p)| a * (2.0 * PI * h as f32 * f0 * t + p).cos())
+                .sum();
+            let w = 0.5 - 0.5 * (2.0 * PI * i as f32 / N as f32).cos();
+            x * w
+        })
+        .collect();
+    fft.process(&mut buf, false).unwrap();
+    buf
+}
+
+#[test]
+fn test_measures_harmonics_between_bins() {
+    // f0 sits at bin 10.3
+    let f0 = 10.3 * FS / N as f32;
+    let parts = [(1, 1.0, 0.4), (2, 0.2, -1.0), (3, 0.05, 2.5)];
+    let packed = windowed_spectrum(f0, &parts);
+    let spectrum = Spectrum::packed(&packed, FS).unwrap();
+
+    // A slightly wrong fundamental estimate still lands on the right peaks
+    let mut out = [Harmonic::default(); 3];
+    assert_eq!(harmonics(&spectrum, f0 * 1.01, 0.5, &mut out).unwrap(), 3);
+
+    for (h, &(order, amplitude, phase)) in out.iter().zip(&parts) {
+        assert_eq!(h.order, order);
+        assert!((h.frequency - order as f32 * f0).abs() < 0.05 * spectrum.resolution());
+        assert!((h.amplitude - amplitude).abs() < 0.03 * amplitude);
+        assert!((h.phase - phase).abs() < 0.05, "{} vs {}", h.phase, phase);
+    }
+
+    let expected_thd = (0.2f32 * 0.2 + 0.05 * 0.05).sqrt();
+    assert!((thd(&out) - expected_thd).abs() < 0.01);
+}
+
+#[test]
+fn test_stops_at_nyquist() {
+    let f0 = 50.0 * FS / N as f32;
+    let packed = windowed_spectrum(f0, &[(1, 1.0, 0.0)]);
+    let spectrum = Spectrum::packed(&packed, FS).unwrap();
+
+    let mut out = [Harmonic::default(); 5];
+    assert_eq!(harmonics(&spectrum, f0, 0.5, &mut out).unwrap(), 2);
+    assert_eq!(thd(&out[..1]), 0.0);
+    assert_eq!(
+        harmonics(&spectrum, 0.0, 0.5, &mut out),
+        Err(FftError::OutOfRange)
+    );
+}
@@ -0,0 +1,49 @@
+use super::*;
+use crate::common::RealFft;
+use crate::float::types::Complex32;
+
+const N: usize = 512;
+const FS: f32 = 3200.0;
+
+/// Hann-windowed packed spectrum of `amplitude * cos(2 pi bin n / N + phase)`.
+fn hann_tone(bin: f64, amplitude: f64, phase: f64) -> Vec<f32> {
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut buf: Vec<f32> = (0..N)
+        .map(|i| {
+            let x = amplitude * (2.0 * PI * bin * i as f64 / N as f64 + phase).cos();
+            let w = 0.5 - 0.5 * (2.0 * PI * i as f64 / N as f64).cos();
+            (x * w) as f32
+        })
+        .collect();
+    fft.process(&mut buf, false).unwrap();
+    buf
+}
+
+#[test]
+fn test_tone_between_bins() {
+    for &(bin, phase) in &[(8.0, 0.3), (8.37, -2.0), (31.5, 1.0), (64.81, 3.0)] {
+        let packed = hann_tone(bin, 0.8, phase);
+        let spectrum = Spectrum::packed(&packed, FS).unwrap();
+        let tone = ipdft_hann(&spectrum).unwrap();
+
+        assert!(
+            (tone.bin as f64 - bin).abs() < 1e-3,
+            "{} vs {bin}",
+            tone.bin
+        );
+        let hz = bin as f32 * FS / N as f32;
+        assert!((tone.frequency - hz).abs() < 1e-3 * spectrum.resolution());
+        assert!((tone.amplitude - 0.8).abs() < 1e-3, "{}", tone.amplitude);
+        let err = wrap_phase(tone.phase - phase as f32).abs();
+        assert!(err < 5e-3, "{} vs {phase}", tone.phase);
+    }
+}
+
+#[test]
+fn test_silence() {
+    let packed = vec![0.0f32; N];
+    let spectrum = Spectrum::packed(&packed, FS).unwrap();
+    assert_eq!(ipdft_hann(&spectrum), None);
+}
@@ -0,0 +1,100 @@
+// src/analysis/mask.rs
+
+use super::power::resolution;
+use crate::common::FftError;
+
+/// Corner of a spectral mask: the limit in dB (of the PSD, i.e.
+/// `10 log10(power per Hz)`) at a frequency. The limit between two
+/// corners is interpolated linearly in dB.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaskPoint {
+    pub freq_hz: f32,
+    pub limit_db: f32,
+}
+
+/// Outcome of one mask segment (between two consecutive corners).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BandReport {
+    pub f_low: f32,
+    pub f_high: f32,
+    /// Smallest `limit - measured` over the bins of the band, in dB.
+    /// Negative when the band violates the mask; infinite when no bin
+    /// falls inside it.
+    pub margin_db: f32,
+    /// Frequency of the bin with the smallest margin.
+    pub worst_hz: f32,
+    /// Number of bins above the limit.
+    pub violations: usize,
+}
+
+impl BandReport {
+    pub fn passed(&self) -> bool {
+        self.violations == 0
+    }
+}
+
+/// Compares a one-sided PSD (power per Hz, bins `0..=N/2`, as from an
+/// averaged or Welch estimate) against `mask` and writes one report per
+/// segment into `out` (`mask.len() - 1` entries). Returns whether every
+/// band passed.
+///
+/// Corners must be in non-decreasing frequency order; two corners at the
+/// same frequency make a vertical step, where the bin on the step is held
+/// to both limits. Bins outside the mask are not checked.
+pub fn check_mask(
+    psd: &[f32],
+    sample_rate: f32,
+    mask: &[MaskPoint],
+    out: &mut [BandReport],
+) -> Result<bool, FftError> {
+    let df = resolution(psd.len(), sample_rate)?;
+    if mask.len() < 2 || out.len() != mask.len() - 1 {
+        return Err(FftError::SizeMismatch);
+    }
+    let ordered = mask.windows(2).all(|p| p[0].freq_hz <= p[1].freq_hz);
+    let finite = mask
+        .iter()
+        .all(|p| p.freq_hz.is_finite() && p.limit_db.is_finite());
+    if !ordered || !finite {
+        return Err(FftError::OutOfRange);
+    }
+
+    let mut passed = true;
+    for (report, corners) in out.iter_mut().zip(mask.windows(2)) {
+        let (a, b) = (corners[0], corners[1]);
+        *report = BandReport {
+            f_low: a.freq_hz,
+            f_high: b.freq_hz,
+            margin_db: f32::INFINITY,
+            worst_hz: a.freq_hz,
+            violations: 0,
+        };
+        if b.freq_hz < 0.0 {
+            continue;
+        }
+        let first = libm::ceilf(a.freq_hz / df).max(0.0) as usize;
+        let last = (libm::floorf(b.freq_hz / df) as usize).min(psd.len() - 1);
+        for (k, &p) in psd.iter().enumerate().take(last + 1).skip(first) {
+            let f = k as f32 * df;
+            let limit = if b.freq_hz > a.freq_hz {
+                a.limit_db + (b.limit_db - a.limit_db) * (f - a.freq_hz) / (b.freq_hz - a.freq_hz)
+            } else {
+                a.limit_db.min(b.limit_db)
+            };
+            let margin = limit - 10.0 * libm::log10f(p);
+            if margin < report.margin_db {
+                report.margin_db = margin;
+                report.worst_hz = f;
+            }
+            if margin < 0.0 {
+                report.violations += 1;
+            }
+        }
+        passed &= report.passed();
+    }
+    Ok(passed)
+}
+
+#[cfg(test)]
+#[path = "mask_tests.rs"]
+mod tests;
@@ -0,0 +1,76 @@
+use super::*;
+
+// 17 bins of 100 Hz: N = 32, fs = 3200 Hz
+const FS: f32 = 3200.0;
+
+fn point(freq_hz: f32, limit_db: f32) -> MaskPoint {
+    MaskPoint { freq_hz, limit_db }
+}
+
+#[test]
+fn test_flat_noise_against_stepped_mask() {
+    // About -41 dB/Hz everywhere, with a -20 dB spur at 1200 Hz
+    let mut psd = [0.8e-4f32; 17];
+    psd[12] = 1e-2;
+
+    // -30 dB up to 1000 Hz, then a ramp from -30 to -50 dB at 1600 Hz
+    let mask = [
+        point(0.0, -30.0),
+        point(1000.0, -30.0),
+        point(1600.0, -50.0),
+    ];
+    let mut out = [BandReport {
+        f_low: 0.0,
+        f_high: 0.0,
+        margin_db: 0.0,
+        worst_hz: 0.0,
+        violations: 0,
+    }; 2];
+    let passed = check_mask(&psd, FS, &mask, &mut out).unwrap();
+    assert!(!passed);
+
+    assert!(out[0].passed());
+    let floor_db = 10.0 * libm::log10f(0.8e-4);
+    assert!((out[0].margin_db - (-30.0 - floor_db)).abs() < 1e-4);
+
+    // The limit at 1200 Hz is -36.7 dB, 16.7 dB below the spur
+    assert_eq!(out[1].worst_hz, 1200.0);
+    assert!((out[1].margin_db + 50.0 / 3.0).abs() < 1e-3);
+    // The floor also crosses the ramp from 1400 Hz on
+    assert_eq!(out[1].violations, 4);
+}
+
+#[test]
+fn test_vertical_step_and_errors() {
+    // -50 dB/Hz everywhere
+    let psd = [1e-5f32; 17];
+    // Step from -30 to -45 dB at 800 Hz: bin 8 is held to -45 dB
+    let mask = [
+        point(0.0, -30.0),
+        point(800.0, -30.0),
+        point(800.0, -45.0),
+        point(1600.0, -45.0),
+    ];
+    let mut out = [BandReport {
+        f_low: 0.0,
+        f_high: 0.0,
+        margin_db: 0.0,
+        worst_hz: 0.0,
+        violations: 0,
+    }; 3];
+    assert!(check_mask(&psd, FS, &mask, &mut out).unwrap());
+    assert!((out[0].margin_db - 20.0).abs() < 1e-4);
+    assert!((out[1].margin_db - 5.0).abs() < 1e-4);
+    assert_eq!(out[1].worst_hz, 800.0);
+    assert!((out[2].margin_db - 5.0).abs() < 1e-4);
+
+    let backwards = [point(800.0, -30.0), point(0.0, -30.0)];
+    assert_eq!(
+        check_mask(&psd, FS, &backwards, &mut out[..1]),
+        Err(FftError::OutOfRange)
+    );
+    assert_eq!(
+        check_mask(&psd, FS, &mask, &mut out[..2]),
+        Err(FftError::SizeMismatch)
+    );
+}
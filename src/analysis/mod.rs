@@ -0,0 +1,41 @@
+pub mod detrend;
+pub mod diff;
+pub mod exact_bin;
+pub mod features;
+pub mod harmonics;
+pub mod ipdft;
+pub mod mask;
+pub mod onset;
+pub mod order;
+pub mod peak_tracker;
+pub mod phase;
+pub mod power;
+pub mod sine_fit;
+pub mod spectrum;
+pub mod spurs;
+pub mod tempo;
+pub mod tone_bank;
+pub mod vad;
+pub mod waterfall;
+pub mod window;
+
+pub use detrend::{Order, detrend};
+pub use diff::{SpectrumDiff, compare_spectra, level_difference};
+pub use exact_bin::{exact_bin, exact_bin_q15};
+pub use features::{FeatureValue, magnitudes, mel_energies};
+pub use harmonics::{Harmonic, harmonics, thd};
+pub use ipdft::{ToneEstimate, ipdft_hann};
+pub use mask::{BandReport, MaskPoint, check_mask};
+pub use onset::{OnsetConfig, OnsetDetector};
+pub use order::{OrderTracker, tacho_pulses};
+pub use peak_tracker::{PeakTracker, PeakTrackerConfig, Track};
+pub use phase::{group_delay, unwrap_phase};
+pub use power::{channel_power, occupied_bandwidth};
+pub use sine_fit::{SineFit, sine_fit4};
+pub use spectrum::{Packing, Spectrum};
+pub use spurs::{Spur, find_spurs, noise_floor};
+pub use tempo::{TempoEstimate, TempoPrior, estimate_tempo};
+pub use tone_bank::{Tone, ToneBank};
+pub use vad::{EnergyVad, VadConfig, band_energy};
+pub use waterfall::{Decimation, Waterfall};
+pub use window::{CorrectionMode, Window, WindowCorrection, remove_mean};
@@ -0,0 +1,158 @@
+// src/analysis/onset.rs
+
+use crate::common::FftError;
+
+/// Tuning parameters for [`OnsetDetector`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OnsetConfig {
+    /// Magnitudes are compressed as `ln(1 + compression * |X|)` before
+    /// differencing, which evens out loud and soft notes. 0 uses linear
+    /// magnitudes.
+    pub compression: f32,
+    /// An onset needs a flux above `multiplier` times the mean flux of the
+    /// recent frames...
+    pub multiplier: f32,
+    /// ...plus this fixed offset, which keeps silence and steady noise from
+    /// triggering.
+    pub delta: f32,
+    /// Minimum distance, in frames, between two onsets.
+    pub min_gap: u32,
+}
+
+impl Default for OnsetConfig {
+    fn default() -> Self {
+        Self {
+            compression: 100.0,
+            multiplier: 1.5,
+            delta: 1.0,
+            min_gap: 3,
+        }
+    }
+}
+
+/// Spectral flux onset detector.
+///
+/// Each call to [`OnsetDetector::update`] receives one packed real FFT frame
+/// of an STFT. The rectified spectral flux (the sum of the magnitude
+/// increases since the previous frame) is compared against an adaptive
+/// threshold built from the mean flux of the last `history.len()` frames,
+/// and the time of the frame is emitted when it exceeds it. The previous
+/// magnitudes and the flux history live in caller-provided storage.
+pub struct OnsetDetector<'a> {
+    previous: &'a mut [f32],
+    history: &'a mut [f32],
+    config: OnsetConfig,
+    hop_seconds: f32,
+    frame: u64,
+    filled: usize,
+    since_onset: u32,
+    flux: f32,
+}
+
+impl<'a> OnsetDetector<'a> {
+    /// `previous` holds one magnitude per bin (N/2 + 1 for an N-point
+    /// FFT), `history` the flux of the frames averaged into the threshold.
+    /// Frames are `hop` samples apart at `sample_rate`.
+    pub fn new(
+        previous: &'a mut [f32],
+        history: &'a mut [f32],
+        hop: usize,
+        sample_rate: f32,
+        config: OnsetConfig,
+    ) -> Result<Self, FftError> {
+        if previous.len() < 2 || history.is_empty() {
+            return Err(FftError::BufferTooSmall);
+        }
+        if hop == 0 || sample_rate.is_nan() || sample_rate <= 0.0 {
+            return Err(FftError::OutOfRange);
+        }
+        let mut detector = Self {
+            previous,
+            history,
+            config,
+            hop_seconds: hop as f32 / sample_rate,
+            frame: 0,
+            filled: 0,
+            since_onset: 0,
+            flux: 0.0,
+        };
+        detector.reset();
+        Ok(detector)
+    }
+
+    /// Forgets the previous frame, the flux history and the frame count.
+    pub fn reset(&mut self) {
+        self.previous.fill(0.0);
+        self.history.fill(0.0);
+        self.frame = 0;
+        self.filled = 0;
+        self.since_onset = u32::MAX;
+        self.flux = 0.0;
+    }
+
+    /// Feeds the next packed frame (N = `2 * (previous.len() - 1)`) and
+    /// returns the time in seconds of the frame if it is an onset. After a
+    /// reset, the first frame only primes the detector and no onset is
+    /// reported until the flux history has been filled.
+    pub fn update(&mut self, packed: &[f32]) -> Result<Option<f32>, FftError> {
+        let bins = self.previous.len();
+        if packed.len() != 2 * (bins - 1) {
+            return Err(FftError::SizeMismatch);
+        }
+
+        let compression = self.config.compression;
+        let magnitude = |k: usize| {
+            let m = match k {
+                0 => libm::fabsf(packed[0]),
+                _ if k == bins - 1 => libm::fabsf(packed[1]),
+                _ => libm::hypotf(packed[2 * k], packed[2 * k + 1]),
+            };
+            if compression > 0.0 {
+                libm::log1pf(compression * m)
+            } else {
+                m
+            }
+        };
+        let mut flux = 0.0;
+        for (k, prev) in self.previous.iter_mut().enumerate() {
+            let m = magnitude(k);
+            flux += (m - *prev).max(0.0);
+            *prev = m;
+        }
+
+        let frame = self.frame;
+        self.frame += 1;
+        if frame == 0 {
+            return Ok(None);
+        }
+        self.flux = flux;
+
+        let len = self.history.len();
+        let mean = self.history.iter().sum::<f32>() / len as f32;
+        let threshold = self.config.multiplier * mean + self.config.delta;
+        self.since_onset = self.since_onset.saturating_add(1);
+        let onset =
+            self.filled == len && flux > threshold && self.since_onset >= self.config.min_gap;
+        if onset {
+            self.since_onset = 0;
+        }
+
+        // Oldest entry out, newest in
+        if self.filled < len {
+            self.filled += 1;
+        }
+        self.history.copy_within(..len - 1, 1);
+        self.history[0] = flux;
+
+        Ok(onset.then_some(frame as f32 * self.hop_seconds))
+    }
+
+    /// Spectral flux of the last frame.
+    pub fn flux(&self) -> f32 {
+        self.flux
+    }
+}
+
+#[cfg(test)]
+#[path = "onset_tests.rs"]
+mod tests;
@@ -0,0 +1,94 @@
+use super::*;
+use crate::analysis::Window;
+use crate::common::RealFft;
+use crate::float::Complex32;
+use core::f32::consts::PI;
+
+const N: usize = 256;
+const HOP: usize = 128;
+const FS: f32 = 8000.0;
+
+/// Decaying 440 Hz notes starting at `starts` over quiet noise.
+fn notes(len: usize, starts: &[usize]) -> Vec<f32> {
+    let mut seed = 7u32;
+    (0..len)
+        .map(|i| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let noise = ((seed >> 8) as f32 / (1 << 24) as f32 - 0.5) * 1e-3;
+            let note: f32 = starts
+                .iter()
+                .filter(|&&s| i >= s)
+                .map(|&s| {
+                    let t = (i - s) as f32 / FS;
+                    libm::expf(-t * 20.0) * libm::sinf(2.0 * PI * 440.0 * t)
+                })
+                .sum();
+            noise + 0.5 * note
+        })
+        .collect()
+}
+
+#[test]
+fn test_finds_note_onsets() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    let starts = [2000, 5000, 8000];
+    let signal = notes(10_000, &starts);
+    let mut previous = vec![0.0; N / 2 + 1];
+    let mut history = [0.0; 8];
+    let mut detector =
+        OnsetDetector::new(&mut previous, &mut history, HOP, FS, OnsetConfig::default()).unwrap();
+
+    let mut onsets = Vec::new();
+    let mut window = vec![0.0; N];
+    Window::Hann.fill(&mut window);
+    let mut frame = vec![0.0; N];
+    for start in (0..signal.len() - N).step_by(HOP) {
+        for ((f, x), w) in frame.iter_mut().zip(&signal[start..]).zip(&window) {
+            *f = x * w;
+        }
+        fft.process(&mut frame, false).unwrap();
+        if let Some(t) = detector.update(&frame).unwrap() {
+            onsets.push(t);
+        }
+    }
+
+    assert_eq!(onsets.len(), starts.len(), "{onsets:?}");
+    for (t, &s) in onsets.iter().zip(&starts) {
+        // A frame is stamped with its first sample, so it lags the note
+        // start by less than one frame
+        let lag = s as f32 / FS - t;
+        assert!(lag > 0.0 && lag < N as f32 / FS, "{t} vs {s}");
+    }
+}
+
+#[test]
+fn test_warm_up_and_reset() {
+    let mut previous = [0.0; 3];
+    let mut history = [0.0; 2];
+    let config = OnsetConfig {
+        compression: 0.0,
+        ..OnsetConfig::default()
+    };
+    let mut detector = OnsetDetector::new(&mut previous, &mut history, 4, 1000.0, config).unwrap();
+
+    // Priming frame, then two frames to fill the history
+    for _ in 0..3 {
+        assert_eq!(detector.update(&[10.0, 0.0, 0.0, 0.0]), Ok(None));
+    }
+    assert_eq!(detector.flux(), 0.0);
+    // Only increases count: bin 1 rises by 5, DC falls
+    assert_eq!(detector.update(&[0.0, 0.0, 3.0, 4.0]), Ok(Some(0.012)));
+    assert_eq!(detector.flux(), 5.0);
+
+    detector.reset();
+    for _ in 0..3 {
+        assert_eq!(detector.update(&[0.0, 0.0, 3.0, 4.0]), Ok(None));
+    }
+    assert_eq!(
+        detector.update(&[0.0, 0.0, 3.0, 4.0, 0.0, 0.0]),
+        Err(FftError::SizeMismatch)
+    );
+}
@@ -0,0 +1,129 @@
+// src/analysis/order.rs
+
+use crate::common::{FftError, RealFft};
+use crate::float::types::Complex32;
+
+/// Times of the rising crossings of `threshold` in a tachometer signal,
+/// in fractional samples (linearly interpolated between the two samples
+/// around each crossing), written to `out`. Returns how many were found;
+/// crossings past `out.len()` are dropped.
+pub fn tacho_pulses(tach: &[f32], threshold: f32, out: &mut [f32]) -> usize {
+    let mut count = 0;
+    for (i, pair) in tach.windows(2).enumerate() {
+        if count == out.len() {
+            break;
+        }
+        let (a, b) = (pair[0], pair[1]);
+        if a < threshold && b >= threshold {
+            out[count] = i as f32 + (threshold - a) / (b - a);
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Computed order tracking: resamples a vibration signal from uniform time
+/// to uniform shaft angle, so that components locked to the rotation stay
+/// in one FFT bin while the speed changes.
+///
+/// The shaft is taken to turn at constant speed between two tachometer
+/// pulses. The angle-domain signal then has `samples_per_rev` samples per
+/// revolution and an N-point FFT of it resolves `samples_per_rev / N`
+/// orders per bin, up to order `samples_per_rev / 2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderTracker {
+    pulses_per_rev: usize,
+    samples_per_rev: usize,
+}
+
+impl OrderTracker {
+    pub fn new(pulses_per_rev: usize, samples_per_rev: usize) -> Result<Self, FftError> {
+        if pulses_per_rev == 0 || samples_per_rev < 2 {
+            return Err(FftError::InvalidStride);
+        }
+        Ok(Self {
+            pulses_per_rev,
+            samples_per_rev,
+        })
+    }
+
+    /// Resamples `signal` at uniform angle steps starting at the first of
+    /// `pulses` (see [`tacho_pulses`]) and writes them to `out`. Returns
+    /// the number of samples written, which stops at the last pulse, the
+    /// end of the signal or the end of `out`.
+    ///
+    /// Samples are linearly interpolated: low-pass the signal below the
+    /// highest order of interest at the lowest speed, or oversample it,
+    /// to keep the resampling from aliasing.
+    pub fn resample(
+        &self,
+        signal: &[f32],
+        pulses: &[f32],
+        out: &mut [f32],
+    ) -> Result<usize, FftError> {
+        if pulses.len() < 2 || signal.len() < 2 {
+            return Err(FftError::SizeMismatch);
+        }
+        if !pulses.windows(2).all(|p| p[1] > p[0]) {
+            return Err(FftError::OutOfRange);
+        }
+
+        // Pulse intervals per output sample
+        let step = self.pulses_per_rev as f32 / self.samples_per_rev as f32;
+        let last = (signal.len() - 1) as f32;
+        for (j, o) in out.iter_mut().enumerate() {
+            let position = j as f32 * step;
+            let i = position as usize;
+            if i + 1 >= pulses.len() {
+                return Ok(j);
+            }
+            let t = pulses[i] + (position - i as f32) * (pulses[i + 1] - pulses[i]);
+            if t < 0.0 || t > last {
+                return Ok(j);
+            }
+            let k = (t as usize).min(signal.len() - 2);
+            let frac = t - k as f32;
+            *o = signal[k] + frac * (signal[k + 1] - signal[k]);
+        }
+        Ok(out.len())
+    }
+
+    /// Order of bin `k` of an N-point FFT of the angle-domain signal.
+    pub fn order_of_bin(&self, k: usize, n: usize) -> f32 {
+        k as f32 * self.samples_per_rev as f32 / n as f32
+    }
+
+    /// Order amplitude spectrum of the N angle-domain samples in `buffer`
+    /// (transformed in place) into `out` (N/2 + 1 values): bin `k` reads
+    /// the peak amplitude at order [`Self::order_of_bin`]`(k, N)`.
+    /// Window `buffer` beforehand when orders fall between bins.
+    pub fn order_spectrum(
+        &self,
+        fft: &RealFft<'_, Complex32>,
+        buffer: &mut [f32],
+        out: &mut [f32],
+    ) -> Result<(), FftError> {
+        let n = fft.n;
+        if buffer.len() != n || out.len() != n / 2 + 1 {
+            return Err(FftError::SizeMismatch);
+        }
+        fft.process(buffer, false)?;
+
+        // Undo the plan's forward scaling, then single-sided peak units
+        let scale = if fft.scaling.is_scaled(false) {
+            1.0
+        } else {
+            1.0 / n as f32
+        };
+        out[0] = libm::fabsf(buffer[0]) * scale;
+        out[n / 2] = libm::fabsf(buffer[1]) * scale;
+        for (o, bin) in out[1..n / 2].iter_mut().zip(buffer[2..].chunks_exact(2)) {
+            *o = 2.0 * libm::hypotf(bin[0], bin[1]) * scale;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "order_tests.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+use super::*;
+use core::f32::consts::PI;
+
+const FS: f32 = 4000.0;
+// Shaft runs up from 20 rev/s at 10 rev/s^2
+const F0: f64 = 20.0;
+const ACCEL: f64 = 10.0;
+
+/// Shaft angle in revolutions at sample `i`.
+fn angle(i: f64) -> f64 {
+    let t = i / FS as f64;
+    F0 * t + 0.5 * ACCEL * t * t
+}
+
+/// Sample time of revolution `k`.
+fn pulse(k: f64) -> f64 {
+    let t = (-F0 + (F0 * F0 + 2.0 * ACCEL * k).sqrt()) / ACCEL;
+    t * FS as f64
+}
+
+#[test]
+fn test_orders_stay_in_their_bins_during_run_up() {
+    // Orders 3 and 8 over a speed sweep that would smear them in time
+    let signal: Vec<f32> = (0..4000)
+        .map(|i| {
+            let phi = angle(i as f64);
+            (libm::sin(2.0 * core::f64::consts::PI * 3.0 * phi)
+                + 0.5 * libm::cos(2.0 * core::f64::consts::PI * 8.0 * phi)) as f32
+        })
+        .collect();
+    let pulses: Vec<f32> = (0..20).map(|k| pulse(k as f64) as f32).collect();
+
+    const N: usize = 256;
+    let tracker = OrderTracker::new(1, 32).unwrap();
+    let mut angle_domain = [0.0f32; 640];
+    let written = tracker
+        .resample(&signal, &pulses, &mut angle_domain)
+        .unwrap();
+    // 19 complete revolutions between 20 pulses
+    assert_eq!(written, 19 * 32);
+
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut buffer = angle_domain[..N].to_vec();
+    let mut orders = [0.0f32; N / 2 + 1];
+    tracker
+        .order_spectrum(&fft, &mut buffer, &mut orders)
+        .unwrap();
+
+    assert_eq!(tracker.order_of_bin(24, N), 3.0);
+    assert_eq!(tracker.order_of_bin(64, N), 8.0);
+    assert!((orders[24] - 1.0).abs() < 0.02, "{}", orders[24]);
+    assert!((orders[64] - 0.5).abs() < 0.02, "{}", orders[64]);
+    let others = orders
+        .iter()
+        .enumerate()
+        .filter(|&(k, _)| k != 24 && k != 64)
+        .fold(0.0f32, |m, (_, &v)| m.max(v));
+    assert!(others < 0.02, "{others}");
+}
+
+#[test]
+fn test_tacho_pulse_detection() {
+    // Once-per-rev sine tach at 25 Hz: rising zero crossings every 160 samples
+    let tach: Vec<f32> = (0..1000)
+        .map(|i| libm::sinf(2.0 * PI * 25.0 * i as f32 / FS - 0.3))
+        .collect();
+    let mut pulses = [0.0f32; 10];
+    let count = tacho_pulses(&tach, 0.0, &mut pulses);
+    assert_eq!(count, 7);
+    for (k, &p) in pulses[..count].iter().enumerate() {
+        let expected = 0.3 / (2.0 * PI) * 160.0 + 160.0 * k as f32;
+        assert!((p - expected).abs() < 0.05, "{p} vs {expected}");
+    }
+    assert_eq!(tacho_pulses(&tach, 0.0, &mut pulses[..2]), 2);
+
+    let tracker = OrderTracker::new(1, 16).unwrap();
+    assert_eq!(
+        tracker.resample(&tach, &[5.0, 3.0], &mut [0.0; 4]),
+        Err(FftError::OutOfRange)
+    );
+    assert_eq!(OrderTracker::new(0, 16), Err(FftError::InvalidStride));
+}
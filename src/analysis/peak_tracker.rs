@@ -0,0 +1,249 @@
+// src/analysis/peak_tracker.rs
+
+use crate::common::FftError;
+
+/// Tuning parameters for [`PeakTracker`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeakTrackerConfig {
+    /// Minimum linear magnitude for a local maximum to be considered a peak.
+    /// Everything below it is treated as noise.
+    pub threshold: f32,
+    /// Maximum distance (in bins) a track may move between two frames
+    /// and still be continued by a peak.
+    pub max_jump: f32,
+    /// Number of consecutive frames a track may go unmatched before it dies.
+    pub max_missed: u32,
+    /// Number of frames a track must survive before it is reported.
+    /// Filters out single-frame noise peaks.
+    pub min_age: u32,
+}
+
+impl Default for PeakTrackerConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 1e-3,
+            max_jump: 2.0,
+            max_missed: 2,
+            min_age: 3,
+        }
+    }
+}
+
+/// A spectral peak followed across frames (a "partial").
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Track {
+    /// Unique identifier, assigned at birth.
+    pub id: u32,
+    /// Interpolated (fractional) bin position of the last matched peak.
+    pub bin: f32,
+    /// Interpolated linear magnitude of the last matched peak.
+    pub magnitude: f32,
+    /// Number of frames since birth.
+    pub age: u32,
+    /// Number of consecutive frames without a matching peak.
+    pub missed: u32,
+    alive: bool,
+    // Best peak claimed during the current update: (bin, magnitude).
+    candidate: Option<(f32, f32)>,
+}
+
+impl Track {
+    /// Returns true if this slot holds a live track.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.alive
+    }
+
+    /// Converts the track position to Hz for an `n`-point real FFT.
+    #[inline]
+    pub fn frequency(&self, n: usize, sample_rate: f32) -> f32 {
+        self.bin * sample_rate / n as f32
+    }
+}
+
+/// Frame-to-frame spectral peak tracker.
+///
+/// Each call to [`PeakTracker::update`] receives one packed real FFT frame
+/// (as produced by `RealFft::process`, e.g. one hop of an STFT), extracts its
+/// local maxima and associates them with the tracks of the previous frame by
+/// nearest frequency. Unmatched peaks give birth to new tracks and tracks that
+/// stay unmatched for more than `max_missed` frames die.
+///
+/// Track storage is supplied by the caller, so no allocation takes place.
+/// When all slots are in use, new births are dropped.
+pub struct PeakTracker<'a> {
+    tracks: &'a mut [Track],
+    config: PeakTrackerConfig,
+    next_id: u32,
+}
+
+impl<'a> PeakTracker<'a> {
+    /// Creates a tracker using `tracks` as storage for up to `tracks.len()` partials.
+    pub fn new(tracks: &'a mut [Track], config: PeakTrackerConfig) -> Result<Self, FftError> {
+        if tracks.is_empty() {
+            return Err(FftError::BufferTooSmall);
+        }
+
+        let mut tracker = Self {
+            tracks,
+            config,
+            next_id: 0,
+        };
+        tracker.reset();
+        Ok(tracker)
+    }
+
+    /// Kills every track and restarts id numbering.
+    pub fn reset(&mut self) {
+        for track in self.tracks.iter_mut() {
+            *track = Track::default();
+        }
+        self.next_id = 0;
+    }
+
+    /// Returns the tracker configuration.
+    pub fn config(&self) -> &PeakTrackerConfig {
+        &self.config
+    }
+
+    /// Iterates over live tracks that have survived at least `min_age` frames.
+    pub fn tracks(&self) -> impl Iterator<Item = &Track> {
+        let min_age = self.config.min_age;
+        self.tracks
+            .iter()
+            .filter(move |t| t.alive && t.age >= min_age)
+    }
+
+    /// Feeds one packed real FFT frame of size N into the tracker.
+    pub fn update(&mut self, packed: &[f32]) -> Result<(), FftError> {
+        let n = packed.len();
+        if n < 4 || !n.is_multiple_of(2) {
+            return Err(FftError::SizeMismatch);
+        }
+        let n_half = n / 2;
+
+        // 1. Every peak claims its nearest live track; each track keeps the closest claim.
+        for k in 1..n_half {
+            if let Some((bin, mag)) = self.peak_at(packed, k)
+                && let Some(slot) = self.nearest_track(bin)
+            {
+                let track = &mut self.tracks[slot];
+                let closer = match track.candidate {
+                    Some((prev, _)) => (bin - track.bin).abs() < (prev - track.bin).abs(),
+                    None => true,
+                };
+                if closer {
+                    track.candidate = Some((bin, mag));
+                }
+            }
+        }
+
+        // 2. Continue or age the existing tracks.
+        for track in self.tracks.iter_mut().filter(|t| t.alive) {
+            track.age = track.age.saturating_add(1);
+            match track.candidate {
+                Some((bin, mag)) => {
+                    track.bin = bin;
+                    track.magnitude = mag;
+                    track.missed = 0;
+                }
+                None => {
+                    track.missed += 1;
+                    if track.missed > self.config.max_missed {
+                        track.alive = false;
+                    }
+                }
+            }
+        }
+
+        // 3. Peaks that were not accepted by any track give birth to new ones.
+        for k in 1..n_half {
+            if let Some((bin, mag)) = self.peak_at(packed, k) {
+                let claimed = self
+                    .tracks
+                    .iter()
+                    .any(|t| t.candidate.is_some_and(|(b, _)| b == bin));
+                if !claimed {
+                    self.birth(bin, mag);
+                }
+            }
+        }
+
+        for track in self.tracks.iter_mut() {
+            track.candidate = None;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the interpolated (bin, magnitude) if bin `k` is a peak above threshold.
+    fn peak_at(&self, packed: &[f32], k: usize) -> Option<(f32, f32)> {
+        let a = packed_magnitude(packed, k - 1);
+        let b = packed_magnitude(packed, k);
+        let c = packed_magnitude(packed, k + 1);
+
+        if b < self.config.threshold || b <= a || b < c {
+            return None;
+        }
+
+        // Parabolic interpolation of the peak position and height
+        let denom = a - 2.0 * b + c;
+        let delta = if denom != 0.0 {
+            0.5 * (a - c) / denom
+        } else {
+            0.0
+        };
+        let mag = b - 0.25 * (a - c) * delta;
+
+        Some((k as f32 + delta, mag))
+    }
+
+    fn nearest_track(&self, bin: f32) -> Option<usize> {
+        let mut best = None;
+        let mut best_dist = self.config.max_jump;
+        for (slot, track) in self.tracks.iter().enumerate() {
+            if !track.alive {
+                continue;
+            }
+            let dist = (track.bin - bin).abs();
+            if dist <= best_dist {
+                best_dist = dist;
+                best = Some(slot);
+            }
+        }
+        best
+    }
+
+    fn birth(&mut self, bin: f32, magnitude: f32) {
+        if let Some(track) = self.tracks.iter_mut().find(|t| !t.alive) {
+            *track = Track {
+                id: self.next_id,
+                bin,
+                magnitude,
+                age: 0,
+                missed: 0,
+                alive: true,
+                candidate: None,
+            };
+            self.next_id = self.next_id.wrapping_add(1);
+        }
+    }
+}
+
+/// Magnitude of bin `k` (0..=N/2) of a packed real spectrum.
+fn packed_magnitude(packed: &[f32], k: usize) -> f32 {
+    let n_half = packed.len() / 2;
+    if k == 0 {
+        libm::fabsf(packed[0])
+    } else if k == n_half {
+        libm::fabsf(packed[1])
+    } else {
+        let re = packed[2 * k];
+        let im = packed[2 * k + 1];
+        libm::sqrtf(re * re + im * im)
+    }
+}
+
+#[cfg(test)]
+#[path = "peak_tracker_tests.rs"]
+mod tests;
@@ -0,0 +1,131 @@
+use super::*;
+use crate::common::RealFft;
+use crate::float::types::Complex32;
+use core::f32::consts::PI;
+
+/// Builds a packed spectrum of size `n` holding triangular peaks at the given bins.
+fn packed_with_peaks(n: usize, peaks: &[(usize, f32)]) -> Vec<f32> {
+    let mut packed = vec![0.0f32; n];
+    for &(k, mag) in peaks {
+        for (offset, scale) in [(-1isize, 0.5f32), (0, 1.0), (1, 0.5)] {
+            let bin = (k as isize + offset) as usize;
+            packed[2 * bin] += mag * scale;
+        }
+    }
+    packed
+}
+
+fn default_config() -> PeakTrackerConfig {
+    PeakTrackerConfig {
+        threshold: 0.1,
+        max_jump: 2.0,
+        max_missed: 1,
+        min_age: 2,
+    }
+}
+
+#[test]
+fn test_rejects_bad_frame_size() {
+    let mut storage = [Track::default(); 4];
+    let mut tracker = PeakTracker::new(&mut storage, default_config()).unwrap();
+    assert_eq!(tracker.update(&[0.0; 3]), Err(FftError::SizeMismatch));
+    assert!(PeakTracker::new(&mut [], default_config()).is_err());
+}
+
+#[test]
+fn test_tracks_keep_identity_while_moving() {
+    let n = 64;
+    let mut storage = [Track::default(); 4];
+    let mut tracker = PeakTracker::new(&mut storage, default_config()).unwrap();
+
+    // Two partials gliding upwards by one bin per frame
+    for frame in 0..5 {
+        let packed = packed_with_peaks(n, &[(5 + frame, 1.0), (20 + frame, 2.0)]);
+        tracker.update(&packed).unwrap();
+    }
+
+    let mut tracks: Vec<Track> = tracker.tracks().copied().collect();
+    tracks.sort_by(|a, b| a.bin.partial_cmp(&b.bin).unwrap());
+
+    assert_eq!(tracks.len(), 2);
+    assert_eq!(tracks[0].id, 0);
+    assert_eq!(tracks[1].id, 1);
+    assert!((tracks[0].bin - 9.0).abs() < 1e-4);
+    assert!((tracks[1].bin - 24.0).abs() < 1e-4);
+    assert!((tracks[1].magnitude - 2.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_single_frame_noise_is_not_reported() {
+    let n = 64;
+    let mut storage = [Track::default(); 4];
+    let mut tracker = PeakTracker::new(&mut storage, default_config()).unwrap();
+
+    tracker.update(&packed_with_peaks(n, &[(10, 1.0)])).unwrap();
+    assert_eq!(tracker.tracks().count(), 0);
+
+    // The blip vanishes: it dies before reaching min_age
+    for _ in 0..3 {
+        tracker.update(&packed_with_peaks(n, &[])).unwrap();
+    }
+    assert_eq!(tracker.tracks().count(), 0);
+    assert!(storage.iter().all(|t| !t.is_alive()));
+}
+
+#[test]
+fn test_track_death_and_rebirth() {
+    let n = 64;
+    let mut storage = [Track::default(); 2];
+    let mut tracker = PeakTracker::new(&mut storage, default_config()).unwrap();
+
+    for _ in 0..3 {
+        tracker.update(&packed_with_peaks(n, &[(12, 1.0)])).unwrap();
+    }
+    assert_eq!(tracker.tracks().next().unwrap().id, 0);
+
+    // One missed frame is tolerated (hangover)
+    tracker.update(&packed_with_peaks(n, &[])).unwrap();
+    assert_eq!(tracker.tracks().next().unwrap().missed, 1);
+    tracker.update(&packed_with_peaks(n, &[(12, 1.0)])).unwrap();
+    assert_eq!(tracker.tracks().next().unwrap().id, 0);
+
+    // Two missed frames kill it; the next peak is a new partial
+    tracker.update(&packed_with_peaks(n, &[])).unwrap();
+    tracker.update(&packed_with_peaks(n, &[])).unwrap();
+    assert_eq!(tracker.tracks().count(), 0);
+
+    for _ in 0..3 {
+        tracker.update(&packed_with_peaks(n, &[(12, 1.0)])).unwrap();
+    }
+    assert_eq!(tracker.tracks().next().unwrap().id, 1);
+}
+
+#[test]
+fn test_tracks_sine_through_real_fft() {
+    let n = 256;
+    let sample_rate = 8000.0;
+    let freq = 1000.0;
+
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    let mut storage = [Track::default(); 8];
+    let config = PeakTrackerConfig {
+        threshold: 1.0,
+        ..PeakTrackerConfig::default()
+    };
+    let mut tracker = PeakTracker::new(&mut storage, config).unwrap();
+
+    for frame in 0..4 {
+        let offset = frame * n / 2;
+        let mut buffer: Vec<f32> = (0..n)
+            .map(|i| libm::sinf(2.0 * PI * freq * (offset + i) as f32 / sample_rate))
+            .collect();
+        fft.process(&mut buffer, false).unwrap();
+        tracker.update(&buffer).unwrap();
+    }
+
+    let track = tracker.tracks().next().unwrap();
+    assert!((track.frequency(n, sample_rate) - freq).abs() < 1.0);
+}
@@ -0,0 +1,59 @@
+// src/analysis/phase.rs
+
+use super::spectrum::Spectrum;
+use crate::common::{FftError, FftNum};
+use core::f32::consts::PI;
+
+/// Removes the 2π jumps from a sequence of phases in place, so consecutive
+/// values never differ by more than π. The first value is kept as is.
+pub fn unwrap_phase(phase: &mut [f32]) {
+    let mut offset = 0.0;
+    let mut prev = match phase.first() {
+        Some(&p) => p,
+        None => return,
+    };
+    for p in phase.iter_mut().skip(1) {
+        let raw = *p;
+        // Whole turns between neighbours; zero for steps within ±π
+        offset -= 2.0 * PI * libm::roundf((raw - prev) / (2.0 * PI));
+        prev = raw;
+        *p = raw + offset;
+    }
+}
+
+/// Group delay `-dφ/dω` in seconds for bins `0..=N/2` of `spectrum`,
+/// typically a transfer function H = Y/X, written to `out`.
+///
+/// The phase is unwrapped and differentiated with central differences
+/// (one-sided at DC and Nyquist), which is exact for a pure delay. Bins
+/// with no energy have no meaningful phase and give unreliable delays
+/// around them. `out` must hold exactly N/2 + 1 values.
+pub fn group_delay<T: FftNum>(spectrum: &Spectrum<'_, T>, out: &mut [f32]) -> Result<(), FftError> {
+    if out.len() != spectrum.num_bins() {
+        return Err(FftError::SizeMismatch);
+    }
+    for (k, o) in out.iter_mut().enumerate() {
+        *o = spectrum.phase(k).unwrap();
+    }
+    unwrap_phase(out);
+
+    // Radians per bin to seconds
+    let scale = -1.0 / (2.0 * PI * spectrum.resolution());
+    let last = out.len() - 1;
+    let mut prev = out[0];
+    for k in 0..=last {
+        let here = out[k];
+        let (lo, hi, span) = match k {
+            0 => (here, out[1], 1.0),
+            _ if k == last => (prev, here, 1.0),
+            _ => (prev, out[k + 1], 2.0),
+        };
+        out[k] = (hi - lo) / span * scale;
+        prev = here;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "phase_tests.rs"]
+mod tests;
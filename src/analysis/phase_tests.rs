@@ -0,0 +1,47 @@
+use super::*;
+use crate::common::RealFft;
+use crate::float::types::Complex32;
+
+#[test]
+fn test_unwrap_phase_removes_jumps() {
+    // A ramp of -1 rad per step, wrapped into (-π, π]
+    let ramp: Vec<f32> = (0..20).map(|i| -(i as f32)).collect();
+    let mut wrapped: Vec<f32> = ramp
+        .iter()
+        .map(|&p| libm::atan2f(p.sin(), p.cos()))
+        .collect();
+    unwrap_phase(&mut wrapped);
+    for (a, b) in wrapped.iter().zip(&ramp) {
+        assert!((a - b).abs() < 1e-4, "{a} vs {b}");
+    }
+
+    let mut empty: [f32; 0] = [];
+    unwrap_phase(&mut empty);
+}
+
+#[test]
+fn test_group_delay_of_a_pure_delay() {
+    let n = 64;
+    let fs = 8000.0;
+    let delay = 5;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    // Impulse response of a 5-sample delay
+    let mut h = vec![0.0f32; n];
+    h[delay] = 1.0;
+    fft.process(&mut h, false).unwrap();
+
+    let spectrum = Spectrum::packed(&h, fs).unwrap();
+    let mut tau = vec![0.0; n / 2 + 1];
+    group_delay(&spectrum, &mut tau).unwrap();
+    for &t in &tau {
+        assert!((t - delay as f32 / fs).abs() < 1e-6, "{t}");
+    }
+
+    assert_eq!(
+        group_delay(&spectrum, &mut tau[..n / 2]),
+        Err(FftError::SizeMismatch)
+    );
+}
@@ -0,0 +1,99 @@
+// src/analysis/power.rs
+
+use crate::common::FftError;
+
+/// Frequency interval `[lo, hi)` in Hz covered by bin `k` of a one-sided
+/// spectrum of `bins` bins. DC and Nyquist only cover half a bin.
+fn bin_edges(k: usize, bins: usize, df: f32) -> (f32, f32) {
+    let lo = if k == 0 { 0.0 } else { (k as f32 - 0.5) * df };
+    let hi = if k == bins - 1 {
+        k as f32 * df
+    } else {
+        (k as f32 + 0.5) * df
+    };
+    (lo, hi)
+}
+
+/// Bin spacing of a one-sided spectrum of `bins` bins (N/2 + 1).
+pub(super) fn resolution(bins: usize, sample_rate: f32) -> Result<f32, FftError> {
+    if bins < 2 {
+        return Err(FftError::SizeMismatch);
+    }
+    Ok(sample_rate / (2 * (bins - 1)) as f32)
+}
+
+/// Power in the band `[f_low, f_high]` of a one-sided power spectral
+/// density.
+///
+/// `spectrum` holds the PSD (power per Hz) of bins `0..=N/2`. Each bin is
+/// taken to cover one bin width around its centre frequency and
+/// contributes in proportion to its overlap with the band, so band edges
+/// that fall inside a bin are weighted correctly instead of rounding to
+/// whole bins. The band is clipped to `0..=fs/2`.
+pub fn channel_power(
+    spectrum: &[f32],
+    f_low: f32,
+    f_high: f32,
+    sample_rate: f32,
+) -> Result<f32, FftError> {
+    let df = resolution(spectrum.len(), sample_rate)?;
+    if f_low.is_nan() || f_high.is_nan() || f_low > f_high {
+        return Err(FftError::OutOfRange);
+    }
+
+    let power = spectrum
+        .iter()
+        .enumerate()
+        .map(|(k, &psd)| {
+            let (lo, hi) = bin_edges(k, spectrum.len(), df);
+            let overlap = hi.min(f_high) - lo.max(f_low);
+            if overlap > 0.0 { psd * overlap } else { 0.0 }
+        })
+        .sum();
+    Ok(power)
+}
+
+/// Occupied bandwidth of a one-sided PSD: the band `(f_low, f_high)` that
+/// contains `fraction` of the total power (0.99 for the usual 99 % OBW),
+/// with the remaining power split equally below and above it.
+///
+/// Edges are interpolated linearly inside the bins where the cumulative
+/// power crosses the thresholds. A spectrum without power yields
+/// `OutOfRange`, as does a fraction outside `(0, 1]`.
+pub fn occupied_bandwidth(
+    spectrum: &[f32],
+    fraction: f32,
+    sample_rate: f32,
+) -> Result<(f32, f32), FftError> {
+    let df = resolution(spectrum.len(), sample_rate)?;
+    if !(fraction > 0.0 && fraction <= 1.0) {
+        return Err(FftError::OutOfRange);
+    }
+    let total = channel_power(spectrum, 0.0, sample_rate / 2.0, sample_rate)?;
+    if total.is_nan() || total <= 0.0 {
+        return Err(FftError::OutOfRange);
+    }
+
+    let tail = 0.5 * (1.0 - fraction) * total;
+    let f_low = crossing(spectrum, df, tail);
+    let f_high = crossing(spectrum, df, total - tail);
+    Ok((f_low, f_high))
+}
+
+/// Frequency at which the cumulative power first reaches `target`.
+fn crossing(spectrum: &[f32], df: f32, target: f32) -> f32 {
+    let mut cumulative = 0.0;
+    for (k, &psd) in spectrum.iter().enumerate() {
+        let (lo, hi) = bin_edges(k, spectrum.len(), df);
+        let p = psd * (hi - lo);
+        if p > 0.0 && cumulative + p >= target {
+            return lo + (hi - lo) * ((target - cumulative) / p).clamp(0.0, 1.0);
+        }
+        cumulative += p;
+    }
+    bin_edges(spectrum.len() - 1, spectrum.len(), df).1
+}
+
+#[cfg(test)]
+#[path = "power_tests.rs"]
+mod tests;
@@ -0,0 +1,48 @@
+use super::*;
+
+// 17 bins of 100 Hz: N = 32, fs = 3200 Hz
+const FS: f32 = 3200.0;
+
+#[test]
+fn test_channel_power_bin_edges() {
+    let flat = [1.0f32; 17];
+    // The whole spectrum covers 0..1600 Hz
+    assert!((channel_power(&flat, 0.0, FS / 2.0, FS).unwrap() - 1600.0).abs() < 1e-3);
+    // Edges inside bins are weighted by their overlap
+    assert!((channel_power(&flat, 225.0, 410.0, FS).unwrap() - 185.0).abs() < 1e-3);
+    // Bands past Nyquist are clipped
+    assert!((channel_power(&flat, 1500.0, 5000.0, FS).unwrap() - 100.0).abs() < 1e-3);
+
+    let mut tone = [0.0f32; 17];
+    tone[4] = 2.0;
+    assert!((channel_power(&tone, 380.0, 420.0, FS).unwrap() - 80.0).abs() < 1e-3);
+    assert_eq!(channel_power(&tone, 100.0, 200.0, FS).unwrap(), 0.0);
+
+    assert_eq!(
+        channel_power(&flat, 500.0, 400.0, FS),
+        Err(FftError::OutOfRange)
+    );
+    assert_eq!(
+        channel_power(&[1.0], 0.0, 1.0, FS),
+        Err(FftError::SizeMismatch)
+    );
+}
+
+#[test]
+fn test_occupied_bandwidth() {
+    // Flat signal between 400 and 800 Hz (bins 4..=8 all full)
+    let mut psd = [0.0f32; 17];
+    psd[4..=8].fill(1.0);
+    let (lo, hi) = occupied_bandwidth(&psd, 0.99, FS).unwrap();
+    // 500 Hz of occupied band, 2.5 Hz trimmed on each side
+    assert!((lo - 352.5).abs() < 1e-2, "{lo}");
+    assert!((hi - 847.5).abs() < 1e-2, "{hi}");
+    let p = channel_power(&psd, lo, hi, FS).unwrap();
+    assert!((p / 500.0 - 0.99).abs() < 1e-5);
+
+    assert_eq!(
+        occupied_bandwidth(&[0.0; 17], 0.99, FS),
+        Err(FftError::OutOfRange)
+    );
+    assert_eq!(occupied_bandwidth(&psd, 1.5, FS), Err(FftError::OutOfRange));
+}
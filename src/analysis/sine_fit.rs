@@ -0,0 +1,140 @@
+// src/analysis/sine_fit.rs
+
+use super::ipdft::ToneEstimate;
+use crate::common::FftError;
+use core::f64::consts::PI;
+
+/// Result of [`sine_fit4`]: the record is modelled as
+/// `offset + amplitude * cos(2 pi frequency n / fs + phase)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SineFit {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Peak amplitude.
+    pub amplitude: f64,
+    /// Phase in radians at the first sample, in (-pi, pi].
+    pub phase: f64,
+    /// DC offset.
+    pub offset: f64,
+    /// RMS of the residual `x - model`, the noise-and-distortion term used
+    /// for SINAD/ENOB.
+    pub rms_residual: f64,
+    /// Number of frequency updates performed.
+    pub iterations: usize,
+}
+
+/// Four-parameter least-squares sine fit (IEEE 1057), initialized from an
+/// FFT estimate.
+///
+/// A three-parameter fit at the initial frequency gives the starting
+/// amplitude, phase and offset; each iteration then linearizes the model
+/// around the current frequency and solves for all four parameters, until
+/// the frequency update falls below 1e-12 cycles per sample or
+/// `max_iterations` is reached. `initial` is usually the output of
+/// `ipdft_hann` on the same record, which is already close enough for the
+/// iteration to converge in two or three steps.
+///
+/// Fails with `SizeMismatch` for records shorter than 4 samples and with
+/// `OutOfRange` if the normal equations are singular (e.g. a zero record).
+pub fn sine_fit4(
+    samples: &[f32],
+    sample_rate: f32,
+    initial: &ToneEstimate,
+    max_iterations: usize,
+) -> Result<SineFit, FftError> {
+    if samples.len() < 4 {
+        return Err(FftError::SizeMismatch);
+    }
+    let fs = sample_rate as f64;
+    let mut omega = 2.0 * PI * initial.frequency as f64 / fs;
+
+    // Three-parameter fit for the starting point
+    let [mut a, mut b, mut c] = solve(normal_equations(samples, |n| {
+        let (s, co) = libm::sincos(omega * n);
+        [co, s, 1.0]
+    }))?;
+
+    let mut iterations = 0;
+    while iterations < max_iterations {
+        iterations += 1;
+        let [da, db, dc, dw] = solve(normal_equations(samples, |n| {
+            let (s, co) = libm::sincos(omega * n);
+            [co, s, 1.0, n * (b * co - a * s)]
+        }))?;
+        (a, b, c) = (da, db, dc);
+        omega += dw;
+        if dw.abs() < 2.0 * PI * 1e-12 {
+            break;
+        }
+    }
+
+    let mut residual = 0.0;
+    for (i, &x) in samples.iter().enumerate() {
+        let (s, co) = libm::sincos(omega * i as f64);
+        let e = x as f64 - (a * co + b * s + c);
+        residual += e * e;
+    }
+
+    Ok(SineFit {
+        frequency: omega * fs / (2.0 * PI),
+        amplitude: libm::hypot(a, b),
+        phase: libm::atan2(-b, a),
+        offset: c,
+        rms_residual: libm::sqrt(residual / samples.len() as f64),
+        iterations,
+    })
+}
+
+/// Accumulates the normal equations `(D^T D, D^T x)` for the design
+/// matrix whose row for sample `n` is `row(n)`.
+fn normal_equations<const M: usize>(
+    samples: &[f32],
+    row: impl Fn(f64) -> [f64; M],
+) -> ([[f64; M]; M], [f64; M]) {
+    let mut ata = [[0.0; M]; M];
+    let mut atx = [0.0; M];
+    for (i, &x) in samples.iter().enumerate() {
+        let r = row(i as f64);
+        for j in 0..M {
+            for k in 0..M {
+                ata[j][k] += r[j] * r[k];
+            }
+            atx[j] += r[j] * x as f64;
+        }
+    }
+    (ata, atx)
+}
+
+/// Gaussian elimination with partial pivoting.
+fn solve<const M: usize>((mut a, mut b): ([[f64; M]; M], [f64; M])) -> Result<[f64; M], FftError> {
+    let scale = a.iter().flatten().fold(0.0f64, |m, v| m.max(v.abs()));
+    for col in 0..M {
+        let pivot = (col..M)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+            .unwrap();
+        if a[pivot][col].abs() <= scale * 1e-14 {
+            return Err(FftError::OutOfRange);
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let pivot_row = a[col];
+        for row in col + 1..M {
+            let f = a[row][col] / pivot_row[col];
+            for (v, p) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *v -= f * p;
+            }
+            b[row] -= f * b[col];
+        }
+    }
+
+    let mut x = [0.0; M];
+    for row in (0..M).rev() {
+        let tail: f64 = (row + 1..M).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+    Ok(x)
+}
+
+#[cfg(test)]
+#[path = "sine_fit_tests.rs"]
+mod tests;
@@ -0,0 +1,69 @@
+use super::*;
+use crate::analysis::{Spectrum, ipdft_hann};
+use crate::common::RealFft;
+use crate::float::types::Complex32;
+
+const N: usize = 1024;
+const FS: f32 = 48000.0;
+
+/// A non-coherently sampled tone with offset and a little deterministic
+/// "noise".
+fn record(hz: f64, amplitude: f64, phase: f64, offset: f64) -> Vec<f32> {
+    let mut seed = 7u32;
+    (0..N)
+        .map(|i| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let noise = ((seed >> 8) as f64 / (1 << 24) as f64 - 0.5) * 1e-4;
+            let t = i as f64 / FS as f64;
+            (offset + amplitude * (2.0 * PI * hz * t + phase).cos() + noise) as f32
+        })
+        .collect()
+}
+
+fn initial_estimate(samples: &[f32]) -> ToneEstimate {
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut buf: Vec<f32> = samples
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| x * (0.5 - 0.5 * (2.0 * PI * i as f64 / N as f64).cos()) as f32)
+        .collect();
+    fft.process(&mut buf, false).unwrap();
+    ipdft_hann(&Spectrum::packed(&buf, FS).unwrap()).unwrap()
+}
+
+#[test]
+fn test_refines_ipdft_estimate() {
+    let hz = 997.123;
+    let samples = record(hz, 0.7, 1.1, 0.05);
+    let initial = initial_estimate(&samples);
+
+    let fit = sine_fit4(&samples, FS, &initial, 10).unwrap();
+    assert!(fit.iterations < 10);
+    assert!((fit.frequency - hz).abs() < 1e-4, "{}", fit.frequency);
+    assert!((fit.frequency - hz).abs() < (initial.frequency as f64 - hz).abs());
+    assert!((fit.amplitude - 0.7).abs() < 1e-5);
+    assert!((fit.phase - 1.1).abs() < 1e-4);
+    assert!((fit.offset - 0.05).abs() < 1e-5);
+    // Uniform noise of width 1e-4 has an RMS of about 2.9e-5
+    assert!(fit.rms_residual > 2e-5 && fit.rms_residual < 4e-5);
+}
+
+#[test]
+fn test_degenerate_records() {
+    let initial = ToneEstimate {
+        frequency: 1000.0,
+        bin: 0.0,
+        amplitude: 1.0,
+        phase: 0.0,
+    };
+    assert_eq!(
+        sine_fit4(&[0.0; 3], FS, &initial, 5),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        sine_fit4(&[0.0; N], FS, &initial, 5),
+        Err(FftError::OutOfRange)
+    );
+}
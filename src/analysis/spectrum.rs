@@ -0,0 +1,162 @@
+// src/analysis/spectrum.rs
+
+use crate::common::{FftError, FftNum};
+
+/// Layout of the data behind a [`Spectrum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Packing {
+    /// N real values as produced by `RealFft::process`: DC and Nyquist in
+    /// the first two slots, then interleaved (re, im) for bins 1..N/2.
+    Packed,
+    /// One-sided spectrum of N/2 + 1 complex bins.
+    Half,
+    /// Full complex spectrum of N bins.
+    Full,
+}
+
+#[derive(Clone, Copy)]
+enum Bins<'a, T: FftNum> {
+    Packed(&'a [T]),
+    Half(&'a [T::Complex]),
+    Full(&'a [T::Complex]),
+}
+
+/// Read-only view of an N-point spectrum that knows its sample rate and
+/// packing, so bins can be addressed and converted to Hz without redoing
+/// the index arithmetic at every call site.
+///
+/// Only the non-negative frequencies `0..=N/2` are exposed, whatever the
+/// packing. Magnitudes and phases are returned in `f32`; fixed-point bins
+/// are converted from their fractional representation.
+#[derive(Clone, Copy)]
+pub struct Spectrum<'a, T: FftNum> {
+    bins: Bins<'a, T>,
+    n: usize,
+    sample_rate: f32,
+}
+
+impl<'a, T: FftNum> Spectrum<'a, T> {
+    /// Wraps a packed real FFT output of N values.
+    pub fn packed(data: &'a [T], sample_rate: f32) -> Result<Self, FftError> {
+        let n = data.len();
+        if n < 2 || !n.is_multiple_of(2) {
+            return Err(FftError::SizeMismatch);
+        }
+        Ok(Self {
+            bins: Bins::Packed(data),
+            n,
+            sample_rate,
+        })
+    }
+
+    /// Wraps a one-sided spectrum of N/2 + 1 bins.
+    pub fn half(data: &'a [T::Complex], sample_rate: f32) -> Result<Self, FftError> {
+        if data.len() < 2 {
+            return Err(FftError::SizeMismatch);
+        }
+        Ok(Self {
+            bins: Bins::Half(data),
+            n: 2 * (data.len() - 1),
+            sample_rate,
+        })
+    }
+
+    /// Wraps a full complex spectrum of N bins.
+    pub fn full(data: &'a [T::Complex], sample_rate: f32) -> Result<Self, FftError> {
+        let n = data.len();
+        if n < 2 || !n.is_multiple_of(2) {
+            return Err(FftError::SizeMismatch);
+        }
+        Ok(Self {
+            bins: Bins::Full(data),
+            n,
+            sample_rate,
+        })
+    }
+
+    /// Layout of the underlying data.
+    pub fn packing(&self) -> Packing {
+        match self.bins {
+            Bins::Packed(_) => Packing::Packed,
+            Bins::Half(_) => Packing::Half,
+            Bins::Full(_) => Packing::Full,
+        }
+    }
+
+    /// Transform size N.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn sample_rate(&self) -> f32 {
+        self.sample_rate
+    }
+
+    /// Number of addressable bins, N/2 + 1.
+    pub fn num_bins(&self) -> usize {
+        self.n / 2 + 1
+    }
+
+    /// Frequency spacing between adjacent bins, in Hz.
+    pub fn resolution(&self) -> f32 {
+        self.sample_rate / self.n as f32
+    }
+
+    /// Converts a (possibly fractional) bin position to Hz.
+    pub fn bin_to_hz(&self, bin: f32) -> f32 {
+        bin * self.resolution()
+    }
+
+    /// Converts a frequency to a fractional bin position.
+    pub fn hz_to_bin(&self, hz: f32) -> f32 {
+        hz / self.resolution()
+    }
+
+    /// Index of the bin closest to `hz`, or `None` outside 0..=fs/2.
+    pub fn nearest_bin(&self, hz: f32) -> Option<usize> {
+        let bin = libm::roundf(self.hz_to_bin(hz));
+        if bin >= 0.0 && bin <= (self.n / 2) as f32 {
+            Some(bin as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Complex value of bin `k` as (re, im), or `None` past N/2.
+    pub fn bin(&self, k: usize) -> Option<(T, T)> {
+        let half = self.n / 2;
+        if k > half {
+            return None;
+        }
+        let value = match self.bins {
+            Bins::Packed(p) => match k {
+                0 => (p[0], T::zero()),
+                _ if k == half => (p[1], T::zero()),
+                _ => (p[2 * k], p[2 * k + 1]),
+            },
+            Bins::Half(c) | Bins::Full(c) => (T::complex_re(&c[k]), T::complex_im(&c[k])),
+        };
+        Some(value)
+    }
+
+    /// Magnitude of bin `k`.
+    pub fn magnitude(&self, k: usize) -> Option<f32> {
+        let (re, im) = self.bin(k)?;
+        Some(libm::hypotf(re.to_f64() as f32, im.to_f64() as f32))
+    }
+
+    /// Phase of bin `k` in radians, in (-pi, pi].
+    pub fn phase(&self, k: usize) -> Option<f32> {
+        let (re, im) = self.bin(k)?;
+        Some(libm::atan2f(im.to_f64() as f32, re.to_f64() as f32))
+    }
+
+    /// Iterates over `(frequency in Hz, magnitude)` for bins 0..=N/2.
+    pub fn iter(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        (0..self.num_bins()).map(move |k| (self.bin_to_hz(k as f32), self.magnitude(k).unwrap()))
+    }
+}
+
+#[cfg(test)]
+#[path = "spectrum_tests.rs"]
+mod tests;
@@ -0,0 +1,89 @@
+use super::*;
+use crate::common::{RealFft, unpack_rfft_spectrum, unpack_rfft_spectrum_half};
+use crate::fixed::Fixed;
+use crate::float::types::Complex32;
+use core::f32::consts::PI;
+
+const N: usize = 64;
+const FS: f32 = 8000.0;
+
+/// Packed spectrum of a unit cosine sitting exactly on `bin`.
+fn cosine_spectrum(bin: usize) -> Vec<f32> {
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut buf: Vec<f32> = (0..N)
+        .map(|i| (2.0 * PI * (bin * i) as f32 / N as f32 + 0.5).cos())
+        .collect();
+    fft.process(&mut buf, false).unwrap();
+    buf
+}
+
+#[test]
+fn test_frequency_mapping() {
+    let packed = vec![0.0f32; N];
+    let s = Spectrum::packed(&packed, FS).unwrap();
+    assert_eq!(s.n(), N);
+    assert_eq!(s.num_bins(), N / 2 + 1);
+    assert_eq!(s.resolution(), 125.0);
+    assert_eq!(s.bin_to_hz(4.0), 500.0);
+    assert_eq!(s.hz_to_bin(562.5), 4.5);
+    assert_eq!(s.nearest_bin(1030.0), Some(8));
+    assert_eq!(s.nearest_bin(FS / 2.0), Some(N / 2));
+    assert_eq!(s.nearest_bin(FS / 2.0 + 100.0), None);
+    assert_eq!(s.nearest_bin(-100.0), None);
+}
+
+#[test]
+fn test_packings_agree() {
+    let packed = cosine_spectrum(5);
+    let mut full = vec![Complex32::new(0., 0.); N];
+    let mut half = vec![Complex32::new(0., 0.); N / 2 + 1];
+    unpack_rfft_spectrum::<f32>(&packed, &mut full);
+    unpack_rfft_spectrum_half::<f32>(&packed, &mut half);
+
+    let views = [
+        Spectrum::<f32>::packed(&packed, FS).unwrap(),
+        Spectrum::<f32>::half(&half, FS).unwrap(),
+        Spectrum::<f32>::full(&full, FS).unwrap(),
+    ];
+    assert_eq!(views[1].packing(), Packing::Half);
+    for v in &views {
+        assert_eq!(v.n(), N);
+        for k in 0..=N / 2 {
+            assert_eq!(v.bin(k), views[0].bin(k));
+        }
+        assert_eq!(v.bin(N / 2 + 1), None);
+    }
+
+    // Cosine with phase 0.5 rad: N/2 magnitude at its bin
+    let s = &views[0];
+    assert!((s.magnitude(5).unwrap() - N as f32 / 2.0).abs() < 1e-3);
+    assert!((s.phase(5).unwrap() - 0.5).abs() < 1e-4);
+
+    let (freq, mag) = s.iter().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+    assert_eq!(freq, 5.0 * FS / N as f32);
+    assert_eq!(mag, s.magnitude(5).unwrap());
+    assert_eq!(s.iter().count(), N / 2 + 1);
+}
+
+#[test]
+fn test_fixed_bins_and_invalid_sizes() {
+    // DC = 0.5, Nyquist = -0.25, bin 1 = (0, 0.75) in Q15
+    let q = |x: f64| Fixed::<15>::from_f64(x);
+    let packed = [q(0.5), q(-0.25), q(0.0), q(0.75)];
+    let s = Spectrum::packed(&packed, 4.0).unwrap();
+    assert_eq!(s.magnitude(0), Some(0.5));
+    assert_eq!(s.magnitude(2), Some(0.25));
+    assert_eq!(s.phase(2), Some(PI));
+    assert_eq!(s.magnitude(1), Some(0.75));
+
+    assert_eq!(
+        Spectrum::<f32>::packed(&[0.0; 3], FS).err(),
+        Some(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        Spectrum::<f32>::half(&[Complex32::new(0., 0.)], FS).err(),
+        Some(FftError::SizeMismatch)
+    );
+}
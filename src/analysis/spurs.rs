@@ -0,0 +1,91 @@
+// src/analysis/spurs.rs
+
+use crate::common::FftError;
+
+/// A spectral line standing out of the noise floor.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Spur {
+    /// Bin index within the analysed spectrum.
+    pub bin: usize,
+    /// Value of the spectrum at that bin.
+    pub level: f32,
+    /// `level / floor`.
+    pub ratio: f32,
+}
+
+/// Median of `spectrum`, a noise floor estimate that ignores tones and
+/// spurs as long as they cover less than half of the bins.
+///
+/// `scratch` must be at least as long as `spectrum`; the spectrum itself is
+/// left untouched. For a power spectrum of Gaussian noise the median is
+/// `ln 2` (about 0.69, or -1.6 dB) times the mean noise power per bin;
+/// divide by `core::f32::consts::LN_2` if the mean is needed.
+pub fn noise_floor(spectrum: &[f32], scratch: &mut [f32]) -> Result<f32, FftError> {
+    if spectrum.is_empty() {
+        return Err(FftError::SizeMismatch);
+    }
+    let values = scratch
+        .get_mut(..spectrum.len())
+        .ok_or(FftError::BufferTooSmall)?;
+    values.copy_from_slice(spectrum);
+
+    let mid = values.len() / 2;
+    let even = values.len().is_multiple_of(2);
+    let (lower, &mut upper, _) = values.select_nth_unstable_by(mid, f32::total_cmp);
+    if even {
+        let below = lower.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        Ok(0.5 * (below + upper))
+    } else {
+        Ok(upper)
+    }
+}
+
+/// Finds the local maxima of `spectrum` that exceed `floor * threshold`.
+///
+/// `threshold` is a linear ratio in the units of the spectrum (e.g. 10.0
+/// is 10 dB above the floor for a power spectrum, 20 dB for magnitudes).
+/// A spur wider than one bin is reported once, at its highest bin. When
+/// more spurs are found than `out` can hold, the largest ones are kept.
+/// `out` is sorted by decreasing level; the number of entries written is
+/// returned.
+pub fn find_spurs(spectrum: &[f32], floor: f32, threshold: f32, out: &mut [Spur]) -> usize {
+    let limit = floor * threshold;
+    let mut count = 0;
+
+    for (k, &level) in spectrum.iter().enumerate() {
+        let left = if k > 0 {
+            spectrum[k - 1]
+        } else {
+            f32::NEG_INFINITY
+        };
+        let right = spectrum.get(k + 1).copied().unwrap_or(f32::NEG_INFINITY);
+        // Plateaus count once, at their first bin
+        if level <= limit || level <= left || level < right {
+            continue;
+        }
+
+        // Insertion into the level-sorted output, dropping the smallest
+        let pos = out[..count].partition_point(|s| s.level >= level);
+        if pos == out.len() {
+            continue;
+        }
+        if count < out.len() {
+            count += 1;
+        }
+        out.copy_within(pos..count - 1, pos + 1);
+        out[pos] = Spur {
+            bin: k,
+            level,
+            ratio: if floor > 0.0 {
+                level / floor
+            } else {
+                f32::INFINITY
+            },
+        };
+    }
+    count
+}
+
+#[cfg(test)]
+#[path = "spurs_tests.rs"]
+mod tests;
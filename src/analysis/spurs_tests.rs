@@ -0,0 +1,56 @@
+use super::*;
+
+/// Noise around 1.0 with a tone at bin 10 and spurs at bins 25 and 40.
+fn spectrum() -> Vec<f32> {
+    let mut seed = 11u32;
+    let mut s: Vec<f32> = (0..64)
+        .map(|_| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            0.8 + 0.4 * (seed >> 8) as f32 / (1 << 24) as f32
+        })
+        .collect();
+    s[9] = 50.0;
+    s[10] = 1000.0;
+    s[11] = 60.0;
+    s[25] = 20.0;
+    s[40] = 8.0;
+    s
+}
+
+#[test]
+fn test_noise_floor_ignores_tones() {
+    let s = spectrum();
+    let mut scratch = vec![0.0; 64];
+    let floor = noise_floor(&s, &mut scratch).unwrap();
+    assert!((floor - 1.0).abs() < 0.1, "{floor}");
+    // The spectrum is not reordered
+    assert_eq!(s, spectrum());
+
+    assert_eq!(noise_floor(&[1.0, 4.0, 2.0, 3.0], &mut scratch), Ok(2.5));
+    assert_eq!(noise_floor(&[5.0, 1.0, 3.0], &mut scratch), Ok(3.0));
+    assert_eq!(
+        noise_floor(&s, &mut [0.0; 8]),
+        Err(FftError::BufferTooSmall)
+    );
+    assert_eq!(noise_floor(&[], &mut scratch), Err(FftError::SizeMismatch));
+}
+
+#[test]
+fn test_find_spurs() {
+    let s = spectrum();
+    let mut out = [Spur::default(); 8];
+    // Bins 9 and 11 are skirts of the tone, not separate spurs
+    let n = find_spurs(&s, 1.0, 5.0, &mut out);
+    assert_eq!(n, 3);
+    let bins: Vec<usize> = out[..n].iter().map(|s| s.bin).collect();
+    assert_eq!(bins, [10, 25, 40]);
+    assert_eq!(out[1].ratio, 20.0);
+
+    // With fewer slots the largest spurs are kept
+    let mut two = [Spur::default(); 2];
+    assert_eq!(find_spurs(&s, 1.0, 5.0, &mut two), 2);
+    assert_eq!((two[0].bin, two[1].bin), (10, 25));
+
+    // A higher threshold drops the small spur
+    assert_eq!(find_spurs(&s, 1.0, 10.0, &mut out), 2);
+}
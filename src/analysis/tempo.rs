@@ -0,0 +1,115 @@
+// src/analysis/tempo.rs
+
+use crate::common::{FftError, RealFft};
+use crate::float::types::Complex32;
+
+/// Plausible tempo range and preference for [`estimate_tempo`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TempoPrior {
+    /// Slowest tempo considered, in beats per minute.
+    pub min_bpm: f32,
+    /// Fastest tempo considered, in beats per minute.
+    pub max_bpm: f32,
+    /// Most likely tempo. Autocorrelation peaks are weighted by a Gaussian
+    /// in log2 tempo around it, which settles half/double tempo ambiguity.
+    pub center_bpm: f32,
+    /// Standard deviation of that Gaussian, in octaves.
+    pub octave_width: f32,
+}
+
+impl Default for TempoPrior {
+    fn default() -> Self {
+        Self {
+            min_bpm: 40.0,
+            max_bpm: 240.0,
+            center_bpm: 120.0,
+            octave_width: 1.0,
+        }
+    }
+}
+
+/// Result of [`estimate_tempo`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TempoEstimate {
+    /// Estimated tempo in beats per minute.
+    pub bpm: f32,
+    /// Beat period in envelope frames, interpolated between lags.
+    pub period: f32,
+    /// Autocorrelation at the beat period relative to lag 0: near 1 for a
+    /// strictly periodic envelope, near 0 without rhythm.
+    pub confidence: f32,
+}
+
+/// Tempo of an onset-strength envelope (e.g. the spectral flux of
+/// [`OnsetDetector`](super::OnsetDetector), one value per frame at
+/// `frame_rate` frames per second).
+///
+/// The mean-removed envelope is autocorrelated with `fft`, and the lag
+/// within the prior's range with the highest prior-weighted correlation
+/// is taken as the beat period. `scratch` holds N samples; N must be at
+/// least the envelope length plus the longest lag, `60 * frame_rate /
+/// min_bpm`, so that the correlation does not wrap.
+pub fn estimate_tempo(
+    fft: &RealFft<'_, Complex32>,
+    envelope: &[f32],
+    frame_rate: f32,
+    prior: TempoPrior,
+    scratch: &mut [f32],
+) -> Result<TempoEstimate, FftError> {
+    let valid = frame_rate > 0.0
+        && prior.min_bpm > 0.0
+        && prior.max_bpm > prior.min_bpm
+        && prior.center_bpm > 0.0
+        && prior.octave_width > 0.0;
+    if !valid {
+        return Err(FftError::OutOfRange);
+    }
+    let n = fft.n;
+    let lag_of = |bpm: f32| 60.0 * frame_rate / bpm;
+    let lag_min = (libm::floorf(lag_of(prior.max_bpm)) as usize).max(1);
+    let lag_max = libm::ceilf(lag_of(prior.min_bpm)) as usize;
+    if lag_max + 1 >= envelope.len() || envelope.len() + lag_max > n {
+        return Err(FftError::SizeMismatch);
+    }
+    let r = scratch.get_mut(..n).ok_or(FftError::BufferTooSmall)?;
+
+    let mean = envelope.iter().sum::<f32>() / envelope.len() as f32;
+    for (x, &e) in r.iter_mut().zip(envelope) {
+        *x = e - mean;
+    }
+    r[envelope.len()..].fill(0.0);
+    fft.autocorrelate(r)?;
+    if r[0] <= 0.0 {
+        return Err(FftError::OutOfRange);
+    }
+
+    let weight = |lag: f32| {
+        let octaves = libm::log2f(lag_of(lag) / prior.center_bpm) / prior.octave_width;
+        libm::expf(-0.5 * octaves * octaves)
+    };
+    let best = (lag_min..=lag_max)
+        .max_by(|&a, &b| {
+            let score = |lag: usize| r[lag] * weight(lag as f32);
+            score(a).total_cmp(&score(b))
+        })
+        .unwrap_or(lag_min);
+
+    // Parabolic refinement on the raw correlation
+    let (left, centre, right) = (r[best - 1], r[best], r[best + 1]);
+    let curvature = left - 2.0 * centre + right;
+    let offset = if curvature < 0.0 {
+        (0.5 * (left - right) / curvature).clamp(-0.5, 0.5)
+    } else {
+        0.0
+    };
+    let period = best as f32 + offset;
+    Ok(TempoEstimate {
+        bpm: lag_of(period),
+        period,
+        confidence: centre / r[0],
+    })
+}
+
+#[cfg(test)]
+#[path = "tempo_tests.rs"]
+mod tests;
@@ -0,0 +1,74 @@
+use super::*;
+
+const FRAME_RATE: f32 = 100.0;
+
+/// Onset envelope with a decaying pulse every `period` frames over noise.
+fn envelope(len: usize, period: usize) -> Vec<f32> {
+    let mut seed = 3u32;
+    (0..len)
+        .map(|i| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let noise = (seed >> 8) as f32 / (1 << 24) as f32 * 0.3;
+            noise + libm::expf(-((i % period) as f32) / 3.0)
+        })
+        .collect()
+}
+
+fn plan(n: usize) -> (Vec<Complex32>, Vec<usize>) {
+    (vec![Complex32::new(0.0, 0.0); n / 2], vec![0; n / 2])
+}
+
+#[test]
+fn test_finds_tempo_and_prior_picks_the_octave() {
+    let n = 2048;
+    let (mut twiddles, mut bitrev) = plan(n);
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let mut scratch = vec![0.0; n];
+
+    // 100 BPM at 100 frames/s: a pulse every 60 frames
+    let env = envelope(1000, 60);
+    let estimate =
+        estimate_tempo(&fft, &env, FRAME_RATE, TempoPrior::default(), &mut scratch).unwrap();
+    assert!((estimate.bpm - 100.0).abs() < 1.0, "{estimate:?}");
+    assert!((estimate.period - 60.0).abs() < 0.6);
+    assert!(estimate.confidence > 0.5);
+
+    // A prior centred on 50 BPM settles on the half tempo
+    let slow = TempoPrior {
+        center_bpm: 50.0,
+        octave_width: 0.3,
+        ..TempoPrior::default()
+    };
+    let estimate = estimate_tempo(&fft, &env, FRAME_RATE, slow, &mut scratch).unwrap();
+    assert!((estimate.bpm - 50.0).abs() < 1.0, "{estimate:?}");
+}
+
+#[test]
+fn test_rejects_short_plans_and_flat_envelopes() {
+    let n = 1024;
+    let (mut twiddles, mut bitrev) = plan(n);
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let mut scratch = vec![0.0; n];
+
+    // 1000 frames plus a 150-frame lag do not fit in 1024
+    assert_eq!(
+        estimate_tempo(
+            &fft,
+            &envelope(1000, 60),
+            FRAME_RATE,
+            TempoPrior::default(),
+            &mut scratch
+        ),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        estimate_tempo(
+            &fft,
+            &[1.0; 500],
+            FRAME_RATE,
+            TempoPrior::default(),
+            &mut scratch
+        ),
+        Err(FftError::OutOfRange)
+    );
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+const FS: f32 = 8000.0;
+
+fn signal(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / FS;
+            0.7 * libm::cosf(2.0 * PI * 440.0 * t + 0.3) + 0.2 * libm::sinf(2.0 * PI * 1234.5 * t)
+        })
+        .collect()
+}
+
+#[test]
+fn test_tracks_off_bin_tones() {
+    let mut tones = [
+        Tone::new(440.0, FS, 0.995).unwrap(),
+        Tone::new(1234.5, FS, 0.995).unwrap(),
+        Tone::new(3000.0, FS, 0.995).unwrap(),
+    ];
+    let mut bank = ToneBank::new(&mut tones);
+    let x = signal(4000);
+    // Chunk boundaries do not matter
+    bank.push(&x[..1234]);
+    bank.push(&x[1234..]);
+
+    let mut amp = [0.0f32; 3];
+    bank.amplitudes(&mut amp).unwrap();
+    assert!((amp[0] - 0.7).abs() < 0.01, "{amp:?}");
+    assert!((amp[1] - 0.2).abs() < 0.01, "{amp:?}");
+    assert!(amp[2] < 0.01, "{amp:?}");
+
+    // Phase of the 440 Hz cosine at the last sample
+    let t = (x.len() - 1) as f32 / FS;
+    let expected = libm::remainderf(2.0 * PI * 440.0 * t + 0.3, 2.0 * PI);
+    let diff = libm::remainderf(bank.tones()[0].phase() - expected, 2.0 * PI);
+    assert!(diff.abs() < 0.05, "{diff}");
+
+    bank.reset();
+    bank.amplitudes(&mut amp).unwrap();
+    assert_eq!(amp, [0.0; 3]);
+    assert_eq!(bank.amplitudes(&mut [0.0; 2]), Err(FftError::SizeMismatch));
+}
+
+#[test]
+fn test_forgets_tones_that_stop() {
+    let mut tones = [Tone::new(440.0, FS, 0.99).unwrap()];
+    let mut bank = ToneBank::new(&mut tones);
+    bank.push(&signal(2000));
+    assert!(bank.tones()[0].amplitude() > 0.6);
+    bank.push(&[0.0; 1000]);
+    // 0.99^1000 is about 4e-5
+    assert!(bank.tones()[0].amplitude() < 1e-3);
+
+    assert_eq!(Tone::new(0.0, FS, 0.99), Err(FftError::OutOfRange));
+    assert_eq!(Tone::new(4000.0, FS, 0.99), Err(FftError::OutOfRange));
+    assert_eq!(Tone::new(440.0, FS, 1.0), Err(FftError::OutOfRange));
+}
@@ -0,0 +1,152 @@
+// src/analysis/vad.rs
+
+use crate::common::FftError;
+use crate::fixed::Fixed;
+
+/// Tuning parameters for [`EnergyVad`]. Integer-only, for MCUs without an
+/// FPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VadConfig {
+    /// A band is active when its energy exceeds its noise floor shifted left
+    /// by this amount (about 3 dB per step).
+    pub snr_shift: u32,
+    /// Absolute energy below which a band is never active, in the raw Q30
+    /// units of [`band_energy`]. Keeps digital silence from triggering.
+    pub min_energy: i64,
+    /// Number of frames the detector stays active after the last detection,
+    /// so word endings and short pauses are not cut.
+    pub hangover: u32,
+    /// Noise floor smoothing: the floor moves 2^-floor_shift of the way
+    /// toward the energy of each inactive frame.
+    pub floor_shift: u32,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self {
+            snr_shift: 2,
+            min_energy: 1 << 16,
+            hangover: 8,
+            floor_shift: 4,
+        }
+    }
+}
+
+/// Energy of bins `lo..hi` of a packed Q15 real FFT frame, as a raw sum of
+/// squares with 30 fractional bits. Bin `N/2` (Nyquist) may be included.
+pub fn band_energy(packed: &[Fixed<15>], lo: usize, hi: usize) -> i64 {
+    let n_half = packed.len() / 2;
+    let sqr = |x: Fixed<15>| (x.to_bits() as i64) * (x.to_bits() as i64);
+
+    (lo..hi.min(n_half + 1))
+        .map(|k| match k {
+            0 => sqr(packed[0]),
+            _ if k == n_half => sqr(packed[1]),
+            _ => sqr(packed[2 * k]) + sqr(packed[2 * k + 1]),
+        })
+        .sum()
+}
+
+/// Band-energy voice/signal activity detector for the Q15 real FFT path.
+///
+/// Each frame (as produced by `RealFft::process` on Q15 data) is split into
+/// caller-defined bands; every band tracks its own noise floor during
+/// inactive frames, and the frame is active when any band rises far enough
+/// above its floor. A hangover keeps the decision active for a few frames
+/// after the last detection. All state lives in caller-provided storage.
+pub struct EnergyVad<'a> {
+    bands: &'a [(usize, usize)],
+    floors: &'a mut [i64],
+    config: VadConfig,
+    hang: u32,
+    active: bool,
+    primed: bool,
+}
+
+impl<'a> EnergyVad<'a> {
+    /// `bands` holds `(lo, hi)` bin ranges (hi exclusive); `floors` must
+    /// have one slot per band.
+    pub fn new(
+        bands: &'a [(usize, usize)],
+        floors: &'a mut [i64],
+        config: VadConfig,
+    ) -> Result<Self, FftError> {
+        if floors.len() != bands.len() {
+            return Err(FftError::SizeMismatch);
+        }
+        if bands.is_empty() || bands.iter().any(|&(lo, hi)| lo >= hi) {
+            return Err(FftError::InvalidStride);
+        }
+        Ok(Self {
+            bands,
+            floors,
+            config,
+            hang: 0,
+            active: false,
+            primed: false,
+        })
+    }
+
+    /// Feeds one packed Q15 frame and returns the (hangover-smoothed)
+    /// activity decision.
+    pub fn update(&mut self, packed: &[Fixed<15>]) -> Result<bool, FftError> {
+        let n = packed.len();
+        if n < 2 || !n.is_multiple_of(2) || self.bands.iter().any(|&(_, hi)| hi > n / 2 + 1) {
+            return Err(FftError::SizeMismatch);
+        }
+
+        // The first frame seeds the noise floors
+        if !self.primed {
+            for (floor, &(lo, hi)) in self.floors.iter_mut().zip(self.bands) {
+                *floor = band_energy(packed, lo, hi);
+            }
+            self.primed = true;
+        }
+
+        let mut detected = false;
+        for (floor, &(lo, hi)) in self.floors.iter().zip(self.bands) {
+            let energy = band_energy(packed, lo, hi);
+            let threshold = floor.saturating_mul(1 << self.config.snr_shift);
+            detected |= energy > threshold && energy > self.config.min_energy;
+        }
+
+        if detected {
+            self.hang = self.config.hangover;
+            self.active = true;
+        } else {
+            // Only noise frames adapt the floors
+            for (floor, &(lo, hi)) in self.floors.iter_mut().zip(self.bands) {
+                let energy = band_energy(packed, lo, hi);
+                *floor += (energy - *floor) >> self.config.floor_shift;
+            }
+            if self.hang > 0 {
+                self.hang -= 1;
+            } else {
+                self.active = false;
+            }
+        }
+
+        Ok(self.active)
+    }
+
+    /// Last decision returned by [`Self::update`].
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Current noise floor estimate of every band.
+    pub fn noise_floors(&self) -> &[i64] {
+        self.floors
+    }
+
+    /// Forgets the noise floors and the hangover state.
+    pub fn reset(&mut self) {
+        self.hang = 0;
+        self.active = false;
+        self.primed = false;
+    }
+}
+
+#[cfg(test)]
+#[path = "vad_tests.rs"]
+mod tests;
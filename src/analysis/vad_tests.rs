@@ -0,0 +1,88 @@
+use super::*;
+use crate::common::RealFft;
+use crate::fixed::ComplexFixed;
+
+const N: usize = 64;
+
+/// Packed Q15 spectrum of a frame of noise, plus a tone at bin 8 if `loud`.
+fn frame(fft: &RealFft<'_, ComplexFixed<31>>, seed: &mut u32, loud: bool) -> Vec<Fixed<15>> {
+    let mut buf: Vec<Fixed<15>> = (0..N)
+        .map(|i| {
+            *seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let noise = ((*seed >> 16) as f64 / 65536.0 - 0.5) * 0.002;
+            let tone = if loud {
+                0.3 * (2.0 * core::f64::consts::PI * 8.0 * i as f64 / N as f64).sin()
+            } else {
+                0.0
+            };
+            Fixed::from_f64(noise + tone)
+        })
+        .collect();
+    fft.process(&mut buf, false).unwrap();
+    buf
+}
+
+#[test]
+fn test_band_energy() {
+    // DC = 0.5, Nyquist = 0.25, bin 1 = (0.5, 0.5)
+    let q = |x: f64| Fixed::<15>::from_f64(x);
+    let packed = [q(0.5), q(0.25), q(0.5), q(0.5)];
+    let unit = 1i64 << 30;
+    assert_eq!(band_energy(&packed, 0, 1), unit / 4);
+    assert_eq!(band_energy(&packed, 1, 2), unit / 2);
+    assert_eq!(band_energy(&packed, 2, 3), unit / 16);
+    assert_eq!(band_energy(&packed, 0, 10), unit * 13 / 16);
+}
+
+#[test]
+fn test_detects_tone_with_hangover() {
+    let mut twiddles = vec![ComplexFixed::ZERO; N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<ComplexFixed<31>>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    let bands = [(1, 12), (12, 33)];
+    let mut floors = [0i64; 2];
+    let config = VadConfig {
+        hangover: 3,
+        ..VadConfig::default()
+    };
+    let mut vad = EnergyVad::new(&bands, &mut floors, config).unwrap();
+    let mut seed = 1;
+
+    for _ in 0..10 {
+        assert!(!vad.update(&frame(&fft, &mut seed, false)).unwrap());
+    }
+    let floor = vad.noise_floors()[0];
+
+    for _ in 0..4 {
+        assert!(vad.update(&frame(&fft, &mut seed, true)).unwrap());
+    }
+    // The tone did not leak into the noise estimate
+    assert_eq!(vad.noise_floors()[0], floor);
+
+    // Active for exactly `hangover` quiet frames, then released
+    for _ in 0..3 {
+        assert!(vad.update(&frame(&fft, &mut seed, false)).unwrap());
+    }
+    assert!(!vad.update(&frame(&fft, &mut seed, false)).unwrap());
+    assert!(!vad.is_active());
+
+    vad.reset();
+    assert!(!vad.is_active());
+}
+
+#[test]
+fn test_invalid_configuration() {
+    let mut floors = [0i64; 1];
+    assert!(matches!(
+        EnergyVad::new(&[(1, 4), (4, 8)], &mut floors, VadConfig::default()),
+        Err(FftError::SizeMismatch)
+    ));
+    assert!(matches!(
+        EnergyVad::new(&[(4, 4)], &mut floors, VadConfig::default()),
+        Err(FftError::InvalidStride)
+    ));
+
+    let mut vad = EnergyVad::new(&[(1, 40)], &mut floors, VadConfig::default()).unwrap();
+    assert_eq!(vad.update(&[Fixed::ZERO; 16]), Err(FftError::SizeMismatch));
+}
@@ -0,0 +1,157 @@
+// src/analysis/waterfall.rs
+
+use crate::common::FftError;
+
+/// How several values are merged into one display cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Decimation {
+    /// Keep the largest value, so narrow peaks and short bursts stay
+    /// visible.
+    #[default]
+    Max,
+    /// Average the values, for a smoother noise floor.
+    Mean,
+}
+
+/// Rolling time-frequency buffer for waterfall displays.
+///
+/// Every pushed spectrum (magnitudes or dB, one value per bin) is reduced
+/// to `cols` cells along the frequency axis; `time_factor` consecutive
+/// spectra are then merged into one row along the time axis. Rows are kept
+/// in a caller-provided ring, so the memory footprint is fixed at
+/// `rows * cols` cells however long the display runs.
+pub struct Waterfall<'a> {
+    rows: &'a mut [f32],
+    pending: &'a mut [f32],
+    freq: Decimation,
+    time: Decimation,
+    time_factor: usize,
+    pending_count: usize,
+    head: usize,
+    filled: usize,
+}
+
+impl<'a> Waterfall<'a> {
+    /// Creates a waterfall whose width is `pending.len()` cells and whose
+    /// height is `rows.len() / pending.len()` rows.
+    ///
+    /// `rows.len()` must be a non-zero multiple of the width, and
+    /// `time_factor` at least 1.
+    pub fn new(
+        rows: &'a mut [f32],
+        pending: &'a mut [f32],
+        time_factor: usize,
+        freq: Decimation,
+        time: Decimation,
+    ) -> Result<Self, FftError> {
+        let cols = pending.len();
+        if cols == 0 || rows.is_empty() || !rows.len().is_multiple_of(cols) {
+            return Err(FftError::SizeMismatch);
+        }
+        if time_factor == 0 {
+            return Err(FftError::InvalidStride);
+        }
+        rows.fill(0.0);
+        Ok(Self {
+            rows,
+            pending,
+            freq,
+            time,
+            time_factor,
+            pending_count: 0,
+            head: 0,
+            filled: 0,
+        })
+    }
+
+    /// Number of cells per row.
+    pub fn width(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Maximum number of rows kept.
+    pub fn height(&self) -> usize {
+        self.rows.len() / self.pending.len()
+    }
+
+    /// Number of rows written so far, up to [`Self::height`].
+    pub fn len(&self) -> usize {
+        self.filled
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.filled == 0
+    }
+
+    /// Adds one spectrum, which must have at least [`Self::width`] bins.
+    ///
+    /// Returns `true` when this spectrum completed a new row.
+    pub fn push(&mut self, spectrum: &[f32]) -> Result<bool, FftError> {
+        let cols = self.pending.len();
+        let bins = spectrum.len();
+        if bins < cols {
+            return Err(FftError::SizeMismatch);
+        }
+
+        for (c, cell) in self.pending.iter_mut().enumerate() {
+            let group = &spectrum[c * bins / cols..(c + 1) * bins / cols];
+            let value = reduce(self.freq, group);
+            *cell = match (self.pending_count, self.time) {
+                (0, _) => value,
+                (_, Decimation::Max) => cell.max(value),
+                (_, Decimation::Mean) => *cell + value,
+            };
+        }
+        self.pending_count += 1;
+        if self.pending_count < self.time_factor {
+            return Ok(false);
+        }
+
+        let row = &mut self.rows[self.head * cols..(self.head + 1) * cols];
+        row.copy_from_slice(self.pending);
+        if self.time == Decimation::Mean {
+            let n = self.pending_count as f32;
+            row.iter_mut().for_each(|x| *x /= n);
+        }
+        self.pending_count = 0;
+        self.head = (self.head + 1) % self.height();
+        self.filled = (self.filled + 1).min(self.height());
+        Ok(true)
+    }
+
+    /// Row `age` rows back in time: 0 is the newest. `None` past
+    /// [`Self::len`].
+    pub fn row(&self, age: usize) -> Option<&[f32]> {
+        if age >= self.filled {
+            return None;
+        }
+        let height = self.height();
+        let cols = self.pending.len();
+        let index = (self.head + height - 1 - age) % height;
+        Some(&self.rows[index * cols..(index + 1) * cols])
+    }
+
+    /// Iterates over the stored rows from newest to oldest.
+    pub fn iter(&self) -> impl Iterator<Item = &[f32]> + '_ {
+        (0..self.filled).map(move |age| self.row(age).unwrap())
+    }
+
+    /// Drops all rows and any partially merged spectra.
+    pub fn clear(&mut self) {
+        self.rows.fill(0.0);
+        self.pending_count = 0;
+        self.head = 0;
+        self.filled = 0;
+    }
+}
+
+fn reduce(mode: Decimation, values: &[f32]) -> f32 {
+    match mode {
+        Decimation::Max => values.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+        Decimation::Mean => values.iter().sum::<f32>() / values.len() as f32,
+    }
+}
+
+#[cfg(test)]
+#[path = "waterfall_tests.rs"]
+mod tests;
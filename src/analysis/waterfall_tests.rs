@@ -0,0 +1,71 @@
+use super::*;
+
+#[test]
+fn test_frequency_decimation() {
+    let (mut rows, mut pending) = (vec![0.0; 8], vec![0.0; 4]);
+    let mut max =
+        Waterfall::new(&mut rows, &mut pending, 1, Decimation::Max, Decimation::Max).unwrap();
+    assert_eq!((max.width(), max.height()), (4, 2));
+
+    // 9 bins into 4 cells: groups of 2, 2, 2 and 3 bins
+    let spectrum = [1.0, 3.0, 2.0, 2.0, 0.0, 4.0, 1.0, 5.0, 3.0];
+    assert!(max.push(&spectrum).unwrap());
+    assert_eq!(max.row(0).unwrap(), &[3.0, 2.0, 4.0, 5.0]);
+
+    let (mut rows, mut pending) = (vec![0.0; 8], vec![0.0; 4]);
+    let mut mean = Waterfall::new(
+        &mut rows,
+        &mut pending,
+        1,
+        Decimation::Mean,
+        Decimation::Max,
+    )
+    .unwrap();
+    mean.push(&spectrum).unwrap();
+    assert_eq!(mean.row(0).unwrap(), &[2.0, 2.0, 2.0, 3.0]);
+    assert_eq!(mean.push(&[0.0; 3]), Err(FftError::SizeMismatch));
+}
+
+#[test]
+fn test_time_decimation_and_rolling() {
+    let (mut rows, mut pending) = (vec![0.0; 6], vec![0.0; 2]);
+    let mut wf = Waterfall::new(
+        &mut rows,
+        &mut pending,
+        2,
+        Decimation::Max,
+        Decimation::Mean,
+    )
+    .unwrap();
+    assert!(wf.is_empty());
+
+    for t in 0..10 {
+        let v = t as f32;
+        // Every second spectrum completes a row
+        assert_eq!(wf.push(&[v, 2.0 * v]).unwrap(), t % 2 == 1);
+    }
+
+    // Three rows kept, newest first: means of (8, 9), (6, 7), (4, 5)
+    assert_eq!(wf.len(), 3);
+    let rows: Vec<&[f32]> = wf.iter().collect();
+    assert_eq!(rows, [&[8.5, 17.0][..], &[6.5, 13.0], &[4.5, 9.0]]);
+    assert_eq!(wf.row(3), None);
+
+    wf.clear();
+    assert!(wf.is_empty());
+    assert!(!wf.push(&[1.0, 1.0]).unwrap());
+}
+
+#[test]
+fn test_invalid_sizes() {
+    let (mut rows, mut pending) = (vec![0.0; 7], vec![0.0; 2]);
+    assert!(matches!(
+        Waterfall::new(&mut rows, &mut pending, 1, Decimation::Max, Decimation::Max),
+        Err(FftError::SizeMismatch)
+    ));
+    let (mut rows, mut pending) = (vec![0.0; 8], vec![0.0; 2]);
+    assert!(matches!(
+        Waterfall::new(&mut rows, &mut pending, 0, Decimation::Max, Decimation::Max),
+        Err(FftError::InvalidStride)
+    ));
+}
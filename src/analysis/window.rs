@@ -0,0 +1,212 @@
+// src/analysis/window.rs
+
+use crate::common::FftError;
+use core::f32::consts::PI;
+
+/// Common cosine-sum windows, with their correction factors.
+///
+/// All windows are generated in their periodic (DFT-even) form,
+/// `w[n] = a0 - a1 cos(2 pi n / N) + a2 cos(4 pi n / N) - ...`, which is the
+/// right choice for spectral analysis and what `scipy.signal.get_window`
+/// returns by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Window {
+    Rectangular,
+    Hann,
+    Hamming,
+    Blackman,
+    /// 4-term Blackman-Harris (-92 dB sidelobes).
+    BlackmanHarris,
+    /// 5-term flat top (MATLAB `flattopwin`), for amplitude accuracy.
+    FlatTop,
+}
+
+impl Window {
+    /// Cosine-sum coefficients `a0, a1, ...`.
+    pub const fn terms(self) -> &'static [f32] {
+        match self {
+            Window::Rectangular => &[1.0],
+            Window::Hann => &[0.5, 0.5],
+            Window::Hamming => &[0.54, 0.46],
+            Window::Blackman => &[0.42, 0.5, 0.08],
+            Window::BlackmanHarris => &[0.35875, 0.48829, 0.14128, 0.01168],
+            Window::FlatTop => &[
+                0.215_578_95,
+                0.416_631_58,
+                0.277_263_16,
+                0.083_578_95,
+                0.006_947_368,
+            ],
+        }
+    }
+
+    /// Mean value of the window: the factor by which it scales the peak of
+    /// a tone centred on a bin.
+    pub const fn coherent_gain(self) -> f32 {
+        self.terms()[0]
+    }
+
+    /// Equivalent noise bandwidth in bins, `N sum(w²) / sum(w)²`.
+    pub fn enbw(self) -> f32 {
+        let terms = self.terms();
+        let a0 = terms[0];
+        let rest: f32 = terms[1..].iter().map(|a| a * a).sum();
+        (a0 * a0 + 0.5 * rest) / (a0 * a0)
+    }
+
+    /// Scallop loss in dB: how much lower a tone halfway between two bins
+    /// reads than one centred on a bin (3.92 dB for the rectangular
+    /// window, close to 0 for the flat top).
+    ///
+    /// Evaluated from the window's continuous transform, i.e. for large N.
+    pub fn scallop_loss(self) -> f32 {
+        let sinc = |x: f32| libm::sinf(PI * x) / (PI * x);
+        let terms = self.terms();
+        // Centred on N/2 every term of the cosine sum adds up
+        let half_bin: f32 = terms[0] * sinc(0.5)
+            + terms[1..]
+                .iter()
+                .enumerate()
+                .map(|(k, a)| {
+                    let k = (k + 1) as f32;
+                    0.5 * a * (sinc(0.5 - k) + sinc(0.5 + k))
+                })
+                .sum::<f32>();
+        -20.0 * libm::log10f(libm::fabsf(half_bin) / terms[0])
+    }
+
+    /// Writes the N-point window into `out`.
+    pub fn fill(self, out: &mut [f32]) {
+        let n = out.len() as f32;
+        for (i, w) in out.iter_mut().enumerate() {
+            let x = 2.0 * PI * i as f32 / n;
+            *w = self
+                .terms()
+                .iter()
+                .enumerate()
+                .map(|(k, a)| {
+                    let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+                    sign * a * libm::cosf(k as f32 * x)
+                })
+                .sum();
+        }
+    }
+
+    /// Correction factors of this window.
+    pub fn correction(self) -> WindowCorrection {
+        WindowCorrection {
+            coherent_gain: self.coherent_gain(),
+            enbw: self.enbw(),
+            scallop_loss: self.scallop_loss(),
+        }
+    }
+}
+
+/// What the bins of a corrected spectrum read, see
+/// [`WindowCorrection::apply`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CorrectionMode {
+    /// Peak amplitude of a sinusoid centred on the bin; DC reads the mean.
+    /// Right for tones, overestimates broadband power by the ENBW.
+    #[default]
+    Amplitude,
+    /// RMS, normalized by the window energy so that the squared bins of a
+    /// band sum to the power of the signal in that band. Right for noise
+    /// and broadband signals; a tone spreads over ENBW bins.
+    Power,
+}
+
+/// Factors that undo the effect of a window on spectral readings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowCorrection {
+    /// Mean of the window.
+    pub coherent_gain: f32,
+    /// Equivalent noise bandwidth, in bins.
+    pub enbw: f32,
+    /// Loss at half a bin from a bin centre, in dB.
+    pub scallop_loss: f32,
+}
+
+impl WindowCorrection {
+    /// Measures the factors of an arbitrary window.
+    pub fn from_coefficients(window: &[f32]) -> Result<Self, FftError> {
+        if window.is_empty() {
+            return Err(FftError::SizeMismatch);
+        }
+        let sum: f32 = window.iter().sum();
+        let sum_sq: f32 = window.iter().map(|w| w * w).sum();
+        if sum <= 0.0 {
+            return Err(FftError::InvalidStride);
+        }
+        let n = window.len() as f32;
+        // Response half a bin off centre, relative to the centre
+        let (re, im) = window
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(re, im), (i, w)| {
+                let phase = PI * i as f32 / n;
+                (re + w * libm::cosf(phase), im - w * libm::sinf(phase))
+            });
+        Ok(Self {
+            coherent_gain: sum / n,
+            enbw: n * sum_sq / (sum * sum),
+            scallop_loss: -20.0 * libm::log10f(libm::hypotf(re, im) / sum),
+        })
+    }
+
+    /// Rescales a packed real spectrum (see `RealFft::process`, unscaled
+    /// forward transform) so that each bin reads the peak amplitude of a
+    /// sinusoid centred on it, and DC reads the mean.
+    pub fn apply_amplitude(&self, packed: &mut [f32]) -> Result<(), FftError> {
+        self.apply(packed, CorrectionMode::Amplitude)
+    }
+
+    /// Rescales a packed real spectrum (unscaled forward transform) to read
+    /// in the given mode.
+    pub fn apply(&self, packed: &mut [f32], mode: CorrectionMode) -> Result<(), FftError> {
+        let n = packed.len();
+        if n < 2 || !n.is_multiple_of(2) {
+            return Err(FftError::SizeMismatch);
+        }
+        let (edge, inner) = match mode {
+            CorrectionMode::Amplitude => {
+                let edge = 1.0 / (n as f32 * self.coherent_gain);
+                (edge, 2.0 * edge)
+            }
+            // N times the RMS of the window; the one-sided bins carry both halves
+            CorrectionMode::Power => {
+                let edge = 1.0 / (n as f32 * self.coherent_gain * libm::sqrtf(self.enbw));
+                (edge, core::f32::consts::SQRT_2 * edge)
+            }
+        };
+        packed[0] *= edge;
+        packed[1] *= edge;
+        packed[2..].iter_mut().for_each(|x| *x *= inner);
+        Ok(())
+    }
+
+    /// Converts a noise power reading into power spectral density (power
+    /// per Hz, two-sided) for an N-point transform at `sample_rate`.
+    ///
+    /// `bin_power` is `|X[k]|²` of the unscaled transform divided by
+    /// `(N * coherent_gain)²`; the ENBW accounts for the window collecting
+    /// noise over more than one bin.
+    pub fn to_density(&self, bin_power: f32, n: usize, sample_rate: f32) -> f32 {
+        bin_power * n as f32 / (self.enbw * sample_rate)
+    }
+}
+
+/// Subtracts the mean from `buffer` and returns it. Done before windowing,
+/// it keeps a DC offset from leaking into the first bins.
+pub fn remove_mean(buffer: &mut [f32]) -> f32 {
+    if buffer.is_empty() {
+        return 0.0;
+    }
+    let mean = buffer.iter().sum::<f32>() / buffer.len() as f32;
+    buffer.iter_mut().for_each(|x| *x -= mean);
+    mean
+}
+
+#[cfg(test)]
+#[path = "window_tests.rs"]
+mod tests;
@@ -0,0 +1,117 @@
+use super::*;
+use crate::common::RealFft;
+use crate::float::types::Complex32;
+
+const N: usize = 256;
+
+const ALL: [Window; 6] = [
+    Window::Rectangular,
+    Window::Hann,
+    Window::Hamming,
+    Window::Blackman,
+    Window::BlackmanHarris,
+    Window::FlatTop,
+];
+
+#[test]
+fn test_constants_match_generated_windows() {
+    assert_eq!(Window::Hann.enbw(), 1.5);
+    assert!((Window::Hamming.enbw() - 1.3628).abs() < 1e-4);
+    assert!((Window::Blackman.enbw() - 1.7268).abs() < 1e-4);
+    assert!((Window::BlackmanHarris.enbw() - 2.0044).abs() < 1e-4);
+    assert!((Window::FlatTop.enbw() - 3.7702).abs() < 1e-3);
+
+    let mut w = vec![0.0; N];
+    for window in ALL {
+        window.fill(&mut w);
+        let measured = WindowCorrection::from_coefficients(&w).unwrap();
+        let c = window.correction();
+        assert!((measured.coherent_gain - c.coherent_gain).abs() < 1e-5);
+        assert!((measured.enbw - c.enbw).abs() < 1e-4, "{window:?}");
+        assert!(
+            (measured.scallop_loss - c.scallop_loss).abs() < 1e-3,
+            "{window:?}"
+        );
+    }
+    // Published figures (Harris, 1978)
+    assert!((Window::Rectangular.scallop_loss() - 3.92).abs() < 0.01);
+    assert!((Window::Hann.scallop_loss() - 1.42).abs() < 0.01);
+    assert!((Window::Hamming.scallop_loss() - 1.75).abs() < 0.01);
+    assert!((Window::Blackman.scallop_loss() - 1.10).abs() < 0.01);
+    assert!(Window::FlatTop.scallop_loss().abs() < 0.02);
+
+    Window::Hann.fill(&mut w);
+    assert_eq!(w[0], 0.0);
+    assert!((w[N / 2] - 1.0).abs() < 1e-6);
+    assert_eq!(
+        WindowCorrection::from_coefficients(&[]),
+        Err(FftError::SizeMismatch)
+    );
+}
+
+#[test]
+fn test_amplitude_correction() {
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut w = vec![0.0; N];
+
+    for window in ALL {
+        window.fill(&mut w);
+        // 0.3 offset plus a 0.8 tone on bin 20
+        let mut buf: Vec<f32> = (0..N)
+            .map(|i| {
+                let x = 0.3 + 0.8 * (2.0 * PI * 20.0 * i as f32 / N as f32).cos();
+                x * w[i]
+            })
+            .collect();
+        fft.process(&mut buf, false).unwrap();
+        window.correction().apply_amplitude(&mut buf).unwrap();
+        assert!((buf[0] - 0.3).abs() < 1e-4, "{window:?}");
+        assert!((buf[40] - 0.8).abs() < 1e-4, "{window:?}");
+    }
+}
+
+#[test]
+fn test_power_correction_preserves_signal_power() {
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut w = vec![0.0; N];
+
+    // Deterministic broadband signal
+    let mut seed = 1u32;
+    let x: Vec<f32> = (0..N)
+        .map(|_| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (seed >> 8) as f32 / (1 << 24) as f32 - 0.5
+        })
+        .collect();
+
+    for window in ALL {
+        window.fill(&mut w);
+        let mut buf: Vec<f32> = x.iter().zip(&w).map(|(x, w)| x * w).collect();
+        // Power of the windowed signal, relative to the window's own
+        let expected =
+            buf.iter().map(|v| v * v).sum::<f32>() / w.iter().map(|v| v * v).sum::<f32>();
+        fft.process(&mut buf, false).unwrap();
+        window
+            .correction()
+            .apply(&mut buf, CorrectionMode::Power)
+            .unwrap();
+        let power: f32 = buf.iter().map(|v| v * v).sum();
+        assert!((power - expected).abs() < 1e-3 * expected, "{window:?}");
+    }
+}
+
+#[test]
+fn test_density_and_mean_removal() {
+    let hann = Window::Hann.correction();
+    // N = 256 at 256 Hz: 1 Hz bins, 1.5 Hz noise bandwidth
+    assert!((hann.to_density(3.0, N, N as f32) - 2.0).abs() < 1e-6);
+
+    let mut x = [1.0, 2.0, 3.0, 6.0];
+    assert_eq!(remove_mean(&mut x), 3.0);
+    assert_eq!(x, [-2.0, -1.0, 0.0, 3.0]);
+    assert_eq!(remove_mean(&mut []), 0.0);
+}
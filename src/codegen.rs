@@ -0,0 +1,204 @@
+// src/codegen.rs
+
+//! Rust source generation for precomputed tables, meant to be called from
+//! a firmware project's `build.rs`.
+//!
+//! The tables are computed by the same planning code as at run time, so
+//! they are bit-identical to what `new` would produce. They are emitted as
+//! `pub const` arrays: copying one into the plan's storage and building
+//! the plan with `CplxFft::from_precomputed` replaces the trigonometry of
+//! `precompute` with a `memcpy`.
+//!
+//! ```ignore
+//! // build.rs
+//! let src = TableWriter::new()
+//!     .complex_plan("FFT256", 256, TableFormat::Q31)?
+//!     .window("HANN256", Window::Hann, 256, TableFormat::Fixed(15))?
+//!     .finish();
+//! std::fs::write(out_dir.join("tables.rs"), src)?;
+//! ```
+
+use crate::analysis::Window;
+use crate::common::{CplxFft, FftError, RealFft};
+use crate::fixed::core::TWIDDLE_FRAC;
+use crate::fixed::{ComplexFixed, Fixed};
+use crate::float::types::Complex32;
+use std::fmt::Write;
+use std::string::String;
+use std::vec;
+
+/// Element type of a generated table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    F32,
+    /// `Fixed<FRAC>` (or `ComplexFixed<FRAC>`) values.
+    Fixed(u32),
+}
+
+impl TableFormat {
+    /// The Q31 twiddle format used by the fixed-point plans.
+    pub const Q31: TableFormat = TableFormat::Fixed(TWIDDLE_FRAC);
+}
+
+/// Accumulates generated table definitions.
+#[derive(Debug, Default)]
+pub struct TableWriter {
+    out: String,
+}
+
+impl TableWriter {
+    pub fn new() -> Self {
+        Self {
+            out: String::from("// @generated by rs_simple_fft::codegen, do not edit\n"),
+        }
+    }
+
+    /// Emits `{name}_TWIDDLES` and `{name}_BITREV` for an N-point complex
+    /// plan. Twiddles are `F32` or [`TableFormat::Q31`].
+    pub fn complex_plan(
+        mut self,
+        name: &str,
+        n: usize,
+        format: TableFormat,
+    ) -> Result<Self, FftError> {
+        let mut bitrev = vec![0; n];
+        match format {
+            TableFormat::F32 => {
+                let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+                CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)?;
+                self.float_twiddles(name, &twiddles);
+            }
+            TableFormat::Fixed(TWIDDLE_FRAC) => {
+                let mut twiddles = vec![ComplexFixed::default(); n / 2];
+                CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)?;
+                self.fixed_twiddles(name, &twiddles);
+            }
+            TableFormat::Fixed(_) => return Err(FftError::OutOfRange),
+        }
+        self.bitrev(name, &bitrev);
+        Ok(self)
+    }
+
+    /// Same as [`Self::complex_plan`] for an N-point real plan.
+    pub fn real_plan(
+        mut self,
+        name: &str,
+        n: usize,
+        format: TableFormat,
+    ) -> Result<Self, FftError> {
+        let mut bitrev = vec![0; n / 2];
+        match format {
+            TableFormat::F32 => {
+                let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+                RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)?;
+                self.float_twiddles(name, &twiddles);
+            }
+            TableFormat::Fixed(TWIDDLE_FRAC) => {
+                let mut twiddles = vec![ComplexFixed::default(); n / 2];
+                RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)?;
+                self.fixed_twiddles(name, &twiddles);
+            }
+            TableFormat::Fixed(_) => return Err(FftError::OutOfRange),
+        }
+        self.bitrev(name, &bitrev);
+        Ok(self)
+    }
+
+    /// Emits the N-point `window` as `name`, in any format. Fixed-point
+    /// values saturate to the `i32` range, so a peak of 1.0 is
+    /// `i32::MAX` in Q31.
+    pub fn window(
+        mut self,
+        name: &str,
+        window: Window,
+        n: usize,
+        format: TableFormat,
+    ) -> Result<Self, FftError> {
+        if n == 0 {
+            return Err(FftError::SizeMismatch);
+        }
+        let mut w = vec![0.0; n];
+        window.fill(&mut w);
+        match format {
+            TableFormat::F32 => {
+                let _ = writeln!(self.out, "pub const {name}: [f32; {n}] = [");
+                for v in &w {
+                    let _ = writeln!(self.out, "    {v:?},");
+                }
+            }
+            TableFormat::Fixed(frac) => {
+                if frac > 31 {
+                    return Err(FftError::OutOfRange);
+                }
+                let _ = writeln!(
+                    self.out,
+                    "pub const {name}: [rs_simple_fft::Fixed<{frac}>; {n}] = ["
+                );
+                for &v in &w {
+                    // Fixed<0> rounds and saturates the pre-scaled value
+                    let bits = Fixed::<0>::from_f64(v as f64 * (1u64 << frac) as f64).to_bits();
+                    let _ = writeln!(self.out, "    rs_simple_fft::Fixed::from_bits({bits}),");
+                }
+            }
+        }
+        self.out.push_str("];\n");
+        Ok(self)
+    }
+
+    /// Returns the generated source.
+    pub fn finish(self) -> String {
+        self.out
+    }
+
+    fn float_twiddles(&mut self, name: &str, twiddles: &[Complex32]) {
+        let _ = writeln!(
+            self.out,
+            "pub const {name}_TWIDDLES: [rs_simple_fft::float::Complex32; {}] = [",
+            twiddles.len()
+        );
+        for t in twiddles {
+            let _ = writeln!(
+                self.out,
+                "    rs_simple_fft::float::Complex32::new({:?}, {:?}),",
+                t.re, t.im
+            );
+        }
+        self.out.push_str("];\n");
+    }
+
+    fn fixed_twiddles(&mut self, name: &str, twiddles: &[ComplexFixed<TWIDDLE_FRAC>]) {
+        let _ = writeln!(
+            self.out,
+            "pub const {name}_TWIDDLES: [rs_simple_fft::ComplexFixed<{TWIDDLE_FRAC}>; {}] = [",
+            twiddles.len()
+        );
+        for t in twiddles {
+            let _ = writeln!(
+                self.out,
+                "    rs_simple_fft::ComplexFixed {{ re: rs_simple_fft::Fixed::from_bits({}), im: rs_simple_fft::Fixed::from_bits({}) }},",
+                t.re.to_bits(),
+                t.im.to_bits()
+            );
+        }
+        self.out.push_str("];\n");
+    }
+
+    fn bitrev(&mut self, name: &str, bitrev: &[usize]) {
+        let _ = write!(
+            self.out,
+            "pub const {name}_BITREV: [usize; {}] = [",
+            bitrev.len()
+        );
+        for (i, b) in bitrev.iter().enumerate() {
+            if i % 16 == 0 {
+                self.out.push_str("\n   ");
+            }
+            let _ = write!(self.out, " {b},");
+        }
+        self.out.push_str("\n];\n");
+    }
+}
+
+#[cfg(test)]
+#[path = "codegen_tests.rs"]
+mod tests;
@@ -0,0 +1,76 @@
+use super::*;
+use std::vec::Vec;
+
+/// Values of the `[...]` array that follows `name` in the generated source.
+fn entries<'a>(src: &'a str, name: &str) -> Vec<&'a str> {
+    let start = src.find(&format!("pub const {name}:")).unwrap();
+    let body = &src[start..];
+    let body = &body[body.find("= [").unwrap() + 3..body.find("];").unwrap()];
+    body.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+#[test]
+fn test_float_plan_matches_runtime_tables() {
+    let n = 32;
+    let src = TableWriter::new()
+        .complex_plan("FFT32", n, TableFormat::F32)
+        .unwrap()
+        .finish();
+    assert!(src.starts_with("// @generated"));
+
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    let values: Vec<f32> = entries(&src, "FFT32_TWIDDLES")
+        .iter()
+        .map(|e| {
+            e.trim_start_matches("rs_simple_fft::float::Complex32::new(")
+                .trim_end_matches(')')
+                .parse()
+                .unwrap()
+        })
+        .collect();
+    let expected: Vec<f32> = twiddles.iter().flat_map(|t| [t.re, t.im]).collect();
+    // Bit-exact: Debug formatting round-trips f32
+    assert_eq!(values, expected);
+
+    let bits: Vec<usize> = entries(&src, "FFT32_BITREV")
+        .iter()
+        .map(|e| e.parse().unwrap())
+        .collect();
+    assert_eq!(bits, bitrev);
+}
+
+#[test]
+fn test_fixed_tables_and_formats() {
+    let src = TableWriter::new()
+        .real_plan("RFFT16", 16, TableFormat::Q31)
+        .unwrap()
+        .window("HANN8", Window::Hann, 8, TableFormat::Fixed(15))
+        .unwrap()
+        .finish();
+    assert!(src.contains("pub const RFFT16_TWIDDLES: [rs_simple_fft::ComplexFixed<31>; 8]"));
+    assert!(src.contains("pub const RFFT16_BITREV: [usize; 8]"));
+    // Hann: 0, 0.146, 0.5, 0.854, 1.0, ...
+    let hann = entries(&src, "HANN8");
+    assert_eq!(hann.len(), 8);
+    assert_eq!(hann[2], "rs_simple_fft::Fixed::from_bits(16384)");
+    assert_eq!(hann[4], "rs_simple_fft::Fixed::from_bits(32768)");
+
+    assert_eq!(
+        TableWriter::new()
+            .complex_plan("BAD", 16, TableFormat::Fixed(15))
+            .err(),
+        Some(FftError::OutOfRange)
+    );
+    assert_eq!(
+        TableWriter::new()
+            .complex_plan("BAD", 12, TableFormat::F32)
+            .err(),
+        Some(FftError::NotPowerOfTwo)
+    );
+}
@@ -1,13 +1,28 @@
 // src/common.rs
 
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum FftError {
     SizeMismatch,
     NotPowerOfTwo,
     BufferTooSmall,
     InvalidStride,
+    /// A value does not fit the target fixed-point format.
+    OutOfRange,
+    /// Serialized plan data or precomputed tables are malformed, or were
+    /// made for another plan type or size.
+    InvalidFormat,
+    /// A shared plan is already borrowed mutably (see the `FftProcess`
+    /// impls for `RefCell`).
+    Busy,
+    /// A watchdog stopped the transform between stages; the buffer holds
+    /// partial results.
+    DeadlineExceeded,
+    /// The input holds a NaN or an infinity (see [`Hygiene::Detect`]).
+    NonFinite,
 }
 
+use crate::fixed::core::swap_table_len;
 use core::fmt;
 
 impl fmt::Display for FftError {
@@ -17,6 +32,11 @@ impl fmt::Display for FftError {
             FftError::NotPowerOfTwo => write!(f, "Size must be a power of 2"),
             FftError::BufferTooSmall => write!(f, "Auxiliary buffers are too small"),
             FftError::InvalidStride => write!(f, "Invalid stride configuration"),
+            FftError::OutOfRange => write!(f, "Value does not fit the fixed-point format"),
+            FftError::InvalidFormat => write!(f, "Malformed or mismatched plan tables"),
+            FftError::Busy => write!(f, "Plan is already borrowed"),
+            FftError::DeadlineExceeded => write!(f, "Transform stopped by its watchdog"),
+            FftError::NonFinite => write!(f, "Input contains NaN or infinity"),
         }
     }
 }
@@ -28,20 +48,321 @@ pub trait FftProcess<T> {
 #[cfg(feature = "std")]
 impl std::error::Error for FftError {}
 
+/// Normalization contract of a plan, shared by the complex and real paths.
+///
+/// The 1/N factor is always applied as a 1/2 shift per butterfly stage, which
+/// also keeps fixed-point data from overflowing in the scaled direction.
+/// Whatever the variant, `inverse(forward(x)) == x` holds only for
+/// `Forward` and `Inverse`; with `None` the round trip yields `N * x`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Scaling {
+    /// Neither direction is scaled (unnormalized DFT and IDFT).
+    /// Fixed-point users must provide log2(N) bits of headroom in both directions.
+    None,
+    /// The forward transform is scaled by 1/N, the inverse is not.
+    Forward,
+    /// The forward transform is not scaled, the inverse is scaled by 1/N.
+    #[default]
+    Inverse,
+}
+
+impl Scaling {
+    /// Returns true if the transform in the given direction is scaled by 1/N.
+    #[inline]
+    pub const fn is_scaled(self, inverse: bool) -> bool {
+        match self {
+            Scaling::None => false,
+            Scaling::Forward => !inverse,
+            Scaling::Inverse => inverse,
+        }
+    }
+
+    /// Factor that turns `forward(a) * conj(forward(b))` followed by an
+    /// inverse transform into the plain N-point correlation sum.
+    pub(crate) fn correlation_norm(self, n: usize) -> f32 {
+        let n = n as f32;
+        let forward = if self.is_scaled(false) { n } else { 1.0 };
+        let inverse = if self.is_scaled(true) { 1.0 } else { 1.0 / n };
+        forward * forward * inverse
+    }
+}
+
+/// Input check a float plan runs before transforming, in `process` and
+/// every other entry point that takes samples.
+///
+/// A single NaN or infinity spreads to every bin of the spectrum, and
+/// subnormal values can slow a transform down by orders of magnitude on
+/// CPUs that handle them in microcode, typically in feedback loops that
+/// decay towards zero. Fixed-point words are always finite, so fixed plans
+/// ignore this setting.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Hygiene {
+    /// The input is used as is.
+    #[default]
+    Off,
+    /// A NaN or infinity fails the call with `FftError::NonFinite`,
+    /// leaving the buffer untouched.
+    Detect,
+    /// NaNs, infinities and subnormals are replaced by zero.
+    Scrub,
+}
+
+/// Butterfly ordering used by a plan's core.
+///
+/// Both variants compute the same transform and produce naturally ordered
+/// output from naturally ordered input; they only differ in where the
+/// bit-reversal permutation happens.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Algorithm {
+    /// Decimation in time: permute the input, then run the butterflies with
+    /// growing stride.
+    #[default]
+    Dit,
+    /// Decimation in frequency: run the butterflies with shrinking stride on
+    /// the natural input, then permute the bit-reversed output.
+    Dif,
+}
+
 /// Generic RealFFT struct.
 /// T represents the Complex Number type used for twiddle factors.
+/// `bitrev` holds the bit-reversal permutation as a compact list of swap pairs.
 pub struct RealFft<'a, T> {
     pub twiddles: &'a mut [T],
     pub bitrev: &'a mut [usize],
     pub n: usize,
+    pub scaling: Scaling,
+    pub algorithm: Algorithm,
+    pub hygiene: Hygiene,
+}
+
+impl<T> RealFft<'_, T> {
+    /// Selects the normalization applied by `process`.
+    pub fn with_scaling(mut self, scaling: Scaling) -> Self {
+        event!(debug, "n = {} plan: scaling {:?}", self.n, scaling);
+        self.scaling = scaling;
+        self
+    }
+
+    /// Selects the butterfly ordering (DIT or DIF) used by the core.
+    pub fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        event!(debug, "n = {} plan: algorithm {:?}", self.n, algorithm);
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Selects the input check run on every transform (float plans only).
+    pub fn with_hygiene(mut self, hygiene: Hygiene) -> Self {
+        event!(debug, "n = {} plan: hygiene {:?}", self.n, hygiene);
+        self.hygiene = hygiene;
+        self
+    }
 }
 
 /// Generic CplxFft struct.
 /// T represents the Complex Number type used for twiddle factors.
+/// `bitrev` holds the bit-reversal permutation as a compact list of swap pairs.
 pub struct CplxFft<'a, T> {
     pub twiddles: &'a mut [T],
     pub bitrev: &'a mut [usize],
     pub n: usize,
+    pub scaling: Scaling,
+    pub algorithm: Algorithm,
+    pub hygiene: Hygiene,
+}
+
+/// Spot check of a bit-reversal swap list (see `precompute_swaps`) for size
+/// N: the first pair must be `(1, N/2)`, the exchange of `0..01` and
+/// `10..0`.
+pub(crate) fn swaps_look_valid(bitrev: &[usize], n: usize) -> bool {
+    n < 4 || (bitrev.len() >= 2 && bitrev[0] == 1 && bitrev[1] == n / 2)
+}
+
+/// Full check of a bit-reversal swap list: the first `swap_table_len(n)`
+/// entries must be exactly the pairs `precompute_swaps` writes, i.e.
+/// `(i, rev(i))` with `i < rev(i)`, in increasing order of `i`.
+///
+/// This is an O(N) pass, so it runs where tables enter a plan
+/// (`from_precomputed`, `import`) rather than on every transform.
+pub(crate) fn swaps_are_exact(bitrev: &[usize], n: usize) -> bool {
+    let bits = n.trailing_zeros();
+    let mut prev = 0;
+    bitrev[..swap_table_len(n)].chunks_exact(2).all(|pair| {
+        let (i, j) = (pair[0], pair[1]);
+        // Reached only with 0 < i < j < n, so n >= 4 and the shift is in range
+        let exact = i > prev && i < j && j < n && i.reverse_bits() >> (usize::BITS - bits) == j;
+        prev = i;
+        exact
+    })
+}
+
+/// Table invariants the cores index by: a power-of-two size of at least
+/// `min_n`, N/2 twiddles and the full swap list for a complex core of
+/// `core_n` points, starting with the pair `swaps_look_valid` expects.
+/// Every check is O(1), so it can run on each call.
+fn check_tables(
+    n: usize,
+    min_n: usize,
+    twiddles: usize,
+    bitrev: &[usize],
+    core_n: usize,
+) -> Result<(), FftError> {
+    let result = if !n.is_power_of_two() || n < min_n {
+        Err(FftError::NotPowerOfTwo)
+    } else if twiddles < n / 2 || bitrev.len() < swap_table_len(core_n) {
+        Err(FftError::BufferTooSmall)
+    } else if !swaps_look_valid(bitrev, core_n) {
+        Err(FftError::InvalidFormat)
+    } else {
+        Ok(())
+    };
+    if let Err(e) = result {
+        event!(
+            warn,
+            "plan rejected ({:?}): n = {}, {} twiddles, {} swap entries",
+            e,
+            n,
+            twiddles,
+            bitrev.len()
+        );
+    }
+    result
+}
+
+impl<T> RealFft<'_, T> {
+    /// Checks that `n`, the table lengths and the start of the swap list
+    /// are consistent.
+    ///
+    /// The fields are public, so a plan can be edited after construction;
+    /// every `process` call runs this O(1) check and returns an error
+    /// instead of panicking on tables that no longer fit. The swap list
+    /// itself is checked in full where it enters the plan.
+    pub fn validate(&self) -> Result<(), FftError> {
+        check_tables(self.n, 2, self.twiddles.len(), self.bitrev, self.n / 2)
+    }
+}
+
+impl<T> CplxFft<'_, T> {
+    /// Checks that `n`, the table lengths and the start of the swap list
+    /// are consistent.
+    ///
+    /// See [`RealFft::validate`].
+    pub fn validate(&self) -> Result<(), FftError> {
+        check_tables(self.n, 1, self.twiddles.len(), self.bitrev, self.n)
+    }
+}
+
+impl<T> CplxFft<'_, T> {
+    /// Selects the normalization applied by `process`.
+    pub fn with_scaling(mut self, scaling: Scaling) -> Self {
+        event!(debug, "n = {} plan: scaling {:?}", self.n, scaling);
+        self.scaling = scaling;
+        self
+    }
+
+    /// Selects the butterfly ordering (DIT or DIF) used by the core.
+    pub fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        event!(debug, "n = {} plan: algorithm {:?}", self.n, algorithm);
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Selects the input check run on every transform (float plans only).
+    pub fn with_hygiene(mut self, hygiene: Hygiene) -> Self {
+        event!(debug, "n = {} plan: hygiene {:?}", self.n, hygiene);
+        self.hygiene = hygiene;
+        self
+    }
+}
+
+impl<T> CplxFft<'_, T> {
+    /// Number of scratch elements needed by the operations of this plan that
+    /// cannot run fully in place, such as `process_pair`.
+    pub fn scratch_len(&self) -> usize {
+        self.n
+    }
+}
+
+impl<T> RealFft<'_, T> {
+    /// Number of complex scratch elements needed by the operations of this
+    /// plan that cannot run fully in place, such as `process_with_scratch`:
+    /// the N/2 points of the half-size complex transform.
+    pub fn scratch_len(&self) -> usize {
+        self.n / 2
+    }
+}
+
+/// Progress of a transform run one stage at a time with a complex plan's
+/// `process_stage`.
+///
+/// Stage 0 is the bit-reversal permutation and stages 1 to log2(N) are the
+/// butterflies, so an N-point transform takes [`CplxFft::stage_count`]
+/// calls. The state only records where the transform stands; the buffer
+/// and plan must be the same on every call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StageState {
+    inverse: bool,
+    next: u32,
+}
+
+impl StageState {
+    /// A transform in the given direction that has not started yet.
+    pub const fn new(inverse: bool) -> Self {
+        Self { inverse, next: 0 }
+    }
+
+    pub fn inverse(&self) -> bool {
+        self.inverse
+    }
+
+    /// Index of the stage the next `process_stage` call will run.
+    pub fn next_stage(&self) -> usize {
+        self.next as usize
+    }
+
+    pub(crate) fn advance(&mut self) {
+        self.next += 1;
+    }
+}
+
+impl<T> CplxFft<'_, T> {
+    /// Number of `process_stage` calls an N-point transform takes:
+    /// the permutation plus log2(N) butterfly stages.
+    pub fn stage_count(&self) -> usize {
+        self.n.trailing_zeros() as usize + 1
+    }
+}
+
+/// Caller-provided temporary storage for operations that cannot run fully in
+/// place.
+///
+/// The crate never allocates: such operations borrow their working memory
+/// from a `Scratch`, sized from the plan's `scratch_len()`, so memory use is
+/// fixed and known up front.
+pub struct Scratch<'a, T> {
+    buffer: &'a mut [T],
+}
+
+impl<'a, T> Scratch<'a, T> {
+    pub fn new(buffer: &'a mut [T]) -> Self {
+        Self { buffer }
+    }
+
+    /// Capacity in elements.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Borrows the first `len` elements, or fails with `BufferTooSmall`.
+    pub fn take(&mut self, len: usize) -> Result<&mut [T], FftError> {
+        self.buffer.get_mut(..len).ok_or(FftError::BufferTooSmall)
+    }
 }
 
 /// Trait to handle generic Scalar operations for FFT packing/unpacking.
@@ -50,11 +371,14 @@ pub trait FftNum: Copy + PartialEq + core::fmt::Debug {
     type Complex: Copy + core::fmt::Debug;
 
     fn from_f64(v: f64) -> Self;
+    fn to_f64(self) -> f64;
     fn zero() -> Self;
     fn val_to_complex(re: Self, im: Self) -> Self::Complex;
     fn complex_re(c: &Self::Complex) -> Self;
     fn complex_im(c: &Self::Complex) -> Self;
     fn negate(self) -> Self;
+    /// Returns (a + b) / 2 without intermediate overflow.
+    fn average(a: Self, b: Self) -> Self;
 }
 
 #[cfg(feature = "std")]
@@ -63,21 +387,25 @@ use std::f32;
 #[cfg(not(feature = "std"))]
 use core::f32;
 
-// Implement for standard floats (using num_complex)
+// Implement for standard floats (using the float path complex type)
 impl FftNum for f32 {
-    type Complex = num_complex::Complex<f32>;
+    type Complex = crate::float::types::Complex32;
 
     #[inline]
     fn from_f64(v: f64) -> Self {
         v as f32
     }
     #[inline]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+    #[inline]
     fn zero() -> Self {
         0.0
     }
     #[inline]
     fn val_to_complex(re: Self, im: Self) -> Self::Complex {
-        num_complex::Complex::new(re, im)
+        crate::float::types::Complex32::new(re, im)
     }
     #[inline]
     fn complex_re(c: &Self::Complex) -> Self {
@@ -91,6 +419,26 @@ impl FftNum for f32 {
     fn negate(self) -> Self {
         -self
     }
+    #[inline]
+    fn average(a: Self, b: Self) -> Self {
+        (a + b) * 0.5
+    }
+}
+
+/// Bins `lo..=hi` of an N-point transform inside the band `[f_low, f_high]`
+/// Hz, limited to the strictly positive frequencies below Nyquist.
+pub(crate) fn band_bins(
+    n: usize,
+    (f_low, f_high): (f32, f32),
+    sample_rate: f32,
+) -> Result<(usize, usize), FftError> {
+    let df = sample_rate / n as f32;
+    let lo = libm::ceilf(f_low / df).max(1.0);
+    let hi = libm::floorf(f_high / df).min((n / 2) as f32 - 1.0);
+    if !(df > 0.0 && lo <= hi) {
+        return Err(FftError::OutOfRange);
+    }
+    Ok((lo as usize, hi as usize))
 }
 
 /// Expands the packed Real FFT format into a full complex array of size N.
@@ -137,3 +485,99 @@ pub fn pack_rfft_spectrum<T: FftNum>(full: &[T::Complex], output: &mut [T]) {
         output[2 * k + 1] = T::complex_im(&full[k]);
     }
 }
+
+/// Expands the packed Real FFT format into the one-sided spectrum of N/2 + 1 bins.
+///
+/// Unlike [`unpack_rfft_spectrum`], the redundant negative frequencies are not
+/// written, so `output` only needs N/2 + 1 elements.
+pub fn unpack_rfft_spectrum_half<T: FftNum>(packed: &[T], output: &mut [T::Complex]) {
+    let n = packed.len();
+    assert_eq!(n % 2, 0, "Input size must be even");
+    assert_eq!(
+        output.len(),
+        n / 2 + 1,
+        "Output buffer must be size N/2 + 1"
+    );
+
+    output[0] = T::val_to_complex(packed[0], T::zero());
+    output[n / 2] = T::val_to_complex(packed[1], T::zero());
+
+    for k in 1..n / 2 {
+        output[k] = T::val_to_complex(packed[2 * k], packed[2 * k + 1]);
+    }
+}
+
+/// Packs a one-sided spectrum of N/2 + 1 bins into the compact Real FFT format.
+/// The imaginary parts of the DC and Nyquist bins are discarded.
+pub fn pack_rfft_spectrum_half<T: FftNum>(half: &[T::Complex], output: &mut [T]) {
+    let n = output.len();
+    assert_eq!(n % 2, 0, "Output size must be even");
+    assert_eq!(half.len(), n / 2 + 1, "Input spectrum must be size N/2 + 1");
+
+    output[0] = T::complex_re(&half[0]);
+    output[1] = T::complex_re(&half[n / 2]);
+
+    for k in 1..n / 2 {
+        output[2 * k] = T::complex_re(&half[k]);
+        output[2 * k + 1] = T::complex_im(&half[k]);
+    }
+}
+
+/// Converts, in place, a packed Real FFT spectrum into the interleaved
+/// one-sided layout `[re0, im0, re1, im1, ..., re(N/2), im(N/2)]`.
+///
+/// `buffer` must hold N + 2 values, the first N being the packed spectrum.
+/// Only the Nyquist term moves, so the conversion costs O(1) and no second
+/// buffer is needed.
+pub fn unpack_rfft_spectrum_in_place<T: FftNum>(buffer: &mut [T]) {
+    let len = buffer.len();
+    assert!(
+        len >= 4 && len.is_multiple_of(2),
+        "Buffer must be size N + 2"
+    );
+    let n = len - 2;
+
+    buffer[n] = buffer[1];
+    buffer[n + 1] = T::zero();
+    buffer[1] = T::zero();
+}
+
+/// Reverses [`unpack_rfft_spectrum_in_place`]: moves the Nyquist term back into
+/// slot 1, leaving the packed spectrum in the first N values of `buffer`.
+pub fn pack_rfft_spectrum_in_place<T: FftNum>(buffer: &mut [T]) {
+    let len = buffer.len();
+    assert!(
+        len >= 4 && len.is_multiple_of(2),
+        "Buffer must be size N + 2"
+    );
+    let n = len - 2;
+
+    buffer[1] = buffer[n];
+    buffer[n] = T::zero();
+    buffer[n + 1] = T::zero();
+}
+
+/// Forces conjugate symmetry on a full complex spectrum of size N, in place.
+///
+/// Each pair X[k], X[N-k] is replaced by the average of X[k] and conj(X[N-k]),
+/// and the DC and Nyquist bins are made purely real. The result is the
+/// spectrum of the real signal closest to the input, which makes it safe to
+/// pack and feed to an inverse real transform after editing bins.
+pub fn enforce_hermitian<T: FftNum>(spectrum: &mut [T::Complex]) {
+    let n = spectrum.len();
+    assert_eq!(n % 2, 0, "Spectrum size must be even");
+
+    spectrum[0] = T::val_to_complex(T::complex_re(&spectrum[0]), T::zero());
+    spectrum[n / 2] = T::val_to_complex(T::complex_re(&spectrum[n / 2]), T::zero());
+
+    for k in 1..n / 2 {
+        let pos = spectrum[k];
+        let neg = spectrum[n - k];
+
+        let re = T::average(T::complex_re(&pos), T::complex_re(&neg));
+        let im = T::average(T::complex_im(&pos), T::negate(T::complex_im(&neg)));
+
+        spectrum[k] = T::val_to_complex(re, im);
+        spectrum[n - k] = T::val_to_complex(re, T::negate(im));
+    }
+}
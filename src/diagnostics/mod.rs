@@ -0,0 +1,5 @@
+pub mod parseval;
+pub mod saturation;
+
+pub use parseval::{Normalization, ParsevalReport, parseval_check, parseval_check_complex};
+pub use saturation::{SaturationReport, saturation_prescan};
@@ -0,0 +1,126 @@
+// src/diagnostics/parseval.rs
+
+use crate::common::{FftError, FftNum};
+
+/// Energy relation between a signal and its forward transform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// `sum|X|² = N * sum|x|²`: the forward transform is unscaled
+    /// (`Scaling::None` or `Scaling::Inverse`).
+    Unscaled,
+    /// `sum|X|² = sum|x|²`: an orthonormal (1/sqrt(N)) convention.
+    Unitary,
+    /// `sum|X|² = sum|x|² / N`: the forward transform is scaled by 1/N
+    /// (`Scaling::Forward`).
+    ScaledByN,
+}
+
+/// Result of a Parseval check.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParsevalReport {
+    /// Transform size N.
+    pub n: usize,
+    /// `sum|x|²` over the time-domain samples.
+    pub time_energy: f64,
+    /// `sum|X|²` over all N bins of the spectrum.
+    pub spectrum_energy: f64,
+    /// Convention matched within the tolerance, or `None` if the energies
+    /// fit none of them (or the signal is all zeros).
+    pub normalization: Option<Normalization>,
+}
+
+impl ParsevalReport {
+    /// `spectrum_energy / time_energy`.
+    pub fn ratio(&self) -> f64 {
+        self.spectrum_energy / self.time_energy
+    }
+
+    fn new(n: usize, time_energy: f64, spectrum_energy: f64, tolerance: f64) -> Self {
+        let mut report = Self {
+            n,
+            time_energy,
+            spectrum_energy,
+            normalization: None,
+        };
+        if time_energy > 0.0 {
+            let ratio = report.ratio();
+            let nf = n as f64;
+            report.normalization = [
+                (Normalization::Unscaled, nf),
+                (Normalization::Unitary, 1.0),
+                (Normalization::ScaledByN, 1.0 / nf),
+            ]
+            .into_iter()
+            .find(|&(_, expected)| (ratio / expected - 1.0).abs() <= tolerance)
+            .map(|(norm, _)| norm);
+        }
+        report
+    }
+}
+
+/// Checks energy conservation between a real signal and its packed real FFT
+/// (as produced by `RealFft::process`), for either the float or the
+/// fixed-point path.
+///
+/// The negative frequencies omitted by the packed format are accounted for,
+/// so the spectrum energy covers all N bins. `tolerance` is relative: a
+/// convention is reported when the measured ratio is within
+/// `expected * (1 ± tolerance)`.
+pub fn parseval_check<T: FftNum>(
+    time: &[T],
+    spectrum: &[T],
+    tolerance: f64,
+) -> Result<ParsevalReport, FftError> {
+    let n = time.len();
+    if spectrum.len() != n || n < 2 || !n.is_multiple_of(2) {
+        return Err(FftError::SizeMismatch);
+    }
+
+    let time_energy = time.iter().map(|&x| sqr(x)).sum();
+    let spectrum_energy = sqr(spectrum[0])
+        + sqr(spectrum[1])
+        + 2.0 * spectrum[2..].iter().map(|&x| sqr(x)).sum::<f64>();
+
+    Ok(ParsevalReport::new(
+        n,
+        time_energy,
+        spectrum_energy,
+        tolerance,
+    ))
+}
+
+/// Complex-path counterpart of [`parseval_check`], taking the N-point
+/// signal and its N-bin spectrum.
+pub fn parseval_check_complex<T: FftNum>(
+    time: &[T::Complex],
+    spectrum: &[T::Complex],
+    tolerance: f64,
+) -> Result<ParsevalReport, FftError> {
+    let n = time.len();
+    if spectrum.len() != n || n == 0 {
+        return Err(FftError::SizeMismatch);
+    }
+
+    let energy = |data: &[T::Complex]| -> f64 {
+        data.iter()
+            .map(|c| sqr(T::complex_re(c)) + sqr(T::complex_im(c)))
+            .sum()
+    };
+
+    Ok(ParsevalReport::new(
+        n,
+        energy(time),
+        energy(spectrum),
+        tolerance,
+    ))
+}
+
+#[inline]
+fn sqr<T: FftNum>(x: T) -> f64 {
+    let x = x.to_f64();
+    x * x
+}
+
+#[cfg(test)]
+#[path = "parseval_tests.rs"]
+mod tests;
@@ -0,0 +1,81 @@
+use super::*;
+use crate::common::{CplxFft, RealFft, Scaling};
+use crate::fixed::{ComplexFixed, Fixed};
+use crate::float::types::Complex32;
+
+const N: usize = 64;
+
+fn signal(i: usize) -> f32 {
+    0.4 * (i as f32 * 0.37).sin() + 0.2 * (i as f32 * 1.9).cos()
+}
+
+#[test]
+fn test_detects_float_real_scaling() {
+    let time: Vec<f32> = (0..N).map(signal).collect();
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let mut fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    for (scaling, expected) in [
+        (Scaling::Inverse, Normalization::Unscaled),
+        (Scaling::None, Normalization::Unscaled),
+        (Scaling::Forward, Normalization::ScaledByN),
+    ] {
+        fft = fft.with_scaling(scaling);
+        let mut spectrum = time.clone();
+        fft.process(&mut spectrum, false).unwrap();
+
+        let report = parseval_check(&time, &spectrum, 1e-5).unwrap();
+        assert_eq!(report.normalization, Some(expected));
+        assert_eq!(report.n, N);
+    }
+
+    // A spectrum of a different signal matches no convention
+    let doubled: Vec<f32> = time.iter().map(|x| x * 2.0).collect();
+    let mut spectrum = doubled.clone();
+    fft.process(&mut spectrum, false).unwrap();
+    assert_eq!(
+        parseval_check(&time, &spectrum, 1e-3)
+            .unwrap()
+            .normalization,
+        None
+    );
+}
+
+#[test]
+fn test_complex_and_fixed_paths() {
+    let time: Vec<Complex32> = (0..N)
+        .map(|i| Complex32::new(signal(i), signal(i + 7)))
+        .collect();
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut spectrum = time.clone();
+    fft.process(&mut spectrum, false).unwrap();
+    let report = parseval_check_complex::<f32>(&time, &spectrum, 1e-5).unwrap();
+    assert_eq!(report.normalization, Some(Normalization::Unscaled));
+
+    // Q15 forward with 1/N scaling keeps within a few LSB of the energy
+    let q = |x: f32| Fixed::<15>::from_f64(x as f64);
+    let time: Vec<Fixed<15>> = (0..N).map(|i| q(signal(i))).collect();
+    let zero = ComplexFixed::ZERO;
+    let mut twiddles = vec![zero; N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<ComplexFixed<31>>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_scaling(Scaling::Forward);
+    let mut spectrum = time.clone();
+    fft.process(&mut spectrum, false).unwrap();
+    let report = parseval_check(&time, &spectrum, 0.05).unwrap();
+    assert_eq!(report.normalization, Some(Normalization::ScaledByN));
+}
+
+#[test]
+fn test_invalid_input() {
+    assert_eq!(
+        parseval_check::<f32>(&[0.0; 4], &[0.0; 2], 0.1),
+        Err(FftError::SizeMismatch)
+    );
+    let silent = parseval_check::<f32>(&[0.0; 4], &[0.0; 4], 0.1).unwrap();
+    assert_eq!(silent.normalization, None);
+}
@@ -0,0 +1,100 @@
+// src/diagnostics/saturation.rs
+
+use crate::common::FftError;
+use crate::fixed::Fixed;
+
+/// Result of [`saturation_prescan`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SaturationReport {
+    /// Number of samples scanned.
+    pub len: usize,
+    /// Samples sitting at either full-scale code.
+    pub clipped: usize,
+    /// Longest run of consecutive clipped samples. Clipping of a real
+    /// waveform flattens its peaks into runs, while a lone full-scale
+    /// sample is more likely a legitimate peak.
+    pub longest_run: usize,
+    /// Largest absolute sample value.
+    pub peak: f64,
+    /// Root mean square of the samples.
+    pub rms: f64,
+}
+
+impl SaturationReport {
+    /// `peak / rms`: about 1.41 for a sine, 1 for a square wave. Clipping
+    /// pushes it down. NaN for an all-zero capture.
+    pub fn crest_factor(&self) -> f64 {
+        self.peak / self.rms
+    }
+
+    /// Crest factor in dB.
+    pub fn crest_factor_db(&self) -> f64 {
+        20.0 * libm::log10(self.crest_factor())
+    }
+
+    /// Share of clipped samples, in `[0, 1]`.
+    pub fn clipped_fraction(&self) -> f64 {
+        self.clipped as f64 / self.len as f64
+    }
+}
+
+/// Scans a fixed-point capture for ADC saturation before it is transformed.
+///
+/// `full_scale` is the magnitude of the converter's range in the samples'
+/// format, e.g. 1.0 for a 16-bit ADC read as Q15. A two's-complement
+/// converter tops out one LSB below it, so a sample counts as clipped at
+/// `full_scale - 1 LSB` and above, or at `-full_scale` and below. The scan
+/// only reads the samples.
+pub fn saturation_prescan<const FRAC: u32>(
+    samples: &[Fixed<FRAC>],
+    full_scale: Fixed<FRAC>,
+) -> Result<SaturationReport, FftError> {
+    if samples.is_empty() {
+        return Err(FftError::SizeMismatch);
+    }
+    let top = full_scale.to_bits() as i64;
+    if top <= 1 {
+        return Err(FftError::OutOfRange);
+    }
+
+    let mut report = SaturationReport {
+        len: samples.len(),
+        clipped: 0,
+        longest_run: 0,
+        peak: 0.0,
+        rms: 0.0,
+    };
+    let mut run = 0;
+    let mut peak = 0i64;
+    let mut energy = 0.0f64;
+    for x in samples {
+        let bits = x.to_bits() as i64;
+        if bits >= top - 1 || bits <= -top {
+            report.clipped += 1;
+            run += 1;
+            report.longest_run = report.longest_run.max(run);
+        } else {
+            run = 0;
+        }
+        peak = peak.max(bits.abs());
+        energy += (bits * bits) as f64;
+    }
+
+    let lsb = libm::ldexp(1.0, -(FRAC as i32));
+    report.peak = peak as f64 * lsb;
+    report.rms = libm::sqrt(energy / samples.len() as f64) * lsb;
+    if report.clipped > 0 {
+        event!(
+            warn,
+            "{} of {} input samples at full scale (longest run {})",
+            report.clipped,
+            report.len,
+            report.longest_run
+        );
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+#[path = "saturation_tests.rs"]
+mod tests;
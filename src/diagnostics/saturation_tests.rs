@@ -0,0 +1,49 @@
+use super::*;
+use core::f64::consts::PI;
+
+/// A 16-bit ADC capture of `amplitude * sin`, as Q15.
+fn capture(amplitude: f64) -> Vec<Fixed<15>> {
+    (0..256)
+        .map(|i| {
+            let x = amplitude * (2.0 * PI * i as f64 / 32.0).sin();
+            let code = (x * 32768.0).round().clamp(-32768.0, 32767.0);
+            Fixed::from_bits(code as i32)
+        })
+        .collect()
+}
+
+#[test]
+fn test_clean_sine() {
+    let report = saturation_prescan(&capture(0.5), Fixed::from_f64(1.0)).unwrap();
+    assert_eq!(report.len, 256);
+    assert_eq!(report.clipped, 0);
+    assert_eq!(report.longest_run, 0);
+    assert!((report.peak - 0.5).abs() < 1e-4);
+    assert!((report.crest_factor() - core::f64::consts::SQRT_2).abs() < 1e-3);
+    assert!((report.crest_factor_db() - 3.01).abs() < 0.01);
+}
+
+#[test]
+fn test_overdriven_sine() {
+    let report = saturation_prescan(&capture(2.0), Fixed::from_f64(1.0)).unwrap();
+    // The sine is beyond full scale two thirds of the time
+    assert!(report.clipped > 256 / 2, "{report:?}");
+    assert!(report.longest_run >= 5);
+    assert!(report.clipped_fraction() > 0.5);
+    // Flattened peaks bring the crest factor towards a square wave's
+    assert!(report.crest_factor() < 1.15);
+}
+
+#[test]
+fn test_invalid_arguments() {
+    assert_eq!(
+        saturation_prescan::<15>(&[], Fixed::from_f64(1.0)),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        saturation_prescan(&capture(0.5), Fixed::from_bits(0)),
+        Err(FftError::OutOfRange)
+    );
+    let silent = saturation_prescan(&[Fixed::<15>::ZERO; 4], Fixed::from_f64(1.0)).unwrap();
+    assert!(silent.crest_factor().is_nan());
+}
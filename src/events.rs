@@ -0,0 +1,26 @@
+// src/events.rs
+//
+// Diagnostic events for integrators: plan construction, the code paths a
+// build or plan falls back to, and rejected plans. With the `log` or
+// `defmt` feature they go through that facade; without either the macro
+// only type-checks its arguments, which the optimizer then drops.
+//
+// Messages use only `{}` on integers and booleans and `{:?}` on the
+// crate's enums, which both facades accept. Per-frame events are logged at
+// trace level.
+
+macro_rules! event {
+    ($level:ident, $($arg:tt)+) => {{
+        #[cfg(feature = "log")]
+        log::$level!($($arg)+);
+        #[cfg(feature = "defmt")]
+        defmt::$level!($($arg)+);
+        // Keeps the arguments used whatever the feature set
+        #[cfg(not(any(feature = "log", feature = "defmt")))]
+        let _ = format_args!($($arg)+);
+    }};
+}
+
+#[cfg(all(test, feature = "log"))]
+#[path = "events_tests.rs"]
+mod tests;
@@ -0,0 +1,52 @@
+use crate::common::{CplxFft, FftError, Scaling};
+use crate::float::types::Complex32;
+use std::string::{String, ToString};
+use std::sync::Mutex;
+use std::vec::Vec;
+
+static MESSAGES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Capture;
+
+impl log::Log for Capture {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        MESSAGES.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+fn logged(prefix: &str) -> bool {
+    MESSAGES
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|m| m.starts_with(prefix))
+}
+
+#[test]
+fn test_plan_events_reach_the_logger() {
+    log::set_logger(&Capture).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    let n = 8;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let mut fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::None);
+    assert!(logged("complex f32 plan: n = 8"));
+    assert!(logged("n = 8 plan: scaling None"));
+
+    fft.n = 16;
+    let mut buffer = vec![Complex32::new(0.0, 0.0); 16];
+    assert_eq!(
+        fft.process(&mut buffer, false),
+        Err(FftError::BufferTooSmall)
+    );
+    assert!(logged("plan rejected (BufferTooSmall): n = 16"));
+}
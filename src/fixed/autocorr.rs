@@ -0,0 +1,78 @@
+use super::core::TWIDDLE_FRAC;
+use super::types::{ComplexFixed, Fixed};
+use crate::common::{FftError, RealFft};
+
+impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
+    /// Replaces `buffer` (N samples) by its circular autocorrelation and
+    /// returns the exponent `e` such that `r[t] = buffer[t] * 2^e`.
+    ///
+    /// The frame is shifted up to use the full word before the scaled
+    /// forward transform, and the power spectrum is renormalized as a block
+    /// so that `r[0]` lands just below 2^30 LSB; neither loud nor quiet
+    /// frames overflow or vanish, and the exponent keeps track of both
+    /// factors. A silent frame returns zeros and an
+    /// exponent of 0. Zero-pad a frame of L samples to N >= 2L for the
+    /// linear autocorrelation. The result does not depend on
+    /// `self.scaling`.
+    pub fn autocorrelate<const FRAC: u32>(
+        &self,
+        buffer: &mut [Fixed<FRAC>],
+    ) -> Result<i32, FftError> {
+        let n = self.n;
+        self.validate()?;
+        let peak = buffer
+            .get(..n)
+            .ok_or(FftError::BufferTooSmall)?
+            .iter()
+            .map(|x| x.to_bits().unsigned_abs())
+            .max()
+            .unwrap_or(0);
+        let headroom = peak.leading_zeros().saturating_sub(2);
+        for x in &mut buffer[..n] {
+            *x = Fixed::from_bits(x.to_bits() << headroom);
+        }
+        self.rfft(buffer, true)?;
+
+        let power = |re: Fixed<FRAC>, im: Fixed<FRAC>| {
+            let (re, im) = (re.to_bits() as i64, im.to_bits() as i64);
+            (re * re + im * im) as u64
+        };
+        let edges = power(buffer[0], Fixed::ZERO) as u128 + power(buffer[1], Fixed::ZERO) as u128;
+        let total: u128 = edges
+            + 2 * buffer[2..]
+                .chunks_exact(2)
+                .map(|b| power(b[0], b[1]) as u128)
+                .sum::<u128>();
+        if total == 0 {
+            buffer.fill(Fixed::ZERO);
+            return Ok(0);
+        }
+
+        // r[0] is the sum of every bin of the two-sided power spectrum
+        let shift = (128 - total.leading_zeros()) as i32 - 30;
+        let normalize = |p: u64| -> Fixed<FRAC> {
+            let p = p as u128;
+            let bits = if shift > 0 {
+                (p + (1 << (shift - 1))) >> shift
+            } else {
+                p << -shift
+            };
+            Fixed::from_bits(bits as i32)
+        };
+        buffer[0] = normalize(power(buffer[0], Fixed::ZERO));
+        buffer[1] = normalize(power(buffer[1], Fixed::ZERO));
+        for bin in buffer[2..].chunks_exact_mut(2) {
+            bin[0] = normalize(power(bin[0], bin[1]));
+            bin[1] = Fixed::ZERO;
+        }
+
+        self.irfft(buffer, false)?;
+        // Bins were |X / N|^2 in Q(2 FRAC), brought to Q<FRAC> by 2^-shift
+        // and the input was 2^headroom too loud
+        Ok(shift - FRAC as i32 + n.trailing_zeros() as i32 - 2 * headroom as i32)
+    }
+}
+
+#[cfg(test)]
+#[path = "autocorr_tests.rs"]
+mod tests;
@@ -0,0 +1,39 @@
+use super::*;
+
+const N: usize = 64;
+
+#[test]
+fn test_matches_direct_sum_with_exponent() {
+    let mut twiddles = vec![ComplexFixed::ZERO; N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    // A loud and a very quiet frame both come out with full precision
+    for amplitude in [0.9, 0.001] {
+        let frame: Vec<f64> = (0..N / 2)
+            .map(|i| amplitude * (((i * 7) % 5) as f64 - 2.0) / 2.0)
+            .collect();
+        let mut buf = vec![Fixed::<15>::ZERO; N];
+        for (b, &x) in buf.iter_mut().zip(&frame) {
+            *b = Fixed::from_f64(x);
+        }
+        let quantized: Vec<f64> = buf.iter().map(|b| b.to_bits() as f64 / 32768.0).collect();
+        let exponent = fft.autocorrelate(&mut buf).unwrap();
+        assert!(buf[0].to_bits() > 1 << 28);
+
+        for lag in 0..N / 2 {
+            let direct: f64 = (0..N - lag)
+                .map(|i| quantized[i] * quantized[i + lag])
+                .sum();
+            let r = buf[lag].to_bits() as f64 / 32768.0 * 2f64.powi(exponent);
+            assert!(
+                (r - direct).abs() < 1e-3 * direct.abs().max(quantized[0].powi(2)),
+                "{amplitude}, lag {lag}: {r} vs {direct}"
+            );
+        }
+    }
+
+    let mut silent = vec![Fixed::<15>::ZERO; N];
+    assert_eq!(fft.autocorrelate(&mut silent), Ok(0));
+    assert!(silent.iter().all(|x| x.to_bits() == 0));
+}
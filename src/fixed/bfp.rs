@@ -0,0 +1,132 @@
+// src/fixed/bfp.rs
+
+use super::core::{TWIDDLE_FRAC, radix_2_fft};
+use super::headroom::input_shift;
+use super::types::{ComplexFixed, Fixed};
+use crate::common::{CplxFft, FftError};
+
+/// Fixed-point spectrum sharing one block exponent: bin `k` is
+/// `data[k] * 2^exponent`.
+///
+/// Returned by [`CplxFft::process_bfp`] so that the exponent travels with
+/// the data; the magnitude helpers below apply it, so downstream code never
+/// has to remember it separately.
+#[derive(Debug)]
+pub struct BfpSpectrum<'a, const FRAC: u32> {
+    pub data: &'a mut [ComplexFixed<FRAC>],
+    pub exponent: i8,
+}
+
+impl<const FRAC: u32> BfpSpectrum<'_, FRAC> {
+    /// Real value of bin `k` as `(re, im)`, exponent applied.
+    pub fn bin(&self, k: usize) -> (f32, f32) {
+        let c = self.data[k];
+        let scale = self.scale();
+        (c.re.to_bits() as f32 * scale, c.im.to_bits() as f32 * scale)
+    }
+
+    /// Magnitude of bin `k`, exponent applied.
+    pub fn magnitude(&self, k: usize) -> f32 {
+        self.data[k].abs().to_bits() as f32 * self.scale()
+    }
+
+    /// Magnitude of bin `k` in dB (20·log10), exponent applied.
+    /// A zero bin yields negative infinity.
+    pub fn magnitude_db(&self, k: usize) -> f32 {
+        20.0 * libm::log10f(self.magnitude(k))
+    }
+
+    /// Writes the magnitude of every bin into `out`.
+    pub fn magnitudes(&self, out: &mut [f32]) -> Result<(), FftError> {
+        if out.len() != self.data.len() {
+            return Err(FftError::SizeMismatch);
+        }
+        for (k, m) in out.iter_mut().enumerate() {
+            *m = self.magnitude(k);
+        }
+        Ok(())
+    }
+
+    /// Writes the magnitude of every bin in dB into `out`.
+    pub fn magnitudes_db(&self, out: &mut [f32]) -> Result<(), FftError> {
+        self.magnitudes(out)?;
+        for m in out.iter_mut() {
+            *m = 20.0 * libm::log10f(*m);
+        }
+        Ok(())
+    }
+
+    // Value of one LSB, exponent included
+    fn scale(&self) -> f32 {
+        libm::exp2f(self.exponent as f32 - FRAC as f32)
+    }
+}
+
+impl<'a> CplxFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
+    /// Block-floating-point FFT: the input is shifted as a block so that it
+    /// keeps exactly the log2(N) + 2 guard bits an unscaled transform needs
+    /// (one spare for rounding), then transformed without scaling.
+    ///
+    /// Small signals gain precision instead of losing bits to per-stage
+    /// scaling, and full-scale signals cannot overflow. The returned
+    /// exponent makes `data * 2^exponent` the unscaled DFT of the original
+    /// input, whatever `self.scaling` says.
+    pub fn process_bfp<'b, const FRAC: u32>(
+        &self,
+        buffer: &'b mut [ComplexFixed<FRAC>],
+        inverse: bool,
+    ) -> Result<BfpSpectrum<'b, FRAC>, FftError> {
+        self.validate()?;
+        if buffer.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+
+        let peak = buffer
+            .iter()
+            .map(|c| {
+                c.re.to_bits()
+                    .unsigned_abs()
+                    .max(c.im.to_bits().unsigned_abs())
+            })
+            .max()
+            .unwrap_or(0);
+        let shift = input_shift(self.n, peak, false);
+        event!(trace, "block floating point: input shift {}", shift);
+
+        for c in buffer.iter_mut() {
+            *c = ComplexFixed::new(shift_bits(c.re, shift), shift_bits(c.im, shift));
+        }
+
+        radix_2_fft(
+            buffer,
+            self.twiddles,
+            self.bitrev,
+            1,
+            self.algorithm,
+            inverse,
+            false,
+        );
+
+        Ok(BfpSpectrum {
+            data: buffer,
+            exponent: -shift as i8,
+        })
+    }
+}
+
+/// Shifts left for positive `shift`, right (rounding to nearest) otherwise.
+fn shift_bits<const FRAC: u32>(x: Fixed<FRAC>, shift: i32) -> Fixed<FRAC> {
+    let bits = x.to_bits() as i64;
+    let shifted = if shift >= 0 {
+        bits << shift
+    } else {
+        // Beyond 63 bits every input rounds to zero anyway
+        let s = (-shift).min(63);
+        (bits + (1 << (s - 1))) >> s
+    };
+    Fixed::from_bits(shifted.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+}
+
+#[cfg(test)]
+#[path = "bfp_tests.rs"]
+mod tests;
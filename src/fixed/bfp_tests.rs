@@ -0,0 +1,91 @@
+use super::*;
+use crate::common::Scaling;
+use core::f64::consts::PI;
+
+const N: usize = 64;
+
+/// Q15 tone at `bin` with the given amplitude, and its exact DFT value.
+fn tone(amplitude: f64, bin: usize) -> Vec<ComplexFixed<15>> {
+    (0..N)
+        .map(|i| {
+            let phase = 2.0 * PI * (bin * i) as f64 / N as f64;
+            ComplexFixed::new(
+                Fixed::from_f64(amplitude * phase.cos()),
+                Fixed::from_f64(amplitude * phase.sin()),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_small_signal_keeps_precision() {
+    let mut twiddles = vec![ComplexFixed::ZERO; N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_scaling(Scaling::Forward);
+
+    // A tone three LSB tall: per-stage scaling leaves only a few noisy
+    // LSB in the output, BFP keeps the full precision
+    let amplitude = 3.0 / 32768.0;
+    let expected = amplitude * N as f64;
+    let mut scaled = tone(amplitude, 3);
+    fft.process(&mut scaled, false).unwrap();
+    let scaled_mag = scaled[3].abs().to_bits() as f64 / 32768.0 * N as f64;
+    let scaled_err = (scaled_mag - expected).abs() / expected;
+
+    let mut buffer = tone(amplitude, 3);
+    let spectrum = fft.process_bfp(&mut buffer, false).unwrap();
+    assert!(spectrum.exponent < 0);
+    let bfp_err = (spectrum.magnitude(3) as f64 - expected).abs() / expected;
+    assert!(bfp_err < 0.01 && bfp_err < scaled_err);
+    let (re, im) = spectrum.bin(3);
+    assert!((re as f64 - expected).abs() < expected * 0.01 && im.abs() < 1e-4);
+    assert!(spectrum.magnitude(5) < spectrum.magnitude(3) * 0.01);
+}
+
+#[test]
+fn test_full_scale_does_not_overflow() {
+    let mut twiddles = vec![ComplexFixed::ZERO; N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    // Constant near full scale: the unscaled DC bin is ~N, far beyond Q31
+    let one = Fixed::<31>::from_f64(0.99);
+    let mut buffer = vec![ComplexFixed::new(one, one); N];
+    let spectrum = fft.process_bfp(&mut buffer, false).unwrap();
+    assert!(spectrum.exponent > 0);
+
+    let mut mags = vec![0.0; N];
+    spectrum.magnitudes(&mut mags).unwrap();
+    let dc = 0.99 * 2f64.sqrt() * N as f64;
+    assert!((mags[0] as f64 - dc).abs() < dc * 1e-3);
+    assert!(mags[1..].iter().all(|&m| m < 0.1));
+
+    let mut db = vec![0.0; N];
+    spectrum.magnitudes_db(&mut db).unwrap();
+    assert!((db[0] - 20.0 * mags[0].log10()).abs() < 1e-4);
+    assert_eq!(spectrum.magnitude_db(0), db[0]);
+    assert_eq!(
+        spectrum.magnitudes(&mut [0.0; 3]),
+        Err(FftError::SizeMismatch)
+    );
+}
+
+#[test]
+fn test_zero_input_and_size_check() {
+    let mut twiddles = vec![ComplexFixed::ZERO; N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    let mut buffer = vec![ComplexFixed::<15>::ZERO; N];
+    let spectrum = fft.process_bfp(&mut buffer, false).unwrap();
+    assert_eq!(spectrum.magnitude(0), 0.0);
+    assert_eq!(spectrum.magnitude_db(0), f32::NEG_INFINITY);
+
+    let mut short = vec![ComplexFixed::<15>::ZERO; N / 2];
+    assert!(matches!(
+        fft.process_bfp(&mut short, false),
+        Err(FftError::SizeMismatch)
+    ));
+}
@@ -1,6 +1,9 @@
-use super::core::{TWIDDLE_FRAC, precompute_bitrev, precompute_twiddles, radix_2_dit_fft_core};
-use super::types::ComplexFixed;
-use crate::common::{CplxFft, FftError, FftProcess};
+use super::core::{
+    TWIDDLE_FRAC, precompute_swaps, precompute_twiddles, radix_2_dit_fft_split, radix_2_fft,
+    radix_2_fft_unordered,
+};
+use super::types::{ComplexFixed, Fixed};
+use crate::common::{Algorithm, CplxFft, FftError, FftProcess, Hygiene, Scaling, swaps_are_exact};
 
 impl<'a> CplxFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
     /// Initializes the tables.
@@ -23,33 +26,127 @@ impl<'a> CplxFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
             twiddles,
             bitrev,
             n,
+            scaling: Scaling::default(),
+            algorithm: Algorithm::default(),
+            hygiene: Hygiene::default(),
         };
         fft.precompute();
+        event!(debug, "complex fixed-point plan: n = {}", n);
         Ok(fft)
     }
 
+    /// Builds a plan on tables that already hold this size's factors (from
+    /// an earlier plan on the same storage, or generated at build time),
+    /// skipping the O(N) trigonometry of [`Self::new`].
+    ///
+    /// The twiddles are spot checked (`twiddles[0]` must be 1 and
+    /// `twiddles[N/4]` must be `-i`) and the swap list is checked in full.
+    /// Tables that fail give `FftError::InvalidFormat`.
+    pub fn from_precomputed(
+        twiddles: &'a mut [ComplexFixed<TWIDDLE_FRAC>],
+        bitrev: &'a mut [usize],
+        n: usize,
+    ) -> Result<Self, FftError> {
+        if !n.is_power_of_two() {
+            return Err(FftError::NotPowerOfTwo);
+        }
+        if twiddles.len() < n / 2 || bitrev.len() < n {
+            return Err(FftError::BufferTooSmall);
+        }
+        // 1.0 saturates to the largest Q31 code, -1.0 is exact
+        let one = ComplexFixed::new(Fixed::from_bits(i32::MAX), Fixed::ZERO);
+        let minus_i = ComplexFixed::new(Fixed::ZERO, Fixed::from_bits(i32::MIN));
+        if (n >= 2 && twiddles[0] != one) || (n >= 4 && twiddles[n / 4] != minus_i) {
+            event!(warn, "precomputed tables rejected for n = {}", n);
+            return Err(FftError::InvalidFormat);
+        }
+        if !swaps_are_exact(bitrev, n) {
+            event!(warn, "precomputed tables rejected for n = {}", n);
+            return Err(FftError::InvalidFormat);
+        }
+
+        Ok(Self {
+            twiddles,
+            bitrev,
+            n,
+            scaling: Scaling::default(),
+            algorithm: Algorithm::default(),
+            hygiene: Hygiene::default(),
+        })
+    }
+
     /// Precomputes Twiddle Factors and Bit Reverse Table
     fn precompute(&mut self) {
-        precompute_bitrev(self.bitrev, self.n);
+        precompute_swaps(self.bitrev, self.n);
         precompute_twiddles(self.twiddles, self.n);
     }
 
     /// Executes the FFT in-place for a specific fixed-point format.
+    /// The normalization follows `self.scaling`.
     pub fn process<const FRAC: u32>(
         &self,
         buffer: &mut [ComplexFixed<FRAC>],
         inverse: bool,
     ) -> Result<(), FftError> {
+        self.validate()?;
+        if buffer.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+
+        let scale = self.scaling.is_scaled(inverse);
+        radix_2_fft(
+            buffer,
+            self.twiddles,
+            self.bitrev,
+            1,
+            self.algorithm,
+            inverse,
+            scale,
+        );
+
+        Ok(())
+    }
+
+    /// Executes the FFT in-place without the bit-reversal permutation.
+    ///
+    /// The forward transform takes natural-order input and leaves the
+    /// spectrum in bit-reversed order; the inverse expects that bit-reversed
+    /// spectrum and returns natural-order output. In FFT → pointwise
+    /// multiply → IFFT pipelines (e.g. convolution) the intermediate order is
+    /// irrelevant, so both permutation passes can be skipped. The plan's
+    /// `algorithm` is ignored; the normalization follows `self.scaling`.
+    pub fn process_unordered<const FRAC: u32>(
+        &self,
+        buffer: &mut [ComplexFixed<FRAC>],
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        self.validate()?;
         if buffer.len() != self.n {
             return Err(FftError::SizeMismatch);
         }
 
-        if inverse {
-            radix_2_dit_fft_core::<FRAC, true>(buffer, self.twiddles, self.bitrev, 1);
-        } else {
-            radix_2_dit_fft_core::<FRAC, false>(buffer, self.twiddles, self.bitrev, 1);
+        let scale = self.scaling.is_scaled(inverse);
+        radix_2_fft_unordered(buffer, self.twiddles, inverse, scale);
+
+        Ok(())
+    }
+
+    /// Executes the FFT in-place on a planar (split) buffer, with real and
+    /// imaginary parts stored in separate slices of size N.
+    pub fn process_split<const FRAC: u32>(
+        &self,
+        re: &mut [Fixed<FRAC>],
+        im: &mut [Fixed<FRAC>],
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        self.validate()?;
+        if re.len() != self.n || im.len() != self.n {
+            return Err(FftError::SizeMismatch);
         }
 
+        let scale = self.scaling.is_scaled(inverse);
+        radix_2_dit_fft_split(re, im, self.twiddles, self.bitrev, inverse, scale);
+
         Ok(())
     }
 }
@@ -1,5 +1,6 @@
 use super::super::types::{ComplexFixed, Fixed};
 use super::*;
+use crate::common::{Algorithm, Scaling};
 
 // Access the TWIDDLE_FRAC constant from the core module
 use super::super::core::TWIDDLE_FRAC;
@@ -65,8 +66,7 @@ fn test_fft_forward_q15() {
         .map(|&(re, im)| ComplexFixed::new(Fixed::from_f64(re), Fixed::from_f64(im)))
         .collect();
 
-    let mut twiddles =
-        vec![ComplexFixed::<TWIDDLE_FRAC>::new(Fixed::from_bits(0), Fixed::from_bits(0)); n / 2];
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
     let mut bitrev = vec![0; n];
 
     let fft =
@@ -121,8 +121,7 @@ fn test_fft_inverse_q15() {
         .map(|&(re, im)| ComplexFixed::new(Fixed::from_f64(re), Fixed::from_f64(im)))
         .collect();
 
-    let mut twiddles =
-        vec![ComplexFixed::<TWIDDLE_FRAC>::new(Fixed::from_bits(0), Fixed::from_bits(0)); n / 2];
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
     let mut bitrev = vec![0; n];
 
     let fft =
@@ -135,3 +134,264 @@ fn test_fft_inverse_q15() {
         assert_complex_close(val, expected_f64[i].0, expected_f64[i].1, 0.1);
     }
 }
+
+#[test]
+fn test_scaling_contract_q15() {
+    const FRAC: u32 = 15;
+    let n = 8;
+    let input: Vec<(f64, f64)> = (0..n)
+        .map(|i| (i as f64 * 0.1 - 0.3, 0.2 - i as f64 * 0.05))
+        .collect();
+    let to_fixed = |v: &[(f64, f64)]| -> Vec<ComplexFixed<FRAC>> {
+        v.iter()
+            .map(|&(re, im)| ComplexFixed::new(Fixed::from_f64(re), Fixed::from_f64(im)))
+            .collect()
+    };
+
+    // Naive DFT reference
+    let reference: Vec<(f64, f64)> = (0..n)
+        .map(|k| {
+            input
+                .iter()
+                .enumerate()
+                .fold((0.0, 0.0), |acc, (t, &(re, im))| {
+                    let angle = -2.0 * core::f64::consts::PI * (k * t) as f64 / n as f64;
+                    let (s, c) = angle.sin_cos();
+                    (acc.0 + re * c - im * s, acc.1 + re * s + im * c)
+                })
+        })
+        .collect();
+
+    // (scaling, forward gain, round trip gain)
+    let cases = [
+        (Scaling::None, 1.0, n as f64),
+        (Scaling::Forward, 1.0 / n as f64, 1.0),
+        (Scaling::Inverse, 1.0, 1.0),
+    ];
+
+    for (scaling, forward_gain, round_trip_gain) in cases {
+        let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+        let mut bitrev = vec![0; n];
+        let fft = CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_scaling(scaling);
+
+        let mut buffer = to_fixed(&input);
+        fft.process(&mut buffer, false).unwrap();
+        for (&val, &(re, im)) in buffer.iter().zip(reference.iter()) {
+            assert_complex_close(val, re * forward_gain, im * forward_gain, 0.001);
+        }
+
+        fft.process(&mut buffer, true).unwrap();
+        for (&val, &(re, im)) in buffer.iter().zip(input.iter()) {
+            assert_complex_close(val, re * round_trip_gain, im * round_trip_gain, 0.001);
+        }
+    }
+}
+
+#[test]
+fn test_process_split_matches_interleaved_q15() {
+    const FRAC: u32 = 15;
+    let n = 16;
+    let input: Vec<ComplexFixed<FRAC>> = (0..n)
+        .map(|i| {
+            ComplexFixed::new(
+                Fixed::from_f64((i as f64 * 0.4).sin() * 0.5),
+                Fixed::from_f64((i as f64 * 0.7).cos() * 0.5),
+            )
+        })
+        .collect();
+
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n];
+    let fft =
+        CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    for inverse in [false, true] {
+        let mut expected = input.clone();
+        fft.process(&mut expected, inverse).unwrap();
+
+        let mut re: Vec<Fixed<FRAC>> = input.iter().map(|c| c.re).collect();
+        let mut im: Vec<Fixed<FRAC>> = input.iter().map(|c| c.im).collect();
+        fft.process_split(&mut re, &mut im, inverse).unwrap();
+
+        for i in 0..n {
+            assert_eq!(ComplexFixed::new(re[i], im[i]), expected[i]);
+        }
+    }
+}
+
+#[test]
+fn test_process_split_bit_exact_at_full_scale() {
+    // Beyond 2^30 the product with a Q31 "1.0" twiddle would lose an LSB,
+    // so the planar core must apply 1 and -i exactly, as the interleaved one does
+    const FRAC: u32 = 15;
+    for n in [4, 8, 32] {
+        let input: Vec<ComplexFixed<FRAC>> = (0..n)
+            .map(|i| {
+                let sign = if i % 3 == 0 { -1 } else { 1 };
+                ComplexFixed::new(
+                    Fixed::from_bits(sign * (i32::MAX - i as i32 * 12345)),
+                    Fixed::from_bits(-sign * (i32::MAX / 2 + 1 + i as i32 * 777)),
+                )
+            })
+            .collect();
+
+        // Only the scaled direction of each plan, so full-scale input cannot overflow
+        for (scaling, inverse) in [(Scaling::Forward, false), (Scaling::Inverse, true)] {
+            let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+            let mut bitrev = vec![0; n];
+            let fft = CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+                .unwrap()
+                .with_scaling(scaling);
+
+            let mut expected = input.clone();
+            fft.process(&mut expected, inverse).unwrap();
+
+            let mut re: Vec<Fixed<FRAC>> = input.iter().map(|c| c.re).collect();
+            let mut im: Vec<Fixed<FRAC>> = input.iter().map(|c| c.im).collect();
+            fft.process_split(&mut re, &mut im, inverse).unwrap();
+
+            let split: Vec<ComplexFixed<FRAC>> = re
+                .iter()
+                .zip(&im)
+                .map(|(&r, &i)| ComplexFixed::new(r, i))
+                .collect();
+            assert_eq!(split, expected, "n = {}, inverse = {}", n, inverse);
+        }
+    }
+}
+
+#[test]
+fn test_dif_matches_dit_q15() {
+    const FRAC: u32 = 15;
+    let n = 16;
+    let input: Vec<ComplexFixed<FRAC>> = (0..n)
+        .map(|i| {
+            ComplexFixed::new(
+                Fixed::from_f64((i as f64 * 0.4).sin() * 0.5),
+                Fixed::from_f64(i as f64 * 0.02 - 0.15),
+            )
+        })
+        .collect();
+
+    for inverse in [false, true] {
+        let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+        let mut bitrev = vec![0; n];
+        let dit = CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_scaling(Scaling::Forward);
+        let mut expected = input.clone();
+        dit.process(&mut expected, inverse).unwrap();
+
+        let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+        let mut bitrev = vec![0; n];
+        let dif = CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_scaling(Scaling::Forward)
+            .with_algorithm(Algorithm::Dif);
+        let mut buffer = input.clone();
+        dif.process(&mut buffer, inverse).unwrap();
+
+        // Rounding differs between the two orderings, so allow a few LSBs
+        for (&val, &exp) in buffer.iter().zip(expected.iter()) {
+            assert_complex_close(val, to_f64(exp.re), to_f64(exp.im), 0.001);
+        }
+    }
+}
+
+#[test]
+fn test_dif_last_stages_exact_at_full_scale() {
+    // With at most two stages both cores only use the 1 and -i twiddles.
+    // Unscaled, the DIF difference reaches past 2^30, where a multiply by
+    // the Q31 "1.0" would lose an LSB; applied exactly, the cores agree.
+    const FRAC: u32 = 15;
+    let p = 0x3900_0000;
+    let q = 0x3500_0000;
+    let bits = |v: i32| Fixed::<FRAC>::from_bits(v);
+    let cases: [Vec<ComplexFixed<FRAC>>; 2] = [
+        vec![
+            ComplexFixed::new(bits(p + 3), bits(-q - 5)),
+            ComplexFixed::new(bits(-p + 7), bits(q + 1)),
+        ],
+        vec![
+            ComplexFixed::new(bits(p + 3), bits(-q - 5)),
+            ComplexFixed::new(bits(1001), bits(-2003)),
+            ComplexFixed::new(bits(-p + 7), bits(q + 1)),
+            ComplexFixed::new(bits(-3005), bits(4007)),
+        ],
+    ];
+
+    for input in cases {
+        let n = input.len();
+        for inverse in [false, true] {
+            let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+            let mut bitrev = vec![0; n];
+            let dit = CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+                .unwrap()
+                .with_scaling(Scaling::None);
+            let mut expected = input.clone();
+            dit.process(&mut expected, inverse).unwrap();
+
+            let dif = dit.with_algorithm(Algorithm::Dif);
+            let mut buffer = input.clone();
+            dif.process(&mut buffer, inverse).unwrap();
+            assert_eq!(buffer, expected, "n = {}, inverse = {}", n, inverse);
+        }
+    }
+}
+
+#[test]
+fn test_process_unordered_q15() {
+    const FRAC: u32 = 15;
+    let n = 16;
+    let input: Vec<ComplexFixed<FRAC>> = (0..n)
+        .map(|i| {
+            ComplexFixed::new(
+                Fixed::from_f64((i as f64 * 0.3).cos() * 0.5),
+                Fixed::from_f64((i as f64 * 0.2).sin() * 0.25),
+            )
+        })
+        .collect();
+
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::Forward);
+
+    let mut ordered = input.clone();
+    fft.process(&mut ordered, false).unwrap();
+    let mut buffer = input.clone();
+    fft.process_unordered(&mut buffer, false).unwrap();
+    for (k, &val) in buffer.iter().enumerate() {
+        let exp = ordered[k.reverse_bits() >> (usize::BITS - n.trailing_zeros())];
+        assert_complex_close(val, to_f64(exp.re), to_f64(exp.im), 0.001);
+    }
+
+    fft.process_unordered(&mut buffer, true).unwrap();
+    for (&val, &exp) in buffer.iter().zip(input.iter()) {
+        assert_complex_close(val, to_f64(exp.re), to_f64(exp.im), 0.002);
+    }
+}
+
+#[test]
+fn test_from_precomputed_checks_tables() {
+    let n = 16;
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n];
+    CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let fft =
+        CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::from_precomputed(&mut twiddles, &mut bitrev, n)
+            .unwrap();
+    assert_eq!(fft.n, n);
+
+    let mut stale = vec![0; n];
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut stale, 8).unwrap();
+    assert_eq!(
+        CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::from_precomputed(&mut twiddles, &mut stale, n)
+            .err(),
+        Some(FftError::InvalidFormat)
+    );
+}
@@ -1,6 +1,7 @@
 // src/fixed/core.rs
 
-use super::types::{ Fixed, ComplexFixed };
+use super::types::{ComplexFixed, Fixed};
+use crate::common::Algorithm;
 use core::f64::consts::PI;
 
 /// Fractional bits for twiddle factors (high precision).
@@ -11,20 +12,28 @@ pub const TWIDDLE_FRAC: u32 = 31;
 /// Twiddle factors are stored in Q31 format for maximum precision.
 pub(crate) fn precompute_twiddles(twiddles: &mut [ComplexFixed<TWIDDLE_FRAC>], n: usize) {
     // We generate only N/2 factors, as required for Radix-2
-    for j in 0..(n / 2) {
+    for (j, twiddle) in twiddles[..n / 2].iter_mut().enumerate() {
         let angle = -2.0 * PI * (j as f64) / (n as f64);
+        #[cfg(all(feature = "std", not(feature = "deterministic")))]
         let (sin, cos) = (angle.sin(), angle.cos());
-        twiddles[j] = ComplexFixed::new(
+        #[cfg(any(not(feature = "std"), feature = "deterministic"))]
+        let (sin, cos) = libm::sincos(angle);
+        // from_f64 saturates, so cos(0) = 1.0 becomes 1 - 2^-31
+        *twiddle = ComplexFixed::new(
             Fixed::<TWIDDLE_FRAC>::from_f64(cos),
             Fixed::<TWIDDLE_FRAC>::from_f64(sin),
         );
     }
 }
 
-/// Fills the bit-reversal table.
-pub(crate) fn precompute_bitrev(bitrev: &mut [usize], n: usize) {
-    bitrev[0] = 0;
+/// Fills `swaps` with the index pairs exchanged by the bit-reversal
+/// permutation, flattened as `[i0, j0, i1, j1, ...]` with `i < j`.
+///
+/// Fixed points are omitted, so only the first `swap_table_len(n)` entries
+/// are written and the permutation pass becomes a branch-free list of swaps.
+pub(crate) fn precompute_swaps(swaps: &mut [usize], n: usize) {
     let mut j = 0;
+    let mut len = 0;
     for i in 1..n {
         let mut k = n >> 1;
         while j >= k {
@@ -32,81 +41,540 @@ pub(crate) fn precompute_bitrev(bitrev: &mut [usize], n: usize) {
             k >>= 1;
         }
         j += k;
-        bitrev[i] = j;
+        if i < j {
+            swaps[len] = i;
+            swaps[len + 1] = j;
+            len += 2;
+        }
     }
 }
 
+/// Number of entries (twice the number of pairs) in the swap list for size N.
+///
+/// Of the N indices, the 2^ceil(log2(N)/2) bit palindromes stay in place and
+/// the rest are exchanged in pairs.
+#[inline]
+pub(crate) fn swap_table_len(n: usize) -> usize {
+    n - (1 << n.trailing_zeros().div_ceil(2))
+}
+
 /// Radix-2 Decimation-in-Time FFT core for fixed-point complex numbers.
-/// 
+///
 /// This is the fixed-point equivalent of `radix_2_dit_fft_core` from the float module.
-/// 
+///
 /// # Type Parameters
 /// - `FRAC`: Fractional bits for the input/output buffer
-/// - `INVERSE`: If true, performs inverse FFT with conjugate twiddles and scaling
-/// 
+/// - `INVERSE`: If true, performs inverse FFT with conjugate twiddles
+/// - `SCALE`: If true, scales by 0.5 at each stage (1/N overall)
+///
 /// # Arguments
 /// - `buffer`: Input/output buffer of complex fixed-point numbers
 /// - `twiddles`: Precomputed twiddle factors in Q31 format
-/// - `bitrev`: Precomputed bit-reversal indices
+/// - `bitrev`: Precomputed bit-reversal swap list (see `precompute_swaps`)
 /// - `twiddle_stride`: Stride for accessing twiddle factors (for smaller FFT sizes)
-pub(crate) fn radix_2_dit_fft_core<const FRAC: u32, const INVERSE: bool>(
-    buffer: &mut [ComplexFixed<FRAC>], 
-    twiddles: &[ComplexFixed<TWIDDLE_FRAC>], 
+pub(crate) fn radix_2_dit_fft_core<const FRAC: u32, const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
     bitrev: &[usize],
-    twiddle_stride: usize
+    twiddle_stride: usize,
+) {
+    if buffer.len() >= 16 {
+        // 1. Bit-reverse permutation fused with the first two butterfly stages
+        dit_permute_first_stages::<FRAC, INVERSE, SCALE>(buffer);
+
+        // 2. Remaining butterfly stages
+        dit_butterflies::<FRAC, INVERSE, SCALE>(buffer, twiddles, twiddle_stride, 4);
+    } else {
+        // 1. Bit-reverse permutation
+        bit_reverse_permute(buffer, bitrev);
+
+        // 2. Butterfly stages
+        dit_butterflies::<FRAC, INVERSE, SCALE>(buffer, twiddles, twiddle_stride, 1);
+    }
+}
+
+/// Bit-reversal permutation fused with the first two DIT stages (N >= 16).
+///
+/// Same block scheme as the float core: the 16 elements `a * N/4 + 4u + b`
+/// map onto those of `rev(u)`, so each pair of blocks is loaded once and
+/// written back through the stage 1 and 2 butterflies. Their twiddles are
+/// only 1 and -i (+i for the inverse), applied without a multiply here and
+/// in `dit_stage` alike, so the result is bit-identical to the unfused
+/// passes.
+fn dit_permute_first_stages<const FRAC: u32, const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [ComplexFixed<FRAC>],
 ) {
     let n = buffer.len();
+    let quarter = n >> 2;
+    let mid_bits = n.trailing_zeros() - 4;
 
-    // 1. Bit-reverse permutation
-    for i in 1..(n - 1) {
-        let j = bitrev[i];
-        if i < j {
-            buffer.swap(i, j);
+    for u in 0..n >> 4 {
+        let v = if mid_bits == 0 {
+            0
+        } else {
+            u.reverse_bits() >> (usize::BITS - mid_bits)
+        };
+        if v < u {
+            continue;
+        }
+
+        let block_u = load_block(buffer, u, quarter);
+        if v == u {
+            store_block::<FRAC, INVERSE, SCALE>(buffer, u, quarter, &block_u);
+        } else {
+            let block_v = load_block(buffer, v, quarter);
+            store_block::<FRAC, INVERSE, SCALE>(buffer, v, quarter, &block_u);
+            store_block::<FRAC, INVERSE, SCALE>(buffer, u, quarter, &block_v);
         }
     }
+}
 
-    // 2. Butterfly stages
-    let mut stride = 1;
-    let mut tw_index = n >> 1;
+/// Loads the 16 elements `a * quarter + 4u + b` as `block[4a + b]`.
+#[inline]
+fn load_block<const FRAC: u32>(
+    buffer: &[ComplexFixed<FRAC>],
+    u: usize,
+    quarter: usize,
+) -> [ComplexFixed<FRAC>; 16] {
+    let zero = Fixed::from_bits(0);
+    let mut block = [ComplexFixed::new(zero, zero); 16];
+    for (a, row) in block.chunks_exact_mut(4).enumerate() {
+        let base = a * quarter + 4 * u;
+        row.copy_from_slice(&buffer[base..base + 4]);
+    }
+    block
+}
 
-    while stride < n {
-        let jmax = n - stride;
-        
-        for j in (0..jmax).step_by(stride << 1) {
-            for i in 0..stride {
-                let mut w = twiddles[i * tw_index * twiddle_stride];
-
-                // The compiler will completely remove this IF because INVERSE is a compile-time constant
-                if INVERSE {
-                    w = w.conj();
-                }
-
-                let index = j + i;
-                let a = buffer[index];
-                let b = buffer[index + stride];
-                
-                // Butterfly: t = b * w
-                let t = b * w;
-
-                let mut v1 = a + t;
-                let mut v2 = a - t;
-
-                // Stage normalization to avoid overflow (essential for fixed-point)
-                // In inverse FFT, we scale by 0.5 at each stage instead of 1/N at the end
-                if INVERSE {
-                    v1 = v1.scale_half();
-                    v2 = v2.scale_half();
-                }
-
-                buffer[index] = v1;
-                buffer[index + stride] = v2;
+/// Writes block `v` from the bit-reversed source block, applying the
+/// stage 1 and 2 butterflies to each group of 4 on the way.
+#[inline]
+fn store_block<const FRAC: u32, const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    v: usize,
+    quarter: usize,
+    src: &[ComplexFixed<FRAC>; 16],
+) {
+    // 2-bit reversal
+    const REV2: [usize; 4] = [0, 2, 1, 3];
+
+    for (a, &r) in REV2.iter().enumerate() {
+        // Position a * quarter + 4v + b holds source element rev2(b) * 4 + rev2(a)
+        let y0 = src[r];
+        let y1 = src[8 + r];
+        let y2 = src[4 + r];
+        let y3 = src[12 + r];
+
+        // Stage 1: stride 1, twiddle 1
+        let (mut s0, mut s1) = (y0 + y1, y0 - y1);
+        let (mut s2, mut s3) = (y2 + y3, y2 - y3);
+        if SCALE {
+            s0 = s0.scale_half();
+            s1 = s1.scale_half();
+            s2 = s2.scale_half();
+            s3 = s3.scale_half();
+        }
+
+        // Stage 2: stride 2, twiddles 1 and -i (+i for the inverse)
+        let t = if INVERSE { s3.mul_i() } else { s3.mul_neg_i() };
+        let mut out = [s0 + s2, s1 + t, s0 - s2, s1 - t];
+        if SCALE {
+            for o in out.iter_mut() {
+                *o = o.scale_half();
             }
         }
+
+        let base = a * quarter + 4 * v;
+        buffer[base..base + 4].copy_from_slice(&out);
+    }
+}
+
+/// In-place bit-reversal permutation of `buffer` using the precomputed swap list.
+pub(crate) fn bit_reverse_permute<const FRAC: u32>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    swaps: &[usize],
+) {
+    for pair in swaps[..swap_table_len(buffer.len())].chunks_exact(2) {
+        buffer.swap(pair[0], pair[1]);
+    }
+}
+
+/// DIT butterfly stages (growing stride) on bit-reversed input, producing
+/// naturally ordered output. Stages below `first_stride` are assumed done.
+fn dit_butterflies<const FRAC: u32, const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    twiddle_stride: usize,
+    first_stride: usize,
+) {
+    let n = buffer.len();
+    let mut stride = first_stride;
+    let mut tw_index = (n >> 1) / first_stride;
+
+    while stride < n {
+        dit_stage::<FRAC, INVERSE, SCALE>(buffer, twiddles, tw_index * twiddle_stride, stride);
         stride <<= 1;
         tw_index >>= 1;
     }
 }
 
+/// One DIT stage: butterflies `stride` apart, in groups of `2 * stride`,
+/// with every `step`-th twiddle.
+#[inline(always)]
+fn dit_stage<const FRAC: u32, const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    step: usize,
+    stride: usize,
+) {
+    // Stages 1 and 2 only use the twiddles 1 and -i (+i for the inverse).
+    // Q31 cannot hold 1.0, so, as in `dit_permute_first_stages`, they are
+    // applied exactly rather than through the multiply.
+    if stride <= 2 {
+        dit_column::<FRAC, SCALE>(buffer, stride, 0, |b| b);
+        if stride == 2 {
+            dit_column::<FRAC, SCALE>(buffer, stride, 1, rotate_quarter::<FRAC, INVERSE>);
+        }
+        return;
+    }
+
+    // The stage's factors are every `step`-th twiddle; each is loaded once
+    // and applied across every group.
+    let factors = twiddles.chunks_exact(step).map(|c| c[0]);
+    for (i, w) in (0..stride).zip(factors) {
+        // The compiler will completely remove this IF because INVERSE is a compile-time constant
+        let w = if INVERSE { w.conj() } else { w };
+        dit_column::<FRAC, SCALE>(buffer, stride, i, |b| b * w);
+    }
+}
+
+/// The butterflies at offset `i` of every group of a DIT stage, with
+/// `twist` applying the twiddle. Splitting a group into its two halves
+/// keeps `i < stride` in range for both.
+#[inline(always)]
+fn dit_column<const FRAC: u32, const SCALE: bool>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    stride: usize,
+    i: usize,
+    twist: impl Fn(ComplexFixed<FRAC>) -> ComplexFixed<FRAC>,
+) {
+    for group in buffer.chunks_exact_mut(stride << 1) {
+        let (lo, hi) = group.split_at_mut(stride);
+        let (a, b) = (&mut lo[i], &mut hi[i]);
+
+        // Butterfly: t = b * w, through `twist`
+        let t = twist(*b);
+
+        let mut v1 = *a + t;
+        let mut v2 = *a - t;
+
+        // Stage normalization to avoid overflow (essential for fixed-point)
+        // When scaling, we scale by 0.5 at each stage instead of 1/N at the end
+        if SCALE {
+            v1 = v1.scale_half();
+            v2 = v2.scale_half();
+        }
+
+        *a = v1;
+        *b = v2;
+    }
+}
+
+/// Exact product with the quarter-turn twiddle: -i, or +i for the inverse.
+#[inline(always)]
+fn rotate_quarter<const FRAC: u32, const INVERSE: bool>(
+    b: ComplexFixed<FRAC>,
+) -> ComplexFixed<FRAC> {
+    if INVERSE { b.mul_i() } else { b.mul_neg_i() }
+}
+
+/// Runtime dispatcher selecting the monomorphized core for the given direction and scaling.
+pub(crate) fn radix_2_dit_fft<const FRAC: u32>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    bitrev: &[usize],
+    twiddle_stride: usize,
+    inverse: bool,
+    scale: bool,
+) {
+    match (inverse, scale) {
+        (false, false) => {
+            radix_2_dit_fft_core::<FRAC, false, false>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (false, true) => {
+            radix_2_dit_fft_core::<FRAC, false, true>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (true, false) => {
+            radix_2_dit_fft_core::<FRAC, true, false>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (true, true) => {
+            radix_2_dit_fft_core::<FRAC, true, true>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+    }
+}
+
+/// Radix-2 Decimation-in-Frequency core for fixed-point complex numbers.
+///
+/// Runs the butterflies on naturally ordered input, from the widest stride
+/// down to 1, which leaves the result in bit-reversed order; the final pass
+/// restores natural order so the output matches `radix_2_dit_fft_core`.
+///
+/// With `SCALE`, the sum and difference are halved before the twiddle
+/// multiply, so each stage keeps the same headroom as the DIT core.
+pub(crate) fn radix_2_dif_fft_core<const FRAC: u32, const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    bitrev: &[usize],
+    twiddle_stride: usize,
+) {
+    // 1. Butterfly stages
+    dif_butterflies::<FRAC, INVERSE, SCALE>(buffer, twiddles, twiddle_stride);
+
+    // 2. Bit-reverse permutation
+    bit_reverse_permute(buffer, bitrev);
+}
+
+/// DIF butterfly stages (shrinking stride) on naturally ordered input,
+/// producing bit-reversed output.
+fn dif_butterflies<const FRAC: u32, const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    twiddle_stride: usize,
+) {
+    let n = buffer.len();
+    let mut stride = n >> 1;
+    let mut tw_index = 1;
+
+    while stride > 2 {
+        let step = tw_index * twiddle_stride;
+        let factors = twiddles.chunks_exact(step).map(|c| c[0]);
+        for (i, w) in (0..stride).zip(factors) {
+            let w = if INVERSE { w.conj() } else { w };
+            dif_column::<FRAC, SCALE>(buffer, stride, i, |v| v * w);
+        }
+        stride >>= 1;
+        tw_index <<= 1;
+    }
+
+    // The last two stages only use 1 and -i (+i for the inverse), applied
+    // exactly as in the DIT core.
+    if stride == 2 {
+        dif_column::<FRAC, SCALE>(buffer, 2, 0, |v| v);
+        dif_column::<FRAC, SCALE>(buffer, 2, 1, rotate_quarter::<FRAC, INVERSE>);
+        stride = 1;
+    }
+    if stride == 1 {
+        dif_column::<FRAC, SCALE>(buffer, 1, 0, |v| v);
+    }
+}
+
+/// The butterflies at offset `i` of every group of a DIF stage, with
+/// `twist` applying the twiddle to the difference.
+#[inline(always)]
+fn dif_column<const FRAC: u32, const SCALE: bool>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    stride: usize,
+    i: usize,
+    twist: impl Fn(ComplexFixed<FRAC>) -> ComplexFixed<FRAC>,
+) {
+    for group in buffer.chunks_exact_mut(stride << 1) {
+        let (lo, hi) = group.split_at_mut(stride);
+        let (a, b) = (&mut lo[i], &mut hi[i]);
+
+        let mut v1 = *a + *b;
+        let mut v2 = *a - *b;
+
+        if SCALE {
+            v1 = v1.scale_half();
+            v2 = v2.scale_half();
+        }
+
+        *a = v1;
+        *b = twist(v2);
+    }
+}
+
+/// Runtime dispatcher for the DIF core.
+pub(crate) fn radix_2_dif_fft<const FRAC: u32>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    bitrev: &[usize],
+    twiddle_stride: usize,
+    inverse: bool,
+    scale: bool,
+) {
+    match (inverse, scale) {
+        (false, false) => {
+            radix_2_dif_fft_core::<FRAC, false, false>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (false, true) => {
+            radix_2_dif_fft_core::<FRAC, false, true>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (true, false) => {
+            radix_2_dif_fft_core::<FRAC, true, false>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (true, true) => {
+            radix_2_dif_fft_core::<FRAC, true, true>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+    }
+}
+
+/// Runs the core selected by the plan's `Algorithm`.
+pub(crate) fn radix_2_fft<const FRAC: u32>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    bitrev: &[usize],
+    twiddle_stride: usize,
+    algorithm: Algorithm,
+    inverse: bool,
+    scale: bool,
+) {
+    match algorithm {
+        Algorithm::Dit => radix_2_dit_fft(buffer, twiddles, bitrev, twiddle_stride, inverse, scale),
+        Algorithm::Dif => radix_2_dif_fft(buffer, twiddles, bitrev, twiddle_stride, inverse, scale),
+    }
+}
+
+/// Runs the butterflies without any permutation: the forward direction uses
+/// DIF (natural in, bit-reversed out) and the inverse uses DIT (bit-reversed
+/// in, natural out), so a forward/inverse pair still round-trips.
+pub(crate) fn radix_2_fft_unordered<const FRAC: u32>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    inverse: bool,
+    scale: bool,
+) {
+    match (inverse, scale) {
+        (false, false) => dif_butterflies::<FRAC, false, false>(buffer, twiddles, 1),
+        (false, true) => dif_butterflies::<FRAC, false, true>(buffer, twiddles, 1),
+        (true, false) => dit_butterflies::<FRAC, true, false>(buffer, twiddles, 1, 1),
+        (true, true) => dit_butterflies::<FRAC, true, true>(buffer, twiddles, 1, 1),
+    }
+}
+
+/// Runs the single DIT stage of the given `stride` on bit-reversed data,
+/// for callers that interleave other work between stages.
+pub(crate) fn radix_2_dit_stage<const FRAC: u32>(
+    buffer: &mut [ComplexFixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    stride: usize,
+    inverse: bool,
+    scale: bool,
+) {
+    let step = (buffer.len() >> 1) / stride;
+    match (inverse, scale) {
+        (false, false) => dit_stage::<FRAC, false, false>(buffer, twiddles, step, stride),
+        (false, true) => dit_stage::<FRAC, false, true>(buffer, twiddles, step, stride),
+        (true, false) => dit_stage::<FRAC, true, false>(buffer, twiddles, step, stride),
+        (true, true) => dit_stage::<FRAC, true, true>(buffer, twiddles, step, stride),
+    }
+}
+
+/// Planar variant of `radix_2_dit_fft_core`: real and imaginary parts live in
+/// separate slices, so callers with split buffers avoid an interleaving pass.
+pub(crate) fn radix_2_dit_fft_split_core<
+    const FRAC: u32,
+    const INVERSE: bool,
+    const SCALE: bool,
+>(
+    re: &mut [Fixed<FRAC>],
+    im: &mut [Fixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    bitrev: &[usize],
+    twiddle_stride: usize,
+) {
+    let n = re.len();
+
+    // 1. Bit-reverse permutation
+    for pair in bitrev[..swap_table_len(n)].chunks_exact(2) {
+        re.swap(pair[0], pair[1]);
+        im.swap(pair[0], pair[1]);
+    }
+
+    // 2. Butterfly stages, the first two with the exact 1 and -i (+i for
+    // the inverse) twiddles, as in `dit_stage`
+    if n >= 2 {
+        split_column::<FRAC, SCALE>(re, im, 1, 0, |b| b);
+    }
+    if n >= 4 {
+        split_column::<FRAC, SCALE>(re, im, 2, 0, |b| b);
+        split_column::<FRAC, SCALE>(re, im, 2, 1, rotate_quarter::<FRAC, INVERSE>);
+    }
+
+    let mut stride = 4;
+    let mut tw_index = n >> 3;
+
+    while stride < n {
+        let step = tw_index * twiddle_stride;
+        let factors = twiddles.chunks_exact(step).map(|c| c[0]);
+        for (i, w) in (0..stride).zip(factors) {
+            let w = if INVERSE { w.conj() } else { w };
+            split_column::<FRAC, SCALE>(re, im, stride, i, |b| b * w);
+        }
+        stride <<= 1;
+        tw_index >>= 1;
+    }
+}
+
+/// Planar counterpart of `dit_column`.
+#[inline(always)]
+fn split_column<const FRAC: u32, const SCALE: bool>(
+    re: &mut [Fixed<FRAC>],
+    im: &mut [Fixed<FRAC>],
+    stride: usize,
+    i: usize,
+    twist: impl Fn(ComplexFixed<FRAC>) -> ComplexFixed<FRAC>,
+) {
+    let groups = re
+        .chunks_exact_mut(stride << 1)
+        .zip(im.chunks_exact_mut(stride << 1));
+    for (re_group, im_group) in groups {
+        let (re_lo, re_hi) = re_group.split_at_mut(stride);
+        let (im_lo, im_hi) = im_group.split_at_mut(stride);
+        let (a_re, a_im) = (&mut re_lo[i], &mut im_lo[i]);
+        let (b_re, b_im) = (&mut re_hi[i], &mut im_hi[i]);
+        let a = ComplexFixed::new(*a_re, *a_im);
+        let b = ComplexFixed::new(*b_re, *b_im);
+
+        let t = twist(b);
+
+        let mut v1 = a + t;
+        let mut v2 = a - t;
+
+        if SCALE {
+            v1 = v1.scale_half();
+            v2 = v2.scale_half();
+        }
+
+        *a_re = v1.re;
+        *a_im = v1.im;
+        *b_re = v2.re;
+        *b_im = v2.im;
+    }
+}
+
+/// Runtime dispatcher for the planar core.
+pub(crate) fn radix_2_dit_fft_split<const FRAC: u32>(
+    re: &mut [Fixed<FRAC>],
+    im: &mut [Fixed<FRAC>],
+    twiddles: &[ComplexFixed<TWIDDLE_FRAC>],
+    bitrev: &[usize],
+    inverse: bool,
+    scale: bool,
+) {
+    match (inverse, scale) {
+        (false, false) => {
+            radix_2_dit_fft_split_core::<FRAC, false, false>(re, im, twiddles, bitrev, 1)
+        }
+        (false, true) => {
+            radix_2_dit_fft_split_core::<FRAC, false, true>(re, im, twiddles, bitrev, 1)
+        }
+        (true, false) => {
+            radix_2_dit_fft_split_core::<FRAC, true, false>(re, im, twiddles, bitrev, 1)
+        }
+        (true, true) => radix_2_dit_fft_split_core::<FRAC, true, true>(re, im, twiddles, bitrev, 1),
+    }
+}
+
 #[cfg(test)]
 #[path = "core_tests.rs"]
-mod tests;
\ No newline at end of file
+mod tests;
@@ -6,25 +6,20 @@ type C = ComplexFixed<FRAC>;
 type F = Fixed<FRAC>;
 
 #[test]
-fn test_precompute_bitrev() {
-    let n = 8;
-    let mut bitrev = vec![0; n];
-    precompute_bitrev(&mut bitrev, n);
-    
-    assert_eq!(bitrev[0], 0);
-    assert_eq!(bitrev[1], 4);
-    assert_eq!(bitrev[2], 2);
-    assert_eq!(bitrev[3], 6);
-    assert_eq!(bitrev[4], 1);
-    assert_eq!(bitrev[5], 5);
-    assert_eq!(bitrev[6], 3);
-    assert_eq!(bitrev[7], 7);
+fn test_precompute_swaps() {
+    let n = 16;
+    let mut swaps = vec![0; n];
+    precompute_swaps(&mut swaps, n);
+
+    // 16 indices, 4 bit palindromes (0, 6, 9, 15) stay in place
+    assert_eq!(swap_table_len(n), 12);
+    assert_eq!(&swaps[..12], &[1, 8, 2, 4, 3, 12, 5, 10, 7, 14, 11, 13]);
 }
 
 #[test]
 fn test_precompute_twiddles() {
     let n = 4;
-    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::new(Fixed::from_bits(0), Fixed::from_bits(0)); n/2];
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
     precompute_twiddles(&mut twiddles, n);
     
     // N=4 -> N/2 = 2 twiddles
@@ -58,14 +53,14 @@ fn test_fft_core_forward_impulse() {
     let mut buffer = vec![C::new(F::from_int(0), F::from_int(0)); n];
     buffer[0] = C::new(F::from_int(1), F::from_int(0));
     
-    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::new(Fixed::from_bits(0), Fixed::from_bits(0)); n/2];
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
     precompute_twiddles(&mut twiddles, n);
     
     let mut bitrev = vec![0; n];
-    precompute_bitrev(&mut bitrev, n);
+    precompute_swaps(&mut bitrev, n);
     
     // Forward FFT
-    radix_2_dit_fft_core::<FRAC, false>(&mut buffer, &twiddles, &bitrev, 1);
+    radix_2_dit_fft_core::<FRAC, false, false>(&mut buffer, &twiddles, &bitrev, 1);
     
     // Expected output: [1, 1, 1, 1, ..., 1]
     let one = F::from_int(1).to_bits();
@@ -84,14 +79,14 @@ fn test_fft_core_inverse_flat() {
     let n = 8;
     let mut buffer = vec![C::new(F::from_int(1), F::from_int(0)); n];
     
-    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::new(Fixed::from_bits(0), Fixed::from_bits(0)); n/2];
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
     precompute_twiddles(&mut twiddles, n);
     
     let mut bitrev = vec![0; n];
-    precompute_bitrev(&mut bitrev, n);
+    precompute_swaps(&mut bitrev, n);
     
     // Inverse FFT
-    radix_2_dit_fft_core::<FRAC, true>(&mut buffer, &twiddles, &bitrev, 1);
+    radix_2_dit_fft_core::<FRAC, true, true>(&mut buffer, &twiddles, &bitrev, 1);
     
     // Expected output: [1, 0, ..., 0]
     let one = F::from_int(1).to_bits();
@@ -102,8 +97,76 @@ fn test_fft_core_inverse_flat() {
     assert_eq!(buffer[0].im.to_bits(), zero, "Imag part at index 0");
     
     // Check others
-    for i in 1..n {
-        assert_eq!(buffer[i].re.to_bits(), zero, "Real part at index {}", i);
-        assert_eq!(buffer[i].im.to_bits(), zero, "Imag part at index {}", i);
+    for (i, val) in buffer.iter().enumerate().skip(1) {
+        assert_eq!(val.re.to_bits(), zero, "Real part at index {}", i);
+        assert_eq!(val.im.to_bits(), zero, "Imag part at index {}", i);
+    }
+}
+
+#[test]
+fn test_fused_first_stages_bit_exact() {
+    // Covers both an even and an odd number of middle bits
+    for n in [16usize, 32, 64, 128] {
+        let input: Vec<C> = (0..n)
+            .map(|i| {
+                C::new(
+                    F::from_bits(((i * 7919) % 4001) as i32 - 2000),
+                    F::from_bits(((i * 104729) % 3001) as i32 - 1500),
+                )
+            })
+            .collect();
+
+        let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+        precompute_twiddles(&mut twiddles, n);
+        let mut swaps = vec![0; n];
+        precompute_swaps(&mut swaps, n);
+
+        let mut fused = input.clone();
+        radix_2_dit_fft_core::<FRAC, false, true>(&mut fused, &twiddles, &swaps, 1);
+        let mut reference = input.clone();
+        bit_reverse_permute(&mut reference, &swaps);
+        dit_butterflies::<FRAC, false, true>(&mut reference, &twiddles, 1, 1);
+        assert_eq!(fused, reference, "forward, n = {}", n);
+
+        let mut fused = input.clone();
+        radix_2_dit_fft_core::<FRAC, true, false>(&mut fused, &twiddles, &swaps, 1);
+        let mut reference = input.clone();
+        bit_reverse_permute(&mut reference, &swaps);
+        dit_butterflies::<FRAC, true, false>(&mut reference, &twiddles, 1, 1);
+        assert_eq!(fused, reference, "inverse, n = {}", n);
+    }
+}
+
+#[test]
+fn test_fused_first_stages_bit_exact_at_full_scale() {
+    // Beyond 2^30 the product with a Q31 "1.0" twiddle would lose an LSB
+    let n = 32;
+    let input: Vec<C> = (0..n)
+        .map(|i| {
+            let sign = if i % 3 == 0 { -1 } else { 1 };
+            C::new(
+                F::from_bits(sign * (i32::MAX - i as i32 * 12345)),
+                F::from_bits(-sign * (i32::MAX / 2 + 1 + i as i32 * 777)),
+            )
+        })
+        .collect();
+
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    precompute_twiddles(&mut twiddles, n);
+    let mut swaps = vec![0; n];
+    precompute_swaps(&mut swaps, n);
+
+    for inverse in [false, true] {
+        let mut fused = input.clone();
+        let mut reference = input.clone();
+        bit_reverse_permute(&mut reference, &swaps);
+        if inverse {
+            radix_2_dit_fft_core::<FRAC, true, true>(&mut fused, &twiddles, &swaps, 1);
+            dit_butterflies::<FRAC, true, true>(&mut reference, &twiddles, 1, 1);
+        } else {
+            radix_2_dit_fft_core::<FRAC, false, true>(&mut fused, &twiddles, &swaps, 1);
+            dit_butterflies::<FRAC, false, true>(&mut reference, &twiddles, 1, 1);
+        }
+        assert_eq!(fused, reference, "inverse = {}", inverse);
     }
 }
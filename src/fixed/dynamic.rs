@@ -0,0 +1,88 @@
+// src/fixed/dynamic.rs
+
+use super::core::TWIDDLE_FRAC;
+use super::types::dyn_fixed::{DynComplexFixed, MAX_FRAC, rescale};
+use super::types::{ComplexFixed, Fixed};
+use crate::common::{CplxFft, FftError, Scratch};
+use core::slice;
+
+/// Complex fixed-point FFT whose Q-format is a run-time setting.
+///
+/// The butterflies only add, shift and multiply by Q31 twiddles, none of
+/// which depends on the data's FRAC, so a single instantiation of the core
+/// serves every format bit-exactly: results equal those of
+/// `CplxFft::process::<FRAC>` for the same bits. The plan's scaling and
+/// algorithm settings are honored.
+pub struct DynFixedFft<'a> {
+    fft: CplxFft<'a, ComplexFixed<TWIDDLE_FRAC>>,
+    frac: u8,
+}
+
+impl<'a> DynFixedFft<'a> {
+    /// Wraps a fixed-point complex plan for data with `frac` fractional bits.
+    pub fn new(fft: CplxFft<'a, ComplexFixed<TWIDDLE_FRAC>>, frac: u8) -> Result<Self, FftError> {
+        if frac > MAX_FRAC {
+            return Err(FftError::OutOfRange);
+        }
+        Ok(Self { fft, frac })
+    }
+
+    /// Q-format of the transformed data.
+    pub fn frac(&self) -> u8 {
+        self.frac
+    }
+
+    /// Transform size N.
+    pub fn len(&self) -> usize {
+        self.fft.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fft.n == 0
+    }
+
+    /// Transforms N complex values stored as 2N interleaved raw words
+    /// `[re0, im0, re1, im1, ...]` in the plan's format.
+    pub fn process_raw(&self, words: &mut [i32], inverse: bool) -> Result<(), FftError> {
+        if words.len() != 2 * self.fft.n {
+            return Err(FftError::SizeMismatch);
+        }
+        // Safety: Fixed is repr(transparent) over i32 and ComplexFixed is
+        // repr(C) over two Fixed, so 2N words are N complex values
+        let raw =
+            unsafe { slice::from_raw_parts_mut(words.as_mut_ptr() as *mut Fixed<0>, words.len()) };
+        self.fft.process(ComplexFixed::pack_mut(raw), inverse)
+    }
+
+    /// Transforms `buffer` in place, with `2 * N` words of scratch.
+    ///
+    /// Elements in another format are first converted (rounded, saturated)
+    /// to the plan's format, which is also the format of the result.
+    pub fn process(
+        &self,
+        buffer: &mut [DynComplexFixed],
+        scratch: &mut Scratch<'_, i32>,
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        if buffer.len() != self.fft.n {
+            return Err(FftError::SizeMismatch);
+        }
+        let words = scratch.take(2 * self.fft.n)?;
+
+        for (w, x) in words.chunks_exact_mut(2).zip(buffer.iter()) {
+            w[0] = rescale(x.re().to_bits(), x.frac(), self.frac);
+            w[1] = rescale(x.im().to_bits(), x.frac(), self.frac);
+        }
+
+        self.process_raw(words, inverse)?;
+
+        for (x, w) in buffer.iter_mut().zip(words.chunks_exact(2)) {
+            *x = DynComplexFixed::from_bits(w[0], w[1], self.frac)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "dynamic_tests.rs"]
+mod tests;
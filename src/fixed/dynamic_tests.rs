@@ -0,0 +1,93 @@
+use super::*;
+use crate::common::Scaling;
+use crate::fixed::DynFixed;
+
+const N: usize = 32;
+
+fn plan<'a>(
+    twiddles: &'a mut [ComplexFixed<TWIDDLE_FRAC>],
+    bitrev: &'a mut [usize],
+) -> CplxFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
+    CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(twiddles, bitrev, N).unwrap()
+}
+
+fn signal<const F: u32>() -> Vec<ComplexFixed<F>> {
+    (0..N)
+        .map(|i| {
+            ComplexFixed::new(
+                Fixed::from_f64((i as f64 * 0.7).sin() * 0.4),
+                Fixed::from_f64((i as f64 * 1.3).cos() * 0.2),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_bit_exact_with_static_formats() {
+    let (mut tw, mut br) = (vec![ComplexFixed::ZERO; N / 2], vec![0; N]);
+    let (mut tw2, mut br2) = (vec![ComplexFixed::ZERO; N / 2], vec![0; N]);
+    let reference = plan(&mut tw, &mut br).with_scaling(Scaling::Forward);
+    let dynamic =
+        DynFixedFft::new(plan(&mut tw2, &mut br2).with_scaling(Scaling::Forward), 13).unwrap();
+
+    let mut expected = signal::<13>();
+    reference.process(&mut expected, false).unwrap();
+
+    let mut buffer: Vec<DynComplexFixed> = signal::<13>()
+        .iter()
+        .map(|c| DynComplexFixed::new(DynFixed::from_fixed(c.re), DynFixed::from_fixed(c.im)))
+        .collect();
+    let mut words = vec![0i32; 2 * N];
+    dynamic
+        .process(&mut buffer, &mut Scratch::new(&mut words), false)
+        .unwrap();
+
+    for (got, want) in buffer.iter().zip(&expected) {
+        assert_eq!(got.frac(), 13);
+        assert_eq!(got.re().to_bits(), want.re.to_bits());
+        assert_eq!(got.im().to_bits(), want.im.to_bits());
+    }
+}
+
+#[test]
+fn test_mixed_input_formats_are_converted() {
+    let (mut tw, mut br) = (vec![ComplexFixed::ZERO; N / 2], vec![0; N]);
+    let dynamic = DynFixedFft::new(plan(&mut tw, &mut br), 20).unwrap();
+    assert_eq!(dynamic.frac(), 20);
+    assert_eq!(dynamic.len(), N);
+
+    // Unit impulse given in Q8: flat spectrum of ones in Q20
+    let mut buffer =
+        vec![DynComplexFixed::new(DynFixed::new(0, 8).unwrap(), DynFixed::new(0, 8).unwrap()); N];
+    buffer[0] = DynComplexFixed::from_bits(1 << 8, 0, 8).unwrap();
+    let mut words = vec![0i32; 2 * N];
+    dynamic
+        .process(&mut buffer, &mut Scratch::new(&mut words), false)
+        .unwrap();
+    assert!(
+        buffer
+            .iter()
+            .all(|x| x.re().to_f64() == 1.0 && x.im().to_bits() == 0)
+    );
+}
+
+#[test]
+fn test_errors() {
+    let (mut tw, mut br) = (vec![ComplexFixed::ZERO; N / 2], vec![0; N]);
+    assert!(matches!(
+        DynFixedFft::new(plan(&mut tw, &mut br), 32),
+        Err(FftError::OutOfRange)
+    ));
+
+    let dynamic = DynFixedFft::new(plan(&mut tw, &mut br), 15).unwrap();
+    let mut words = vec![0i32; 2 * N - 1];
+    assert_eq!(
+        dynamic.process_raw(&mut words, false),
+        Err(FftError::SizeMismatch)
+    );
+    let mut buffer = vec![DynComplexFixed::from_bits(0, 0, 15).unwrap(); N];
+    assert_eq!(
+        dynamic.process(&mut buffer, &mut Scratch::new(&mut words), false),
+        Err(FftError::BufferTooSmall)
+    );
+}
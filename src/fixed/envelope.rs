@@ -0,0 +1,81 @@
+use super::core::{TWIDDLE_FRAC, radix_2_fft};
+use super::types::{ComplexFixed, Fixed};
+use crate::common::{CplxFft, FftError, band_bins};
+
+impl<'a> CplxFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
+    /// Fixed-point envelope spectrum, see the `Complex32` version.
+    ///
+    /// Both forward transforms are scaled by 1/N and the inverse is not,
+    /// whatever `self.scaling` says, so nothing grows past the input's
+    /// range: `out` reads single-sided amplitudes in `Q<FRAC>`.
+    pub fn envelope_spectrum<const FRAC: u32>(
+        &self,
+        signal: &[Fixed<FRAC>],
+        band_hz: (f32, f32),
+        sample_rate: f32,
+        scratch: &mut [ComplexFixed<FRAC>],
+        out: &mut [Fixed<FRAC>],
+    ) -> Result<(), FftError> {
+        self.validate()?;
+        let n = self.n;
+        if signal.len() != n || out.len() != n / 2 + 1 {
+            return Err(FftError::SizeMismatch);
+        }
+        let (lo, hi) = band_bins(n, band_hz, sample_rate)?;
+        let buffer = scratch.get_mut(..n).ok_or(FftError::BufferTooSmall)?;
+
+        for (b, &x) in buffer.iter_mut().zip(signal) {
+            *b = ComplexFixed::new(x, Fixed::ZERO);
+        }
+        self.transform(buffer, false, true);
+        for (k, b) in buffer.iter_mut().enumerate() {
+            *b = if (lo..=hi).contains(&k) {
+                ComplexFixed::new(b.re + b.re, b.im + b.im)
+            } else {
+                ComplexFixed::ZERO
+            };
+        }
+        self.transform(buffer, true, false);
+
+        let mut sum = 0i64;
+        for b in buffer.iter_mut() {
+            let envelope = b.abs();
+            sum += envelope.to_bits() as i64;
+            *b = ComplexFixed::new(envelope, Fixed::ZERO);
+        }
+        let mean = Fixed::<FRAC>::from_bits((sum / n as i64) as i32);
+        for b in buffer.iter_mut() {
+            b.re -= mean;
+        }
+        self.transform(buffer, false, true);
+
+        out[0] = buffer[0].abs();
+        out[n / 2] = buffer[n / 2].abs();
+        for (o, b) in out[1..n / 2].iter_mut().zip(&buffer[1..n / 2]) {
+            let m = b.abs();
+            *o = m + m;
+        }
+        Ok(())
+    }
+
+    fn transform<const FRAC: u32>(
+        &self,
+        buffer: &mut [ComplexFixed<FRAC>],
+        inverse: bool,
+        scale: bool,
+    ) {
+        radix_2_fft(
+            buffer,
+            self.twiddles,
+            self.bitrev,
+            1,
+            self.algorithm,
+            inverse,
+            scale,
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "envelope_tests.rs"]
+mod tests;
@@ -0,0 +1,36 @@
+use super::*;
+use core::f32::consts::PI;
+
+const N: usize = 1024;
+const FS: f32 = 8000.0;
+
+#[test]
+fn test_modulation_shows_in_fixed_envelope_spectrum() {
+    let mut twiddles = vec![ComplexFixed::ZERO; N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    // The float test's signal at a quarter of the level, in Q15
+    let signal: Vec<Fixed<15>> = (0..N)
+        .map(|i| {
+            let t = i as f32 / FS;
+            let am = 1.0 + 0.5 * (2.0 * PI * 62.5 * t).cos();
+            let x =
+                0.25 * (am * (2.0 * PI * 1000.0 * t).cos() + 2.0 * (2.0 * PI * 93.75 * t).sin());
+            Fixed::from_f64(x as f64)
+        })
+        .collect();
+    let mut scratch = vec![ComplexFixed::ZERO; N];
+    let mut out = vec![Fixed::<15>::ZERO; N / 2 + 1];
+    fft.envelope_spectrum(&signal, (800.0, 1200.0), FS, &mut scratch, &mut out)
+        .unwrap();
+
+    let read = |x: Fixed<15>| x.to_bits() as f32 / 32768.0;
+    assert!((read(out[8]) - 0.125).abs() < 2e-3, "{}", read(out[8]));
+    let others = out
+        .iter()
+        .enumerate()
+        .filter(|&(k, _)| k != 8)
+        .fold(0.0f32, |m, (_, &v)| m.max(read(v)));
+    assert!(others < 2e-3, "{others}");
+}
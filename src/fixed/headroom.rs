@@ -0,0 +1,142 @@
+// src/fixed/headroom.rs
+
+use super::core::TWIDDLE_FRAC;
+use super::types::{ComplexFixed, Fixed};
+use crate::common::{CplxFft, FftError, RealFft, Scaling};
+
+/// Worst-case bit growth of a fixed transform, stage by stage.
+///
+/// Unscaled, every butterfly stage can double a magnitude, so the output
+/// may reach N times the input: one bit per stage. Per-stage scaling halves
+/// each stage, which cancels the doubling. On top of that, a complex
+/// rotation can move up to sqrt(2) of a magnitude into one component, and
+/// rounding needs one spare bit; both are paid once, not per stage. For a
+/// real plan the post-processing stage takes the place of the complex
+/// core's last one, so `stages` is log2(N) for both.
+///
+/// Every figure is a bound that holds for any input, which is what makes
+/// it suitable for a written headroom argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuardBitReport {
+    /// Transform size N.
+    pub n: usize,
+    /// Number of butterfly stages, log2(N).
+    pub stages: u32,
+    /// Bits a single stage can add: 1 unscaled, 0 scaled.
+    pub stage_growth: u32,
+    /// Bit for the sqrt(2) a rotation can move into one component.
+    pub rotation: u32,
+    /// Spare bit for rounding.
+    pub rounding: u32,
+}
+
+impl GuardBitReport {
+    /// Guard bits needed above the input's largest component once the
+    /// first `stage` stages have run (`stages` for the whole transform).
+    pub const fn bits_after_stage(&self, stage: u32) -> u32 {
+        let stage = if stage < self.stages {
+            stage
+        } else {
+            self.stages
+        };
+        stage * self.stage_growth + self.rotation + self.rounding
+    }
+
+    /// Guard bits the whole transform needs.
+    pub const fn total(&self) -> u32 {
+        self.bits_after_stage(self.stages)
+    }
+
+    /// Largest safe input: every component must stay below
+    /// `2^max_input_bits` LSB (sign excluded).
+    pub const fn max_input_bits(&self) -> u32 {
+        31u32.saturating_sub(self.total())
+    }
+}
+
+/// Guard-bit analysis of an `n`-point fixed transform with the given
+/// scaling and direction. A `const fn`, so a headroom budget can be
+/// asserted at compile time:
+///
+/// ```
+/// use rs_simple_fft::Scaling;
+/// use rs_simple_fft::fixed::headroom::guard_bit_report;
+///
+/// // 12-bit ADC samples fit a 1024-point unscaled transform
+/// const REPORT: rs_simple_fft::fixed::headroom::GuardBitReport =
+///     guard_bit_report(1024, Scaling::Inverse, false);
+/// const _: () = assert!(REPORT.max_input_bits() >= 12);
+/// ```
+///
+/// `n` is taken as a power of two.
+pub const fn guard_bit_report(n: usize, scaling: Scaling, inverse: bool) -> GuardBitReport {
+    GuardBitReport {
+        n,
+        stages: n.trailing_zeros(),
+        stage_growth: if scaling.is_scaled(inverse) { 0 } else { 1 },
+        rotation: 1,
+        rounding: 1,
+    }
+}
+
+/// Guard bits an `n`-point fixed transform needs between the input peak and
+/// the top of the 32-bit word, see [`GuardBitReport`].
+pub(crate) const fn guard_bits(n: usize, scaled: bool) -> u32 {
+    let scaling = if scaled {
+        Scaling::Forward
+    } else {
+        Scaling::None
+    };
+    guard_bit_report(n, scaling, false).total()
+}
+
+impl CplxFft<'_, ComplexFixed<TWIDDLE_FRAC>> {
+    /// Guard-bit analysis of this plan in the given direction, under its
+    /// current scaling.
+    pub const fn guard_bits(&self, inverse: bool) -> GuardBitReport {
+        guard_bit_report(self.n, self.scaling, inverse)
+    }
+}
+
+impl RealFft<'_, ComplexFixed<TWIDDLE_FRAC>> {
+    /// Guard-bit analysis of this plan in the given direction, under its
+    /// current scaling.
+    pub const fn guard_bits(&self, inverse: bool) -> GuardBitReport {
+        guard_bit_report(self.n, self.scaling, inverse)
+    }
+}
+
+/// Left shift to apply to an input whose largest component is
+/// `signal_peak` before an `n`-point fixed transform with the given scaling
+/// and direction: the largest shift that still cannot overflow.
+///
+/// A negative result is a right shift the input needs to be safe; a
+/// positive one is precision left unused. The bound is analytic (see
+/// [`CplxFft::process_bfp`](crate::CplxFft::process_bfp), which applies
+/// it), so it holds for every input of that peak, not just typical ones.
+pub fn suggest_input_shift<const FRAC: u32>(
+    n: usize,
+    signal_peak: Fixed<FRAC>,
+    scaling: Scaling,
+    inverse: bool,
+) -> Result<i32, FftError> {
+    if !n.is_power_of_two() {
+        return Err(FftError::NotPowerOfTwo);
+    }
+    Ok(input_shift(
+        n,
+        signal_peak.to_bits().unsigned_abs(),
+        scaling.is_scaled(inverse),
+    ))
+}
+
+/// [`suggest_input_shift`] on a raw peak magnitude.
+pub(crate) fn input_shift(n: usize, peak: u32, scaled: bool) -> i32 {
+    // Redundant sign bits available above the peak
+    let headroom = peak.leading_zeros() as i32 - 1;
+    headroom - guard_bits(n, scaled) as i32
+}
+
+#[cfg(test)]
+#[path = "headroom_tests.rs"]
+mod tests;
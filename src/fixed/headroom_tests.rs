@@ -0,0 +1,208 @@
+use super::*;
+use crate::common::{CplxFft, RealFft};
+use crate::fixed::ComplexFixed;
+use crate::fixed::core::TWIDDLE_FRAC;
+use core::f64::consts::PI;
+
+/// Inputs that drive the butterflies hardest: a constant, the Nyquist
+/// alternation and a tone, all with components up to `peak` LSB.
+fn worst_cases(n: usize, peak: i32) -> Vec<Vec<(i32, i32)>> {
+    let p = peak as f64;
+    vec![
+        vec![(peak, peak); n],
+        (0..n)
+            .map(|i| {
+                if i % 2 == 0 {
+                    (peak, -peak)
+                } else {
+                    (-peak, peak)
+                }
+            })
+            .collect(),
+        (0..n)
+            .map(|i| {
+                let phase = 2.0 * PI * (3 * i) as f64 / n as f64;
+                ((p * phase.cos()) as i32, (p * phase.sin()) as i32)
+            })
+            .collect(),
+    ]
+}
+
+fn dft(x: &[(i32, i32)], k: usize) -> (f64, f64) {
+    let n = x.len();
+    x.iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(re, im), (i, &(a, b))| {
+            let phase = -2.0 * PI * ((k * i) % n) as f64 / n as f64;
+            let (s, c) = phase.sin_cos();
+            (
+                re + a as f64 * c - b as f64 * s,
+                im + a as f64 * s + b as f64 * c,
+            )
+        })
+}
+
+#[test]
+fn test_complex_transform_stays_in_range() {
+    for n in [8, 64, 256] {
+        let mut twiddles = vec![ComplexFixed::ZERO; n / 2];
+        let mut bitrev = vec![0; n];
+        for scaling in [Scaling::None, Scaling::Forward] {
+            let fft = CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+                .unwrap()
+                .with_scaling(scaling);
+            // A 16-bit capture, shifted up as far as the suggestion allows
+            let peak = Fixed::<15>::from_bits(32767);
+            let shift = suggest_input_shift(n, peak, scaling, false).unwrap();
+            assert!(shift > 0);
+
+            for input in worst_cases(n, 32767 << shift) {
+                let mut buf: Vec<ComplexFixed<15>> = input
+                    .iter()
+                    .map(|&(a, b)| ComplexFixed::new(Fixed::from_bits(a), Fixed::from_bits(b)))
+                    .collect();
+                fft.process(&mut buf, false).unwrap();
+
+                let norm = if scaling == Scaling::Forward {
+                    n as f64
+                } else {
+                    1.0
+                };
+                for (k, c) in buf.iter().enumerate() {
+                    let (re, im) = dft(&input, k);
+                    let tolerance = 1e-4 * (n as f64) * (32767u32 << shift) as f64 / norm;
+                    assert!(
+                        (c.re.to_bits() as f64 - re / norm).abs() < tolerance,
+                        "n {n}, bin {k}"
+                    );
+                    assert!(
+                        (c.im.to_bits() as f64 - im / norm).abs() < tolerance,
+                        "n {n}, bin {k}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_real_transform_stays_in_range() {
+    let n = 128;
+    let mut twiddles = vec![ComplexFixed::ZERO; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::None);
+    let peak = Fixed::<15>::from_bits(-32768);
+    let shift = suggest_input_shift(n, peak, Scaling::None, false).unwrap();
+    let p = 32768 << shift;
+
+    // DC and Nyquist collect N times the peak
+    let mut dc = vec![Fixed::<15>::from_bits(-p); n];
+    fft.process(&mut dc, false).unwrap();
+    assert_eq!(dc[0].to_bits() as i64, -(n as i64) * p as i64);
+    let mut nyquist: Vec<Fixed<15>> = (0..n)
+        .map(|i| Fixed::from_bits(if i % 2 == 0 { p } else { -p }))
+        .collect();
+    fft.process(&mut nyquist, false).unwrap();
+    assert_eq!(nyquist[1].to_bits() as i64, n as i64 * p as i64);
+}
+
+#[test]
+fn test_suggestion_follows_peak_and_policy() {
+    let q = |bits: i32| Fixed::<15>::from_bits(bits);
+    // 2^14 has 16 redundant sign bits; 1024 points need 12 guard bits
+    assert_eq!(
+        suggest_input_shift(1024, q(1 << 14), Scaling::None, false),
+        Ok(4)
+    );
+    assert_eq!(
+        suggest_input_shift(1024, q(-(1 << 14)), Scaling::None, true),
+        Ok(4)
+    );
+    // Scaled direction: only the two spare bits
+    assert_eq!(
+        suggest_input_shift(1024, q(1 << 14), Scaling::Inverse, true),
+        Ok(14)
+    );
+    // Already too loud for an unscaled transform
+    assert_eq!(
+        suggest_input_shift(1024, q(1 << 25), Scaling::Forward, true),
+        Ok(-7)
+    );
+    assert_eq!(
+        suggest_input_shift(1000, q(1), Scaling::None, false),
+        Err(FftError::NotPowerOfTwo)
+    );
+}
+
+#[test]
+fn test_guard_bit_report() {
+    const UNSCALED: GuardBitReport = guard_bit_report(1024, Scaling::Inverse, false);
+    assert_eq!(UNSCALED.stages, 10);
+    assert_eq!(UNSCALED.bits_after_stage(0), 2);
+    assert_eq!(UNSCALED.bits_after_stage(4), 6);
+    assert_eq!(UNSCALED.total(), 12);
+    assert_eq!(UNSCALED.bits_after_stage(99), 12);
+    assert_eq!(UNSCALED.max_input_bits(), 19);
+
+    let scaled = guard_bit_report(1024, Scaling::Inverse, true);
+    assert_eq!(scaled.stage_growth, 0);
+    assert_eq!(scaled.total(), 2);
+
+    // The plans report under their own scaling
+    let n = 64;
+    let mut twiddles = vec![ComplexFixed::ZERO; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::Forward);
+    assert_eq!(fft.guard_bits(false).total(), 2);
+    assert_eq!(fft.guard_bits(true).total(), 8);
+    // suggest_input_shift applies the same budget
+    let peak = Fixed::<15>::from_bits((1 << fft.guard_bits(true).max_input_bits()) - 1);
+    assert_eq!(suggest_input_shift(n, peak, Scaling::Forward, true), Ok(0));
+}
+
+#[test]
+fn test_stages_stay_within_their_budget() {
+    use crate::common::StageState;
+
+    let n = 256;
+    let mut twiddles = vec![ComplexFixed::ZERO; n / 2];
+    let mut bitrev = vec![0; n];
+    for scaling in [Scaling::None, Scaling::Forward] {
+        let fft = CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_scaling(scaling);
+        let report = fft.guard_bits(false);
+        let peak = (1 << report.max_input_bits()) - 1;
+
+        for input in worst_cases(n, peak) {
+            let mut buf: Vec<ComplexFixed<15>> = input
+                .iter()
+                .map(|&(a, b)| ComplexFixed::new(Fixed::from_bits(a), Fixed::from_bits(b)))
+                .collect();
+            let mut state = StageState::new(false);
+            // Stage 0 is the permutation, butterfly stage s follows it
+            loop {
+                let done = fft.process_stage(&mut buf, &mut state).unwrap();
+                let stage = state.next_stage() as u32 - 1;
+                let limit = 1i64 << (report.max_input_bits() + report.bits_after_stage(stage));
+                let largest = buf
+                    .iter()
+                    .map(|c| {
+                        (c.re.to_bits() as i64)
+                            .abs()
+                            .max((c.im.to_bits() as i64).abs())
+                    })
+                    .max()
+                    .unwrap();
+                assert!(largest < limit, "{scaling:?}, stage {stage}");
+                if done {
+                    break;
+                }
+            }
+        }
+    }
+}
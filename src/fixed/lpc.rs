@@ -0,0 +1,32 @@
+use super::core::TWIDDLE_FRAC;
+use super::types::{ComplexFixed, Fixed};
+use crate::common::{FftError, RealFft};
+use crate::solvers::levinson_durbin_fixed;
+
+impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
+    /// Fixed-point linear prediction, see the `Complex32` version. The
+    /// autocorrelation's block exponent does not matter to the recursion,
+    /// which is [`levinson_durbin_fixed`]; its relative prediction error is
+    /// returned.
+    pub fn lpc<const FRAC: u32, const AF: u32>(
+        &self,
+        frame: &[Fixed<FRAC>],
+        a: &mut [Fixed<AF>],
+        k: &mut [Fixed<31>],
+        scratch: &mut [Fixed<FRAC>],
+    ) -> Result<Fixed<31>, FftError> {
+        let n = self.n;
+        if frame.len() + a.len() > n {
+            return Err(FftError::SizeMismatch);
+        }
+        let r = scratch.get_mut(..n).ok_or(FftError::BufferTooSmall)?;
+        r[..frame.len()].copy_from_slice(frame);
+        r[frame.len()..].fill(Fixed::ZERO);
+        self.autocorrelate(r)?;
+        levinson_durbin_fixed(&r[..=a.len()], a, k)
+    }
+}
+
+#[cfg(test)]
+#[path = "lpc_tests.rs"]
+mod tests;
@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn test_matches_float_lpc() {
+    const N: usize = 512;
+    // A decaying resonance: x[n] = 1.6 x[n-1] - 0.8 x[n-2] after a kick
+    let mut x = vec![0.0f64; 200];
+    x[0] = 0.5;
+    for n in 1..x.len() {
+        let x2 = if n >= 2 { x[n - 2] } else { 0.0 };
+        x[n] = 1.6 * x[n - 1] - 0.8 * x2;
+    }
+    let frame: Vec<Fixed<15>> = x.iter().map(|&v| Fixed::from_f64(v)).collect();
+
+    let mut twiddles = vec![ComplexFixed::ZERO; N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut a = [Fixed::<27>::ZERO; 2];
+    let mut k = [Fixed::<31>::ZERO; 2];
+    let mut scratch = vec![Fixed::<15>::ZERO; N];
+    let err = fft.lpc(&frame, &mut a, &mut k, &mut scratch).unwrap();
+
+    let read = |v: Fixed<27>| v.to_bits() as f64 / (1 << 27) as f64;
+    assert!((read(a[0]) + 1.6).abs() < 0.02, "{}", read(a[0]));
+    assert!((read(a[1]) - 0.8).abs() < 0.02, "{}", read(a[1]));
+    // Only the kick is unpredictable: 0.25 / r[0] = 0.0756
+    let err = err.to_bits() as f64 / 2f64.powi(31);
+    assert!((err - 0.0756).abs() < 0.002, "{err}");
+}
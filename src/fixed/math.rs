@@ -0,0 +1,143 @@
+// src/fixed/math.rs
+
+use super::types::Fixed;
+
+/// Fractional bits of a fixed-point angle in radians.
+///
+/// Q29 leaves two integer bits, enough for the full (-pi, pi] range.
+pub const ANGLE_FRAC: u32 = 29;
+
+/// Angle in radians, as returned by the phase helpers.
+pub type Angle = Fixed<ANGLE_FRAC>;
+
+/// Accuracy tier of [`ComplexFixed::magnitude_approx`](super::ComplexFixed::magnitude_approx).
+///
+/// All tiers are alpha-max-plus-beta-min estimators with coefficients chosen
+/// to be cheap in shifts and adds; errors are relative to the exact magnitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MagnitudeTier {
+    /// `max + min/2`: shifts only, error within 0 .. +11.8%.
+    Fast,
+    /// `15/16 max + 15/32 min`: error within -6.25% .. +4.8%.
+    #[default]
+    Balanced,
+    /// Larger of `max + 5/32 min` and `27/32 max + 71/128 min`:
+    /// error within ±1.22%.
+    Accurate,
+}
+
+/// pi in Q29.
+const PI_Q29: i64 = 1_686_629_713;
+
+/// atan(2^-i) in Q29 for the CORDIC iterations.
+const ATAN_TABLE: [i64; 30] = [
+    421_657_428,
+    248_918_915,
+    131_521_918,
+    66_762_579,
+    33_510_843,
+    16_771_758,
+    8_387_925,
+    4_194_219,
+    2_097_141,
+    1_048_575,
+    524_288,
+    262_144,
+    131_072,
+    65_536,
+    32_768,
+    16_384,
+    8_192,
+    4_096,
+    2_048,
+    1_024,
+    512,
+    256,
+    128,
+    64,
+    32,
+    16,
+    8,
+    4,
+    2,
+    1,
+];
+
+impl<const FRAC: u32> Fixed<FRAC> {
+    /// Square root, correctly rounded to the nearest LSB (max error 0.5 LSB).
+    ///
+    /// Computed with Heron's method on the 64-bit value `bits << FRAC`, so no
+    /// float unit is needed. Negative inputs return zero.
+    pub fn sqrt(self) -> Self {
+        let bits = self.to_bits();
+        if bits <= 0 {
+            return Self::from_bits(0);
+        }
+        let root = isqrt_round((bits as u64) << FRAC);
+        Self::from_bits(root.min(i32::MAX as u64) as i32)
+    }
+}
+
+/// Rounded integer square root of a 64-bit value.
+pub(crate) fn isqrt_round(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    // Start above the root so that Heron's iteration decreases monotonically
+    let mut x = 1u64 << (64 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            break;
+        }
+        x = y;
+    }
+    // x = floor(sqrt(n)); round up past (x + 0.5)²
+    if n - x * x > x { x + 1 } else { x }
+}
+
+/// Four-quadrant arctangent of `y / x` in radians, in (-pi, pi].
+///
+/// Uses 30 CORDIC vectoring iterations on normalized 64-bit intermediates,
+/// so the accuracy does not depend on the input magnitude: the maximum error
+/// is 6 LSB of Q29 (about 1.1e-8 rad). `atan2(0, 0)` is zero.
+pub fn atan2<const FRAC: u32>(y: Fixed<FRAC>, x: Fixed<FRAC>) -> Angle {
+    let (mut x, mut y) = (x.to_bits() as i64, y.to_bits() as i64);
+    if x == 0 && y == 0 {
+        return Angle::from_bits(0);
+    }
+
+    // Fold the left half-plane onto the right one by rotating by pi
+    let mut z = 0i64;
+    if x < 0 {
+        z = if y >= 0 { PI_Q29 } else { -PI_Q29 };
+        x = -x;
+        y = -y;
+    }
+
+    // Normalize to ~2^59 so small inputs keep full precision, leaving
+    // headroom for the CORDIC gain (~1.65)
+    let shift = (x | y.abs()).leading_zeros().saturating_sub(5);
+    x <<= shift;
+    y <<= shift;
+
+    for (i, &step) in ATAN_TABLE.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            z += step;
+        } else {
+            x -= dx;
+            y += dy;
+            z -= step;
+        }
+    }
+
+    // Keep the residual error from stepping outside (-pi, pi]
+    Angle::from_bits(z.clamp(-PI_Q29 + 1, PI_Q29) as i32)
+}
+
+#[cfg(test)]
+#[path = "math_tests.rs"]
+mod tests;
@@ -0,0 +1,70 @@
+use super::*;
+use core::f64::consts::PI;
+
+#[test]
+fn test_sqrt_correctly_rounded() {
+    assert_eq!(Fixed::<16>::from_int(9).sqrt(), Fixed::from_int(3));
+    assert_eq!(Fixed::<16>::from_int(0).sqrt(), Fixed::from_int(0));
+    assert_eq!(Fixed::<16>::from_int(-4).sqrt(), Fixed::from_int(0));
+    assert_eq!(
+        Fixed::<31>::from_bits(1 << 29).sqrt(),
+        Fixed::from_bits(1 << 30)
+    );
+
+    // Full-range sweep against the f64 reference, for several formats
+    for bits in (1..i32::MAX).step_by(9_999_991).chain([i32::MAX]) {
+        let q15 = Fixed::<15>::from_bits(bits).sqrt().to_bits() as f64;
+        let q31 = Fixed::<31>::from_bits(bits).sqrt().to_bits() as f64;
+        let v = bits as f64;
+        assert!((q15 - (v * 32768.0).sqrt()).abs() <= 0.5, "bits = {}", bits);
+        assert!(
+            (q31 - (v * 2147483648.0).sqrt()).abs() <= 0.5,
+            "bits = {}",
+            bits
+        );
+    }
+}
+
+#[test]
+fn test_isqrt_round() {
+    for n in 0..10_000u64 {
+        let r = isqrt_round(n);
+        assert_eq!(r, (n as f64).sqrt().round() as u64, "n = {}", n);
+    }
+    assert_eq!(isqrt_round(u64::MAX), 1 << 32);
+}
+
+#[test]
+fn test_atan2_accuracy() {
+    let lsb = 1.0 / (1u64 << ANGLE_FRAC) as f64;
+    let mut worst = 0.0f64;
+
+    // Points on circles of several radii, including tiny ones
+    for radius in [3i32, 100, 30_000, 1 << 20, i32::MAX / 2] {
+        for step in 0..720 {
+            let theta = -PI + (step as f64 + 0.5) * PI / 360.0;
+            let x = (radius as f64 * theta.cos()).round() as i32;
+            let y = (radius as f64 * theta.sin()).round() as i32;
+            let got = atan2(Fixed::<15>::from_bits(y), Fixed::<15>::from_bits(x));
+            let expected = (y as f64).atan2(x as f64);
+            worst = worst.max((got.to_bits() as f64 * lsb - expected).abs());
+        }
+    }
+    assert!(worst <= 6.0 * lsb, "worst error {} LSB", worst / lsb);
+}
+
+#[test]
+fn test_atan2_axes() {
+    let one = Fixed::<16>::from_int(1);
+    let zero = Fixed::<16>::from_int(0);
+    let neg = Fixed::<16>::from_int(-1);
+    let q = |a: f64| (a * (1u64 << ANGLE_FRAC) as f64).round() as i32;
+
+    assert_eq!(atan2(zero, zero).to_bits(), 0);
+    assert!((atan2(zero, one).to_bits()).abs() <= 2);
+    assert!((atan2(one, zero).to_bits() - q(PI / 2.0)).abs() <= 4);
+    assert!((atan2(neg, zero).to_bits() + q(PI / 2.0)).abs() <= 4);
+    // The negative real axis is +pi, never -pi
+    let pi = atan2(zero, neg).to_bits();
+    assert!(pi > 0 && q(PI) - pi <= 4);
+}
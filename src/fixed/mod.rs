@@ -1,7 +1,23 @@
+mod autocorr;
+pub mod bfp;
 pub mod complex;
-mod core;
+pub(crate) mod core;
+pub mod dynamic;
+mod envelope;
+pub mod headroom;
+mod lpc;
 pub mod math;
+mod pair;
+pub mod power;
+pub mod quantize;
 pub mod real;
+mod staged;
 pub mod types;
 
-pub use types::{ComplexFixed, Fixed};
+pub use bfp::BfpSpectrum;
+pub use dynamic::DynFixedFft;
+pub use headroom::{GuardBitReport, guard_bit_report, suggest_input_shift};
+pub use math::MagnitudeTier;
+pub use power::PowerAccumulator;
+pub use quantize::{Dither, dequantize_slice, quantize_slice};
+pub use types::{ComplexFixed, DynComplexFixed, DynFixed, Fixed};
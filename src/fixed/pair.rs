@@ -0,0 +1,105 @@
+// src/fixed/pair.rs
+
+use super::core::TWIDDLE_FRAC;
+use super::types::{ComplexFixed, Fixed};
+use crate::common::{CplxFft, FftError, Scratch};
+
+impl<'a> CplxFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
+    /// Transforms two real signals of size N at once with a single N-point complex FFT.
+    ///
+    /// `a` and `b` are packed in-place into the Real FFT format (DC, Nyquist,
+    /// then positive frequencies), exactly as `RealFft::process` would produce.
+    /// `scratch` must hold at least `self.scratch_len()` (N) complex values.
+    pub fn process_pair<const FRAC: u32>(
+        &self,
+        a: &mut [Fixed<FRAC>],
+        b: &mut [Fixed<FRAC>],
+        scratch: &mut Scratch<'_, ComplexFixed<FRAC>>,
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if a.len() != n || b.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+        if n < 2 {
+            return Err(FftError::BufferTooSmall);
+        }
+        let z = scratch.take(n)?;
+
+        // z = a + i*b
+        for (i, val) in z.iter_mut().enumerate() {
+            *val = ComplexFixed::new(a[i], b[i]);
+        }
+
+        self.process(z, false)?;
+
+        // DC and Nyquist of both signals are purely real
+        a[0] = z[0].re;
+        b[0] = z[0].im;
+        a[1] = z[n / 2].re;
+        b[1] = z[n / 2].im;
+
+        for k in 1..n / 2 {
+            let zk = z[k].scale_half();
+            let zn = z[n - k].conj().scale_half();
+
+            // A[k] = (Z[k] + conj(Z[N-k])) / 2
+            let fa = zk + zn;
+            // B[k] = (Z[k] - conj(Z[N-k])) / 2i
+            let fb = (zk - zn).mul_neg_i();
+
+            a[2 * k] = fa.re;
+            a[2 * k + 1] = fa.im;
+            b[2 * k] = fb.re;
+            b[2 * k + 1] = fb.im;
+        }
+
+        Ok(())
+    }
+
+    /// Synthesizes two real signals of size N from two packed spectra with a single
+    /// N-point complex inverse FFT. This is the inverse of [`Self::process_pair`].
+    ///
+    /// `scratch` must hold at least `self.scratch_len()` (N) complex values.
+    pub fn process_pair_inverse<const FRAC: u32>(
+        &self,
+        a: &mut [Fixed<FRAC>],
+        b: &mut [Fixed<FRAC>],
+        scratch: &mut Scratch<'_, ComplexFixed<FRAC>>,
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if a.len() != n || b.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+        if n < 2 {
+            return Err(FftError::BufferTooSmall);
+        }
+        let z = scratch.take(n)?;
+
+        // Z[k] = A[k] + i*B[k], using the Hermitian symmetry of both spectra
+        z[0] = ComplexFixed::new(a[0], b[0]);
+        z[n / 2] = ComplexFixed::new(a[1], b[1]);
+
+        for k in 1..n / 2 {
+            let (a_re, a_im) = (a[2 * k], a[2 * k + 1]);
+            let (b_re, b_im) = (b[2 * k], b[2 * k + 1]);
+
+            // Z[k] = A + iB = (a_re - b_im) + i(a_im + b_re)
+            z[k] = ComplexFixed::new(a_re - b_im, a_im + b_re);
+            // Z[N-k] = conj(A) + i*conj(B) = (a_re + b_im) + i(b_re - a_im)
+            z[n - k] = ComplexFixed::new(a_re + b_im, b_re - a_im);
+        }
+
+        self.process(z, true)?;
+
+        for (i, val) in z.iter().enumerate() {
+            a[i] = val.re;
+            b[i] = val.im;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "pair_tests.rs"]
+mod tests;
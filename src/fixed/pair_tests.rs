@@ -0,0 +1,86 @@
+use super::super::core::TWIDDLE_FRAC;
+use super::super::types::{ComplexFixed, Fixed};
+use crate::common::{CplxFft, RealFft, Scratch};
+
+const FRAC: u32 = 15;
+
+fn to_f64<const F: u32>(val: Fixed<F>) -> f64 {
+    val.to_bits() as f64 / (1u64 << F) as f64
+}
+
+fn assert_fixed_close<const F: u32>(val: Fixed<F>, expected: f64, tolerance: f64) {
+    let float_val = to_f64(val);
+    assert!(
+        (float_val - expected).abs() < tolerance,
+        "Expected: {:.4}, Got: {:.4} (diff: {:.4})",
+        expected,
+        float_val,
+        (float_val - expected).abs()
+    );
+}
+
+fn zero_twiddles(len: usize) -> Vec<ComplexFixed<TWIDDLE_FRAC>> {
+    vec![ComplexFixed::ZERO; len]
+}
+
+fn signals(n: usize) -> (Vec<Fixed<FRAC>>, Vec<Fixed<FRAC>>) {
+    let a = (0..n)
+        .map(|i| Fixed::from_f64((i as f64 * 0.9).sin() * 0.5))
+        .collect();
+    let b = (0..n)
+        .map(|i| Fixed::from_f64((i as f64 * 0.3).cos() * 0.25 - 0.1))
+        .collect();
+    (a, b)
+}
+
+#[test]
+fn test_process_pair_matches_real_fft_q15() {
+    let n = 16;
+    let (mut a, mut b) = signals(n);
+
+    let mut rtw = zero_twiddles(n / 2);
+    let mut rbr = vec![0; n / 2];
+    let rfft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut rtw, &mut rbr, n).unwrap();
+    let mut expected_a = a.clone();
+    let mut expected_b = b.clone();
+    rfft.process(&mut expected_a, false).unwrap();
+    rfft.process(&mut expected_b, false).unwrap();
+
+    let mut twiddles = zero_twiddles(n / 2);
+    let mut bitrev = vec![0; n];
+    let mut scratch_buf =
+        vec![ComplexFixed::new(Fixed::<FRAC>::from_bits(0), Fixed::from_bits(0)); n];
+    let mut scratch = Scratch::new(&mut scratch_buf);
+    let fft =
+        CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    fft.process_pair(&mut a, &mut b, &mut scratch).unwrap();
+
+    for i in 0..n {
+        assert_fixed_close(a[i], to_f64(expected_a[i]), 0.002);
+        assert_fixed_close(b[i], to_f64(expected_b[i]), 0.002);
+    }
+}
+
+#[test]
+fn test_process_pair_inverse_round_trip_q15() {
+    let n = 16;
+    let (orig_a, orig_b) = signals(n);
+    let (mut a, mut b) = (orig_a.clone(), orig_b.clone());
+
+    let mut twiddles = zero_twiddles(n / 2);
+    let mut bitrev = vec![0; n];
+    let mut scratch_buf =
+        vec![ComplexFixed::new(Fixed::<FRAC>::from_bits(0), Fixed::from_bits(0)); n];
+    let mut scratch = Scratch::new(&mut scratch_buf);
+    let fft =
+        CplxFft::<'_, ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    fft.process_pair(&mut a, &mut b, &mut scratch).unwrap();
+    fft.process_pair_inverse(&mut a, &mut b, &mut scratch)
+        .unwrap();
+
+    for i in 0..n {
+        assert_fixed_close(a[i], to_f64(orig_a[i]), 0.002);
+        assert_fixed_close(b[i], to_f64(orig_b[i]), 0.002);
+    }
+}
@@ -0,0 +1,142 @@
+// src/fixed/power.rs
+
+use super::bfp::BfpSpectrum;
+use super::types::ComplexFixed;
+use crate::common::FftError;
+
+/// Running sum of `|X[k]|^2` over fixed-point spectra, for power averaging
+/// on targets without an FPU.
+///
+/// Each bin is summed in an `i64` that stands for `acc[k] * 2^exponent`
+/// squared LSBs. A frame's powers are exact products of the `i32` parts;
+/// when the next sum would overflow, every accumulator is halved and the
+/// exponent raised, so an arbitrarily long average never wraps and only
+/// gives up the lowest bits once the largest bin needs them. Block-floating
+/// point frames keep their exponent, so quiet frames add their full
+/// precision instead of being truncated to the common Q format.
+#[derive(Debug)]
+pub struct PowerAccumulator<'a, const FRAC: u32> {
+    acc: &'a mut [i64],
+    exponent: i32,
+    frames: u32,
+}
+
+impl<'a, const FRAC: u32> PowerAccumulator<'a, FRAC> {
+    /// Wraps one accumulator per bin; `acc` is cleared.
+    pub fn new(acc: &'a mut [i64]) -> Self {
+        acc.fill(0);
+        Self {
+            acc,
+            exponent: 0,
+            frames: 0,
+        }
+    }
+
+    /// Clears the sum.
+    pub fn reset(&mut self) {
+        self.acc.fill(0);
+        self.exponent = 0;
+        self.frames = 0;
+    }
+
+    /// Adds the power of one spectrum in `Q<FRAC>`.
+    pub fn accumulate(&mut self, spectrum: &[ComplexFixed<FRAC>]) -> Result<(), FftError> {
+        self.add(spectrum, 0)
+    }
+
+    /// Adds the power of a block-floating-point spectrum, exponent applied.
+    pub fn accumulate_bfp(&mut self, spectrum: &BfpSpectrum<'_, FRAC>) -> Result<(), FftError> {
+        self.add(spectrum.data, 2 * spectrum.exponent as i32)
+    }
+
+    fn add(&mut self, spectrum: &[ComplexFixed<FRAC>], exponent: i32) -> Result<(), FftError> {
+        if spectrum.len() != self.acc.len() {
+            return Err(FftError::SizeMismatch);
+        }
+        // An empty sum takes the first frame's exponent as is
+        if self.frames == 0 {
+            self.exponent = exponent;
+        } else if exponent > self.exponent {
+            self.rescale(exponent - self.exponent);
+        }
+
+        let peak = spectrum.iter().map(bin_power).max().unwrap_or(0);
+        let mut shift = (self.exponent - exponent) as u32;
+        let mut acc_peak = self.acc.iter().copied().max().unwrap_or(0) as u64;
+        while acc_peak + round_shift(peak, shift) > i64::MAX as u64 {
+            self.rescale(1);
+            acc_peak = round_shift(acc_peak, 1);
+            shift += 1;
+        }
+
+        for (a, c) in self.acc.iter_mut().zip(spectrum.iter()) {
+            *a += round_shift(bin_power(c), shift) as i64;
+        }
+        self.frames += 1;
+        Ok(())
+    }
+
+    /// Shifts every accumulator right by `bits` and raises the exponent.
+    fn rescale(&mut self, bits: i32) {
+        for a in self.acc.iter_mut() {
+            *a = round_shift(*a as u64, bits as u32) as i64;
+        }
+        self.exponent += bits;
+    }
+
+    /// Number of frames summed so far.
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// Exponent of the raw sums: bin `k` totals `acc[k] * 2^exponent`
+    /// squared LSBs of `Q<FRAC>`.
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// The raw per-bin sums.
+    pub fn accumulators(&self) -> &[i64] {
+        self.acc
+    }
+
+    /// Mean power of bin `k`, in squared units of the input. Zero before
+    /// the first frame.
+    pub fn mean_power(&self, k: usize) -> f32 {
+        if self.frames == 0 {
+            return 0.0;
+        }
+        let scale = libm::exp2(self.exponent as f64 - 2.0 * FRAC as f64);
+        (self.acc[k] as f64 * scale / self.frames as f64) as f32
+    }
+
+    /// Writes the mean power of every bin into `out`.
+    pub fn mean_powers(&self, out: &mut [f32]) -> Result<(), FftError> {
+        if out.len() != self.acc.len() {
+            return Err(FftError::SizeMismatch);
+        }
+        for (k, p) in out.iter_mut().enumerate() {
+            *p = self.mean_power(k);
+        }
+        Ok(())
+    }
+}
+
+/// `re^2 + im^2` in squared LSBs; at most 2^63, so it always fits.
+fn bin_power<const FRAC: u32>(c: &ComplexFixed<FRAC>) -> u64 {
+    let (re, im) = (c.re.to_bits() as i64, c.im.to_bits() as i64);
+    (re * re) as u64 + (im * im) as u64
+}
+
+/// Right shift rounding to nearest; shifts past the width give zero.
+fn round_shift(x: u64, shift: u32) -> u64 {
+    match shift {
+        0 => x,
+        1..=63 => (x >> shift) + ((x >> (shift - 1)) & 1),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+#[path = "power_tests.rs"]
+mod tests;
@@ -0,0 +1,78 @@
+use super::*;
+use crate::fixed::Fixed;
+
+fn bin(re: i32, im: i32) -> ComplexFixed<15> {
+    ComplexFixed::new(Fixed::from_bits(re), Fixed::from_bits(im))
+}
+
+#[test]
+fn test_mean_power_is_exact_for_small_sums() {
+    let mut acc = [0i64; 3];
+    let mut power = PowerAccumulator::<15>::new(&mut acc);
+    assert_eq!(power.mean_power(0), 0.0);
+
+    power
+        .accumulate(&[bin(3, 4), bin(0, 0), bin(-1, 0)])
+        .unwrap();
+    power
+        .accumulate(&[bin(0, 5), bin(1, 1), bin(0, -3)])
+        .unwrap();
+    assert_eq!(power.frames(), 2);
+    assert_eq!(power.exponent(), 0);
+    assert_eq!(power.accumulators(), &[50, 2, 10]);
+
+    let lsb2 = 1.0 / (32768.0f32 * 32768.0);
+    let mut out = [0.0f32; 3];
+    power.mean_powers(&mut out).unwrap();
+    assert_eq!(out, [25.0 * lsb2, lsb2, 5.0 * lsb2]);
+
+    assert_eq!(power.accumulate(&[bin(0, 0)]), Err(FftError::SizeMismatch));
+    power.reset();
+    assert_eq!(power.frames(), 0);
+    assert_eq!(power.accumulators(), &[0, 0, 0]);
+}
+
+#[test]
+fn test_full_scale_frames_do_not_overflow() {
+    let mut acc = [0i64; 2];
+    let mut power = PowerAccumulator::<15>::new(&mut acc);
+    let frame = [bin(i32::MIN, i32::MIN), bin(1 << 20, 0)];
+    for _ in 0..1000 {
+        power.accumulate(&frame).unwrap();
+    }
+    assert!(power.exponent() > 0);
+
+    let lsb2 = 1.0 / (32768.0f64 * 32768.0);
+    let expected = [
+        2.0 * (i32::MIN as f64).powi(2) * lsb2,
+        (1u64 << 40) as f64 * lsb2,
+    ];
+    for (k, e) in expected.iter().enumerate() {
+        let p = power.mean_power(k) as f64;
+        assert!((p - e).abs() / e < 1e-6, "bin {k}: {p} vs {e}");
+    }
+}
+
+#[test]
+fn test_bfp_frames_keep_their_exponent() {
+    let mut acc = [0i64; 2];
+    let mut power = PowerAccumulator::<15>::new(&mut acc);
+
+    // A quiet frame, boosted by 2^8 by the block exponent
+    let mut quiet = [bin(3 << 8, 0), bin(0, 1 << 8)];
+    power
+        .accumulate_bfp(&BfpSpectrum {
+            data: &mut quiet,
+            exponent: -8,
+        })
+        .unwrap();
+    assert_eq!(power.exponent(), -16);
+
+    // A louder plain frame raises the exponent back to 0
+    power.accumulate(&[bin(100, 0), bin(0, 0)]).unwrap();
+    assert_eq!(power.exponent(), 0);
+
+    let lsb2 = 1.0 / (32768.0f32 * 32768.0);
+    assert_eq!(power.mean_power(0), (9.0 + 10000.0) / 2.0 * lsb2);
+    assert_eq!(power.mean_power(1), 0.5 * lsb2);
+}
@@ -0,0 +1,90 @@
+// src/fixed/quantize.rs
+
+use super::types::Fixed;
+use crate::common::FftError;
+
+/// Dither applied when quantizing floating-point samples to fixed point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dither {
+    /// Plain round-to-nearest.
+    None,
+    /// Triangular PDF dither of ±1 LSB added before rounding, which decorrelates
+    /// the quantization error from the signal. The seed makes the noise
+    /// sequence reproducible; vary it between calls to avoid repeating patterns.
+    Tpdf { seed: u32 },
+}
+
+/// Converts a float slice to fixed point with round-to-nearest and saturation.
+///
+/// Values outside the representable range of `Fixed<FRAC>` clamp to the
+/// nearest limit instead of wrapping.
+pub fn quantize_slice<const FRAC: u32>(
+    input: &[f32],
+    output: &mut [Fixed<FRAC>],
+    dither: Dither,
+) -> Result<(), FftError> {
+    if input.len() != output.len() {
+        return Err(FftError::SizeMismatch);
+    }
+
+    let scaling_factor = (1u64 << FRAC) as f64;
+    let mut rng = match dither {
+        Dither::None => None,
+        // xorshift32 must not start at zero
+        Dither::Tpdf { seed } => Some(XorShift32(seed.max(1))),
+    };
+
+    for (dst, &x) in output.iter_mut().zip(input) {
+        let mut scaled = x as f64 * scaling_factor;
+        if let Some(rng) = rng.as_mut() {
+            // Sum of two uniform [-0.5, 0.5) LSB values is triangular over (-1, 1) LSB
+            scaled += rng.next_uniform() + rng.next_uniform();
+        }
+        // `as` saturates on overflow and maps NaN to zero
+        *dst = Fixed::from_bits(libm::round(scaled) as i32);
+    }
+
+    Ok(())
+}
+
+/// Converts a fixed-point slice back to floats.
+pub fn dequantize_slice<const FRAC: u32>(
+    input: &[Fixed<FRAC>],
+    output: &mut [f32],
+) -> Result<(), FftError> {
+    if input.len() != output.len() {
+        return Err(FftError::SizeMismatch);
+    }
+
+    let scaling_factor = (1u64 << FRAC) as f64;
+    for (dst, &x) in output.iter_mut().zip(input) {
+        *dst = (x.to_bits() as f64 / scaling_factor) as f32;
+    }
+
+    Ok(())
+}
+
+/// Minimal deterministic PRNG for dither generation.
+struct XorShift32(u32);
+
+impl XorShift32 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        x
+    }
+
+    /// Uniform value in [-0.5, 0.5).
+    #[inline]
+    fn next_uniform(&mut self) -> f64 {
+        self.next_u32() as f64 / 4294967296.0 - 0.5
+    }
+}
+
+#[cfg(test)]
+#[path = "quantize_tests.rs"]
+mod tests;
@@ -0,0 +1,77 @@
+use super::*;
+
+#[test]
+fn test_quantize_rounds_to_nearest() {
+    let input = [0.5f32, -0.25, 1.0 / 3.0, 0.0];
+    let mut output = [Fixed::<15>::from_bits(0); 4];
+
+    quantize_slice(&input, &mut output, Dither::None).unwrap();
+
+    for (q, &x) in output.iter().zip(input.iter()) {
+        assert_eq!(*q, Fixed::<15>::from_f64(x as f64));
+    }
+}
+
+#[test]
+fn test_quantize_saturates() {
+    let input = [1.0f32, -1.0, 2.5, -7.0, f32::NAN];
+    let mut output = [Fixed::<31>::from_bits(0); 5];
+
+    quantize_slice(&input, &mut output, Dither::None).unwrap();
+
+    assert_eq!(output[0].to_bits(), i32::MAX);
+    assert_eq!(output[1].to_bits(), i32::MIN);
+    assert_eq!(output[2].to_bits(), i32::MAX);
+    assert_eq!(output[3].to_bits(), i32::MIN);
+    assert_eq!(output[4].to_bits(), 0);
+}
+
+#[test]
+fn test_dequantize_round_trip() {
+    let input = [0.125f32, -0.75, 0.999, -0.001];
+    let mut fixed = [Fixed::<23>::from_bits(0); 4];
+    let mut back = [0.0f32; 4];
+
+    quantize_slice(&input, &mut fixed, Dither::None).unwrap();
+    dequantize_slice(&fixed, &mut back).unwrap();
+
+    for (&b, &x) in back.iter().zip(input.iter()) {
+        assert!((b - x).abs() <= 0.5 / (1 << 23) as f32);
+    }
+}
+
+#[test]
+fn test_tpdf_dither_is_bounded_and_unbiased() {
+    // A constant exactly halfway between two Q8 codes
+    let lsb = 1.0 / 256.0;
+    let input = [0.5f32 * lsb + 10.0 * lsb; 4096];
+    let mut output = [Fixed::<8>::from_bits(0); 4096];
+
+    quantize_slice(&input, &mut output, Dither::Tpdf { seed: 1234 }).unwrap();
+
+    // TPDF spans ±1 LSB, so codes stay within one step of the value
+    assert!(output.iter().all(|q| (9..=12).contains(&q.to_bits())));
+
+    // The dithered mean converges to the true (unquantizable) value
+    let mean = output.iter().map(|q| q.to_bits() as f64).sum::<f64>() / output.len() as f64;
+    assert!((mean - 10.5).abs() < 0.05, "mean = {}", mean);
+
+    // Same seed, same noise
+    let mut again = [Fixed::<8>::from_bits(0); 4096];
+    quantize_slice(&input, &mut again, Dither::Tpdf { seed: 1234 }).unwrap();
+    assert_eq!(output, again);
+}
+
+#[test]
+fn test_size_mismatch() {
+    let mut output = [Fixed::<15>::from_bits(0); 2];
+    let mut back = [0.0f32; 3];
+    assert_eq!(
+        quantize_slice(&[0.0; 3], &mut output, Dither::None),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        dequantize_slice(&output, &mut back),
+        Err(FftError::SizeMismatch)
+    );
+}
@@ -1,6 +1,8 @@
-use super::core::{TWIDDLE_FRAC, precompute_bitrev, precompute_twiddles, radix_2_dit_fft_core};
+use super::core::{TWIDDLE_FRAC, precompute_swaps, precompute_twiddles, radix_2_fft};
 use super::types::{ComplexFixed, Fixed};
-use crate::common::{FftError, FftProcess, RealFft};
+use crate::common::{
+    Algorithm, FftError, FftProcess, Hygiene, RealFft, Scaling, Scratch, pack_rfft_spectrum_half,
+};
 use core::slice;
 
 impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
@@ -11,7 +13,8 @@ impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
         bitrev: &'a mut [usize],
         n: usize,
     ) -> Result<Self, FftError> {
-        if !n.is_power_of_two() {
+        // The packed format needs at least the DC and Nyquist slots
+        if !n.is_power_of_two() || n < 2 {
             return Err(FftError::NotPowerOfTwo);
         }
 
@@ -27,14 +30,18 @@ impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
             twiddles,
             bitrev,
             n,
+            scaling: Scaling::default(),
+            algorithm: Algorithm::default(),
+            hygiene: Hygiene::default(),
         };
         fft.precompute();
+        event!(debug, "real fixed-point plan: n = {}", n);
         Ok(fft)
     }
 
     fn precompute(&mut self) {
         // 1. Bitrev is generated for N/2 (size of the internal FFT)
-        precompute_bitrev(self.bitrev, self.n / 2);
+        precompute_swaps(self.bitrev, self.n / 2);
 
         // 2. Twiddles are generated for N (full circle, though size N/2)
         // This is what allows the post-processing to work
@@ -46,17 +53,52 @@ impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
     /// - buffer[0].re = DC (Frequency 0)
     /// - buffer[0].im = Nyquist (Frequency N/2)
     /// - buffer[1..N/2] = Normal positive frequencies.
-    fn rfft<const FRAC: u32>(&self, buffer: &mut [Fixed<FRAC>]) -> Result<(), FftError> {
+    ///
+    /// If `scale` is set, the spectrum is scaled by 1/N.
+    pub(super) fn rfft<const FRAC: u32>(
+        &self,
+        buffer: &mut [Fixed<FRAC>],
+        scale: bool,
+    ) -> Result<(), FftError> {
+        self.validate()?;
         if buffer.len() != self.n {
             return Err(FftError::SizeMismatch);
         }
 
+        // N = 2: the spectrum is only DC and Nyquist, no complex FFT is needed
+        if self.n == 2 {
+            let (x0, x1) = (buffer[0], buffer[1]);
+            let (dc, nyquist) = if scale {
+                ((x0 + x1).scale_half(), (x0 - x1).scale_half())
+            } else {
+                (x0 + x1, x0 - x1)
+            };
+            buffer[0] = dc;
+            buffer[1] = nyquist;
+            return Ok(());
+        }
+
         // C TRICK: Reinterpret fixed array as ComplexFixed array
         // Uses the helper method which is safe wrapper around reinterpret_cast
         let cbuffer = ComplexFixed::pack_mut(buffer);
 
+        self.rfft_complex(cbuffer, scale);
+        Ok(())
+    }
+
+    /// Body of `rfft` on the N/2 complex view of the buffer.
+    fn rfft_complex<const FRAC: u32>(&self, cbuffer: &mut [ComplexFixed<FRAC>], scale: bool) {
         // FFT of the complex sequence of N/2 points, interleaved from real input
-        radix_2_dit_fft_core::<FRAC, false>(cbuffer, self.twiddles, self.bitrev, 2);
+        // When scaling, the core applies 1/(N/2) and the unweaving the last 1/2
+        radix_2_fft(
+            cbuffer,
+            self.twiddles,
+            self.bitrev,
+            2,
+            self.algorithm,
+            false,
+            scale,
+        );
 
         // Unweaving
         let n_half = self.n / 2;
@@ -76,10 +118,12 @@ impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
             let dc = val.re + val.im;
             let nyquist = val.re - val.im;
 
-            cbuffer[0] = ComplexFixed::new(dc, nyquist);
+            cbuffer[0] = half_if(ComplexFixed::new(dc, nyquist), scale);
         }
 
-        cbuffer[n_quarter] = cbuffer[n_quarter].conj();
+        // Bin N/4 pairs with itself and its twiddle is -i, which reduces to a conjugate.
+        // For N = 4 it is the only bin left and the loop below is empty.
+        cbuffer[n_quarter] = half_if(cbuffer[n_quarter].conj(), scale);
 
         // Main unweaving loop
         for i in 1..n_quarter {
@@ -91,10 +135,10 @@ impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
             let val_b_conj = val_b.conj();
 
             // even = (cdata[i] + conj(cdata[n/2-i])) / 2
-            let even = (val_a + val_b_conj).scale_half();
+            let even = half_if((val_a + val_b_conj).scale_half(), scale);
 
             // odd = (cdata[i] - conj(cdata[n/2-i])) / 2
-            let odd = (val_a - val_b_conj).scale_half();
+            let odd = half_if((val_a - val_b_conj).scale_half(), scale);
 
             // Twiddle calculation
             let w = self.twiddles[i];
@@ -102,9 +146,8 @@ impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
             // tmp1 = odd * w
             let tmp1 = odd * w;
 
-            // tmp = I * tmp1 (re: -tmp1.im, im: tmp1.re)
-            let tmp =
-                ComplexFixed::new(Fixed::from_bits(tmp1.im.to_bits().wrapping_neg()), tmp1.re);
+            // tmp = I * tmp1
+            let tmp = tmp1.mul_i();
 
             // cdata[i] = even - I * odd * w  => even - tmp
             cbuffer[idx_a] = even - tmp;
@@ -115,29 +158,60 @@ impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
             let val_b_res = (even + tmp).conj();
             cbuffer[idx_b] = val_b_res;
         }
-
-        Ok(())
     }
 
-    fn irfft<const FRAC: u32>(&self, buffer: &mut [Fixed<FRAC>]) -> Result<(), FftError> {
+    /// Executes the Real FFT Inverse on a packed spectrum.
+    ///
+    /// If `scale` is set, the output is scaled by 1/N, otherwise it is N times the signal.
+    pub(super) fn irfft<const FRAC: u32>(
+        &self,
+        buffer: &mut [Fixed<FRAC>],
+        scale: bool,
+    ) -> Result<(), FftError> {
+        self.validate()?;
         if buffer.len() != self.n {
             return Err(FftError::SizeMismatch);
         }
 
+        // N = 2: recover the two samples directly from DC and Nyquist
+        if self.n == 2 {
+            let (dc, nyquist) = (buffer[0], buffer[1]);
+            if scale {
+                buffer[0] = (dc + nyquist).scale_half();
+                buffer[1] = (dc - nyquist).scale_half();
+            } else {
+                buffer[0] = dc + nyquist;
+                buffer[1] = dc - nyquist;
+            }
+            return Ok(());
+        }
+
         let cbuffer = unsafe {
             slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut ComplexFixed<FRAC>, self.n / 2)
         };
 
+        self.irfft_complex(cbuffer, scale);
+        Ok(())
+    }
+
+    /// Body of `irfft` on the N/2 complex view of the packed spectrum.
+    fn irfft_complex<const FRAC: u32>(&self, cbuffer: &mut [ComplexFixed<FRAC>], scale: bool) {
         let n_half = self.n / 2;
         let n_quarter = n_half / 2;
 
         // 1. Reweaving
+        // Every bin is halved here; the unscaled inverse skips that 1/2 to return N * x
 
-        cbuffer[0] = ComplexFixed::new(
-            (cbuffer[0].re + cbuffer[0].im).scale_half(),
-            (cbuffer[0].re - cbuffer[0].im).scale_half(),
+        cbuffer[0] = half_if(
+            ComplexFixed::new(cbuffer[0].re + cbuffer[0].im, cbuffer[0].re - cbuffer[0].im),
+            scale,
         );
+        // Bin N/4 pairs with itself and its twiddle is -i, which reduces to a conjugate.
+        // For N = 4 it is the only bin left and the loop below is empty.
         cbuffer[n_quarter] = cbuffer[n_quarter].conj();
+        if !scale {
+            cbuffer[n_quarter] = cbuffer[n_quarter] + cbuffer[n_quarter];
+        }
 
         for i in 1..n_quarter {
             let idx_a = i;
@@ -147,10 +221,10 @@ impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
             let val_b = cbuffer[idx_b];
 
             // even = (cdata[i] + conj(cdata[n/2-i])) / 2
-            let even = (val_a + val_b.conj()).scale_half();
+            let even = half_if(val_a + val_b.conj(), scale);
 
             // odd = (cdata[i] - conj(cdata[n/2-i])) / 2
-            let odd = (val_a - val_b.conj()).scale_half();
+            let odd = half_if(val_a - val_b.conj(), scale);
 
             // w = conj(twd[i])
             let w = self.twiddles[i].conj();
@@ -164,8 +238,7 @@ impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
             let tmp1 = odd * w;
 
             // tmp = I * odd * w
-            let tmp =
-                ComplexFixed::new(Fixed::from_bits(tmp1.im.to_bits().wrapping_neg()), tmp1.re);
+            let tmp = tmp1.mul_i();
 
             cbuffer[idx_a] = even + tmp;
 
@@ -173,23 +246,118 @@ impl<'a> RealFft<'a, ComplexFixed<TWIDDLE_FRAC>> {
         }
 
         // 2. Inverse FFT of the complex sequence of N/2 points
-        // The core will handle 1/2 scaling per stage
-        radix_2_dit_fft_core::<FRAC, true>(cbuffer, self.twiddles, self.bitrev, 2);
-
-        Ok(())
+        // When scaling, the core will handle 1/2 scaling per stage
+        radix_2_fft(
+            cbuffer,
+            self.twiddles,
+            self.bitrev,
+            2,
+            self.algorithm,
+            true,
+            scale,
+        );
     }
 
+    /// Executes the Real FFT in-place. The normalization follows `self.scaling`.
     pub fn process<const FRAC: u32>(
         &self,
         buffer: &mut [Fixed<FRAC>],
         inverse: bool,
     ) -> Result<(), FftError> {
+        let scale = self.scaling.is_scaled(inverse);
+        if inverse {
+            self.irfft(buffer, scale)
+        } else {
+            self.rfft(buffer, scale)
+        }
+    }
+
+    /// Same as [`Self::process`], with the N/2-point complex stage run on a
+    /// copy in `scratch` (at least `self.scratch_len()` elements) rather
+    /// than on a `ComplexFixed` view of `buffer`. No pointer cast is
+    /// involved, for targets and checkers that reject the aliased view; the
+    /// result is bit-identical.
+    pub fn process_with_scratch<const FRAC: u32>(
+        &self,
+        buffer: &mut [Fixed<FRAC>],
+        scratch: &mut Scratch<'_, ComplexFixed<FRAC>>,
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        self.validate()?;
+        if buffer.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+        // N = 2 never takes the complex view
+        if self.n == 2 {
+            return self.process(buffer, inverse);
+        }
+        let cbuffer = scratch.take(self.scratch_len())?;
+
+        for (c, pair) in cbuffer.iter_mut().zip(buffer.chunks_exact(2)) {
+            *c = ComplexFixed::new(pair[0], pair[1]);
+        }
+        let scale = self.scaling.is_scaled(inverse);
         if inverse {
-            self.irfft(buffer)
+            self.irfft_complex(cbuffer, scale);
         } else {
-            self.rfft(buffer)
+            self.rfft_complex(cbuffer, scale);
+        }
+        for (pair, c) in buffer.chunks_exact_mut(2).zip(cbuffer.iter()) {
+            pair[0] = c.re;
+            pair[1] = c.im;
+        }
+
+        Ok(())
+    }
+
+    /// Executes the forward Real FFT on 16-bit PCM samples.
+    ///
+    /// Each `i16` sample is read as a Q15 value and widened into the first
+    /// N elements of `scratch`, which receive the packed spectrum. The 16
+    /// extra integer bits of the `Fixed<15>` storage provide the headroom for
+    /// the log2(N) bit growth of the unscaled forward transform, so
+    /// codec/DMA buffers can be passed as-is without a separate conversion
+    /// pass.
+    pub fn process_pcm16(
+        &self,
+        pcm: &[i16],
+        scratch: &mut Scratch<'_, Fixed<15>>,
+    ) -> Result<(), FftError> {
+        if pcm.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+        let buffer = scratch.take(self.n)?;
+
+        for (dst, &sample) in buffer.iter_mut().zip(pcm) {
+            *dst = Fixed::from_bits(sample as i32);
         }
+
+        self.process(buffer, false)
     }
+
+    /// Inverse Real FFT from a standard one-sided spectrum of N/2 + 1 bins.
+    ///
+    /// Fixed-point counterpart of the float `process_inverse_from`: the
+    /// spectrum is packed into `out` and transformed in place. The imaginary
+    /// parts of the DC and Nyquist bins are ignored.
+    pub fn process_inverse_from<const FRAC: u32>(
+        &self,
+        spectrum: &[ComplexFixed<FRAC>],
+        out: &mut [Fixed<FRAC>],
+    ) -> Result<(), FftError> {
+        if spectrum.len() != self.n / 2 + 1 || out.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+
+        pack_rfft_spectrum_half::<Fixed<FRAC>>(spectrum, out);
+        self.process(out, true)
+    }
+}
+
+/// Applies the optional 1/2 normalization step of the unweaving.
+#[inline]
+fn half_if<const FRAC: u32>(value: ComplexFixed<FRAC>, scale: bool) -> ComplexFixed<FRAC> {
+    if scale { value.scale_half() } else { value }
 }
 
 // Implement trait for generic FRAC
@@ -1,7 +1,13 @@
 use super::super::core::TWIDDLE_FRAC;
 use super::super::types::{ComplexFixed, Fixed};
 use super::*;
-use crate::common::{FftNum, pack_rfft_spectrum, unpack_rfft_spectrum};
+use crate::common::{Algorithm, Scaling, Scratch};
+use crate::common::{
+    FftNum, enforce_hermitian, pack_rfft_spectrum, pack_rfft_spectrum_half,
+    pack_rfft_spectrum_in_place, unpack_rfft_spectrum, unpack_rfft_spectrum_half,
+    unpack_rfft_spectrum_in_place,
+};
+use core::f64::consts::FRAC_1_SQRT_2;
 
 fn to_f64<const FRAC: u32>(val: Fixed<FRAC>) -> f64 {
     val.to_bits() as f64 / (1u64 << FRAC) as f64
@@ -30,8 +36,7 @@ fn test_rfft_forward_impulse() {
         Fixed::<FRAC>::from_int(0),
     ];
 
-    let mut twiddles =
-        vec![ComplexFixed::<TWIDDLE_FRAC>::new(Fixed::from_bits(0), Fixed::from_bits(0)); n / 2];
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
     // NOTE: RealFft allocates bitrev tables of size N/2
     let mut bitrev = vec![0; n / 2];
 
@@ -85,20 +90,19 @@ fn test_rfft_inverse_impulse() {
 
     let mut input = [
         Fixed::<FRAC>::from_f64(1.0),
-        Fixed::<FRAC>::from_f64(0.7071),
+        Fixed::<FRAC>::from_f64(FRAC_1_SQRT_2),
         Fixed::<FRAC>::from_f64(0.0),
-        Fixed::<FRAC>::from_f64(-0.7071),
+        Fixed::<FRAC>::from_f64(-FRAC_1_SQRT_2),
         Fixed::<FRAC>::from_f64(-1.0),
-        Fixed::<FRAC>::from_f64(-0.7071),
+        Fixed::<FRAC>::from_f64(-FRAC_1_SQRT_2),
         Fixed::<FRAC>::from_f64(0.0),
-        Fixed::<FRAC>::from_f64(0.7071),
+        Fixed::<FRAC>::from_f64(FRAC_1_SQRT_2),
     ];
 
     // Keep a copy for check
-    let original = input.clone();
+    let original = input;
 
-    let mut twiddles =
-        vec![ComplexFixed::<TWIDDLE_FRAC>::new(Fixed::from_bits(0), Fixed::from_bits(0)); n / 2];
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
     let mut bitrev = vec![0; n / 2];
 
     let fft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
@@ -179,3 +183,309 @@ fn test_unpack_pack_spectrum_fixed() {
         assert_fixed_close(packed_back[i], to_f64(packed[i]), 0.001);
     }
 }
+
+#[test]
+fn test_unpack_pack_spectrum_half_and_in_place_fixed() {
+    const FRAC: u32 = 15;
+    let packed: [Fixed<FRAC>; 8] = core::array::from_fn(|i| Fixed::<FRAC>::from_int(i as i32 + 1));
+
+    let mut half = [ComplexFixed::<FRAC>::new(Fixed::zero(), Fixed::zero()); 5];
+    unpack_rfft_spectrum_half(&packed, &mut half);
+
+    assert_eq!(half[0], ComplexFixed::new(packed[0], Fixed::zero()));
+    assert_eq!(half[2], ComplexFixed::new(packed[4], packed[5]));
+    assert_eq!(half[4], ComplexFixed::new(packed[1], Fixed::zero()));
+
+    let mut packed_back = [Fixed::<FRAC>::zero(); 8];
+    pack_rfft_spectrum_half(&half, &mut packed_back);
+    assert_eq!(packed_back, packed);
+
+    // In place: the interleaved N + 2 buffer viewed as complex matches `half`
+    let mut buffer = [Fixed::<FRAC>::zero(); 10];
+    buffer[..8].copy_from_slice(&packed);
+    unpack_rfft_spectrum_in_place(&mut buffer);
+    assert_eq!(ComplexFixed::pack(&buffer), &half[..]);
+
+    pack_rfft_spectrum_in_place(&mut buffer);
+    assert_eq!(buffer[..8], packed);
+}
+
+#[test]
+fn test_enforce_hermitian_fixed() {
+    const FRAC: u32 = 15;
+    let c = |re: f64, im: f64| {
+        ComplexFixed::new(Fixed::<FRAC>::from_f64(re), Fixed::<FRAC>::from_f64(im))
+    };
+    let mut spectrum = [
+        c(4.0, 1.0),
+        c(1.0, 2.0),
+        c(3.0, -1.0),
+        c(5.0, 0.0),
+        c(-2.0, 3.0),
+        c(2.0, 5.0),
+        c(3.0, 1.0),
+        c(3.0, 0.0),
+    ];
+
+    enforce_hermitian::<Fixed<FRAC>>(&mut spectrum);
+
+    assert_eq!(spectrum[0], c(4.0, 0.0));
+    assert_eq!(spectrum[4], c(-2.0, 0.0));
+    assert_eq!(spectrum[1], c(2.0, 1.0));
+    assert_eq!(spectrum[3], c(3.5, -2.5));
+    for k in 1..4 {
+        assert_eq!(spectrum[8 - k], spectrum[k].conj());
+    }
+}
+
+#[test]
+fn test_small_sizes_fixed() {
+    const FRAC: u32 = 15;
+    // (input, packed spectrum) for N = 2 and N = 4
+    let cases: [(&[f64], &[f64]); 2] = [
+        (&[0.25, 0.5], &[0.75, -0.25]),
+        (&[0.125, 0.25, 0.375, 0.5], &[1.25, -0.25, -0.25, 0.25]),
+    ];
+
+    for (input, expected) in cases {
+        let n = input.len();
+        let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+        let mut bitrev = vec![0; n / 2];
+        let fft =
+            RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+        let mut buffer: Vec<Fixed<FRAC>> = input.iter().map(|&v| Fixed::from_f64(v)).collect();
+        fft.process(&mut buffer, false).unwrap();
+        for (&val, &exp) in buffer.iter().zip(expected) {
+            assert_fixed_close(val, exp, 0.001);
+        }
+
+        fft.process(&mut buffer, true).unwrap();
+        for (&val, &exp) in buffer.iter().zip(input) {
+            assert_fixed_close(val, exp, 0.001);
+        }
+    }
+}
+
+#[test]
+fn test_scaling_contract_fixed() {
+    const FRAC: u32 = 15;
+    for n in [2usize, 4, 16] {
+        let input: Vec<f64> = (0..n).map(|i| (i as f64 * 0.7).sin() * 0.5).collect();
+
+        // Naive DFT reference, packed
+        let mut reference = vec![0.0f64; n];
+        for k in 0..=n / 2 {
+            let (mut re, mut im) = (0.0, 0.0);
+            for (t, &x) in input.iter().enumerate() {
+                let angle = -2.0 * core::f64::consts::PI * (k * t) as f64 / n as f64;
+                re += x * angle.cos();
+                im += x * angle.sin();
+            }
+            match k {
+                0 => reference[0] = re,
+                k if k == n / 2 => reference[1] = re,
+                k => {
+                    reference[2 * k] = re;
+                    reference[2 * k + 1] = im;
+                }
+            }
+        }
+
+        // (scaling, forward gain, round trip gain)
+        let cases = [
+            (Scaling::None, 1.0, n as f64),
+            (Scaling::Forward, 1.0 / n as f64, 1.0),
+            (Scaling::Inverse, 1.0, 1.0),
+        ];
+
+        for (scaling, forward_gain, round_trip_gain) in cases {
+            let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+            let mut bitrev = vec![0; n / 2];
+            let fft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+                .unwrap()
+                .with_scaling(scaling);
+
+            let mut buffer: Vec<Fixed<FRAC>> = input.iter().map(|&v| Fixed::from_f64(v)).collect();
+            fft.process(&mut buffer, false).unwrap();
+            for (&val, &expected) in buffer.iter().zip(reference.iter()) {
+                assert_fixed_close(val, expected * forward_gain, 0.002);
+            }
+
+            fft.process(&mut buffer, true).unwrap();
+            for (&val, &expected) in buffer.iter().zip(input.iter()) {
+                assert_fixed_close(val, expected * round_trip_gain, 0.002);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_process_pcm16_matches_q15() {
+    let n = 32;
+    let mut pcm: Vec<i16> = (0..n)
+        .map(|i| ((i as f64 * 0.8).sin() * 32767.0) as i16)
+        .collect();
+    pcm[3] = i16::MIN;
+
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    let mut expected: Vec<Fixed<15>> = pcm.iter().map(|&s| Fixed::from_bits(s as i32)).collect();
+    fft.process(&mut expected, false).unwrap();
+
+    let mut storage = vec![Fixed::<15>::zero(); n];
+    fft.process_pcm16(&pcm, &mut Scratch::new(&mut storage))
+        .unwrap();
+    assert_eq!(storage, expected);
+
+    // Full-scale DC does not overflow: DC = N * (-1.0)
+    let pcm = vec![i16::MIN; n];
+    let mut scratch = Scratch::new(&mut storage);
+    fft.process_pcm16(&pcm, &mut scratch).unwrap();
+    assert_eq!(
+        fft.process_pcm16(&pcm[..n - 2], &mut scratch),
+        Err(FftError::SizeMismatch)
+    );
+    assert_fixed_close(storage[0], -(n as f64), 1e-6);
+
+    assert_eq!(
+        fft.process_pcm16(&pcm, &mut Scratch::new(&mut storage[..n - 2])),
+        Err(FftError::BufferTooSmall)
+    );
+}
+
+#[test]
+fn test_dif_round_trip_fixed() {
+    const FRAC: u32 = 15;
+    let n = 32;
+    let input: Vec<f64> = (0..n).map(|i| (i as f64 * 0.45).cos() * 0.4).collect();
+
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let dit = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::Forward);
+    let mut expected: Vec<Fixed<FRAC>> = input.iter().map(|&v| Fixed::from_f64(v)).collect();
+    dit.process(&mut expected, false).unwrap();
+
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let dif = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::Forward)
+        .with_algorithm(Algorithm::Dif);
+    let mut buffer: Vec<Fixed<FRAC>> = input.iter().map(|&v| Fixed::from_f64(v)).collect();
+    dif.process(&mut buffer, false).unwrap();
+    for (&val, &exp) in buffer.iter().zip(expected.iter()) {
+        assert_fixed_close(val, to_f64(exp), 0.001);
+    }
+
+    dif.process(&mut buffer, true).unwrap();
+    for (&val, &exp) in buffer.iter().zip(input.iter()) {
+        assert_fixed_close(val, exp, 0.002);
+    }
+}
+
+#[test]
+fn test_process_inverse_from_half_spectrum_fixed() {
+    const FRAC: u32 = 15;
+    let n = 16;
+    let input: Vec<Fixed<FRAC>> = (0..n)
+        .map(|i| Fixed::from_f64((i as f64 * 0.9).cos() * 0.4))
+        .collect();
+
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    let mut packed = input.clone();
+    fft.process(&mut packed, false).unwrap();
+    let mut half = vec![ComplexFixed::<FRAC>::new(Fixed::zero(), Fixed::zero()); n / 2 + 1];
+    unpack_rfft_spectrum_half(&packed, &mut half);
+
+    let mut out = vec![Fixed::<FRAC>::zero(); n];
+    fft.process_inverse_from(&half, &mut out).unwrap();
+    for (&val, &expected) in out.iter().zip(input.iter()) {
+        assert_fixed_close(val, to_f64(expected), 0.001);
+    }
+
+    let mut short = vec![Fixed::<FRAC>::zero(); n / 2];
+    assert_eq!(
+        fft.process_inverse_from(&half, &mut short),
+        Err(FftError::SizeMismatch)
+    );
+}
+
+#[test]
+fn test_edited_plan_errors_instead_of_panicking() {
+    const FRAC: u32 = 15;
+    let n = 16;
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let mut fft =
+        RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let mut buffer = vec![Fixed::<FRAC>::zero(); 2 * n];
+
+    // A larger n without larger tables
+    fft.n = 2 * n;
+    assert_eq!(
+        fft.process(&mut buffer, false),
+        Err(FftError::BufferTooSmall)
+    );
+    fft.n = 24;
+    assert_eq!(
+        fft.process(&mut buffer[..24], true),
+        Err(FftError::NotPowerOfTwo)
+    );
+    fft.n = 1;
+    assert_eq!(
+        fft.process(&mut buffer[..1], false),
+        Err(FftError::NotPowerOfTwo)
+    );
+
+    // A corrupted swap list is reported, not followed
+    fft.n = n;
+    fft.bitrev[0] = 1000;
+    assert!(fft.process(&mut buffer[..n], false).is_err());
+}
+
+#[test]
+fn test_unscaled_overflow_wraps_without_panicking() {
+    const FRAC: u32 = 30;
+    let n = 16;
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::None);
+
+    // The DC sum of 16 samples near 1.0 cannot fit in Q1.30
+    let mut buffer = vec![Fixed::<FRAC>::from_f64(1.0); n];
+    assert!(fft.process(&mut buffer, false).is_ok());
+}
+
+#[test]
+fn test_process_with_scratch_is_bit_identical() {
+    const FRAC: u32 = 15;
+    let n = 64;
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let input: Vec<Fixed<FRAC>> = (0..n)
+        .map(|i| Fixed::from_f64((i as f64 * 0.3).sin() * 0.5))
+        .collect();
+    let mut storage =
+        vec![ComplexFixed::<FRAC>::new(Fixed::zero(), Fixed::zero()); fft.scratch_len()];
+    let mut scratch = Scratch::new(&mut storage);
+
+    for inverse in [false, true] {
+        let mut expected = input.clone();
+        fft.process(&mut expected, inverse).unwrap();
+        let mut copied = input.clone();
+        fft.process_with_scratch(&mut copied, &mut scratch, inverse)
+            .unwrap();
+        assert_eq!(copied, expected);
+    }
+}
@@ -0,0 +1,68 @@
+use super::core::{TWIDDLE_FRAC, bit_reverse_permute, radix_2_dit_stage};
+use super::types::ComplexFixed;
+use crate::common::{CplxFft, FftError, StageState};
+
+impl CplxFft<'_, ComplexFixed<TWIDDLE_FRAC>> {
+    /// Runs the next stage of the transform tracked by `state`, so a long
+    /// FFT can be spread over several scheduler ticks or interrupts.
+    ///
+    /// Returns `Ok(true)` once the last stage has run; further calls do
+    /// nothing. Stages run in DIT order whatever the plan's `algorithm`.
+    pub fn process_stage<const FRAC: u32>(
+        &self,
+        buffer: &mut [ComplexFixed<FRAC>],
+        state: &mut StageState,
+    ) -> Result<bool, FftError> {
+        self.validate()?;
+        if buffer.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+
+        let stages = self.stage_count();
+        let inverse = state.inverse();
+        match state.next_stage() {
+            0 => bit_reverse_permute(buffer, self.bitrev),
+            k if k < stages => {
+                let scale = self.scaling.is_scaled(inverse);
+                radix_2_dit_stage(buffer, self.twiddles, 1 << (k - 1), inverse, scale);
+            }
+            k if k == stages => return Ok(true),
+            // A state from a larger plan
+            _ => return Err(FftError::SizeMismatch),
+        }
+        state.advance();
+        Ok(state.next_stage() == stages)
+    }
+
+    /// Executes the FFT in-place like [`Self::process`], checking `expired`
+    /// before each of the log2(N) stages and stopping with
+    /// `FftError::DeadlineExceeded` once it returns `true`.
+    ///
+    /// Stages always run in DIT order, whatever the plan's `algorithm`; for
+    /// a DIT plan the output is bit-identical to `process`. After an abort
+    /// the buffer is only partly transformed.
+    pub fn process_with_watchdog<const FRAC: u32>(
+        &self,
+        buffer: &mut [ComplexFixed<FRAC>],
+        inverse: bool,
+        mut expired: impl FnMut() -> bool,
+    ) -> Result<(), FftError> {
+        let mut state = StageState::new(inverse);
+        while !self.process_stage(buffer, &mut state)? {
+            if expired() {
+                event!(
+                    warn,
+                    "n = {} transform stopped before stage {}",
+                    self.n,
+                    state.next_stage()
+                );
+                return Err(FftError::DeadlineExceeded);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "staged_tests.rs"]
+mod tests;
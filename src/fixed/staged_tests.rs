@@ -0,0 +1,94 @@
+use super::super::core::TWIDDLE_FRAC;
+use super::super::types::{ComplexFixed, Fixed};
+use crate::common::{CplxFft, FftError, Scaling, StageState};
+
+fn signal(n: usize) -> Vec<ComplexFixed<15>> {
+    (0..n)
+        .map(|i| {
+            ComplexFixed::new(
+                Fixed::from_f64((i as f64 * 0.4).sin() * 0.5),
+                Fixed::from_f64((i as f64 * 0.9).cos() * 0.5),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_watchdog_output_is_bit_identical() {
+    let n = 32;
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    for inverse in [false, true] {
+        let mut expected = signal(n);
+        fft.process(&mut expected, inverse).unwrap();
+        let mut buffer = signal(n);
+        fft.process_with_watchdog(&mut buffer, inverse, || false)
+            .unwrap();
+        assert_eq!(buffer, expected);
+    }
+
+    let mut buffer = signal(n);
+    assert_eq!(
+        fft.process_with_watchdog(&mut buffer, false, || true),
+        Err(FftError::DeadlineExceeded)
+    );
+}
+
+#[test]
+fn test_process_stage_matches_process() {
+    let n = 16;
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::Forward);
+    assert_eq!(fft.stage_count(), 5);
+
+    for inverse in [false, true] {
+        let mut expected = signal(n);
+        fft.process(&mut expected, inverse).unwrap();
+
+        let mut state = StageState::new(inverse);
+        let mut buffer = signal(n);
+        let mut calls = 1;
+        while !fft.process_stage(&mut buffer, &mut state).unwrap() {
+            calls += 1;
+        }
+        assert_eq!(calls, fft.stage_count());
+        // Bit-identical, so well within the 1 LSB budget
+        assert_eq!(buffer, expected);
+
+        // A finished state leaves the buffer alone
+        assert_eq!(fft.process_stage(&mut buffer, &mut state), Ok(true));
+        assert_eq!(buffer, expected);
+    }
+}
+
+#[test]
+fn test_process_stage_rejects_foreign_state() {
+    let mut twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; 32];
+    let mut bitrev = vec![0; 64];
+    let large = CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, 64).unwrap();
+    let mut small_twiddles = vec![ComplexFixed::<TWIDDLE_FRAC>::ZERO; 4];
+    let mut small_bitrev = vec![0; 8];
+    let small =
+        CplxFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut small_twiddles, &mut small_bitrev, 8)
+            .unwrap();
+
+    // Five of the large plan's seven stages, past the small plan's four
+    let mut state = StageState::new(false);
+    let mut buffer = signal(64);
+    for _ in 0..5 {
+        assert_eq!(large.process_stage(&mut buffer, &mut state), Ok(false));
+    }
+    assert_eq!(
+        small.process_stage(&mut buffer[..8], &mut state),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        small.process_stage(&mut buffer, &mut StageState::new(false)),
+        Err(FftError::SizeMismatch)
+    );
+}
@@ -0,0 +1,233 @@
+use super::fixed::Fixed;
+use crate::common::FftError;
+use core::ops::{Add, Mul, Sub};
+
+/// Largest supported number of fractional bits, as for [`Fixed`].
+pub const MAX_FRAC: u8 = 31;
+
+/// Fixed-point value whose Q-format is chosen at run time.
+///
+/// Behaves like `Fixed<FRAC>` with `frac` stored next to the bits, for
+/// pipelines where the format is configuration (e.g. per sensor). Binary
+/// operators return a value in the format of the left operand; the right
+/// operand is converted with rounding and saturation first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DynFixed {
+    bits: i32,
+    frac: u8,
+}
+
+impl DynFixed {
+    /// Creates a value from raw bits. Fails with `OutOfRange` if `frac`
+    /// exceeds [`MAX_FRAC`].
+    pub fn new(bits: i32, frac: u8) -> Result<Self, FftError> {
+        if frac > MAX_FRAC {
+            return Err(FftError::OutOfRange);
+        }
+        Ok(Self { bits, frac })
+    }
+
+    /// Converts an f64 with rounding, saturating like [`Fixed::from_f64`].
+    pub fn from_f64(value: f64, frac: u8) -> Result<Self, FftError> {
+        let scaled = libm::round(value * (1u64 << frac.min(MAX_FRAC)) as f64);
+        let bits = if scaled.is_nan() {
+            0
+        } else {
+            scaled.clamp(i32::MIN as f64, i32::MAX as f64) as i32
+        };
+        Self::new(bits, frac)
+    }
+
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.bits as f64 / (1u64 << self.frac) as f64
+    }
+
+    #[inline]
+    pub fn to_bits(self) -> i32 {
+        self.bits
+    }
+
+    #[inline]
+    pub fn frac(self) -> u8 {
+        self.frac
+    }
+
+    /// Re-expresses the value with `frac` fractional bits, rounding to
+    /// nearest when narrowing and saturating when widening.
+    pub fn convert(self, frac: u8) -> Result<Self, FftError> {
+        Self::new(rescale(self.bits, self.frac, frac), frac)
+    }
+
+    /// Converts from a compile-time format.
+    #[inline]
+    pub fn from_fixed<const FRAC: u32>(x: Fixed<FRAC>) -> Self {
+        Self {
+            bits: x.to_bits(),
+            frac: FRAC as u8,
+        }
+    }
+
+    /// Converts to a compile-time format, rounding and saturating.
+    #[inline]
+    pub fn to_fixed<const FRAC: u32>(self) -> Fixed<FRAC> {
+        Fixed::from_bits(rescale(self.bits, self.frac, FRAC as u8))
+    }
+}
+
+/// Moves `bits` from `from` to `to` fractional bits (round to nearest,
+/// saturate to i32).
+#[inline]
+pub(crate) fn rescale(bits: i32, from: u8, to: u8) -> i32 {
+    let v = bits as i64;
+    let wide = if to >= from {
+        v << (to - from)
+    } else {
+        let shift = from - to;
+        (v + (1 << (shift - 1))) >> shift
+    };
+    wide.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// Rounded product in the left operand's format (same rule as `Fixed`).
+#[inline]
+fn mul_bits(a: i32, b: i32, b_frac: u8) -> i32 {
+    let product = a as i64 * b as i64;
+    let rounded = if b_frac > 0 {
+        (product + (1i64 << (b_frac - 1))) >> b_frac
+    } else {
+        product
+    };
+    rounded as i32
+}
+
+impl Add for DynFixed {
+    type Output = DynFixed;
+
+    #[inline]
+    fn add(self, rhs: DynFixed) -> DynFixed {
+        let rhs = rescale(rhs.bits, rhs.frac, self.frac);
+        DynFixed {
+            bits: self.bits + rhs,
+            frac: self.frac,
+        }
+    }
+}
+
+impl Sub for DynFixed {
+    type Output = DynFixed;
+
+    #[inline]
+    fn sub(self, rhs: DynFixed) -> DynFixed {
+        let rhs = rescale(rhs.bits, rhs.frac, self.frac);
+        DynFixed {
+            bits: self.bits - rhs,
+            frac: self.frac,
+        }
+    }
+}
+
+impl Mul for DynFixed {
+    type Output = DynFixed;
+
+    #[inline]
+    fn mul(self, rhs: DynFixed) -> DynFixed {
+        DynFixed {
+            bits: mul_bits(self.bits, rhs.bits, rhs.frac),
+            frac: self.frac,
+        }
+    }
+}
+
+/// Complex counterpart of [`DynFixed`]: both parts share one run-time
+/// format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DynComplexFixed {
+    re: i32,
+    im: i32,
+    frac: u8,
+}
+
+impl DynComplexFixed {
+    /// Builds a complex value, converting `im` to the format of `re`.
+    pub fn new(re: DynFixed, im: DynFixed) -> Self {
+        Self {
+            re: re.bits,
+            im: rescale(im.bits, im.frac, re.frac),
+            frac: re.frac,
+        }
+    }
+
+    /// Creates a value from raw bits in the given format.
+    pub fn from_bits(re: i32, im: i32, frac: u8) -> Result<Self, FftError> {
+        if frac > MAX_FRAC {
+            return Err(FftError::OutOfRange);
+        }
+        Ok(Self { re, im, frac })
+    }
+
+    #[inline]
+    pub fn re(self) -> DynFixed {
+        DynFixed {
+            bits: self.re,
+            frac: self.frac,
+        }
+    }
+
+    #[inline]
+    pub fn im(self) -> DynFixed {
+        DynFixed {
+            bits: self.im,
+            frac: self.frac,
+        }
+    }
+
+    #[inline]
+    pub fn frac(self) -> u8 {
+        self.frac
+    }
+
+    /// Re-expresses both parts with `frac` fractional bits (see
+    /// [`DynFixed::convert`]).
+    pub fn convert(self, frac: u8) -> Result<Self, FftError> {
+        Self::from_bits(
+            rescale(self.re, self.frac, frac),
+            rescale(self.im, self.frac, frac),
+            frac,
+        )
+    }
+}
+
+impl Add for DynComplexFixed {
+    type Output = DynComplexFixed;
+
+    #[inline]
+    fn add(self, rhs: DynComplexFixed) -> DynComplexFixed {
+        DynComplexFixed::new(self.re() + rhs.re(), self.im() + rhs.im())
+    }
+}
+
+impl Sub for DynComplexFixed {
+    type Output = DynComplexFixed;
+
+    #[inline]
+    fn sub(self, rhs: DynComplexFixed) -> DynComplexFixed {
+        DynComplexFixed::new(self.re() - rhs.re(), self.im() - rhs.im())
+    }
+}
+
+impl Mul for DynComplexFixed {
+    type Output = DynComplexFixed;
+
+    #[inline]
+    fn mul(self, rhs: DynComplexFixed) -> DynComplexFixed {
+        // (ac - bd) + (ad + bc)i, each product rounded as for ComplexFixed
+        let re = self.re() * rhs.re() - self.im() * rhs.im();
+        let im = self.re() * rhs.im() + self.im() * rhs.re();
+        DynComplexFixed::new(re, im)
+    }
+}
+
+#[cfg(test)]
+#[path = "dyn_fixed_tests.rs"]
+mod tests;
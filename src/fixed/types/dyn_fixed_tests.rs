@@ -0,0 +1,56 @@
+use super::*;
+use crate::fixed::ComplexFixed;
+
+#[test]
+fn test_matches_static_formats() {
+    let a = Fixed::<16>::from_f64(1.25);
+    let b = Fixed::<24>::from_f64(-0.3);
+    let (da, db) = (DynFixed::from_fixed(a), DynFixed::from_fixed(b));
+
+    assert_eq!((da + db).to_fixed::<16>(), a + b);
+    assert_eq!((da - db).to_fixed::<16>(), a - b);
+    assert_eq!((da * db).to_fixed::<16>(), a * b);
+    assert_eq!((da * db).frac(), 16);
+    assert_eq!(da.to_f64(), 1.25);
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(DynFixed::new(1, 32), Err(FftError::OutOfRange));
+    assert_eq!(DynFixed::from_f64(1.0, 31).unwrap().to_bits(), i32::MAX);
+
+    // 0.75 LSB of Q8 rounds up when narrowing from Q16
+    let x = DynFixed::new(192, 16).unwrap();
+    assert_eq!(x.convert(8).unwrap().to_bits(), 1);
+    // 4.0 saturates in Q30
+    let big = DynFixed::from_f64(4.0, 16).unwrap();
+    assert_eq!(big.convert(30).unwrap().to_bits(), i32::MAX);
+    assert_eq!(big.to_fixed::<30>().to_bits(), i32::MAX);
+}
+
+#[test]
+fn test_complex_matches_static() {
+    let q = |x: f64| Fixed::<20>::from_f64(x);
+    let a = ComplexFixed::new(q(0.5), q(-1.5));
+    let b = ComplexFixed::new(q(0.25), q(0.75));
+    let d = |c: ComplexFixed<20>| {
+        DynComplexFixed::new(DynFixed::from_fixed(c.re), DynFixed::from_fixed(c.im))
+    };
+
+    for (got, want) in [
+        (d(a) * d(b), a * b),
+        (d(a) + d(b), a + b),
+        (d(a) - d(b), a - b),
+    ] {
+        assert_eq!(got.re().to_fixed::<20>(), want.re);
+        assert_eq!(got.im().to_fixed::<20>(), want.im);
+    }
+
+    let c = d(a).convert(12).unwrap();
+    assert_eq!(c.frac(), 12);
+    assert_eq!(c.re().to_f64(), 0.5);
+    assert_eq!(
+        DynComplexFixed::from_bits(0, 0, 40),
+        Err(FftError::OutOfRange)
+    );
+}
@@ -1,14 +1,30 @@
 // src/fixed/types.rs
 /// Generic fixed-point structure based on the number of fractional bits (FRAC).
 /// The internal value is stored as a signed 32-bit integer.
+///
+/// FRAC is limited to 31 bits. The bound is checked at compile time by every
+/// constructor, so an out-of-range format is rejected by the compiler:
+///
+/// ```compile_fail
+/// let x = rs_simple_fft::Fixed::<40>::from_bits(1);
+/// ```
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct Fixed<const FRAC: u32>(i32);
 
 impl<const FRAC: u32> Fixed<FRAC> {
+    const VALID_FRAC: () = assert!(FRAC <= 31, "FRAC cannot be greater than 31 bits for i32");
+
+    /// 0.0
+    pub const ZERO: Self = Self::from_bits(0);
+    /// 1.0, saturated to 1 - 2^-31 for Q31.
+    pub const ONE: Self = Self::from_bits(if FRAC >= 31 { i32::MAX } else { 1 << FRAC });
+
     /// Creates a Fixed from the raw integer value (without shift).
+    /// All other constructors and operators go through here.
     #[inline]
     pub const fn from_bits(bits: i32) -> Self {
+        let () = Self::VALID_FRAC;
         Self(bits)
     }
 
@@ -16,18 +32,36 @@ impl<const FRAC: u32> Fixed<FRAC> {
     /// E.g.: Fixed::<8>::from_int(1) will result in internal value 256.
     #[inline]
     pub fn from_int(value: i32) -> Self {
-        Self(value << FRAC)
+        Self::from_bits(value << FRAC)
     }
 
     /// Converts an f64 to Fixed, applying correct rounding.
     /// Useful for initializing constants and Twiddle Factors.
+    ///
+    /// Values outside the format saturate: `Fixed::<31>::from_f64(1.0)` is
+    /// 1 - 2^-31 (`i32::MAX`) and -1.0 is exact. NaN maps to zero.
     pub fn from_f64(value: f64) -> Self {
         // Multiply the float by 2^FRAC and round to the nearest integer
-        let scaling_factor = (1u64 << FRAC) as f64;
-        let bits = (value * scaling_factor).round() as i32;
+        let scaled = libm::round(value * (1u64 << FRAC) as f64);
+        let bits = if scaled.is_nan() {
+            0
+        } else {
+            scaled.clamp(i32::MIN as f64, i32::MAX as f64) as i32
+        };
         Self::from_bits(bits)
     }
 
+    /// Like [`Self::from_f64`], but returns `FftError::OutOfRange` instead
+    /// of saturating when the rounded value does not fit (or is NaN).
+    pub fn try_from_f64(value: f64) -> Result<Self, FftError> {
+        let scaled = libm::round(value * (1u64 << FRAC) as f64);
+        if scaled >= i32::MIN as f64 && scaled <= i32::MAX as f64 {
+            Ok(Self::from_bits(scaled as i32))
+        } else {
+            Err(FftError::OutOfRange)
+        }
+    }
+
     /// Returns the stored raw value.
     #[inline]
     pub fn to_bits(self) -> i32 {
@@ -37,11 +71,14 @@ impl<const FRAC: u32> Fixed<FRAC> {
     /// Scales the value by 0.5 (shifts right by 1).
     #[inline]
     pub fn scale_half(self) -> Self {
-        Self(self.0 >> 1)
+        Self::from_bits(self.0 >> 1)
     }
 }
 
 impl<const FRAC: u32> Fixed<FRAC> {
+    /// Raw shift to another format: narrowing truncates toward negative
+    /// infinity and widening wraps on overflow.
+    /// See [`Self::convert_round`] and [`Self::convert_sat`].
     #[inline]
     pub fn convert<const TO_FRAC: u32>(self) -> Fixed<TO_FRAC> {
         if TO_FRAC > FRAC {
@@ -50,41 +87,76 @@ impl<const FRAC: u32> Fixed<FRAC> {
             Fixed::from_bits(self.0 >> (FRAC - TO_FRAC))
         }
     }
+
+    /// Converts to another format, rounding to nearest (ties toward positive
+    /// infinity) when narrowing. Widening still wraps on overflow.
+    #[inline]
+    pub fn convert_round<const TO_FRAC: u32>(self) -> Fixed<TO_FRAC> {
+        Fixed::from_bits(self.rescale_wide::<TO_FRAC>() as i32)
+    }
+
+    /// Converts to another format, rounding to nearest when narrowing and
+    /// saturating to the i32 range when widening.
+    #[inline]
+    pub fn convert_sat<const TO_FRAC: u32>(self) -> Fixed<TO_FRAC> {
+        let wide = self.rescale_wide::<TO_FRAC>();
+        Fixed::from_bits(wide.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+
+    // Raw value in the TO_FRAC format, rounded, in 64 bits
+    #[inline]
+    fn rescale_wide<const TO_FRAC: u32>(self) -> i64 {
+        let v = self.0 as i64;
+        if TO_FRAC >= FRAC {
+            v << (TO_FRAC - FRAC)
+        } else {
+            let shift = FRAC - TO_FRAC;
+            (v + (1 << (shift - 1))) >> shift
+        }
+    }
+}
+
+impl<const FRAC: u32> Default for Fixed<FRAC> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
 }
 
-use std::ops::Add;
+use core::ops::Add;
 
 impl<const F1: u32, const F2: u32> Add<Fixed<F2>> for Fixed<F1> {
     type Output = Fixed<F1>;
 
     #[inline]
-    fn add(self, rhs: Fixed<F2>) -> Self::Output {
-        let rhs_converted: Fixed<F1> = rhs.convert();
-        // When F1 == F2, convert is a no-op and we just add the raw values
-        Fixed(self.0 + rhs_converted.0)
+    fn add(self, rhs: Fixed<F2>) -> Fixed<F1> {
+        let rhs_converted: Fixed<F1> = rhs.convert_sat();
+        // When F1 == F2, convert_sat is a no-op and we just add the raw values.
+        // Overflow wraps in every build profile, as in the release core.
+        Fixed::from_bits(self.0.wrapping_add(rhs_converted.0))
     }
 }
 
-use std::ops::AddAssign;
+use core::ops::AddAssign;
 
 impl<const F1: u32, const F2: u32> AddAssign<Fixed<F2>> for Fixed<F1> {
     #[inline]
     fn add_assign(&mut self, rhs: Fixed<F2>) {
-        // Use the convert method to match rhs scale to self scale (F1)
-        let adjusted_rhs = rhs.convert::<F1>();
+        // Match rhs scale to self scale (F1), rounding and saturating
+        let adjusted_rhs = rhs.convert_sat::<F1>();
 
         // Add the raw internal value
-        self.0 += adjusted_rhs.to_bits();
+        self.0 = self.0.wrapping_add(adjusted_rhs.to_bits());
     }
 }
 
-use std::ops::Mul;
+use core::ops::Mul;
 
 impl<const F1: u32, const F2: u32> Mul<Fixed<F2>> for Fixed<F1> {
     type Output = Fixed<F1>;
 
     #[inline]
-    fn mul(self, rhs: Fixed<F2>) -> Self::Output {
+    fn mul(self, rhs: Fixed<F2>) -> Fixed<F1> {
         let a = self.0 as i64;
         let b = rhs.0 as i64;
 
@@ -102,7 +174,7 @@ impl<const F1: u32, const F2: u32> Mul<Fixed<F2>> for Fixed<F1> {
     }
 }
 
-use std::ops::MulAssign;
+use core::ops::MulAssign;
 
 impl<const F1: u32, const F2: u32> MulAssign<Fixed<F2>> for Fixed<F1> {
     #[inline]
@@ -112,7 +184,7 @@ impl<const F1: u32, const F2: u32> MulAssign<Fixed<F2>> for Fixed<F1> {
     }
 }
 
-use std::fmt;
+use core::fmt;
 
 impl<const FRAC: u32> fmt::Display for Fixed<FRAC> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -133,34 +205,33 @@ impl<const FRAC: u32> fmt::Debug for Fixed<FRAC> {
 
 impl<const FRAC: u32> Fixed<FRAC> {
     pub fn new(bits: i32) -> Self {
-        assert!(FRAC <= 31, "FRAC cannot be greater than 31 bits for i32");
-        Self(bits)
+        Self::from_bits(bits)
     }
 }
 
-use std::ops::Sub;
+use core::ops::Sub;
 
 impl<const F1: u32, const F2: u32> Sub<Fixed<F2>> for Fixed<F1> {
     type Output = Fixed<F1>;
 
     #[inline]
-    fn sub(self, rhs: Fixed<F2>) -> Self::Output {
-        let rhs_converted = rhs.convert::<F1>();
-        Fixed::from_bits(self.0 - rhs_converted.to_bits())
+    fn sub(self, rhs: Fixed<F2>) -> Fixed<F1> {
+        let rhs_converted = rhs.convert_sat::<F1>();
+        Fixed::from_bits(self.0.wrapping_sub(rhs_converted.to_bits()))
     }
 }
 
-use std::ops::SubAssign;
+use core::ops::SubAssign;
 impl<const F1: u32, const F2: u32> SubAssign<Fixed<F2>> for Fixed<F1> {
     #[inline]
     fn sub_assign(&mut self, rhs: Fixed<F2>) {
-        let rhs_converted = rhs.convert::<F1>();
-        self.0 -= rhs_converted.to_bits();
+        let rhs_converted = rhs.convert_sat::<F1>();
+        self.0 = self.0.wrapping_sub(rhs_converted.to_bits());
     }
 }
 
 use super::fixed_complex::ComplexFixed;
-use crate::common::FftNum;
+use crate::common::{FftError, FftNum};
 
 impl<const FRAC: u32> FftNum for Fixed<FRAC> {
     type Complex = ComplexFixed<FRAC>;
@@ -170,6 +241,11 @@ impl<const FRAC: u32> FftNum for Fixed<FRAC> {
         Self::from_f64(v)
     }
 
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / (1i64 << FRAC) as f64
+    }
+
     #[inline]
     fn zero() -> Self {
         Self::from_int(0)
@@ -194,6 +270,11 @@ impl<const FRAC: u32> FftNum for Fixed<FRAC> {
     fn negate(self) -> Self {
         Self::from_bits(self.to_bits().wrapping_neg())
     }
+
+    #[inline]
+    fn average(a: Self, b: Self) -> Self {
+        Self::from_bits(((a.to_bits() as i64 + b.to_bits() as i64) >> 1) as i32)
+    }
 }
 
 #[cfg(test)]
@@ -215,6 +296,38 @@ mod tests {
         assert_eq!(res.to_bits(), 3 << 16);
     }
 
+    #[test]
+    fn test_convert_round_and_sat() {
+        // -0.75 LSB of Q8 in Q16: truncation floors, rounding goes to -1
+        let x = Fixed::<16>::from_bits(-192);
+        assert_eq!(x.convert::<8>().to_bits(), -1);
+        assert_eq!(x.convert_round::<8>().to_bits(), -1);
+        // +0.75 LSB: truncation drops it, rounding gives 1
+        let y = Fixed::<16>::from_bits(192);
+        assert_eq!(y.convert::<8>().to_bits(), 0);
+        assert_eq!(y.convert_round::<8>().to_bits(), 1);
+        assert_eq!(y.convert_sat::<8>().to_bits(), 1);
+
+        // 4.0 does not fit in Q30: convert wraps, convert_sat clamps
+        let big = Fixed::<16>::from_int(4);
+        assert_eq!(big.convert::<30>().to_bits(), 0);
+        assert_eq!(big.convert_sat::<30>().to_bits(), i32::MAX);
+        assert_eq!(
+            Fixed::<16>::from_int(-4).convert_sat::<30>().to_bits(),
+            i32::MIN
+        );
+        assert_eq!(big.convert_sat::<16>(), big);
+    }
+
+    #[test]
+    fn test_mixed_format_add_rounds() {
+        // 1.0 (Q8) + 0.75 LSB of Q8 expressed in Q16
+        let a = Fixed::<8>::from_int(1);
+        let b = Fixed::<16>::from_bits(192);
+        assert_eq!((a + b).to_bits(), 257);
+        assert_eq!((a - b).to_bits(), 255);
+    }
+
     #[test]
     fn test_multiplication_with_rounding() {
         // 0.5 (Q31) * 0.5 (Q31) = 0.25
@@ -259,4 +372,27 @@ mod tests {
         let approx = rounded.to_bits() as f64 / (1 << 16) as f64;
         assert!((approx - 1.0 / 3.0).abs() < 0.0001);
     }
+
+    #[test]
+    fn test_from_f64_saturates() {
+        assert_eq!(Fixed::<31>::from_f64(1.0).to_bits(), i32::MAX);
+        assert_eq!(Fixed::<31>::from_f64(-1.0).to_bits(), i32::MIN);
+        assert_eq!(Fixed::<31>::from_f64(-3.0).to_bits(), i32::MIN);
+        assert_eq!(Fixed::<15>::from_f64(1e9).to_bits(), i32::MAX);
+        assert_eq!(Fixed::<15>::from_f64(f64::NAN).to_bits(), 0);
+
+        assert_eq!(Fixed::<31>::try_from_f64(1.0), Err(FftError::OutOfRange));
+        assert_eq!(
+            Fixed::<31>::try_from_f64(f64::NAN),
+            Err(FftError::OutOfRange)
+        );
+        assert_eq!(Fixed::<31>::try_from_f64(-1.0).unwrap().to_bits(), i32::MIN);
+        assert_eq!(Fixed::<15>::try_from_f64(0.5).unwrap().to_bits(), 1 << 14);
+        // Just below 1.0 rounds into range
+        let below = 1.0 - 0.6 / 2147483648.0;
+        assert_eq!(
+            Fixed::<31>::try_from_f64(below).unwrap().to_bits(),
+            i32::MAX
+        );
+    }
 }
@@ -1,4 +1,6 @@
 use super::fixed::Fixed;
+use crate::common::FftNum;
+use crate::fixed::math::{self, Angle, MagnitudeTier};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[repr(C)]
@@ -8,10 +10,32 @@ pub struct ComplexFixed<const FRAC: u32> {
 }
 
 impl<const FRAC: u32> ComplexFixed<FRAC> {
-    pub fn new(re: Fixed<FRAC>, im: Fixed<FRAC>) -> Self {
+    /// 0 + 0i.
+    pub const ZERO: Self = Self::new(Fixed::ZERO, Fixed::ZERO);
+    /// 1 + 0i, saturated to 1 - 2^-31 for Q31.
+    pub const ONE: Self = Self::new(Fixed::ONE, Fixed::ZERO);
+    /// 0 + 1i, saturated to (1 - 2^-31)i for Q31.
+    pub const I: Self = Self::new(Fixed::ZERO, Fixed::ONE);
+
+    pub const fn new(re: Fixed<FRAC>, im: Fixed<FRAC>) -> Self {
         Self { re, im }
     }
 
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    pub const fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline]
+    pub const fn i() -> Self {
+        Self::I
+    }
+
     /// Views a Real slice as a Complex slice (zero-copy packing).
     /// Safe wrapper for reinterpret_cast.
     pub fn pack(reals: &[Fixed<FRAC>]) -> &[Self] {
@@ -65,6 +89,38 @@ impl<const FRAC: u32> ComplexFixed<FRAC> {
         }
     }
 
+    /// Multiplies by i: (re, im) -> (-im, re).
+    ///
+    /// Negation wraps, so -i32::MIN stays i32::MIN, exactly like a multiply
+    /// by a Q31 -1 twiddle; the FFT relies on both being bit-identical.
+    #[inline]
+    pub fn mul_i(self) -> Self {
+        ComplexFixed {
+            re: Fixed::from_bits(self.im.to_bits().wrapping_neg()),
+            im: self.re,
+        }
+    }
+
+    /// Multiplies by -i: (re, im) -> (im, -re). Negation wraps as in
+    /// [`Self::mul_i`].
+    #[inline]
+    pub fn mul_neg_i(self) -> Self {
+        ComplexFixed {
+            re: self.im,
+            im: Fixed::from_bits(self.re.to_bits().wrapping_neg()),
+        }
+    }
+
+    /// Multiplies both parts by a real factor of any format, with the
+    /// rounding of `Fixed` multiplication.
+    #[inline]
+    pub fn scale<const F2: u32>(self, k: Fixed<F2>) -> Self {
+        ComplexFixed {
+            re: self.re * k,
+            im: self.im * k,
+        }
+    }
+
     /// Scales both real and imaginary parts by 0.5 (right shift by 1).
     /// Used for stage normalization in inverse FFT to avoid overflow.
     #[inline]
@@ -74,16 +130,77 @@ impl<const FRAC: u32> ComplexFixed<FRAC> {
             im: Fixed::from_bits(self.im.to_bits() >> 1),
         }
     }
+
+    /// Squared magnitude re² + im² as a raw i64 with `2 * FRAC` fractional
+    /// bits.
+    ///
+    /// Exact for every input except `re == im == i32::MIN` (a sum of 2^63),
+    /// which saturates to `i64::MAX`. Use it instead of multiplying in 32
+    /// bits, which loses the low half of the product and overflows for
+    /// magnitudes above sqrt(0.5) in Q31.
+    #[inline]
+    pub fn norm_sqr_i64(self) -> i64 {
+        let re = self.re.to_bits() as i64;
+        let im = self.im.to_bits() as i64;
+        (re * re).saturating_add(im * im)
+    }
+
+    /// Magnitude sqrt(re² + im²), correctly rounded and saturated to the
+    /// format's range. Integer-only.
+    pub fn abs(self) -> Fixed<FRAC> {
+        let mag = math::isqrt_round(self.norm_sqr_i64() as u64);
+        Fixed::from_bits(mag.min(i32::MAX as u64) as i32)
+    }
+
+    /// Cheap magnitude estimate without any square root, saturated to the
+    /// format's range. See [`MagnitudeTier`] for the error of each tier.
+    pub fn magnitude_approx(self, tier: MagnitudeTier) -> Fixed<FRAC> {
+        let re = (self.re.to_bits() as i64).abs();
+        let im = (self.im.to_bits() as i64).abs();
+        let (max, min) = if re >= im { (re, im) } else { (im, re) };
+
+        let mag = match tier {
+            MagnitudeTier::Fast => max + (min >> 1),
+            MagnitudeTier::Balanced => (30 * max + 15 * min) >> 5,
+            MagnitudeTier::Accurate => (max + ((5 * min) >> 5)).max((108 * max + 71 * min) >> 7),
+        };
+        Fixed::from_bits(mag.min(i32::MAX as i64) as i32)
+    }
+
+    /// Phase atan2(im, re) in radians, in (-pi, pi]. Zero for the origin.
+    /// See [`math::atan2`] for the accuracy.
+    pub fn arg(self) -> Angle {
+        math::atan2(self.im, self.re)
+    }
+
+    /// Returns `(abs, arg)`.
+    pub fn to_polar(self) -> (Fixed<FRAC>, Angle) {
+        (self.abs(), self.arg())
+    }
+
+    /// Builds `r * (cos(theta) + i sin(theta))`.
+    pub fn from_polar(r: Fixed<FRAC>, theta: Angle) -> Self {
+        let (sin, cos) = libm::sincos(theta.to_f64());
+        let r = r.to_f64();
+        Self::new(Fixed::from_f64(r * cos), Fixed::from_f64(r * sin))
+    }
 }
 
-use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+impl<const FRAC: u32> Default for ComplexFixed<FRAC> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
 
 // Addition: ComplexFixed<F1> + ComplexFixed<F2> -> ComplexFixed<F1>
 impl<const F1: u32, const F2: u32> Add<ComplexFixed<F2>> for ComplexFixed<F1> {
     type Output = ComplexFixed<F1>;
 
     #[inline]
-    fn add(self, rhs: ComplexFixed<F2>) -> Self::Output {
+    fn add(self, rhs: ComplexFixed<F2>) -> ComplexFixed<F1> {
         ComplexFixed {
             re: self.re + rhs.re,
             im: self.im + rhs.im,
@@ -104,7 +221,7 @@ impl<const F1: u32, const F2: u32> Sub<ComplexFixed<F2>> for ComplexFixed<F1> {
     type Output = ComplexFixed<F1>;
 
     #[inline]
-    fn sub(self, rhs: ComplexFixed<F2>) -> Self::Output {
+    fn sub(self, rhs: ComplexFixed<F2>) -> ComplexFixed<F1> {
         ComplexFixed {
             re: self.re - rhs.re,
             im: self.im - rhs.im,
@@ -125,7 +242,7 @@ impl<const F1: u32, const F2: u32> Mul<ComplexFixed<F2>> for ComplexFixed<F1> {
     type Output = ComplexFixed<F1>;
 
     #[inline]
-    fn mul(self, rhs: ComplexFixed<F2>) -> Self::Output {
+    fn mul(self, rhs: ComplexFixed<F2>) -> ComplexFixed<F1> {
         // (ac - bd)
         let re = (self.re * rhs.re) - (self.im * rhs.im);
         // (ad + bc)
@@ -135,6 +252,16 @@ impl<const F1: u32, const F2: u32> Mul<ComplexFixed<F2>> for ComplexFixed<F1> {
     }
 }
 
+// Scalar multiplication: ComplexFixed<F1> * Fixed<F2> -> ComplexFixed<F1>
+impl<const F1: u32, const F2: u32> Mul<Fixed<F2>> for ComplexFixed<F1> {
+    type Output = ComplexFixed<F1>;
+
+    #[inline]
+    fn mul(self, rhs: Fixed<F2>) -> ComplexFixed<F1> {
+        self.scale(rhs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -363,6 +490,143 @@ mod tests {
         assert_eq!(result.im, Fixed::<16>::from_f64(-0.25));
     }
 
+    // --- Polar form tests ---
+
+    #[test]
+    fn test_polar_round_trip() {
+        // 3 + 4i: magnitude 5, phase atan2(4, 3)
+        let a = ComplexFixed::new(Fixed::<16>::from_int(3), Fixed::<16>::from_int(4));
+        let (r, theta) = a.to_polar();
+        assert_eq!(r, Fixed::<16>::from_int(5));
+        assert!((theta.to_bits() - Angle::from_f64(4f64.atan2(3.0)).to_bits()).abs() <= 6);
+
+        let back = ComplexFixed::from_polar(r, theta);
+        assert!((back.re.to_bits() - a.re.to_bits()).abs() <= 1);
+        assert!((back.im.to_bits() - a.im.to_bits()).abs() <= 1);
+        // (-0.5, -0.5) has a magnitude of sqrt(0.5), rounded to the LSB
+        let c = ComplexFixed::new(Fixed::<16>::from_f64(-0.5), Fixed::<16>::from_f64(-0.5));
+        assert_eq!(c.abs(), Fixed::<16>::from_f64(0.5f64.sqrt()));
+
+        // The negative real axis maps to +pi, the origin to 0
+        let neg = ComplexFixed::new(Fixed::<16>::from_int(-1), Fixed::<16>::from_int(0));
+        let pi = Angle::from_f64(core::f64::consts::PI).to_bits();
+        assert!((0..=6).contains(&(pi - neg.arg().to_bits())));
+        let zero = ComplexFixed::<16>::ZERO;
+        assert_eq!(zero.to_polar(), (Fixed::from_int(0), Angle::from_bits(0)));
+    }
+
+    #[test]
+    fn test_abs_saturates() {
+        // |(1 - 2^-31)(1 + i)| exceeds the Q31 range
+        let max = Fixed::<31>::from_bits(i32::MAX);
+        assert_eq!(ComplexFixed::new(max, max).abs().to_bits(), i32::MAX);
+    }
+
+    #[test]
+    fn test_norm_sqr_i64() {
+        // (3 + 4i) in Q16: 25 with 32 fractional bits
+        let a = ComplexFixed::new(Fixed::<16>::from_int(3), Fixed::<16>::from_int(4));
+        assert_eq!(a.norm_sqr_i64(), 25 << 32);
+
+        // Full-scale Q31 keeps every bit, where 32-bit math would overflow
+        let max = Fixed::<31>::from_bits(i32::MAX);
+        let z = ComplexFixed::new(max, max);
+        assert_eq!(z.norm_sqr_i64(), 2 * (i32::MAX as i64) * (i32::MAX as i64));
+        let min = Fixed::<31>::from_bits(i32::MIN);
+        assert_eq!(ComplexFixed::new(min, min).norm_sqr_i64(), i64::MAX);
+        assert_eq!(
+            ComplexFixed::new(min, Fixed::from_bits(0)).norm_sqr_i64(),
+            1 << 62
+        );
+    }
+
+    #[test]
+    fn test_magnitude_approx_tiers() {
+        let bounds = [
+            (MagnitudeTier::Fast, 0.0, 0.1181),
+            (MagnitudeTier::Balanced, -0.0626, 0.0482),
+            (MagnitudeTier::Accurate, -0.0122, 0.0122),
+        ];
+        for step in 0..=64 {
+            let theta = step as f64 * core::f64::consts::PI / 32.0;
+            let z = ComplexFixed::new(
+                Fixed::<24>::from_f64(theta.cos()),
+                Fixed::<24>::from_f64(theta.sin()),
+            );
+            let exact = z.abs().to_bits() as f64;
+            for (tier, lo, hi) in bounds {
+                let err = z.magnitude_approx(tier).to_bits() as f64 / exact - 1.0;
+                assert!(
+                    err >= lo && err <= hi,
+                    "{:?} at step {}: {}",
+                    tier,
+                    step,
+                    err
+                );
+            }
+        }
+
+        // Full-scale inputs saturate instead of wrapping
+        let min = Fixed::<31>::from_bits(i32::MIN);
+        let z = ComplexFixed::new(min, min);
+        assert_eq!(z.magnitude_approx(MagnitudeTier::Fast).to_bits(), i32::MAX);
+    }
+
+    // --- Constants ---
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(ComplexFixed::<16>::default(), ComplexFixed::zero());
+        assert_eq!(ComplexFixed::<16>::ONE.re, Fixed::from_int(1));
+        assert_eq!(ComplexFixed::<16>::I.im, Fixed::from_int(1));
+        assert_eq!(
+            ComplexFixed::<16>::I * ComplexFixed::<16>::I,
+            ComplexFixed::new(Fixed::from_int(-1), Fixed::ZERO)
+        );
+
+        // Q31 cannot hold 1.0 and saturates instead of wrapping to -1.0
+        assert_eq!(ComplexFixed::<31>::one().re.to_bits(), i32::MAX);
+        assert_eq!(ComplexFixed::<31>::i().im.to_bits(), i32::MAX);
+        assert_eq!(ComplexFixed::<31>::one().im, Fixed::default());
+    }
+
+    // --- i-rotation and scalar tests ---
+
+    #[test]
+    fn test_mul_i_matches_complex_multiply() {
+        let a = ComplexFixed::new(Fixed::<16>::from_int(3), Fixed::<16>::from_int(-4));
+        let i = ComplexFixed::new(Fixed::<31>::from_bits(0), Fixed::<31>::from_bits(i32::MAX));
+
+        assert_eq!(
+            a.mul_i(),
+            ComplexFixed::new(Fixed::from_int(4), Fixed::from_int(3))
+        );
+        assert_eq!(
+            a.mul_neg_i(),
+            ComplexFixed::new(Fixed::from_int(-4), Fixed::from_int(-3))
+        );
+        assert_eq!(a.mul_i().mul_neg_i(), a);
+        // Within one LSB of the Q31 (1 - 2^-31)i multiply
+        let m = a * i;
+        assert!((m.re.to_bits() - a.mul_i().re.to_bits()).abs() <= 1);
+        assert!((m.im.to_bits() - a.mul_i().im.to_bits()).abs() <= 1);
+
+        // -i32::MIN wraps like the Q31 twiddle path
+        let min = ComplexFixed::new(Fixed::<15>::from_bits(0), Fixed::<15>::from_bits(i32::MIN));
+        assert_eq!(min.mul_i().re.to_bits(), i32::MIN);
+    }
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let a = ComplexFixed::new(Fixed::<16>::from_int(3), Fixed::<16>::from_int(-4));
+        let half = Fixed::<31>::from_bits(1 << 30);
+
+        let expected = ComplexFixed::new(Fixed::<16>::from_f64(1.5), Fixed::<16>::from_int(-2));
+        assert_eq!(a.scale(half), expected);
+        assert_eq!(a * half, expected);
+        assert_eq!(a * Fixed::<8>::from_int(2), a + a);
+    }
+
     // --- scale_half tests ---
 
     #[test]
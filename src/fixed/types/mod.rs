@@ -1,5 +1,7 @@
+pub mod dyn_fixed;
 pub mod fixed;
 pub mod fixed_complex;
 
+pub use dyn_fixed::{DynComplexFixed, DynFixed};
 pub use fixed::Fixed;
-pub use fixed_complex::ComplexFixed;
\ No newline at end of file
+pub use fixed_complex::ComplexFixed;
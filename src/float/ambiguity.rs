@@ -0,0 +1,81 @@
+use super::types::Complex32;
+use crate::common::{CplxFft, FftError, Scratch};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+impl<'a> CplxFft<'a, Complex32> {
+    /// Scratch needed by [`Self::ambiguity`]: the spectra of `rx` and of
+    /// the reference, N samples each.
+    pub fn ambiguity_scratch_len(&self) -> usize {
+        2 * self.n
+    }
+
+    /// Cross-ambiguity function of `rx` against `reference`: one delay
+    /// profile per Doppler hypothesis.
+    ///
+    /// Row `r` of `out` (N samples each, rows packed back to back) is the
+    /// matched filter of `rx` against `reference` shifted in frequency by
+    /// `doppler_bins[r]` bins, i.e.
+    /// `out[r][d] = sum_n rx[n + d] * conj(reference[n] * e^(2 pi j f n / N))`
+    /// with `f = doppler_bins[r]`. An echo delayed by `d` samples and
+    /// shifted by `f` bins peaks at row `f`, column `d`. Doppler shifts are
+    /// circular, so `-1` and `N - 1` are the same hypothesis.
+    ///
+    /// Both spectra are computed once into `scratch`
+    /// (`self.ambiguity_scratch_len()` samples); a Doppler shift is then
+    /// just a rotation of the reference spectrum, so each row costs one
+    /// pointwise product and one inverse transform. With the `rayon` feature
+    /// the rows are computed in parallel.
+    pub fn ambiguity(
+        &self,
+        rx: &[Complex32],
+        reference: &[Complex32],
+        doppler_bins: &[i32],
+        out: &mut [Complex32],
+        scratch: &mut Scratch<'_, Complex32>,
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if rx.len() != n || reference.is_empty() || reference.len() > n {
+            return Err(FftError::SizeMismatch);
+        }
+        if out.len() != n * doppler_bins.len() {
+            return Err(FftError::SizeMismatch);
+        }
+        let (rx_spec, ref_spec) = scratch.take(self.ambiguity_scratch_len())?.split_at_mut(n);
+
+        rx_spec.copy_from_slice(rx);
+        ref_spec[..reference.len()].copy_from_slice(reference);
+        ref_spec[reference.len()..].fill(Complex32::new(0.0, 0.0));
+        self.process(rx_spec, false)?;
+        self.process(ref_spec, false)?;
+
+        let norm = self.scaling.correlation_norm(n);
+        let (rx_spec, ref_spec) = (&*rx_spec, &*ref_spec);
+        let row = |(profile, &shift): (&mut [Complex32], &i32)| {
+            // Shifting the reference up by f bins multiplies it by
+            // e^(2 pi j f n / N) in time
+            let shift = shift.rem_euclid(n as i32) as usize;
+            for (k, p) in profile.iter_mut().enumerate() {
+                let r = ref_spec[(k + n - shift) % n];
+                *p = rx_spec[k] * r.conj() * norm;
+            }
+            self.process(profile, true)
+        };
+
+        #[cfg(feature = "rayon")]
+        {
+            out.par_chunks_mut(n)
+                .zip(doppler_bins.par_iter())
+                .try_for_each(row)
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            out.chunks_mut(n).zip(doppler_bins.iter()).try_for_each(row)
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "ambiguity_tests.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+use super::*;
+use crate::common::Scratch;
+use core::f32::consts::PI;
+
+const N: usize = 64;
+
+/// Pseudo-random unit-magnitude phase code: a thumbtack-like reference.
+fn phase_code(len: usize) -> Vec<Complex32> {
+    let mut seed = 5u32;
+    (0..len)
+        .map(|_| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let phase = (seed >> 8) as f32 / (1 << 24) as f32 * 2.0 * PI;
+            Complex32::new(phase.cos(), phase.sin())
+        })
+        .collect()
+}
+
+#[test]
+fn test_locates_delayed_doppler_shifted_echo() {
+    let reference = phase_code(N);
+    // Echo delayed by 9 samples and shifted by -3 bins
+    let rx: Vec<Complex32> = (0..N)
+        .map(|i| {
+            let phase = 2.0 * PI * -3.0 * i as f32 / N as f32;
+            reference[(i + N - 9) % N] * Complex32::new(phase.cos(), phase.sin())
+        })
+        .collect();
+
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    let doppler: Vec<i32> = (-5..=5).collect();
+    let mut out = vec![Complex32::new(0.0, 0.0); N * doppler.len()];
+    let mut storage = vec![Complex32::new(0.0, 0.0); fft.ambiguity_scratch_len()];
+    let mut scratch = Scratch::new(&mut storage);
+    fft.ambiguity(&rx, &reference, &doppler, &mut out, &mut scratch)
+        .unwrap();
+
+    let peak = (0..out.len())
+        .max_by(|&a, &b| out[a].norm_sqr().total_cmp(&out[b].norm_sqr()))
+        .unwrap();
+    assert_eq!((doppler[peak / N], peak % N), (-3, 9));
+    // The matching cell holds the full energy of the reference
+    assert!((out[peak].norm_sqr().sqrt() - N as f32).abs() < 1e-2);
+
+    // The zero-Doppler row matches the plain matched filter
+    let mut mf = vec![Complex32::new(0.0, 0.0); N];
+    fft.matched_filter(&rx, &reference, None, &mut mf, &mut scratch)
+        .unwrap();
+    let zero = &out[5 * N..6 * N];
+    for (a, b) in zero.iter().zip(&mf) {
+        assert!((*a - *b).norm_sqr() < 1e-6);
+    }
+}
+
+#[test]
+fn test_size_checks() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let signal = phase_code(N);
+    assert_eq!(fft.ambiguity_scratch_len(), 2 * N);
+    let mut storage = vec![Complex32::new(0.0, 0.0); fft.ambiguity_scratch_len()];
+    let mut scratch = Scratch::new(&mut storage);
+
+    let mut short = vec![Complex32::new(0.0, 0.0); N];
+    assert_eq!(
+        fft.ambiguity(&signal, &signal, &[0, 1], &mut short, &mut scratch),
+        Err(FftError::SizeMismatch)
+    );
+    let mut out = vec![Complex32::new(0.0, 0.0); 2 * N];
+    assert_eq!(
+        fft.ambiguity(
+            &signal,
+            &signal,
+            &[0, 1],
+            &mut out,
+            &mut Scratch::new(&mut storage[..N])
+        ),
+        Err(FftError::BufferTooSmall)
+    );
+}
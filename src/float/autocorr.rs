@@ -0,0 +1,32 @@
+use super::types::Complex32;
+use crate::common::{FftError, RealFft};
+
+impl<'a> RealFft<'a, Complex32> {
+    /// Replaces `buffer` (N samples) by its circular autocorrelation,
+    /// `r[t] = sum_n x[n] * x[(n + t) mod N]`, computed as the inverse
+    /// transform of the power spectrum.
+    ///
+    /// For the linear (non-wrapping) autocorrelation of a frame of L
+    /// samples, zero-pad it to N >= 2L: lags `0..L` are then exact. The
+    /// result does not depend on `self.scaling`.
+    pub fn autocorrelate(&self, buffer: &mut [f32]) -> Result<(), FftError> {
+        let n = self.n;
+        self.process(buffer, false)?;
+
+        let norm = self.scaling.correlation_norm(n);
+
+        // Packed power spectrum: real, so every imaginary slot is zero
+        buffer[0] = buffer[0] * buffer[0] * norm;
+        buffer[1] = buffer[1] * buffer[1] * norm;
+        for bin in buffer[2..].chunks_exact_mut(2) {
+            bin[0] = (bin[0] * bin[0] + bin[1] * bin[1]) * norm;
+            bin[1] = 0.0;
+        }
+
+        self.process(buffer, true)
+    }
+}
+
+#[cfg(test)]
+#[path = "autocorr_tests.rs"]
+mod tests;
@@ -0,0 +1,39 @@
+use super::*;
+use crate::common::Scaling;
+
+const N: usize = 32;
+
+#[test]
+fn test_matches_direct_sum() {
+    let frame: Vec<f32> = (0..N / 2).map(|i| ((i * 7) % 5) as f32 - 2.0).collect();
+
+    for scaling in [Scaling::Inverse, Scaling::Forward, Scaling::None] {
+        let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+        let mut bitrev = vec![0; N / 2];
+        let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+            .unwrap()
+            .with_scaling(scaling);
+
+        let mut buf = vec![0.0; N];
+        buf[..frame.len()].copy_from_slice(&frame);
+        fft.autocorrelate(&mut buf).unwrap();
+
+        for lag in 0..frame.len() {
+            let direct: f32 = (0..frame.len() - lag)
+                .map(|i| frame[i] * frame[i + lag])
+                .sum();
+            assert!((buf[lag] - direct).abs() < 1e-3, "{scaling:?}, lag {lag}");
+        }
+    }
+}
+
+#[test]
+fn test_size_check() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    assert_eq!(
+        fft.autocorrelate(&mut [0.0; 8]),
+        Err(FftError::SizeMismatch)
+    );
+}
@@ -0,0 +1,100 @@
+use super::core::swap_table_len;
+use super::types::Complex32;
+use crate::common::{CplxFft, FftError};
+
+/// One complex sample of `L` independent signals, split into real and
+/// imaginary lanes.
+pub type Lanes<const L: usize> = [f32; L];
+
+impl<'a> CplxFft<'a, Complex32> {
+    /// Transforms `L` independent N-point signals at once.
+    ///
+    /// The batch is stored structure-of-arrays: `re[k][l]` and `im[k][l]`
+    /// are sample `k` of signal `l`. Every butterfly then works on whole
+    /// lane arrays, which the compiler turns into vector instructions for
+    /// `L` = 4 (SSE, NEON) or 8 (AVX). Each lane gets the same result as
+    /// [`Self::process`] on that signal; use [`load_lane`] and
+    /// [`store_lane`] to move signals in and out.
+    pub fn process_batch<const L: usize>(
+        &self,
+        re: &mut [Lanes<L>],
+        im: &mut [Lanes<L>],
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if re.len() != n || im.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+
+        for pair in self.bitrev[..swap_table_len(n)].chunks_exact(2) {
+            re.swap(pair[0], pair[1]);
+            im.swap(pair[0], pair[1]);
+        }
+
+        let half = if self.scaling.is_scaled(inverse) {
+            0.5
+        } else {
+            1.0
+        };
+        let mut stride = 1;
+        let mut tw_index = n >> 1;
+        while stride < n {
+            for i in 0..stride {
+                let w = self.twiddles[i * tw_index];
+                let (wr, wi) = (w.re, if inverse { -w.im } else { w.im });
+                for j in (i..n).step_by(stride << 1) {
+                    let (ar, ai) = (re[j], im[j]);
+                    let (br, bi) = (re[j + stride], im[j + stride]);
+                    for l in 0..L {
+                        let tr = br[l] * wr - bi[l] * wi;
+                        let ti = br[l] * wi + bi[l] * wr;
+                        re[j][l] = (ar[l] + tr) * half;
+                        im[j][l] = (ai[l] + ti) * half;
+                        re[j + stride][l] = (ar[l] - tr) * half;
+                        im[j + stride][l] = (ai[l] - ti) * half;
+                    }
+                }
+            }
+            stride <<= 1;
+            tw_index >>= 1;
+        }
+        Ok(())
+    }
+}
+
+/// Copies `signal` into lane `lane` of a structure-of-arrays batch.
+pub fn load_lane<const L: usize>(
+    signal: &[Complex32],
+    re: &mut [Lanes<L>],
+    im: &mut [Lanes<L>],
+    lane: usize,
+) -> Result<(), FftError> {
+    if lane >= L || re.len() != signal.len() || im.len() != signal.len() {
+        return Err(FftError::SizeMismatch);
+    }
+    for ((s, r), i) in signal.iter().zip(re.iter_mut()).zip(im.iter_mut()) {
+        r[lane] = s.re;
+        i[lane] = s.im;
+    }
+    Ok(())
+}
+
+/// Copies lane `lane` of a batch out into `signal`.
+pub fn store_lane<const L: usize>(
+    re: &[Lanes<L>],
+    im: &[Lanes<L>],
+    lane: usize,
+    signal: &mut [Complex32],
+) -> Result<(), FftError> {
+    if lane >= L || re.len() != signal.len() || im.len() != signal.len() {
+        return Err(FftError::SizeMismatch);
+    }
+    for ((s, r), i) in signal.iter_mut().zip(re.iter()).zip(im.iter()) {
+        *s = Complex32::new(r[lane], i[lane]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "batch_tests.rs"]
+mod tests;
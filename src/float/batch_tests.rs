@@ -0,0 +1,66 @@
+use super::*;
+use crate::common::Scaling;
+
+fn signal(seed: usize, n: usize) -> Vec<Complex32> {
+    (0..n)
+        .map(|i| {
+            let x = (i * 7 + seed * 13) % 17;
+            Complex32::new(x as f32 - 8.0, ((x * 3) % 5) as f32)
+        })
+        .collect()
+}
+
+fn check_lanes<const L: usize>(n: usize, scaling: Scaling) {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(scaling);
+
+    let mut re = vec![[0.0; L]; n];
+    let mut im = vec![[0.0; L]; n];
+    for lane in 0..L {
+        load_lane(&signal(lane, n), &mut re, &mut im, lane).unwrap();
+    }
+
+    for inverse in [false, true] {
+        fft.process_batch(&mut re, &mut im, inverse).unwrap();
+        let mut out = vec![Complex32::new(0.0, 0.0); n];
+        for lane in 0..L {
+            let mut expected = signal(lane, n);
+            fft.process(&mut expected, false).unwrap();
+            if inverse {
+                fft.process(&mut expected, true).unwrap();
+            }
+            store_lane(&re, &im, lane, &mut out).unwrap();
+            for (a, b) in out.iter().zip(&expected) {
+                assert!((*a - *b).norm_sqr() < 1e-6, "L={L} lane {lane}: {a} vs {b}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lanes_match_single_transforms() {
+    check_lanes::<4>(64, Scaling::Inverse);
+    check_lanes::<8>(16, Scaling::Forward);
+    check_lanes::<8>(2, Scaling::None);
+}
+
+#[test]
+fn test_rejects_bad_shapes() {
+    let n = 8;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let mut re = vec![[0.0; 4]; n];
+    let mut im = vec![[0.0; 4]; n - 1];
+    assert_eq!(
+        fft.process_batch(&mut re, &mut im, false),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        load_lane(&signal(0, n), &mut re, &mut im, 4),
+        Err(FftError::SizeMismatch)
+    );
+}
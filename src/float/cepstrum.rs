@@ -0,0 +1,93 @@
+use super::types::Complex32;
+use crate::common::{FftError, RealFft};
+
+/// Zeroes the cepstral coefficients at quefrencies of `cutoff` samples and
+/// above (and their mirror images), keeping the slowly varying part of the
+/// log spectrum. Coefficient 0, the mean log level, is always kept.
+pub fn lifter(cepstrum: &mut [f32], cutoff: usize) {
+    let n = cepstrum.len();
+    let cutoff = cutoff.max(1);
+    if cutoff <= n / 2 {
+        cepstrum[cutoff..=n - cutoff].fill(0.0);
+    }
+}
+
+impl<'a> RealFft<'a, Complex32> {
+    /// Real cepstrum (N samples) of the frame whose packed spectrum is
+    /// `packed`: the inverse transform of `ln |X[k]|`.
+    ///
+    /// Magnitudes are floored at 1e-6 of the largest one (-120 dB) so
+    /// empty bins do not dominate the result. Independent of
+    /// `self.scaling`; `cepstrum[q]` is the plain `1/N`-normalized IDFT.
+    pub fn real_cepstrum(&self, packed: &[f32], cepstrum: &mut [f32]) -> Result<(), FftError> {
+        let n = self.n;
+        if packed.len() != n || cepstrum.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+
+        let magnitude = |k: usize| match k {
+            0 => libm::fabsf(packed[0]),
+            _ if k == n / 2 => libm::fabsf(packed[1]),
+            _ => libm::hypotf(packed[2 * k], packed[2 * k + 1]),
+        };
+        let peak = (0..=n / 2).map(magnitude).fold(0.0f32, f32::max);
+        let floor = (peak * 1e-6).max(f32::MIN_POSITIVE);
+        let log = |k: usize| libm::logf(magnitude(k).max(floor));
+
+        // ln|X| is real and even, so its packed spectrum has no imaginary parts
+        cepstrum[0] = log(0);
+        cepstrum[1] = log(n / 2);
+        for k in 1..n / 2 {
+            cepstrum[2 * k] = log(k);
+            cepstrum[2 * k + 1] = 0.0;
+        }
+        self.process(cepstrum, true)?;
+        if !self.scaling.is_scaled(true) {
+            let norm = 1.0 / n as f32;
+            cepstrum.iter_mut().for_each(|c| *c *= norm);
+        }
+        Ok(())
+    }
+
+    /// Smoothed spectral envelope of the frame whose packed spectrum is
+    /// `packed`, in dB, for bins `0..=N/2`.
+    ///
+    /// The real cepstrum is liftered at `cutoff` samples and transformed
+    /// back. With `cutoff` below the pitch period in samples, the
+    /// harmonics of voiced speech are removed and the formants remain as
+    /// the peaks of `out`. `scratch` holds N samples.
+    pub fn spectral_envelope(
+        &self,
+        packed: &[f32],
+        cutoff: usize,
+        out: &mut [f32],
+        scratch: &mut [f32],
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if out.len() != n / 2 + 1 {
+            return Err(FftError::SizeMismatch);
+        }
+        let cepstrum = scratch.get_mut(..n).ok_or(FftError::BufferTooSmall)?;
+        self.real_cepstrum(packed, cepstrum)?;
+        lifter(cepstrum, cutoff);
+
+        self.process(cepstrum, false)?;
+        // Back to ln|X| whatever the forward scaling, then to dB
+        let to_db = 20.0 / core::f32::consts::LN_10
+            * if self.scaling.is_scaled(false) {
+                n as f32
+            } else {
+                1.0
+            };
+        out[0] = cepstrum[0] * to_db;
+        out[n / 2] = cepstrum[1] * to_db;
+        for (o, bin) in out[1..n / 2].iter_mut().zip(cepstrum[2..].chunks_exact(2)) {
+            *o = bin[0] * to_db;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "cepstrum_tests.rs"]
+mod tests;
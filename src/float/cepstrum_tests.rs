@@ -0,0 +1,79 @@
+use super::*;
+use crate::common::Scaling;
+use core::f32::consts::PI;
+
+const N: usize = 256;
+// Harmonics every N / PERIOD bins: a pitch period of PERIOD samples
+const PERIOD: usize = 32;
+
+/// Packed spectrum `exp(0.8 cos(6 pi k / N)) * (1 + 0.9 cos(2 pi k PERIOD / N))`:
+/// a smooth envelope times a harmonic comb, with zero phase.
+fn voiced_spectrum() -> Vec<f32> {
+    let magnitude = |k: usize| {
+        let envelope = libm::expf(0.8 * libm::cosf(6.0 * PI * k as f32 / N as f32));
+        let comb = 1.0 + 0.9 * libm::cosf(2.0 * PI * (k * PERIOD) as f32 / N as f32);
+        envelope * comb
+    };
+    let mut packed = vec![0.0f32; N];
+    packed[0] = magnitude(0);
+    packed[1] = magnitude(N / 2);
+    for k in 1..N / 2 {
+        packed[2 * k] = magnitude(k);
+    }
+    packed
+}
+
+#[test]
+fn test_cepstrum_separates_envelope_and_pitch() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    for scaling in [Scaling::None, Scaling::Forward, Scaling::Inverse] {
+        let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+            .unwrap()
+            .with_scaling(scaling);
+        let mut cepstrum = vec![0.0f32; N];
+        fft.real_cepstrum(&voiced_spectrum(), &mut cepstrum)
+            .unwrap();
+        // The envelope sits at quefrency 3, the pitch at multiples of PERIOD
+        assert!((cepstrum[3] - 0.4).abs() < 1e-4);
+        assert!((cepstrum[N - 3] - 0.4).abs() < 1e-4);
+        assert!(cepstrum[PERIOD].abs() > 0.1);
+        assert!(cepstrum[10..PERIOD].iter().all(|c| c.abs() < 1e-4));
+    }
+}
+
+#[test]
+fn test_liftered_envelope_drops_the_harmonics() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    for scaling in [Scaling::None, Scaling::Forward, Scaling::Inverse] {
+        let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+            .unwrap()
+            .with_scaling(scaling);
+        let mut envelope = vec![0.0f32; N / 2 + 1];
+        let mut scratch = vec![0.0f32; N];
+        fft.spectral_envelope(&voiced_spectrum(), 10, &mut envelope, &mut scratch)
+            .unwrap();
+
+        // The comb only contributes its mean log level
+        let comb_mean = (0..N)
+            .map(|k| libm::logf(1.0 + 0.9 * libm::cosf(2.0 * PI * (k * PERIOD) as f32 / N as f32)))
+            .sum::<f32>()
+            / N as f32;
+        for (k, &e) in envelope.iter().enumerate() {
+            let expected = 0.8 * libm::cosf(6.0 * PI * k as f32 / N as f32) + comb_mean;
+            let db = 20.0 / core::f32::consts::LN_10 * expected;
+            assert!((e - db).abs() < 1e-2, "{scaling:?} bin {k}: {e} vs {db}");
+        }
+    }
+}
+
+#[test]
+fn test_lifter_edges() {
+    let mut c = [1.0f32; 8];
+    lifter(&mut c, 2);
+    assert_eq!(c, [1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+    let mut c = [1.0f32; 8];
+    lifter(&mut c, 5);
+    assert_eq!(c, [1.0; 8]);
+}
@@ -0,0 +1,130 @@
+use super::core::swap_table_len;
+use super::types::Complex32;
+use crate::common::{CplxFft, FftError};
+
+/// Error-free product: `a * b == p + e` exactly.
+#[inline]
+fn two_prod(a: f32, b: f32) -> (f32, f32) {
+    let p = a * b;
+    (p, libm::fmaf(a, b, -p))
+}
+
+/// Error-free sum: `a + b == s + e` exactly.
+#[inline]
+fn two_sum(a: f32, b: f32) -> (f32, f32) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Renormalizes `hi + lo` so that `hi` is the rounded sum.
+#[inline]
+fn fast_two_sum(hi: f32, lo: f32) -> (f32, f32) {
+    let s = hi + lo;
+    (s, lo - (s - hi))
+}
+
+/// `(ah + al) * (bh + bl) + (ch + cl) * (dh + dl)` as a normalized pair,
+/// dropping only the `lo * lo` terms.
+#[inline]
+fn dot2(a: (f32, f32), b: (f32, f32), c: (f32, f32), d: (f32, f32)) -> (f32, f32) {
+    let (p1, e1) = two_prod(a.0, b.0);
+    let (p2, e2) = two_prod(c.0, d.0);
+    let (s, e3) = two_sum(p1, p2);
+    let lo = e1 + e2 + e3 + a.0 * b.1 + a.1 * b.0 + c.0 * d.1 + c.1 * d.0;
+    fast_two_sum(s, lo)
+}
+
+#[inline]
+fn add2(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    let (s, e) = two_sum(a.0, b.0);
+    fast_two_sum(s, e + a.1 + b.1)
+}
+
+impl<'a> CplxFft<'a, Complex32> {
+    /// Writes into `out` (N/2 samples) the difference between the exact
+    /// twiddles and the plan's f32 table, for
+    /// [`Self::process_compensated`]. Computed once per plan.
+    pub fn twiddle_residuals(&self, out: &mut [Complex32]) -> Result<(), FftError> {
+        let n = self.n;
+        let out = out.get_mut(..n / 2).ok_or(FftError::BufferTooSmall)?;
+        for (j, (r, t)) in out.iter_mut().zip(self.twiddles.iter()).enumerate() {
+            let angle = -2.0 * core::f64::consts::PI * j as f64 / n as f64;
+            let (sin, cos) = libm::sincos(angle);
+            *r = Complex32::new((cos - t.re as f64) as f32, (sin - t.im as f64) as f32);
+        }
+        Ok(())
+    }
+
+    /// [`Self::process`] in compensated (float-float) arithmetic.
+    ///
+    /// Every sample carries a correction term in `lo` (N samples) and every
+    /// butterfly uses error-free sums and FMA products, with the twiddles
+    /// extended by `residuals` from [`Self::twiddle_residuals`]. The
+    /// rounding error then no longer grows with `log2(N)`: the result in
+    /// `buffer` is correctly rounded to within an ulp or so, and
+    /// `buffer + lo` is accurate well beyond f32. The cost is a few times
+    /// that of the plain transform.
+    pub fn process_compensated(
+        &self,
+        buffer: &mut [Complex32],
+        lo: &mut [Complex32],
+        residuals: &[Complex32],
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if buffer.len() != n || lo.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+        if residuals.len() < n / 2 {
+            return Err(FftError::BufferTooSmall);
+        }
+        lo.fill(Complex32::new(0.0, 0.0));
+        for pair in self.bitrev[..swap_table_len(n)].chunks_exact(2) {
+            buffer.swap(pair[0], pair[1]);
+        }
+
+        let half = if self.scaling.is_scaled(inverse) {
+            0.5
+        } else {
+            1.0
+        };
+        let mut stride = 1;
+        let mut tw_index = n >> 1;
+        while stride < n {
+            for i in 0..stride {
+                let (t, r) = (self.twiddles[i * tw_index], residuals[i * tw_index]);
+                let wr = (t.re, r.re);
+                let wi = if inverse {
+                    (-t.im, -r.im)
+                } else {
+                    (t.im, r.im)
+                };
+                let neg_wi = (-wi.0, -wi.1);
+                for j in (i..n).step_by(stride << 1) {
+                    let k = j + stride;
+                    let (ar, ai) = ((buffer[j].re, lo[j].re), (buffer[j].im, lo[j].im));
+                    let (br, bi) = ((buffer[k].re, lo[k].re), (buffer[k].im, lo[k].im));
+                    let tr = dot2(br, wr, bi, neg_wi);
+                    let ti = dot2(br, wi, bi, wr);
+
+                    let sr = add2(ar, tr);
+                    let si = add2(ai, ti);
+                    let dr = add2(ar, (-tr.0, -tr.1));
+                    let di = add2(ai, (-ti.0, -ti.1));
+                    buffer[j] = Complex32::new(sr.0 * half, si.0 * half);
+                    lo[j] = Complex32::new(sr.1 * half, si.1 * half);
+                    buffer[k] = Complex32::new(dr.0 * half, di.0 * half);
+                    lo[k] = Complex32::new(dr.1 * half, di.1 * half);
+                }
+            }
+            stride <<= 1;
+            tw_index >>= 1;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "compensated_tests.rs"]
+mod tests;
@@ -1,6 +1,10 @@
-use super::core::{precompute_bitrev, precompute_twiddles, radix_2_dit_fft_core};
-use crate::common::{CplxFft, FftError, FftProcess}; // Adicione FftProcess aqui
-use num_complex::Complex32; // Complex<f32>
+use super::core::{
+    precompute_swaps, precompute_twiddles, radix_2_dit_fft_split, radix_2_fft,
+    radix_2_fft_unordered,
+};
+use super::types::Complex32;
+use crate::common::{Algorithm, CplxFft, FftError, FftProcess, Hygiene, Scaling, swaps_are_exact};
+use core::slice;
 
 // In no_std, we need to import math functions from somewhere.
 // If the "std" feature is enabled, we use native f32::sin/cos.
@@ -28,31 +32,167 @@ impl<'a> CplxFft<'a, Complex32> {
             twiddles,
             bitrev,
             n,
+            scaling: Scaling::default(),
+            algorithm: Algorithm::default(),
+            hygiene: Hygiene::default(),
         };
         fft.precompute();
+        event!(
+            debug,
+            "complex f32 plan: n = {}, fused multiply-add {}",
+            n,
+            super::core::FUSED
+        );
         Ok(fft)
     }
 
+    /// Builds a plan on tables that already hold this size's factors (from
+    /// an earlier plan on the same storage, or generated at build time),
+    /// skipping the O(N) trigonometry of [`Self::new`].
+    ///
+    /// The twiddles are spot checked (`twiddles[0]` must be 1 and
+    /// `twiddles[N/4]` must be `-i`) and the swap list is checked in full.
+    /// Tables that fail give `FftError::InvalidFormat`.
+    pub fn from_precomputed(
+        twiddles: &'a mut [Complex32],
+        bitrev: &'a mut [usize],
+        n: usize,
+    ) -> Result<Self, FftError> {
+        if !n.is_power_of_two() {
+            return Err(FftError::NotPowerOfTwo);
+        }
+        if twiddles.len() < n / 2 || bitrev.len() < n {
+            return Err(FftError::BufferTooSmall);
+        }
+        // cos(-pi/2) is not exactly zero in f32
+        let ok = |k: usize, re: f32, im: f32| {
+            let t = twiddles[k];
+            (t.re - re).abs() < 1e-6 && (t.im - im).abs() < 1e-6
+        };
+        if (n >= 2 && !ok(0, 1.0, 0.0)) || (n >= 4 && !ok(n / 4, 0.0, -1.0)) {
+            event!(warn, "precomputed tables rejected for n = {}", n);
+            return Err(FftError::InvalidFormat);
+        }
+        if !swaps_are_exact(bitrev, n) {
+            event!(warn, "precomputed tables rejected for n = {}", n);
+            return Err(FftError::InvalidFormat);
+        }
+
+        Ok(Self {
+            twiddles,
+            bitrev,
+            n,
+            scaling: Scaling::default(),
+            algorithm: Algorithm::default(),
+            hygiene: Hygiene::default(),
+        })
+    }
+
     /// Precomputes Twiddle Factors and Bit Reverse Table
     fn precompute(&mut self) {
-        precompute_bitrev(self.bitrev, self.n);
+        precompute_swaps(self.bitrev, self.n);
         precompute_twiddles(self.twiddles, self.n);
     }
 
     /// Executes the FFT in-place (Port from `radix_2_dit_fft` in `fft_core.c`)
+    /// The normalization follows `self.scaling`.
     pub fn process(&self, buffer: &mut [Complex32], inverse: bool) -> Result<(), FftError> {
+        self.validate()?;
         if buffer.len() != self.n {
             return Err(FftError::SizeMismatch);
         }
+        super::hygiene::check_complex(self.hygiene, buffer)?;
 
-        if inverse {
-            radix_2_dit_fft_core::<true>(buffer, self.twiddles, self.bitrev, 1);
-        } else {
-            radix_2_dit_fft_core::<false>(buffer, self.twiddles, self.bitrev, 1);
+        let scale = self.scaling.is_scaled(inverse);
+        radix_2_fft(
+            buffer,
+            self.twiddles,
+            self.bitrev,
+            1,
+            self.algorithm,
+            inverse,
+            scale,
+        );
+
+        Ok(())
+    }
+
+    /// Executes the FFT in-place without the bit-reversal permutation.
+    ///
+    /// The forward transform takes natural-order input and leaves the
+    /// spectrum in bit-reversed order; the inverse expects that bit-reversed
+    /// spectrum and returns natural-order output. In FFT → pointwise
+    /// multiply → IFFT pipelines (e.g. convolution) the intermediate order is
+    /// irrelevant, so both permutation passes can be skipped. The plan's
+    /// `algorithm` is ignored; the normalization follows `self.scaling`.
+    pub fn process_unordered(
+        &self,
+        buffer: &mut [Complex32],
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        self.validate()?;
+        if buffer.len() != self.n {
+            return Err(FftError::SizeMismatch);
         }
+        super::hygiene::check_complex(self.hygiene, buffer)?;
+
+        let scale = self.scaling.is_scaled(inverse);
+        radix_2_fft_unordered(buffer, self.twiddles, inverse, scale);
 
         Ok(())
     }
+
+    /// Executes the FFT in-place on a planar (split) buffer, with real and
+    /// imaginary parts stored in separate slices of size N.
+    pub fn process_split(
+        &self,
+        re: &mut [f32],
+        im: &mut [f32],
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        self.validate()?;
+        if re.len() != self.n || im.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+        // `Detect` leaves `re` untouched when only `im` fails
+        super::hygiene::check(self.hygiene, re)?;
+        super::hygiene::check(self.hygiene, im)?;
+
+        let scale = self.scaling.is_scaled(inverse);
+        radix_2_dit_fft_split(re, im, self.twiddles, self.bitrev, inverse, scale);
+
+        Ok(())
+    }
+}
+
+// `(f32, f32)` has no guaranteed layout, so the cast in `process_tuples` is only
+// allowed to compile when it matches Complex32 (re at 0, im at 4, size 8).
+const _: () = assert!(
+    core::mem::size_of::<(f32, f32)>() == core::mem::size_of::<Complex32>()
+        && core::mem::offset_of!((f32, f32), 0) == core::mem::offset_of!(Complex32, re)
+        && core::mem::offset_of!((f32, f32), 1) == core::mem::offset_of!(Complex32, im)
+);
+
+impl<'a> CplxFft<'a, Complex32> {
+    /// Executes the FFT in-place on `[re, im]` pairs, for callers that do not
+    /// expose `num_complex` types in their API.
+    pub fn process_arrays(&self, buffer: &mut [[f32; 2]], inverse: bool) -> Result<(), FftError> {
+        // Safety: Complex32 is repr(C) of two f32s, exactly like [f32; 2].
+        let cbuffer = unsafe {
+            slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut Complex32, buffer.len())
+        };
+        self.process(cbuffer, inverse)
+    }
+
+    /// Executes the FFT in-place on `(re, im)` tuples, for callers that do not
+    /// expose `num_complex` types in their API.
+    pub fn process_tuples(&self, buffer: &mut [(f32, f32)], inverse: bool) -> Result<(), FftError> {
+        // Safety: the layout of (f32, f32) is checked against Complex32 at compile time above.
+        let cbuffer = unsafe {
+            slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut Complex32, buffer.len())
+        };
+        self.process(cbuffer, inverse)
+    }
 }
 
 // Implementação da trait FftProcess para CplxFft
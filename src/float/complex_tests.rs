@@ -1,5 +1,6 @@
 use super::CplxFft;
-use num_complex::Complex32;
+use crate::common::{Algorithm, FftError, Scaling};
+use crate::float::types::Complex32;
 
 fn assert_complex_close(val: Complex32, expected: Complex32) {
     let tolerance = 1e-4;
@@ -88,3 +89,241 @@ fn test_fft_reverse() {
         assert_complex_close(val, expected_input[i]);
     }
 }
+
+#[test]
+fn test_scaling_contract() {
+    let n = 8;
+    let input: Vec<Complex32> = (0..n)
+        .map(|i| Complex32::new(i as f32 - 3.0, 1.0 - i as f32 * 0.5))
+        .collect();
+
+    let mut reference = input.clone();
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    assert_eq!(fft.scaling, Scaling::Inverse);
+    fft.process(&mut reference, false).unwrap();
+
+    // (scaling, forward gain, round trip gain)
+    let cases = [
+        (Scaling::None, 1.0, n as f32),
+        (Scaling::Forward, 1.0 / n as f32, 1.0),
+        (Scaling::Inverse, 1.0, 1.0),
+    ];
+
+    for (scaling, forward_gain, round_trip_gain) in cases {
+        let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+        let mut bitrev = vec![0; n];
+        let fft = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_scaling(scaling);
+
+        let mut buffer = input.clone();
+        fft.process(&mut buffer, false).unwrap();
+        for (&val, &expected) in buffer.iter().zip(reference.iter()) {
+            assert_complex_close(val, expected * forward_gain);
+        }
+
+        fft.process(&mut buffer, true).unwrap();
+        for (&val, &expected) in buffer.iter().zip(input.iter()) {
+            assert_complex_close(val, expected * round_trip_gain);
+        }
+    }
+}
+
+#[test]
+fn test_process_split_matches_interleaved() {
+    let n = 16;
+    let input: Vec<Complex32> = (0..n)
+        .map(|i| Complex32::new((i as f32 * 0.4).sin(), (i as f32 * 0.7).cos()))
+        .collect();
+
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    for inverse in [false, true] {
+        let mut expected = input.clone();
+        fft.process(&mut expected, inverse).unwrap();
+
+        let mut re: Vec<f32> = input.iter().map(|c| c.re).collect();
+        let mut im: Vec<f32> = input.iter().map(|c| c.im).collect();
+        fft.process_split(&mut re, &mut im, inverse).unwrap();
+
+        for i in 0..n {
+            assert_complex_close(Complex32::new(re[i], im[i]), expected[i]);
+        }
+    }
+
+    let mut re = vec![0.0f32; n];
+    let mut im = vec![0.0f32; n - 1];
+    assert!(fft.process_split(&mut re, &mut im, false).is_err());
+}
+
+#[test]
+fn test_process_arrays_and_tuples() {
+    let n = 8;
+    let input: Vec<Complex32> = (0..n)
+        .map(|i| Complex32::new(i as f32 - 2.0, 3.0 - i as f32 * 0.5))
+        .collect();
+
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    let mut expected = input.clone();
+    fft.process(&mut expected, false).unwrap();
+
+    let mut arrays: Vec<[f32; 2]> = input.iter().map(|c| [c.re, c.im]).collect();
+    fft.process_arrays(&mut arrays, false).unwrap();
+
+    let mut tuples: Vec<(f32, f32)> = input.iter().map(|c| (c.re, c.im)).collect();
+    fft.process_tuples(&mut tuples, false).unwrap();
+
+    for i in 0..n {
+        assert_complex_close(Complex32::new(arrays[i][0], arrays[i][1]), expected[i]);
+        assert_complex_close(Complex32::new(tuples[i].0, tuples[i].1), expected[i]);
+    }
+
+    assert!(fft.process_arrays(&mut arrays[..4], false).is_err());
+    assert!(fft.process_tuples(&mut tuples[..4], true).is_err());
+}
+
+#[test]
+fn test_dif_matches_dit() {
+    let n = 16;
+    let input: Vec<Complex32> = (0..n)
+        .map(|i| Complex32::new((i as f32 * 0.4).sin(), i as f32 * 0.1 - 0.5))
+        .collect();
+
+    for scaling in [Scaling::None, Scaling::Forward, Scaling::Inverse] {
+        for inverse in [false, true] {
+            let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+            let mut bitrev = vec![0; n];
+            let dit = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n)
+                .unwrap()
+                .with_scaling(scaling);
+            let mut expected = input.clone();
+            dit.process(&mut expected, inverse).unwrap();
+
+            let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+            let mut bitrev = vec![0; n];
+            let dif = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n)
+                .unwrap()
+                .with_scaling(scaling)
+                .with_algorithm(Algorithm::Dif);
+            let mut buffer = input.clone();
+            dif.process(&mut buffer, inverse).unwrap();
+
+            for (&val, &exp) in buffer.iter().zip(expected.iter()) {
+                assert_complex_close(val, exp);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_process_unordered() {
+    let n = 16;
+    let x: Vec<Complex32> = (0..n)
+        .map(|i| Complex32::new((i as f32 * 0.3).cos(), 0.0))
+        .collect();
+    let h: Vec<Complex32> = (0..n)
+        .map(|i| Complex32::new(if i < 3 { 1.0 / (i + 1) as f32 } else { 0.0 }, 0.0))
+        .collect();
+
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    // Forward output is the ordered spectrum in bit-reversed positions
+    let mut ordered = x.clone();
+    fft.process(&mut ordered, false).unwrap();
+    let mut unordered = x.clone();
+    fft.process_unordered(&mut unordered, false).unwrap();
+    for (k, &val) in unordered.iter().enumerate() {
+        assert_complex_close(
+            val,
+            ordered[k.reverse_bits() >> (usize::BITS - n.trailing_zeros())],
+        );
+    }
+
+    // Circular convolution without any permutation pass
+    let mut hf = h.clone();
+    fft.process_unordered(&mut hf, false).unwrap();
+    for (a, &b) in unordered.iter_mut().zip(hf.iter()) {
+        *a *= b;
+    }
+    fft.process_unordered(&mut unordered, true).unwrap();
+
+    for (t, &val) in unordered.iter().enumerate() {
+        let expected: f32 = (0..n).map(|k| x[k].re * h[(t + n - k) % n].re).sum();
+        assert_complex_close(val, Complex32::new(expected, 0.0));
+    }
+}
+
+#[test]
+fn test_from_precomputed_reuses_tables() {
+    let n = 32;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let input: Vec<Complex32> = (0..n).map(|i| Complex32::new(i as f32, 1.0)).collect();
+    let mut expected = input.clone();
+    CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .process(&mut expected, false)
+        .unwrap();
+
+    let fft = CplxFft::<Complex32>::from_precomputed(&mut twiddles, &mut bitrev, n).unwrap();
+    let mut buffer = input;
+    fft.process(&mut buffer, false).unwrap();
+    assert_eq!(buffer, expected);
+
+    // Tables of another size fail the spot check
+    assert_eq!(
+        CplxFft::<Complex32>::from_precomputed(&mut twiddles, &mut bitrev, 16).err(),
+        Some(FftError::InvalidFormat)
+    );
+    let mut blank = vec![Complex32::new(0.0, 0.0); n / 2];
+    assert_eq!(
+        CplxFft::<Complex32>::from_precomputed(&mut blank, &mut bitrev, n).err(),
+        Some(FftError::InvalidFormat)
+    );
+}
+
+#[test]
+fn test_edited_plan_errors_instead_of_panicking() {
+    let n = 16;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let mut fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let mut buffer = vec![Complex32::new(1.0, 0.0); 2 * n];
+
+    fft.n = 2 * n;
+    assert_eq!(
+        fft.process(&mut buffer, false),
+        Err(FftError::BufferTooSmall)
+    );
+    fft.n = 12;
+    assert_eq!(
+        fft.process_unordered(&mut buffer[..12], false),
+        Err(FftError::NotPowerOfTwo)
+    );
+
+    // A corrupted swap list is reported, not followed
+    fft.n = 8;
+    fft.bitrev[0] = 1000;
+    assert_eq!(
+        fft.process(&mut buffer[..8], false),
+        Err(FftError::InvalidFormat)
+    );
+    fft.bitrev[0] = 2;
+    assert!(fft.process(&mut buffer[..8], false).is_err());
+
+    fft.bitrev = &mut [];
+    let (mut re, mut im) = (vec![0.0; 8], vec![0.0; 8]);
+    assert_eq!(
+        fft.process_split(&mut re, &mut im, false),
+        Err(FftError::BufferTooSmall)
+    );
+}
@@ -0,0 +1,128 @@
+use super::fft2d::RealFft2d;
+use super::types::Complex32;
+use crate::common::{FftError, Scratch};
+
+/// How [`RealFft2d::convolve2d`] extends an image past its borders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeMode {
+    /// Zeros outside the image.
+    #[default]
+    Zero,
+    /// The nearest edge pixel (`a a | a b c | c c`).
+    Replicate,
+    /// Mirror image including the edge pixel (`b a | a b c | c b`).
+    Reflect,
+    /// Periodic continuation (`b c | a b c | a b`).
+    Wrap,
+}
+
+impl EdgeMode {
+    /// Maps a possibly out-of-range coordinate onto `0..len`, or `None`
+    /// for a zero.
+    fn index(self, i: isize, len: usize) -> Option<usize> {
+        let n = len as isize;
+        if (0..n).contains(&i) {
+            return Some(i as usize);
+        }
+        match self {
+            EdgeMode::Zero => None,
+            EdgeMode::Replicate => Some(i.clamp(0, n - 1) as usize),
+            EdgeMode::Reflect => {
+                let period = 2 * n;
+                let r = i.rem_euclid(period);
+                Some(if r < n { r } else { period - 1 - r } as usize)
+            }
+            EdgeMode::Wrap => Some(i.rem_euclid(n) as usize),
+        }
+    }
+}
+
+impl RealFft2d<'_, '_> {
+    /// Scratch needed by [`Self::convolve2d`]: the padded image and the
+    /// padded kernel, `nx * ny` samples each.
+    pub fn convolve2d_scratch_len(&self) -> usize {
+        let (nx, ny) = self.dims();
+        2 * nx * ny
+    }
+
+    /// Filters `image` (`width` pixels per row) with `kernel`
+    /// (`kernel_width` taps per row) and writes a result of the image's
+    /// size into `out`.
+    ///
+    /// The kernel is centred on its tap `((kh - 1) / 2, (kw - 1) / 2)`:
+    /// `out[y][x] = sum kernel[i][j] * image[y + ay - i][x + ax - j]`, with
+    /// pixels outside the image supplied by `edge`. This is
+    /// `scipy.signal.convolve2d(image, kernel, mode="same")` for the
+    /// matching boundary.
+    ///
+    /// The plan must cover the padded image, `nx >= width + kw - 1` and
+    /// `ny >= height + kh - 1`. `scratch` holds at least
+    /// `self.convolve2d_scratch_len()` samples and `column` holds `ny`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn convolve2d(
+        &self,
+        image: &[f32],
+        width: usize,
+        kernel: &[f32],
+        kernel_width: usize,
+        out: &mut [f32],
+        edge: EdgeMode,
+        scratch: &mut Scratch<'_, f32>,
+        column: &mut [Complex32],
+    ) -> Result<(), FftError> {
+        let (nx, ny) = self.dims();
+        if width == 0 || image.is_empty() || !image.len().is_multiple_of(width) {
+            return Err(FftError::SizeMismatch);
+        }
+        if kernel_width == 0 || kernel.is_empty() || !kernel.len().is_multiple_of(kernel_width) {
+            return Err(FftError::SizeMismatch);
+        }
+        let height = image.len() / width;
+        let (kw, kh) = (kernel_width, kernel.len() / kernel_width);
+        if out.len() != image.len() || nx < width + kw - 1 || ny < height + kh - 1 {
+            return Err(FftError::SizeMismatch);
+        }
+        let (padded, taps) = scratch
+            .take(self.convolve2d_scratch_len())?
+            .split_at_mut(nx * ny);
+
+        // The padded image starts kw - 1 - ax pixels left of the image, so
+        // output pixel (y, x) is linear convolution sample
+        // (y + kh - 1, x + kw - 1) and never meets the circular wrap.
+        let (ax, ay) = ((kw - 1) / 2, (kh - 1) / 2);
+        let (ox, oy) = ((kw - 1 - ax) as isize, (kh - 1 - ay) as isize);
+        padded.fill(0.0);
+        for py in 0..height + kh - 1 {
+            let Some(sy) = edge.index(py as isize - oy, height) else {
+                continue;
+            };
+            for px in 0..width + kw - 1 {
+                if let Some(sx) = edge.index(px as isize - ox, width) {
+                    padded[py * nx + px] = image[sy * width + sx];
+                }
+            }
+        }
+        taps.fill(0.0);
+        for (dst, src) in taps.chunks_exact_mut(nx).zip(kernel.chunks_exact(kw)) {
+            dst[..kw].copy_from_slice(src);
+        }
+
+        self.process(padded, column, false)?;
+        self.process(taps, column, false)?;
+        self.multiply(padded, taps)?;
+        self.process(padded, column, true)?;
+
+        let norm = self.rows.scaling.correlation_norm(nx) * self.cols.scaling.correlation_norm(ny);
+        for (y, row) in out.chunks_exact_mut(width).enumerate() {
+            let src = &padded[(y + kh - 1) * nx + kw - 1..][..width];
+            for (o, &v) in row.iter_mut().zip(src) {
+                *o = v * norm;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "convolve2d_tests.rs"]
+mod tests;
@@ -0,0 +1,106 @@
+use super::*;
+use crate::common::{CplxFft, RealFft, Scaling, Scratch};
+
+/// Direct evaluation of the documented sum.
+fn reference(image: &[f32], w: usize, kernel: &[f32], kw: usize, edge: EdgeMode) -> Vec<f32> {
+    let h = image.len() / w;
+    let kh = kernel.len() / kw;
+    let (ax, ay) = ((kw - 1) / 2, (kh - 1) / 2);
+    let mut out = vec![0.0; image.len()];
+    for y in 0..h {
+        for x in 0..w {
+            for i in 0..kh {
+                for j in 0..kw {
+                    let sy = edge.index(y as isize + ay as isize - i as isize, h);
+                    let sx = edge.index(x as isize + ax as isize - j as isize, w);
+                    if let (Some(sy), Some(sx)) = (sy, sx) {
+                        out[y * w + x] += kernel[i * kw + j] * image[sy * w + sx];
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn test_matches_direct_convolution() {
+    let (nx, ny) = (16, 8);
+    let (w, kw) = (11, 3);
+    let image: Vec<f32> = (0..w * 5).map(|i| ((i * 13) % 17) as f32 * 0.1).collect();
+    // 2 rows x 3 columns: an even kernel height exercises the anchor
+    let kernel = [0.5, -1.0, 0.25, 2.0, 0.0, -0.75];
+
+    for scaling in [Scaling::Inverse, Scaling::Forward, Scaling::None] {
+        let mut tr = vec![Complex32::new(0.0, 0.0); nx / 2];
+        let mut br = vec![0; nx / 2];
+        let mut tc = vec![Complex32::new(0.0, 0.0); ny / 2];
+        let mut bc = vec![0; ny];
+        let rows = RealFft::<Complex32>::new(&mut tr, &mut br, nx)
+            .unwrap()
+            .with_scaling(scaling);
+        let cols = CplxFft::<Complex32>::new(&mut tc, &mut bc, ny).unwrap();
+        let fft = RealFft2d::new(&rows, &cols);
+        assert_eq!(fft.convolve2d_scratch_len(), 2 * nx * ny);
+        let mut storage = vec![0.0; fft.convolve2d_scratch_len()];
+        let mut scratch = Scratch::new(&mut storage);
+        let mut column = vec![Complex32::new(0.0, 0.0); ny];
+
+        for edge in [
+            EdgeMode::Zero,
+            EdgeMode::Replicate,
+            EdgeMode::Reflect,
+            EdgeMode::Wrap,
+        ] {
+            let mut out = vec![0.0; image.len()];
+            fft.convolve2d(
+                &image,
+                w,
+                &kernel,
+                kw,
+                &mut out,
+                edge,
+                &mut scratch,
+                &mut column,
+            )
+            .unwrap();
+            let expected = reference(&image, w, &kernel, kw, edge);
+            for (a, b) in out.iter().zip(&expected) {
+                assert!((a - b).abs() < 1e-4, "{scaling:?} {edge:?}: {a} vs {b}");
+            }
+        }
+
+        // 15 columns plus the kernel do not fit a 16-point row plan
+        let wide = vec![0.0; 15 * 2];
+        let mut out = vec![0.0; wide.len()];
+        assert_eq!(
+            fft.convolve2d(
+                &wide,
+                14,
+                &kernel,
+                kw,
+                &mut out,
+                EdgeMode::Zero,
+                &mut scratch,
+                &mut column
+            ),
+            Err(FftError::SizeMismatch)
+        );
+
+        let mut out = vec![0.0; image.len()];
+        let mut short = vec![0.0; fft.convolve2d_scratch_len() - 1];
+        assert_eq!(
+            fft.convolve2d(
+                &image,
+                w,
+                &kernel,
+                kw,
+                &mut out,
+                EdgeMode::Zero,
+                &mut Scratch::new(&mut short),
+                &mut column
+            ),
+            Err(FftError::BufferTooSmall)
+        );
+    }
+}
@@ -1,29 +1,34 @@
 // src/float/core.rs
 
-use num_complex::Complex32;
+use super::types::Complex32;
+use crate::common::Algorithm;
 use core::f32::consts::PI;
 
-#[cfg(feature = "std")]
-use std::f32;
 #[cfg(not(feature = "std"))]
 use libm::Libm;
+#[cfg(feature = "std")]
+use std::f32;
 
 // --- Public Auxiliary Functions for the Module (pub(crate)) ---
 
 /// Computes the rotation factors (Twiddle Factors) for an FFT of size N.
 pub(crate) fn precompute_twiddles(twiddles: &mut [Complex32], n: usize) {
     // Only N/2 factors are generated, which is sufficient for Radix-2
-    for j in 0..(n / 2) {
+    for (j, twiddle) in twiddles[..n / 2].iter_mut().enumerate() {
         let angle = -2.0 * PI * (j as f32) / (n as f32);
         let (sin, cos) = sin_cos(angle);
-        twiddles[j] = Complex32::new(cos, sin);
+        *twiddle = Complex32::new(cos, sin);
     }
 }
 
-/// Fills the bit-reversal table.
-pub(crate) fn precompute_bitrev(bitrev: &mut [usize], n: usize) {
-    bitrev[0] = 0;
+/// Fills `swaps` with the index pairs exchanged by the bit-reversal
+/// permutation, flattened as `[i0, j0, i1, j1, ...]` with `i < j`.
+///
+/// Fixed points are omitted, so only the first `swap_table_len(n)` entries
+/// are written and the permutation pass becomes a branch-free list of swaps.
+pub(crate) fn precompute_swaps(swaps: &mut [usize], n: usize) {
     let mut j = 0;
+    let mut len = 0;
     for i in 1..n {
         let mut k = n >> 1;
         while j >= k {
@@ -31,35 +36,422 @@ pub(crate) fn precompute_bitrev(bitrev: &mut [usize], n: usize) {
             k >>= 1;
         }
         j += k;
-        bitrev[i] = j;
+        if i < j {
+            swaps[len] = i;
+            swaps[len + 1] = j;
+            len += 2;
+        }
     }
 }
 
+/// Number of entries (twice the number of pairs) in the swap list for size N.
+///
+/// Of the N indices, the 2^ceil(log2(N)/2) bit palindromes stay in place and
+/// the rest are exchanged in pairs.
+#[inline]
+pub(crate) fn swap_table_len(n: usize) -> usize {
+    n - (1 << n.trailing_zeros().div_ceil(2))
+}
+
 /// Agnostic helper function for sin/cos
+///
+/// The pure-Rust libm gives the same bits on every target; the platform
+/// math library behind `std` may not, so `deterministic` opts out of it.
 fn sin_cos(angle: f32) -> (f32, f32) {
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(feature = "deterministic")))]
     return (angle.sin(), angle.cos());
-    
-    #[cfg(not(feature = "std"))]
+
+    #[cfg(any(not(feature = "std"), feature = "deterministic"))]
     return (libm::sinf(angle), libm::cosf(angle));
 }
 
+/// Whether [`dot`] fuses its first product on this build.
+pub(crate) const FUSED: bool = cfg!(all(
+    feature = "std",
+    not(feature = "deterministic"),
+    any(target_feature = "fma", target_arch = "aarch64")
+));
+
+/// `a * b + c * d` for the butterflies.
+///
+/// With hardware FMA the first product is fused, saving an instruction and
+/// a rounding. It stays unfused without it (a software `fmaf` would be far
+/// slower) and under `deterministic`, since fused and unfused results
+/// differ in the last bit.
+#[inline(always)]
+pub(crate) fn dot(a: f32, b: f32, c: f32, d: f32) -> f32 {
+    #[cfg(all(
+        feature = "std",
+        not(feature = "deterministic"),
+        any(target_feature = "fma", target_arch = "aarch64")
+    ))]
+    return a.mul_add(b, c * d);
+
+    #[cfg(not(all(
+        feature = "std",
+        not(feature = "deterministic"),
+        any(target_feature = "fma", target_arch = "aarch64")
+    )))]
+    return a * b + c * d;
+}
+
+/// Complex product `a * w` through [`dot`].
+#[inline(always)]
+pub(crate) fn cmul(a: Complex32, w: Complex32) -> Complex32 {
+    Complex32::new(dot(a.re, w.re, -a.im, w.im), dot(a.re, w.im, a.im, w.re))
+}
+
 /// This function is the direct equivalent of `radix_2_dit_fft` from your C code.
 /// It is not pub(crate) for the end user, only for internal use by the real and complex modules.
-pub(crate) fn radix_2_dit_fft_core<const INVERSE: bool>(
-    buffer: &mut [Complex32], 
-    twiddles: &[Complex32], 
+///
+/// `SCALE` applies a 1/2 factor per stage, i.e. 1/N overall.
+pub(crate) fn radix_2_dit_fft_core<const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [Complex32],
+    twiddles: &[Complex32],
     bitrev: &[usize],
-    twiddle_stride: usize
+    twiddle_stride: usize,
 ) {
+    if buffer.len() >= 16 {
+        // 1. Bit-reverse fused with the first two butterfly stages
+        dit_permute_first_stages::<INVERSE, SCALE>(buffer);
+
+        // 2. Remaining butterflies
+        dit_butterflies::<INVERSE, SCALE>(buffer, twiddles, twiddle_stride, 4);
+    } else {
+        // 1. Bit-reverse
+        bit_reverse_permute(buffer, bitrev);
+
+        // 2. Butterfly
+        dit_butterflies::<INVERSE, SCALE>(buffer, twiddles, twiddle_stride, 1);
+    }
+}
+
+/// Bit-reversal permutation fused with the first two DIT stages (N >= 16).
+///
+/// Writing an index as `(a: 2 bits)(u: m - 4 bits)(b: 2 bits)`, bit reversal
+/// maps the 16 elements `a * N/4 + 4u + b` onto the 16 elements of `rev(u)`.
+/// Each pair of such blocks is loaded once and written back already through
+/// the stage 1 and 2 butterflies, whose twiddles are only 1 and -i (+i for the
+/// inverse), saving a full sweep over the buffer.
+fn dit_permute_first_stages<const INVERSE: bool, const SCALE: bool>(buffer: &mut [Complex32]) {
     let n = buffer.len();
+    let quarter = n >> 2;
+    let mid_bits = n.trailing_zeros() - 4;
 
-    // 1. Bit-reverse
-    for i in 1..(n - 1) {
-        let j = bitrev[i];
-        if i < j {
-            buffer.swap(i, j);
+    for u in 0..n >> 4 {
+        let v = if mid_bits == 0 {
+            0
+        } else {
+            u.reverse_bits() >> (usize::BITS - mid_bits)
+        };
+        if v < u {
+            continue;
+        }
+
+        let block_u = load_block(buffer, u, quarter);
+        if v == u {
+            store_block::<INVERSE, SCALE>(buffer, u, quarter, &block_u);
+        } else {
+            let block_v = load_block(buffer, v, quarter);
+            store_block::<INVERSE, SCALE>(buffer, v, quarter, &block_u);
+            store_block::<INVERSE, SCALE>(buffer, u, quarter, &block_v);
+        }
+    }
+}
+
+/// Loads the 16 elements `a * quarter + 4u + b` as `block[4a + b]`.
+#[inline]
+fn load_block(buffer: &[Complex32], u: usize, quarter: usize) -> [Complex32; 16] {
+    let mut block = [Complex32::default(); 16];
+    for (a, row) in block.chunks_exact_mut(4).enumerate() {
+        let base = a * quarter + 4 * u;
+        row.copy_from_slice(&buffer[base..base + 4]);
+    }
+    block
+}
+
+/// Writes block `v` from the bit-reversed source block, applying the
+/// stage 1 and 2 butterflies to each group of 4 on the way.
+#[inline]
+fn store_block<const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [Complex32],
+    v: usize,
+    quarter: usize,
+    src: &[Complex32; 16],
+) {
+    // 2-bit reversal
+    const REV2: [usize; 4] = [0, 2, 1, 3];
+
+    for (a, &r) in REV2.iter().enumerate() {
+        // Position a * quarter + 4v + b holds source element rev2(b) * 4 + rev2(a)
+        let y0 = src[r];
+        let y1 = src[8 + r];
+        let y2 = src[4 + r];
+        let y3 = src[12 + r];
+
+        // Stage 1: stride 1, twiddle 1
+        let (mut s0, mut s1) = (y0 + y1, y0 - y1);
+        let (mut s2, mut s3) = (y2 + y3, y2 - y3);
+        if SCALE {
+            s0 = s0.scale(0.5);
+            s1 = s1.scale(0.5);
+            s2 = s2.scale(0.5);
+            s3 = s3.scale(0.5);
+        }
+
+        // Stage 2: stride 2, twiddles 1 and -i (+i for the inverse)
+        let t = if INVERSE {
+            Complex32::new(-s3.im, s3.re)
+        } else {
+            Complex32::new(s3.im, -s3.re)
+        };
+        let mut out = [s0 + s2, s1 + t, s0 - s2, s1 - t];
+        if SCALE {
+            for o in out.iter_mut() {
+                *o = o.scale(0.5);
+            }
+        }
+
+        let base = a * quarter + 4 * v;
+        buffer[base..base + 4].copy_from_slice(&out);
+    }
+}
+
+/// In-place bit-reversal permutation of `buffer` using the precomputed swap list.
+pub(crate) fn bit_reverse_permute(buffer: &mut [Complex32], swaps: &[usize]) {
+    for pair in swaps[..swap_table_len(buffer.len())].chunks_exact(2) {
+        buffer.swap(pair[0], pair[1]);
+    }
+}
+
+/// DIT butterfly stages (growing stride) on bit-reversed input, producing
+/// naturally ordered output. Stages below `first_stride` are assumed done.
+fn dit_butterflies<const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [Complex32],
+    twiddles: &[Complex32],
+    twiddle_stride: usize,
+    first_stride: usize,
+) {
+    let n = buffer.len();
+    let mut stride = first_stride;
+    let mut tw_index = (n >> 1) / first_stride;
+
+    while stride < n {
+        dit_stage::<INVERSE, SCALE>(buffer, twiddles, tw_index * twiddle_stride, stride);
+        stride <<= 1;
+        tw_index >>= 1;
+    }
+}
+
+/// One DIT stage: butterflies `stride` apart, in groups of `2 * stride`,
+/// with every `step`-th twiddle.
+#[inline(always)]
+fn dit_stage<const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [Complex32],
+    twiddles: &[Complex32],
+    step: usize,
+    stride: usize,
+) {
+    // Each twiddle is loaded once and applied across every group. Groups
+    // are split into their two halves, so `i < stride` indexes both in range
+    let factors = twiddles.chunks_exact(step).map(|c| c[0]);
+    for (i, w) in (0..stride).zip(factors) {
+        // The compiler will completely remove this IF because INVERSE is a compile-time constant
+        let w = if INVERSE { w.conj() } else { w };
+        for group in buffer.chunks_exact_mut(stride << 1) {
+            let (lo, hi) = group.split_at_mut(stride);
+            let (a, b) = (&mut lo[i], &mut hi[i]);
+            let t = cmul(*b, w);
+
+            let mut v1 = *a + t;
+            let mut v2 = *a - t;
+
+            // Stage normalization to avoid saturation (fixed-point behavior)
+            // The compiler will optimize this for SCALE = true/false
+            if SCALE {
+                v1 = v1.scale(0.5);
+                v2 = v2.scale(0.5);
+            }
+
+            *a = v1;
+            *b = v2;
+        }
+    }
+}
+
+/// Runtime dispatcher selecting the monomorphized core for the given direction and scaling.
+pub(crate) fn radix_2_dit_fft(
+    buffer: &mut [Complex32],
+    twiddles: &[Complex32],
+    bitrev: &[usize],
+    twiddle_stride: usize,
+    inverse: bool,
+    scale: bool,
+) {
+    match (inverse, scale) {
+        (false, false) => {
+            radix_2_dit_fft_core::<false, false>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (false, true) => {
+            radix_2_dit_fft_core::<false, true>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (true, false) => {
+            radix_2_dit_fft_core::<true, false>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (true, true) => {
+            radix_2_dit_fft_core::<true, true>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+    }
+}
+
+/// Radix-2 Decimation-in-Frequency core.
+///
+/// Runs the butterflies on naturally ordered input, from the widest stride
+/// down to 1, which leaves the result in bit-reversed order; the final pass
+/// restores natural order so the output matches `radix_2_dit_fft_core`.
+///
+/// `SCALE` applies a 1/2 factor per stage, i.e. 1/N overall.
+pub(crate) fn radix_2_dif_fft_core<const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [Complex32],
+    twiddles: &[Complex32],
+    bitrev: &[usize],
+    twiddle_stride: usize,
+) {
+    // 1. Butterfly
+    dif_butterflies::<INVERSE, SCALE>(buffer, twiddles, twiddle_stride);
+
+    // 2. Bit-reverse
+    bit_reverse_permute(buffer, bitrev);
+}
+
+/// DIF butterfly stages (shrinking stride) on naturally ordered input,
+/// producing bit-reversed output.
+fn dif_butterflies<const INVERSE: bool, const SCALE: bool>(
+    buffer: &mut [Complex32],
+    twiddles: &[Complex32],
+    twiddle_stride: usize,
+) {
+    let n = buffer.len();
+    let mut stride = n >> 1;
+    let mut tw_index = 1;
+
+    while stride > 0 {
+        let step = tw_index * twiddle_stride;
+        let factors = twiddles.chunks_exact(step).map(|c| c[0]);
+        for (i, w) in (0..stride).zip(factors) {
+            let w = if INVERSE { w.conj() } else { w };
+            for group in buffer.chunks_exact_mut(stride << 1) {
+                let (lo, hi) = group.split_at_mut(stride);
+                let (a, b) = (&mut lo[i], &mut hi[i]);
+
+                let mut v1 = *a + *b;
+                let mut v2 = *a - *b;
+
+                if SCALE {
+                    v1 = v1.scale(0.5);
+                    v2 = v2.scale(0.5);
+                }
+
+                *a = v1;
+                *b = cmul(v2, w);
+            }
+        }
+        stride >>= 1;
+        tw_index <<= 1;
+    }
+}
+
+/// Runtime dispatcher for the DIF core.
+pub(crate) fn radix_2_dif_fft(
+    buffer: &mut [Complex32],
+    twiddles: &[Complex32],
+    bitrev: &[usize],
+    twiddle_stride: usize,
+    inverse: bool,
+    scale: bool,
+) {
+    match (inverse, scale) {
+        (false, false) => {
+            radix_2_dif_fft_core::<false, false>(buffer, twiddles, bitrev, twiddle_stride)
         }
+        (false, true) => {
+            radix_2_dif_fft_core::<false, true>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (true, false) => {
+            radix_2_dif_fft_core::<true, false>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+        (true, true) => {
+            radix_2_dif_fft_core::<true, true>(buffer, twiddles, bitrev, twiddle_stride)
+        }
+    }
+}
+
+/// Runs the core selected by the plan's `Algorithm`.
+pub(crate) fn radix_2_fft(
+    buffer: &mut [Complex32],
+    twiddles: &[Complex32],
+    bitrev: &[usize],
+    twiddle_stride: usize,
+    algorithm: Algorithm,
+    inverse: bool,
+    scale: bool,
+) {
+    match algorithm {
+        Algorithm::Dit => radix_2_dit_fft(buffer, twiddles, bitrev, twiddle_stride, inverse, scale),
+        Algorithm::Dif => radix_2_dif_fft(buffer, twiddles, bitrev, twiddle_stride, inverse, scale),
+    }
+}
+
+/// Runs the butterflies without any permutation: the forward direction uses
+/// DIF (natural in, bit-reversed out) and the inverse uses DIT (bit-reversed
+/// in, natural out), so a forward/inverse pair still round-trips.
+pub(crate) fn radix_2_fft_unordered(
+    buffer: &mut [Complex32],
+    twiddles: &[Complex32],
+    inverse: bool,
+    scale: bool,
+) {
+    match (inverse, scale) {
+        (false, false) => dif_butterflies::<false, false>(buffer, twiddles, 1),
+        (false, true) => dif_butterflies::<false, true>(buffer, twiddles, 1),
+        (true, false) => dit_butterflies::<true, false>(buffer, twiddles, 1, 1),
+        (true, true) => dit_butterflies::<true, true>(buffer, twiddles, 1, 1),
+    }
+}
+
+/// Runs the single DIT stage of the given `stride` on bit-reversed data,
+/// for callers that interleave other work between stages.
+pub(crate) fn radix_2_dit_stage(
+    buffer: &mut [Complex32],
+    twiddles: &[Complex32],
+    stride: usize,
+    inverse: bool,
+    scale: bool,
+) {
+    let step = (buffer.len() >> 1) / stride;
+    match (inverse, scale) {
+        (false, false) => dit_stage::<false, false>(buffer, twiddles, step, stride),
+        (false, true) => dit_stage::<false, true>(buffer, twiddles, step, stride),
+        (true, false) => dit_stage::<true, false>(buffer, twiddles, step, stride),
+        (true, true) => dit_stage::<true, true>(buffer, twiddles, step, stride),
+    }
+}
+
+/// Planar variant of `radix_2_dit_fft_core`: real and imaginary parts live in
+/// separate slices, so callers with split buffers avoid an interleaving pass.
+pub(crate) fn radix_2_dit_fft_split_core<const INVERSE: bool, const SCALE: bool>(
+    re: &mut [f32],
+    im: &mut [f32],
+    twiddles: &[Complex32],
+    bitrev: &[usize],
+    twiddle_stride: usize,
+) {
+    let n = re.len();
+
+    // 1. Bit-reverse
+    for pair in bitrev[..swap_table_len(n)].chunks_exact(2) {
+        re.swap(pair[0], pair[1]);
+        im.swap(pair[0], pair[1]);
     }
 
     // 2. Butterfly
@@ -67,34 +459,37 @@ pub(crate) fn radix_2_dit_fft_core<const INVERSE: bool>(
     let mut tw_index = n >> 1;
 
     while stride < n {
-        let jmax = n - stride;
-        
-        for j in (0..jmax).step_by(stride << 1) {
-            for i in 0..stride {
-                let mut w = twiddles[i * tw_index * twiddle_stride];
-
-                // The compiler will completely remove this IF because INVERSE is a compile-time constant
-                if INVERSE {
-                    w = w.conj();
-                }
+        let step = tw_index * twiddle_stride;
+        let factors = twiddles.chunks_exact(step).map(|c| c[0]);
+        for (i, w) in (0..stride).zip(factors) {
+            let w = if INVERSE { w.conj() } else { w };
+            let groups = re
+                .chunks_exact_mut(stride << 1)
+                .zip(im.chunks_exact_mut(stride << 1));
+            for (re_group, im_group) in groups {
+                let (re_lo, re_hi) = re_group.split_at_mut(stride);
+                let (im_lo, im_hi) = im_group.split_at_mut(stride);
+                let (a_re, a_im) = (&mut re_lo[i], &mut im_lo[i]);
+                let (b_re, b_im) = (&mut re_hi[i], &mut im_hi[i]);
 
-                let index = j + i;
-                let a = buffer[index];
-                let b = buffer[index + stride];
-                let t = b * w;
+                // t = b * w
+                let t_re = dot(*b_re, w.re, -*b_im, w.im);
+                let t_im = dot(*b_re, w.im, *b_im, w.re);
 
-                let mut v1 = a + t;
-                let mut v2 = a - t;
+                let (mut v1_re, mut v1_im) = (*a_re + t_re, *a_im + t_im);
+                let (mut v2_re, mut v2_im) = (*a_re - t_re, *a_im - t_im);
 
-                // Stage normalization to avoid saturation (fixed-point behavior)
-                // The compiler will optimize this for INVERSE = true/false
-                if INVERSE {
-                    v1 = v1.scale(0.5);
-                    v2 = v2.scale(0.5);
+                if SCALE {
+                    v1_re *= 0.5;
+                    v1_im *= 0.5;
+                    v2_re *= 0.5;
+                    v2_im *= 0.5;
                 }
 
-                buffer[index] = v1;
-                buffer[index + stride] = v2;
+                *a_re = v1_re;
+                *a_im = v1_im;
+                *b_re = v2_re;
+                *b_im = v2_im;
             }
         }
         stride <<= 1;
@@ -102,6 +497,23 @@ pub(crate) fn radix_2_dit_fft_core<const INVERSE: bool>(
     }
 }
 
+/// Runtime dispatcher for the planar core.
+pub(crate) fn radix_2_dit_fft_split(
+    re: &mut [f32],
+    im: &mut [f32],
+    twiddles: &[Complex32],
+    bitrev: &[usize],
+    inverse: bool,
+    scale: bool,
+) {
+    match (inverse, scale) {
+        (false, false) => radix_2_dit_fft_split_core::<false, false>(re, im, twiddles, bitrev, 1),
+        (false, true) => radix_2_dit_fft_split_core::<false, true>(re, im, twiddles, bitrev, 1),
+        (true, false) => radix_2_dit_fft_split_core::<true, false>(re, im, twiddles, bitrev, 1),
+        (true, true) => radix_2_dit_fft_split_core::<true, true>(re, im, twiddles, bitrev, 1),
+    }
+}
+
 #[cfg(test)]
 #[path = "core_tests.rs"]
-mod tests;
\ No newline at end of file
+mod tests;
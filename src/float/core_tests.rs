@@ -1,5 +1,5 @@
 use super::*;
-use num_complex::Complex32;
+use crate::float::types::Complex32;
 use core::f32::consts::PI;
 
 const EPSILON: f32 = 1e-4;
@@ -19,12 +19,12 @@ fn assert_cplx_eq(a: Complex32, b: Complex32) {
 }
 
 #[test]
-fn test_precompute_bitrev_8() {
+fn test_precompute_swaps_8() {
     let n = 8;
-    let mut bitrev = vec![0; n];
-    precompute_bitrev(&mut bitrev, n);
+    let mut swaps = vec![0; n];
+    precompute_swaps(&mut swaps, n);
 
-    // Expected bit reversal for N=8:
+    // Bit reversal for N=8:
     // 0 (000) -> 0 (000)
     // 1 (001) -> 4 (100)
     // 2 (010) -> 2 (010)
@@ -33,8 +33,25 @@ fn test_precompute_bitrev_8() {
     // 5 (101) -> 5 (101)
     // 6 (110) -> 3 (011)
     // 7 (111) -> 7 (111)
-    let expected = vec![0, 4, 2, 6, 1, 5, 3, 7];
-    assert_eq!(bitrev, expected);
+    // Only the pairs (1, 4) and (3, 6) need a swap
+    assert_eq!(swap_table_len(n), 4);
+    assert_eq!(&swaps[..4], &[1, 4, 3, 6]);
+}
+
+#[test]
+fn test_swap_table_len() {
+    for n in [1usize, 2, 4, 8, 16, 32, 64, 1024] {
+        let bits = n.trailing_zeros();
+        let moved = (0..n)
+            .filter(|&i| {
+                i.reverse_bits()
+                    .checked_shr(usize::BITS - bits)
+                    .unwrap_or(0)
+                    != i
+            })
+            .count();
+        assert_eq!(swap_table_len(n), moved, "n = {}", n);
+    }
 }
 
 #[test]
@@ -70,11 +87,11 @@ fn test_radix_2_dit_fft_core_basic() {
     let mut twiddles = vec![Complex32::default(); n/2];
     let mut bitrev = vec![0; n];
     
-    precompute_bitrev(&mut bitrev, n);
+    precompute_swaps(&mut bitrev, n);
     precompute_twiddles(&mut twiddles, n);
 
     // Run Forward FFT
-    radix_2_dit_fft_core::<false>(&mut buffer, &twiddles, &bitrev, 1);
+    radix_2_dit_fft_core::<false, false>(&mut buffer, &twiddles, &bitrev, 1);
 
     // Expected: [4, 0, 0, 0]
     assert_cplx_eq(buffer[0], Complex32::new(4.0, 0.0));
@@ -83,7 +100,7 @@ fn test_radix_2_dit_fft_core_basic() {
     assert_cplx_eq(buffer[3], Complex32::new(0.0, 0.0));
     
     // Run Inverse FFT
-    radix_2_dit_fft_core::<true>(&mut buffer, &twiddles, &bitrev, 1);
+    radix_2_dit_fft_core::<true, true>(&mut buffer, &twiddles, &bitrev, 1);
 
     // Expected: [1, 1, 1, 1] 
     for sample in buffer {
@@ -98,4 +115,47 @@ fn test_sin_cos() {
     let sqrt2_2 = (2.0f32).sqrt() / 2.0;
     assert_feq(s, sqrt2_2);
     assert_feq(c, sqrt2_2);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_fused_first_stages() {
+    for n in [16usize, 32, 64, 128] {
+        let input: Vec<Complex32> = (0..n)
+            .map(|i| Complex32::new((i as f32 * 0.37).sin(), (i as f32 * 0.11).cos()))
+            .collect();
+
+        let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+        precompute_twiddles(&mut twiddles, n);
+        let mut swaps = vec![0; n];
+        precompute_swaps(&mut swaps, n);
+
+        for inverse in [false, true] {
+            let mut fused = input.clone();
+            let mut reference = input.clone();
+            bit_reverse_permute(&mut reference, &swaps);
+            if inverse {
+                radix_2_dit_fft_core::<true, true>(&mut fused, &twiddles, &swaps, 1);
+                dit_butterflies::<true, true>(&mut reference, &twiddles, 1, 1);
+            } else {
+                radix_2_dit_fft_core::<false, false>(&mut fused, &twiddles, &swaps, 1);
+                dit_butterflies::<false, false>(&mut reference, &twiddles, 1, 1);
+            }
+            for (&a, &b) in fused.iter().zip(reference.iter()) {
+                assert_cplx_eq(a, b);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "deterministic")]
+#[test]
+fn test_deterministic_twiddles_come_from_libm() {
+    let n = 64;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    precompute_twiddles(&mut twiddles, n);
+    for (j, t) in twiddles.iter().enumerate() {
+        let angle = -2.0 * PI * (j as f32) / (n as f32);
+        assert_eq!(t.re.to_bits(), libm::cosf(angle).to_bits());
+        assert_eq!(t.im.to_bits(), libm::sinf(angle).to_bits());
+    }
+}
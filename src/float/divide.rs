@@ -0,0 +1,36 @@
+use crate::common::FftError;
+
+/// Regularized division of two packed real spectra, in place:
+/// `num <- num * conj(den) / (|den|^2 + epsilon)`.
+///
+/// Both slices use the packed layout of `RealFft::process` (DC and Nyquist
+/// in the first two slots, then interleaved bins), so the two real slots
+/// are divided as reals. An `epsilon` of 0 is plain division and turns
+/// zero bins of `den` into NaN; a small positive value (Tikhonov
+/// regularization) caps the gain at `1 / (2 sqrt(epsilon))` so
+/// near-empty bins of `den` do not blow up the noise in `num`. It is in
+/// the units of `|den|^2`, so scale it to the peak of `den` when the
+/// level is not known in advance.
+pub fn spectral_divide(num: &mut [f32], den: &[f32], epsilon: f32) -> Result<(), FftError> {
+    if num.len() != den.len() || num.len() < 2 || !num.len().is_multiple_of(2) {
+        return Err(FftError::SizeMismatch);
+    }
+    if epsilon.is_nan() || epsilon < 0.0 {
+        return Err(FftError::OutOfRange);
+    }
+
+    for (y, &x) in num[..2].iter_mut().zip(den[..2].iter()) {
+        *y *= x / (x * x + epsilon);
+    }
+    for (y, x) in num[2..].chunks_exact_mut(2).zip(den[2..].chunks_exact(2)) {
+        let (yr, yi, xr, xi) = (y[0], y[1], x[0], x[1]);
+        let g = 1.0 / (xr * xr + xi * xi + epsilon);
+        y[0] = (yr * xr + yi * xi) * g;
+        y[1] = (yi * xr - yr * xi) * g;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "divide_tests.rs"]
+mod tests;
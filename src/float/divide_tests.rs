@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn test_divide_inverts_multiplication() {
+    // Packed: DC, Nyquist, then (re, im) pairs
+    let den = [2.0f32, -4.0, 1.0, 1.0, 0.0, 3.0];
+    let mut num = [6.0f32, 2.0, 1.0, 3.0, -6.0, 3.0];
+    spectral_divide(&mut num, &den, 0.0).unwrap();
+    // (1 + 3i) / (1 + i) = 2 + i, (-6 + 3i) / 3i = 1 + 2i
+    let expected = [3.0f32, -0.5, 2.0, 1.0, 1.0, 2.0];
+    for (a, b) in num.iter().zip(&expected) {
+        assert!((a - b).abs() < 1e-6, "{a} vs {b}");
+    }
+}
+
+#[test]
+fn test_epsilon_bounds_empty_bins() {
+    let den = [1.0f32, 0.0, 1e-6, 0.0];
+    let mut num = [1.0f32, 1.0, 1.0, 1.0];
+    spectral_divide(&mut num, &den, 1e-4).unwrap();
+    assert!(num.iter().all(|v| v.is_finite()));
+    // Bins well above epsilon are nearly untouched
+    assert!((num[0] - 1.0).abs() < 1e-3);
+    // Empty and near-empty bins stay below the 1 / (2 sqrt(eps)) cap
+    assert_eq!(num[1], 0.0);
+    assert!(num[2].abs() <= 50.0 && num[3].abs() <= 50.0);
+
+    assert_eq!(
+        spectral_divide(&mut num, &den, -1.0),
+        Err(FftError::OutOfRange)
+    );
+    assert_eq!(
+        spectral_divide(&mut num[..3], &den[..3], 0.0),
+        Err(FftError::SizeMismatch)
+    );
+}
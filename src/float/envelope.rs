@@ -0,0 +1,86 @@
+use super::core::radix_2_fft;
+use super::types::Complex32;
+use crate::common::{CplxFft, FftError, band_bins};
+
+impl<'a> CplxFft<'a, Complex32> {
+    /// Envelope spectrum of `signal` (N samples) in the band
+    /// `band_hz = (f_low, f_high)`, the usual bearing-fault analysis.
+    ///
+    /// The band is selected and made analytic in one pass (negative and
+    /// out-of-band bins zeroed, the rest doubled), and the magnitude of the
+    /// inverse transform is the envelope of the band-passed signal. Its mean
+    /// is removed and its single-sided amplitude spectrum, bins `0..=N/2`,
+    /// written to `out`: a resonance amplitude-modulated at a fault rate
+    /// shows a line at that rate. Pick a band around the structural
+    /// resonance the impacts excite.
+    ///
+    /// `scratch` holds N complex samples; the plan's `hygiene` applies to the
+    /// copy of `signal` made there. The result does not depend on
+    /// `self.scaling`.
+    pub fn envelope_spectrum(
+        &self,
+        signal: &[f32],
+        band_hz: (f32, f32),
+        sample_rate: f32,
+        scratch: &mut [Complex32],
+        out: &mut [f32],
+    ) -> Result<(), FftError> {
+        self.validate()?;
+        let n = self.n;
+        if signal.len() != n || out.len() != n / 2 + 1 {
+            return Err(FftError::SizeMismatch);
+        }
+        let (lo, hi) = band_bins(n, band_hz, sample_rate)?;
+        let buffer = scratch.get_mut(..n).ok_or(FftError::BufferTooSmall)?;
+
+        for (b, &x) in buffer.iter_mut().zip(signal) {
+            *b = Complex32::new(x, 0.0);
+        }
+        super::hygiene::check_complex(self.hygiene, buffer)?;
+        // Scaled forward and unscaled inverse, whatever the plan says
+        self.transform(buffer, false, true);
+        for (k, b) in buffer.iter_mut().enumerate() {
+            *b = if (lo..=hi).contains(&k) {
+                b.scale(2.0)
+            } else {
+                Complex32::new(0.0, 0.0)
+            };
+        }
+        self.transform(buffer, true, false);
+
+        let mut mean = 0.0;
+        for b in buffer.iter_mut() {
+            let envelope = libm::sqrtf(b.norm_sqr());
+            mean += envelope;
+            *b = Complex32::new(envelope, 0.0);
+        }
+        mean /= n as f32;
+        for b in buffer.iter_mut() {
+            b.re -= mean;
+        }
+        self.transform(buffer, false, true);
+
+        out[0] = libm::sqrtf(buffer[0].norm_sqr());
+        out[n / 2] = libm::sqrtf(buffer[n / 2].norm_sqr());
+        for (o, b) in out[1..n / 2].iter_mut().zip(&buffer[1..n / 2]) {
+            *o = 2.0 * libm::sqrtf(b.norm_sqr());
+        }
+        Ok(())
+    }
+
+    fn transform(&self, buffer: &mut [Complex32], inverse: bool, scale: bool) {
+        radix_2_fft(
+            buffer,
+            self.twiddles,
+            self.bitrev,
+            1,
+            self.algorithm,
+            inverse,
+            scale,
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "envelope_tests.rs"]
+mod tests;
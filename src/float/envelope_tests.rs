@@ -0,0 +1,55 @@
+use super::*;
+use core::f32::consts::PI;
+
+const N: usize = 1024;
+const FS: f32 = 8000.0;
+
+/// A 1 kHz resonance modulated at 62.5 Hz (bin 8), plus a strong 93.75 Hz
+/// tone (bin 12) outside the band.
+fn bearing_signal() -> Vec<f32> {
+    (0..N)
+        .map(|i| {
+            let t = i as f32 / FS;
+            let am = 1.0 + 0.5 * (2.0 * PI * 62.5 * t).cos();
+            am * (2.0 * PI * 1000.0 * t).cos() + 2.0 * (2.0 * PI * 93.75 * t).sin()
+        })
+        .collect()
+}
+
+#[test]
+fn test_modulation_shows_in_envelope_spectrum() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    let signal = bearing_signal();
+    let mut scratch = vec![Complex32::new(0.0, 0.0); N];
+    let mut out = vec![0.0f32; N / 2 + 1];
+    fft.envelope_spectrum(&signal, (800.0, 1200.0), FS, &mut scratch, &mut out)
+        .unwrap();
+
+    assert!((out[8] - 0.5).abs() < 1e-3, "{}", out[8]);
+    // The mean is gone, and so is the out-of-band tone
+    assert!(out[0] < 1e-3);
+    let others = out
+        .iter()
+        .enumerate()
+        .filter(|&(k, _)| k != 8)
+        .fold(0.0f32, |m, (_, &v)| m.max(v));
+    assert!(others < 1e-3, "{others}");
+
+    assert_eq!(
+        fft.envelope_spectrum(&signal, (5000.0, 6000.0), FS, &mut scratch, &mut out),
+        Err(FftError::OutOfRange)
+    );
+    assert_eq!(
+        fft.envelope_spectrum(
+            &signal,
+            (800.0, 1200.0),
+            FS,
+            &mut scratch[..N / 2],
+            &mut out
+        ),
+        Err(FftError::BufferTooSmall)
+    );
+}
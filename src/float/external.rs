@@ -0,0 +1,197 @@
+use super::types::Complex32;
+use crate::common::{CplxFft, FftError};
+use crate::transpose::transpose_in_place;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+/// Random-access storage of complex samples that may not fit in memory.
+///
+/// Offsets and lengths count samples, not bytes.
+pub trait BlockStorage {
+    fn read_block(&mut self, offset: usize, buf: &mut [Complex32]) -> io::Result<()>;
+    fn write_block(&mut self, offset: usize, buf: &[Complex32]) -> io::Result<()>;
+}
+
+impl BlockStorage for [Complex32] {
+    fn read_block(&mut self, offset: usize, buf: &mut [Complex32]) -> io::Result<()> {
+        let src = self
+            .get(offset..offset + buf.len())
+            .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+        buf.copy_from_slice(src);
+        Ok(())
+    }
+
+    fn write_block(&mut self, offset: usize, buf: &[Complex32]) -> io::Result<()> {
+        let dst = self
+            .get_mut(offset..offset + buf.len())
+            .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+        dst.copy_from_slice(buf);
+        Ok(())
+    }
+}
+
+/// [`BlockStorage`] over a byte stream (a file, a memory map wrapped in a
+/// cursor, ...) holding samples as little-endian `f32` pairs.
+pub struct StreamStorage<S>(pub S);
+
+const SAMPLE_BYTES: usize = 8;
+
+impl<S: Read + Write + Seek> BlockStorage for StreamStorage<S> {
+    fn read_block(&mut self, offset: usize, buf: &mut [Complex32]) -> io::Result<()> {
+        self.0
+            .seek(SeekFrom::Start((offset * SAMPLE_BYTES) as u64))?;
+        let mut bytes = [0u8; SAMPLE_BYTES];
+        for c in buf.iter_mut() {
+            self.0.read_exact(&mut bytes)?;
+            let (re, im) = bytes.split_at(4);
+            *c = Complex32::new(
+                f32::from_le_bytes(re.try_into().unwrap()),
+                f32::from_le_bytes(im.try_into().unwrap()),
+            );
+        }
+        Ok(())
+    }
+
+    fn write_block(&mut self, offset: usize, buf: &[Complex32]) -> io::Result<()> {
+        self.0
+            .seek(SeekFrom::Start((offset * SAMPLE_BYTES) as u64))?;
+        for c in buf {
+            self.0.write_all(&c.re.to_le_bytes())?;
+            self.0.write_all(&c.im.to_le_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+/// Four-step FFT of `N = N1 * N2` samples kept in a [`BlockStorage`], for
+/// transforms larger than the available memory.
+///
+/// The storage is viewed as `N2` rows of `N1` samples (sample `n` at row
+/// `n / N1`, column `n % N1`). The forward transform runs length-`N2`
+/// FFTs down the columns, a slab of columns per pass, applies the twiddles
+/// `W_N^(n1 k2)` and runs length-`N1` FFTs along the rows. Only `work` is
+/// held in memory, and every storage access is a contiguous run.
+///
+/// The spectrum is left transposed: bin `k` is stored at
+/// [`Self::position`]`(k)`. The inverse takes that layout and restores the
+/// natural order, so a forward / pointwise / inverse chain never needs the
+/// transpose. Each step follows the scaling of its own plan.
+pub struct FourStep<'p, 'a> {
+    /// Length-`N1` plan for the rows.
+    pub rows: &'p CplxFft<'a, Complex32>,
+    /// Length-`N2` plan for the columns.
+    pub cols: &'p CplxFft<'a, Complex32>,
+}
+
+impl<'p, 'a> FourStep<'p, 'a> {
+    pub fn new(rows: &'p CplxFft<'a, Complex32>, cols: &'p CplxFft<'a, Complex32>) -> Self {
+        Self { rows, cols }
+    }
+
+    /// Transform size `N1 * N2`.
+    pub fn len(&self) -> usize {
+        self.rows.n * self.cols.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Storage offset of spectrum bin `k` after a forward transform.
+    pub fn position(&self, k: usize) -> usize {
+        let (n1, n2) = (self.rows.n, self.cols.n);
+        (k % n2) * n1 + k / n2
+    }
+
+    /// Transforms the first `N` samples of `storage`.
+    ///
+    /// `work` must hold at least `max(N1, N2)` samples; each further `N2`
+    /// adds a column to every pass, cutting the number of passes.
+    pub fn process<S: BlockStorage + ?Sized>(
+        &self,
+        storage: &mut S,
+        work: &mut [Complex32],
+        inverse: bool,
+    ) -> io::Result<()> {
+        let (n1, n2) = (self.rows.n, self.cols.n);
+        if work.len() < n1.max(n2) {
+            return Err(io::Error::other(FftError::BufferTooSmall));
+        }
+        if inverse {
+            self.row_pass(storage, work, true)?;
+            self.column_pass(storage, work, true)
+        } else {
+            self.column_pass(storage, work, false)?;
+            self.row_pass(storage, work, false)
+        }
+    }
+
+    fn column_pass<S: BlockStorage + ?Sized>(
+        &self,
+        storage: &mut S,
+        work: &mut [Complex32],
+        inverse: bool,
+    ) -> io::Result<()> {
+        let (n1, n2) = (self.rows.n, self.cols.n);
+        let width = (work.len() / n2).min(n1);
+        for c0 in (0..n1).step_by(width) {
+            let w = width.min(n1 - c0);
+            let slab = &mut work[..w * n2];
+            for (r, run) in slab.chunks_exact_mut(w).enumerate() {
+                storage.read_block(r * n1 + c0, run)?;
+            }
+            // Lay each column out contiguously for the FFT, then back
+            transpose_in_place(slab, n2, w).map_err(io::Error::other)?;
+            for column in slab.chunks_exact_mut(n2) {
+                self.cols
+                    .process(column, inverse)
+                    .map_err(io::Error::other)?;
+            }
+            transpose_in_place(slab, w, n2).map_err(io::Error::other)?;
+            for (r, run) in slab.chunks_exact(w).enumerate() {
+                storage.write_block(r * n1 + c0, run)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Row FFTs, with the twiddles applied on the way in (forward) or out
+    /// (inverse).
+    fn row_pass<S: BlockStorage + ?Sized>(
+        &self,
+        storage: &mut S,
+        work: &mut [Complex32],
+        inverse: bool,
+    ) -> io::Result<()> {
+        let (n1, n2) = (self.rows.n, self.cols.n);
+        let row = &mut work[..n1];
+        for r in 0..n2 {
+            storage.read_block(r * n1, row)?;
+            if !inverse {
+                twiddle(row, r, n1 * n2, false);
+            }
+            self.rows.process(row, inverse).map_err(io::Error::other)?;
+            if inverse {
+                twiddle(row, r, n1 * n2, true);
+            }
+            storage.write_block(r * n1, row)?;
+        }
+        Ok(())
+    }
+}
+
+/// Multiplies element `j` of row `r` by `W_N^(j r)` (conjugated for the
+/// inverse). The angles are reduced and computed in f64 so that large N
+/// keeps its accuracy.
+fn twiddle(row: &mut [Complex32], r: usize, n: usize, inverse: bool) {
+    let sign = if inverse { 1.0 } else { -1.0 };
+    for (j, c) in row.iter_mut().enumerate() {
+        let m = (j * r) % n;
+        let angle = sign * 2.0 * core::f64::consts::PI * m as f64 / n as f64;
+        let (sin, cos) = libm::sincos(angle);
+        *c *= Complex32::new(cos as f32, sin as f32);
+    }
+}
+
+#[cfg(test)]
+#[path = "external_tests.rs"]
+mod tests;
@@ -0,0 +1,63 @@
+use super::*;
+use std::io::Cursor;
+
+const N1: usize = 16;
+const N2: usize = 8;
+
+fn signal() -> Vec<Complex32> {
+    (0..N1 * N2)
+        .map(|i| Complex32::new(((i * 7) % 13) as f32 - 6.0, ((i * 5) % 11) as f32 * 0.5))
+        .collect()
+}
+
+#[test]
+fn test_matches_direct_fft_in_any_storage() {
+    let mut t1 = vec![Complex32::new(0.0, 0.0); N1 / 2];
+    let mut b1 = vec![0; N1];
+    let mut t2 = vec![Complex32::new(0.0, 0.0); N2 / 2];
+    let mut b2 = vec![0; N2];
+    let mut tn = vec![Complex32::new(0.0, 0.0); N1 * N2 / 2];
+    let mut bn = vec![0; N1 * N2];
+    let rows = CplxFft::<Complex32>::new(&mut t1, &mut b1, N1).unwrap();
+    let cols = CplxFft::<Complex32>::new(&mut t2, &mut b2, N2).unwrap();
+    let direct = CplxFft::<Complex32>::new(&mut tn, &mut bn, N1 * N2).unwrap();
+    let fft = FourStep::new(&rows, &cols);
+
+    let original = signal();
+    let mut expected = original.clone();
+    direct.process(&mut expected, false).unwrap();
+
+    // Minimal work (one column per pass) on a slice
+    let mut data = original.clone();
+    let mut work = vec![Complex32::new(0.0, 0.0); N1];
+    fft.process(data.as_mut_slice(), &mut work, false).unwrap();
+    for (k, e) in expected.iter().enumerate() {
+        assert!((data[fft.position(k)] - *e).norm_sqr() < 1e-6, "bin {k}");
+    }
+    fft.process(data.as_mut_slice(), &mut work, true).unwrap();
+    for (a, b) in data.iter().zip(&original) {
+        assert!((*a - *b).norm_sqr() < 1e-8);
+    }
+
+    // Three columns per pass on a byte stream
+    let bytes: Vec<u8> = original
+        .iter()
+        .flat_map(|c| c.re.to_le_bytes().into_iter().chain(c.im.to_le_bytes()))
+        .collect();
+    let mut storage = StreamStorage(Cursor::new(bytes));
+    let mut work = vec![Complex32::new(0.0, 0.0); 3 * N2 + 1];
+    fft.process(&mut storage, &mut work, false).unwrap();
+    let mut spectrum = vec![Complex32::new(0.0, 0.0); N1 * N2];
+    storage.read_block(0, &mut spectrum).unwrap();
+    for (k, e) in expected.iter().enumerate() {
+        assert!(
+            (spectrum[fft.position(k)] - *e).norm_sqr() < 1e-6,
+            "bin {k}"
+        );
+    }
+
+    let err = fft
+        .process(data.as_mut_slice(), &mut work[..4], false)
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::Other);
+}
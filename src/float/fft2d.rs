@@ -0,0 +1,151 @@
+use super::types::Complex32;
+use crate::common::{CplxFft, FftError, RealFft};
+
+/// 2D real FFT over an `nx` x `ny` image, built from a real row plan and a
+/// complex column plan.
+///
+/// The image is row-major (`data[y * nx + x]`) and the spectrum is packed
+/// in the same `nx * ny` samples. Every row holds its packed real spectrum
+/// (see `RealFft::process`), transformed along y:
+///
+/// - columns `k = 1..nx/2` (samples `2k`, `2k+1` of each row) hold the
+///   complex bins `X[ky][k]` for every `ky`;
+/// - column 0 holds the DC and Nyquist columns, which are real before the
+///   y transform and therefore Hermitian after it. Row 0 is
+///   `(D[0], D[ny/2])`, rows `1..ny/2` are `D[ky]`, row `ny/2` is
+///   `(Q[0], Q[ny/2])` and rows `ny/2+1..ny` are `Q[ky - ny/2]`, where `D`
+///   and `Q` are the y transforms of the DC and Nyquist columns.
+///
+/// The inverse expects the same layout. Each axis follows the scaling of
+/// its own plan.
+pub struct RealFft2d<'p, 'a> {
+    pub rows: &'p RealFft<'a, Complex32>,
+    pub cols: &'p CplxFft<'a, Complex32>,
+}
+
+impl<'p, 'a> RealFft2d<'p, 'a> {
+    pub fn new(rows: &'p RealFft<'a, Complex32>, cols: &'p CplxFft<'a, Complex32>) -> Self {
+        Self { rows, cols }
+    }
+
+    /// Image dimensions `(nx, ny)`.
+    pub fn dims(&self) -> (usize, usize) {
+        (self.rows.n, self.cols.n)
+    }
+
+    /// Transforms `data` in place. `column` holds `ny` samples.
+    pub fn process(
+        &self,
+        data: &mut [f32],
+        column: &mut [Complex32],
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        let (nx, ny) = self.dims();
+        if ny < 2 || data.len() != nx * ny || column.len() != ny {
+            return Err(FftError::SizeMismatch);
+        }
+
+        if !inverse {
+            for row in data.chunks_exact_mut(nx) {
+                self.rows.process(row, false)?;
+            }
+        }
+
+        for k in 1..nx / 2 {
+            gather(data, nx, 2 * k, column);
+            self.cols.process(column, inverse)?;
+            scatter(column, data, nx, 2 * k);
+        }
+
+        let half = ny / 2;
+        if inverse {
+            // Rebuild D + iQ from the packed halves
+            let at = |y: usize| Complex32::new(data[y * nx], data[y * nx + 1]);
+            let (d0, q0) = (at(0), at(half));
+            for (ky, c) in column.iter_mut().enumerate() {
+                let (d, q) = match ky {
+                    0 => (Complex32::new(d0.re, 0.0), Complex32::new(q0.re, 0.0)),
+                    _ if ky == half => (Complex32::new(d0.im, 0.0), Complex32::new(q0.im, 0.0)),
+                    _ if ky < half => (at(ky), at(half + ky)),
+                    _ => (at(ny - ky).conj(), at(half + ny - ky).conj()),
+                };
+                *c = d + Complex32::new(-q.im, q.re);
+            }
+            self.cols.process(column, true)?;
+            scatter(column, data, nx, 0);
+
+            for row in data.chunks_exact_mut(nx) {
+                self.rows.process(row, true)?;
+            }
+        } else {
+            gather(data, nx, 0, column);
+            self.cols.process(column, false)?;
+            for ky in 0..=half {
+                let z = column[ky];
+                let zc = column[(ny - ky) % ny].conj();
+                let d = (z + zc).scale(0.5);
+                let q = (z - zc).scale(0.5);
+                // q is i * Q
+                let q = Complex32::new(q.im, -q.re);
+                let (row_d, row_q) = (ky * nx, (half + ky) * nx);
+                match ky {
+                    0 => {
+                        data[0] = d.re;
+                        data[half * nx] = q.re;
+                    }
+                    _ if ky == half => {
+                        data[1] = d.re;
+                        data[half * nx + 1] = q.re;
+                    }
+                    _ => {
+                        data[row_d] = d.re;
+                        data[row_d + 1] = d.im;
+                        data[row_q] = q.re;
+                        data[row_q + 1] = q.im;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Multiplies two packed spectra bin by bin, `a *= b`.
+    pub fn multiply(&self, a: &mut [f32], b: &[f32]) -> Result<(), FftError> {
+        let (nx, ny) = self.dims();
+        if a.len() != nx * ny || b.len() != nx * ny {
+            return Err(FftError::SizeMismatch);
+        }
+        for (y, (ra, rb)) in a.chunks_exact_mut(nx).zip(b.chunks_exact(nx)).enumerate() {
+            for (k, (ca, cb)) in ra.chunks_exact_mut(2).zip(rb.chunks_exact(2)).enumerate() {
+                if k == 0 && (y == 0 || y == ny / 2) {
+                    // Two real bins side by side
+                    ca[0] *= cb[0];
+                    ca[1] *= cb[1];
+                } else {
+                    let p = Complex32::new(ca[0], ca[1]) * Complex32::new(cb[0], cb[1]);
+                    ca[0] = p.re;
+                    ca[1] = p.im;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Copies the complex column starting at sample `offset` of each row.
+fn gather(data: &[f32], nx: usize, offset: usize, column: &mut [Complex32]) {
+    for (c, row) in column.iter_mut().zip(data.chunks_exact(nx)) {
+        *c = Complex32::new(row[offset], row[offset + 1]);
+    }
+}
+
+fn scatter(column: &[Complex32], data: &mut [f32], nx: usize, offset: usize) {
+    for (c, row) in column.iter().zip(data.chunks_exact_mut(nx)) {
+        row[offset] = c.re;
+        row[offset + 1] = c.im;
+    }
+}
+
+#[cfg(test)]
+#[path = "fft2d_tests.rs"]
+mod tests;
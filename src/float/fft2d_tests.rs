@@ -0,0 +1,64 @@
+use super::*;
+use core::f32::consts::PI;
+
+const NX: usize = 8;
+const NY: usize = 4;
+
+fn image() -> Vec<f32> {
+    (0..NX * NY).map(|i| ((i * 7) % 10) as f32 - 4.5).collect()
+}
+
+/// Bin (ky, kx) of the 2D DFT, computed directly.
+fn dft(data: &[f32], ky: usize, kx: usize) -> Complex32 {
+    let mut acc = Complex32::new(0.0, 0.0);
+    for y in 0..NY {
+        for x in 0..NX {
+            let phase = -2.0 * PI * ((ky * y) as f32 / NY as f32 + (kx * x) as f32 / NX as f32);
+            acc += Complex32::new(phase.cos(), phase.sin()).scale(data[y * NX + x]);
+        }
+    }
+    acc
+}
+
+#[test]
+fn test_packed_layout_matches_dft() {
+    let mut tr = vec![Complex32::new(0.0, 0.0); NX / 2];
+    let mut br = vec![0; NX / 2];
+    let mut tc = vec![Complex32::new(0.0, 0.0); NY / 2];
+    let mut bc = vec![0; NY];
+    let rows = RealFft::<Complex32>::new(&mut tr, &mut br, NX).unwrap();
+    let cols = CplxFft::<Complex32>::new(&mut tc, &mut bc, NY).unwrap();
+    let fft = RealFft2d::new(&rows, &cols);
+
+    let original = image();
+    let mut data = original.clone();
+    let mut column = vec![Complex32::new(0.0, 0.0); NY];
+    fft.process(&mut data, &mut column, false).unwrap();
+
+    let close = |a: f32, b: f32| assert!((a - b).abs() < 1e-3, "{a} vs {b}");
+    for ky in 0..NY {
+        for k in 1..NX / 2 {
+            let expected = dft(&original, ky, k);
+            close(data[ky * NX + 2 * k], expected.re);
+            close(data[ky * NX + 2 * k + 1], expected.im);
+        }
+    }
+    let half = NY / 2;
+    close(data[0], dft(&original, 0, 0).re);
+    close(data[1], dft(&original, half, 0).re);
+    close(data[half * NX], dft(&original, 0, NX / 2).re);
+    close(data[half * NX + 1], dft(&original, half, NX / 2).re);
+    for ky in 1..half {
+        let d = dft(&original, ky, 0);
+        let q = dft(&original, ky, NX / 2);
+        close(data[ky * NX], d.re);
+        close(data[ky * NX + 1], d.im);
+        close(data[(half + ky) * NX], q.re);
+        close(data[(half + ky) * NX + 1], q.im);
+    }
+
+    fft.process(&mut data, &mut column, true).unwrap();
+    for (a, b) in data.iter().zip(&original) {
+        close(*a, *b);
+    }
+}
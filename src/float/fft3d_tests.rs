@@ -0,0 +1,105 @@
+use super::*;
+use crate::common::{Scaling, Scratch};
+use core::f32::consts::PI;
+
+const NX: usize = 8;
+const NY: usize = 4;
+const NZ: usize = 16;
+
+fn plan<'a>(twiddles: &'a mut [Complex32], bitrev: &'a mut [usize]) -> CplxFft<'a, Complex32> {
+    let n = bitrev.len();
+    CplxFft::<Complex32>::new(twiddles, bitrev, n).unwrap()
+}
+
+#[test]
+fn test_plane_wave_lands_on_one_bin() {
+    let (mut tx, mut bx) = (vec![Complex32::new(0.0, 0.0); NX / 2], vec![0; NX]);
+    let (mut ty, mut by) = (vec![Complex32::new(0.0, 0.0); NY / 2], vec![0; NY]);
+    let (mut tz, mut bz) = (vec![Complex32::new(0.0, 0.0); NZ / 2], vec![0; NZ]);
+    let (px, py, pz) = (
+        plan(&mut tx, &mut bx),
+        plan(&mut ty, &mut by),
+        plan(&mut tz, &mut bz),
+    );
+    let fft = Fft3d::new(&px, &py, &pz);
+    assert_eq!(fft.dims(), (NX, NY, NZ));
+
+    let (kx, ky, kz) = (3, 1, 13);
+    let mut data = vec![Complex32::new(0.0, 0.0); fft.len()];
+    for z in 0..NZ {
+        for y in 0..NY {
+            for x in 0..NX {
+                let phase = 2.0
+                    * PI
+                    * ((kx * x) as f32 / NX as f32
+                        + (ky * y) as f32 / NY as f32
+                        + (kz * z) as f32 / NZ as f32);
+                data[(z * NY + y) * NX + x] = Complex32::new(phase.cos(), phase.sin());
+            }
+        }
+    }
+    let original = data.clone();
+    let mut storage = vec![Complex32::new(0.0, 0.0); fft.scratch_len()];
+    let mut scratch = Scratch::new(&mut storage);
+    fft.process(&mut data, &mut scratch, false).unwrap();
+
+    let peak = (kz * NY + ky) * NX + kx;
+    for (i, v) in data.iter().enumerate() {
+        let expected = if i == peak { fft.len() as f32 } else { 0.0 };
+        assert!(
+            (v.re - expected).abs() < 1e-2 && v.im.abs() < 1e-2,
+            "{i}: {v}"
+        );
+    }
+
+    fft.process(&mut data, &mut scratch, true).unwrap();
+    for (a, b) in data.iter().zip(&original) {
+        assert!((*a - *b).norm_sqr() < 1e-8);
+    }
+}
+
+#[test]
+fn test_matches_separable_reference_and_checks_sizes() {
+    let (mut tx, mut bx) = (vec![Complex32::new(0.0, 0.0); NX / 2], vec![0; NX]);
+    let (mut tz, mut bz) = (vec![Complex32::new(0.0, 0.0); NZ / 2], vec![0; NZ]);
+    let px = plan(&mut tx, &mut bx).with_scaling(Scaling::None);
+    let pz = plan(&mut tz, &mut bz);
+    // A 8 x 16 x 1 volume is a 2D transform with a trivial z axis
+    let (mut t1, mut b1) = (vec![Complex32::new(0.0, 0.0); 1], vec![0; 1]);
+    let p1 = plan(&mut t1, &mut b1);
+    let fft = Fft3d::new(&px, &pz, &p1);
+
+    let mut data: Vec<Complex32> = (0..fft.len())
+        .map(|i| Complex32::new((i % 5) as f32 - 2.0, (i % 3) as f32))
+        .collect();
+    let mut expected = data.clone();
+    for row in expected.chunks_exact_mut(NX) {
+        px.process(row, false).unwrap();
+    }
+    let mut column = vec![Complex32::new(0.0, 0.0); NZ];
+    for x in 0..NX {
+        for (c, v) in column.iter_mut().zip(expected[x..].iter().step_by(NX)) {
+            *c = *v;
+        }
+        pz.process(&mut column, false).unwrap();
+        for (v, c) in expected[x..].iter_mut().step_by(NX).zip(&column) {
+            *v = *c;
+        }
+    }
+
+    let mut storage = vec![Complex32::new(0.0, 0.0); fft.scratch_len()];
+    let mut scratch = Scratch::new(&mut storage);
+    fft.process(&mut data, &mut scratch, false).unwrap();
+    for (a, b) in data.iter().zip(&expected) {
+        assert!((*a - *b).norm_sqr() < 1e-8);
+    }
+
+    assert_eq!(
+        fft.process(&mut data[1..], &mut scratch, false),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        fft.process(&mut data, &mut Scratch::new(&mut storage[1..]), false),
+        Err(FftError::BufferTooSmall)
+    );
+}
@@ -0,0 +1,62 @@
+use super::types::Complex32;
+use crate::common::{CplxFft, FftError, RealFft};
+use half::f16;
+
+impl<'a> CplxFft<'a, Complex32> {
+    /// [`Self::process`] on half-precision storage.
+    ///
+    /// `buffer` holds N complex samples as interleaved `re, im` pairs of
+    /// `f16`. They are widened into `scratch` (N samples), transformed in
+    /// f32 and narrowed back, so only the storage is half precision: the
+    /// result carries about 3 significant digits, not the error growth of
+    /// f16 butterflies. Values beyond the f16 range become infinities,
+    /// which matters for unscaled transforms of large N.
+    pub fn process_f16(
+        &self,
+        buffer: &mut [f16],
+        scratch: &mut [Complex32],
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        if buffer.len() != 2 * self.n {
+            return Err(FftError::SizeMismatch);
+        }
+        let work = scratch.get_mut(..self.n).ok_or(FftError::BufferTooSmall)?;
+        for (w, pair) in work.iter_mut().zip(buffer.chunks_exact(2)) {
+            *w = Complex32::new(pair[0].to_f32(), pair[1].to_f32());
+        }
+        self.process(work, inverse)?;
+        for (pair, w) in buffer.chunks_exact_mut(2).zip(work.iter()) {
+            pair[0] = f16::from_f32(w.re);
+            pair[1] = f16::from_f32(w.im);
+        }
+        Ok(())
+    }
+}
+
+impl<'a> RealFft<'a, Complex32> {
+    /// [`Self::process`] on half-precision storage, with f32 compute in
+    /// `scratch` (N samples). The spectrum uses the same packed layout.
+    pub fn process_f16(
+        &self,
+        buffer: &mut [f16],
+        scratch: &mut [f32],
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        if buffer.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+        let work = scratch.get_mut(..self.n).ok_or(FftError::BufferTooSmall)?;
+        for (w, &h) in work.iter_mut().zip(buffer.iter()) {
+            *w = h.to_f32();
+        }
+        self.process(work, inverse)?;
+        for (h, &w) in buffer.iter_mut().zip(work.iter()) {
+            *h = f16::from_f32(w);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "half_tests.rs"]
+mod tests;
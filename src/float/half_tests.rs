@@ -0,0 +1,56 @@
+use super::*;
+
+const N: usize = 64;
+
+#[test]
+fn test_complex_matches_f32_within_f16_precision() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    let input: Vec<Complex32> = (0..N)
+        .map(|i| Complex32::new(((i * 5) % 9) as f32 * 0.25, (i % 4) as f32 * -0.5))
+        .collect();
+    let mut expected = input.clone();
+    fft.process(&mut expected, false).unwrap();
+
+    // The inputs are exact in f16
+    let mut halves: Vec<f16> = input
+        .iter()
+        .flat_map(|c| [f16::from_f32(c.re), f16::from_f32(c.im)])
+        .collect();
+    let mut scratch = vec![Complex32::new(0.0, 0.0); N];
+    fft.process_f16(&mut halves, &mut scratch, false).unwrap();
+    for (pair, e) in halves.chunks_exact(2).zip(&expected) {
+        let tol = 1e-3 * (1.0 + e.norm_sqr().sqrt());
+        assert!((pair[0].to_f32() - e.re).abs() < tol);
+        assert!((pair[1].to_f32() - e.im).abs() < tol);
+    }
+
+    assert_eq!(
+        fft.process_f16(&mut halves, &mut scratch[1..], false),
+        Err(FftError::BufferTooSmall)
+    );
+}
+
+#[test]
+fn test_real_round_trip() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    let input: Vec<f16> = (0..N)
+        .map(|i| f16::from_f32((i % 7) as f32 - 3.0))
+        .collect();
+    let mut buffer = input.clone();
+    let mut scratch = vec![0.0; N];
+    fft.process_f16(&mut buffer, &mut scratch, false).unwrap();
+    assert_eq!(
+        buffer[0].to_f32(),
+        input.iter().map(|h| h.to_f32()).sum::<f32>()
+    );
+    fft.process_f16(&mut buffer, &mut scratch, true).unwrap();
+    for (a, b) in buffer.iter().zip(&input) {
+        assert!((a.to_f32() - b.to_f32()).abs() < 5e-3);
+    }
+}
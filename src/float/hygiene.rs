@@ -0,0 +1,79 @@
+use super::types::Complex32;
+use crate::common::{FftError, Hygiene};
+
+/// Runs the plan's input check over `buffer`. `Detect` scans everything
+/// before failing, so the buffer is untouched on error.
+pub(crate) fn check(hygiene: Hygiene, buffer: &mut [f32]) -> Result<(), FftError> {
+    match hygiene {
+        Hygiene::Off => Ok(()),
+        Hygiene::Detect => {
+            if buffer.iter().all(|x| x.is_finite()) {
+                Ok(())
+            } else {
+                event!(warn, "non-finite input rejected");
+                Err(FftError::NonFinite)
+            }
+        }
+        Hygiene::Scrub => {
+            buffer.iter_mut().for_each(scrub);
+            Ok(())
+        }
+    }
+}
+
+/// [`check`] for complex buffers.
+pub(crate) fn check_complex(hygiene: Hygiene, buffer: &mut [Complex32]) -> Result<(), FftError> {
+    match hygiene {
+        Hygiene::Off => Ok(()),
+        Hygiene::Detect => {
+            if buffer.iter().all(|c| c.re.is_finite() && c.im.is_finite()) {
+                Ok(())
+            } else {
+                event!(warn, "non-finite input rejected");
+                Err(FftError::NonFinite)
+            }
+        }
+        Hygiene::Scrub => {
+            for c in buffer.iter_mut() {
+                scrub(&mut c.re);
+                scrub(&mut c.im);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// [`check`] for input that is only read, such as the hop fed to a
+/// sliding update: `Detect` scans it here, while `Scrub` is applied to each
+/// value as it is used, through [`filter`].
+pub(crate) fn check_read_only(
+    hygiene: Hygiene,
+    mut samples: impl Iterator<Item = f32>,
+) -> Result<(), FftError> {
+    if hygiene == Hygiene::Detect && !samples.all(|x| x.is_finite()) {
+        event!(warn, "non-finite input rejected");
+        return Err(FftError::NonFinite);
+    }
+    Ok(())
+}
+
+/// `x` as the plan's hygiene lets it through.
+#[inline]
+pub(crate) fn filter(hygiene: Hygiene, mut x: f32) -> f32 {
+    if hygiene == Hygiene::Scrub {
+        scrub(&mut x);
+    }
+    x
+}
+
+/// Flushes NaN, infinities and subnormals to zero.
+#[inline]
+fn scrub(x: &mut f32) {
+    if !x.is_normal() {
+        *x = 0.0;
+    }
+}
+
+#[cfg(test)]
+#[path = "hygiene_tests.rs"]
+mod tests;
@@ -0,0 +1,190 @@
+use crate::common::{CplxFft, FftError, Hygiene, RealFft, Scratch, StageState};
+use crate::float::types::Complex32;
+
+const N: usize = 8;
+
+#[test]
+fn test_detect_rejects_and_leaves_the_buffer() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_hygiene(Hygiene::Detect);
+
+    for bad in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        let mut buffer = [1.0, 2.0, bad, 4.0, 5.0, 6.0, 7.0, 8.0];
+        assert_eq!(fft.process(&mut buffer, false), Err(FftError::NonFinite));
+        assert_eq!(buffer[..2], [1.0, 2.0]);
+        assert_eq!(buffer[3..], [4.0, 5.0, 6.0, 7.0, 8.0]);
+    }
+    // Subnormals are finite and pass
+    let mut buffer = [f32::MIN_POSITIVE / 4.0; N];
+    assert_eq!(fft.process(&mut buffer, false), Ok(()));
+}
+
+#[test]
+fn test_scrub_flushes_to_zero() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_hygiene(Hygiene::Scrub);
+
+    let mut buffer = [Complex32::new(1.0, 0.0); N];
+    buffer[2] = Complex32::new(f32::NAN, f32::MIN_POSITIVE / 2.0);
+    buffer[5] = Complex32::new(-0.0, f32::INFINITY);
+    fft.process(&mut buffer, false).unwrap();
+    // What is left is six ones
+    assert_eq!(buffer[0].re, 6.0);
+    assert!(buffer.iter().all(|c| c.re.is_finite() && c.im.is_finite()));
+
+    // Off lets the NaN through to every bin
+    let fft = fft.with_hygiene(Hygiene::Off);
+    let mut buffer = [Complex32::new(1.0, 0.0); N];
+    buffer[2].re = f32::NAN;
+    fft.process(&mut buffer, false).unwrap();
+    assert!(buffer.iter().all(|c| c.re.is_nan()));
+}
+
+#[test]
+fn test_unordered_and_split_check_the_input() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_hygiene(Hygiene::Detect);
+
+    let mut buffer = [Complex32::new(1.0, 0.0); N];
+    buffer[3].im = f32::NAN;
+    assert_eq!(
+        fft.process_unordered(&mut buffer, false),
+        Err(FftError::NonFinite)
+    );
+
+    let (mut re, mut im) = ([1.0; N], [0.0; N]);
+    im[6] = f32::INFINITY;
+    assert_eq!(
+        fft.process_split(&mut re, &mut im, false),
+        Err(FftError::NonFinite)
+    );
+    assert_eq!(re, [1.0; N]);
+
+    let fft = fft.with_hygiene(Hygiene::Scrub);
+    fft.process_split(&mut re, &mut im, false).unwrap();
+    assert_eq!((re[0], im[0]), (8.0, 0.0));
+    let mut buffer = [Complex32::new(1.0, 0.0); N];
+    buffer[3].re = f32::NAN;
+    fft.process_unordered(&mut buffer, false).unwrap();
+    // Bin 0 stays in place in bit-reversed order
+    assert_eq!(buffer[0].re, 7.0);
+}
+
+#[test]
+fn test_staged_transform_checks_on_the_first_stage() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_hygiene(Hygiene::Detect);
+
+    let mut buffer = [Complex32::new(1.0, 0.0); N];
+    buffer[1].re = f32::NEG_INFINITY;
+    let mut state = StageState::new(false);
+    assert_eq!(
+        fft.process_stage(&mut buffer, &mut state),
+        Err(FftError::NonFinite)
+    );
+    assert_eq!(state.next_stage(), 0);
+    assert_eq!(
+        fft.process_with_watchdog(&mut buffer, false, || false),
+        Err(FftError::NonFinite)
+    );
+
+    let fft = fft.with_hygiene(Hygiene::Scrub);
+    fft.process_with_watchdog(&mut buffer, false, || false)
+        .unwrap();
+    assert_eq!(buffer[0].re, 7.0);
+}
+
+#[test]
+fn test_envelope_checks_its_copy_of_the_signal() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_hygiene(Hygiene::Detect);
+
+    let mut signal = [0.5; N];
+    signal[4] = f32::NAN;
+    let mut scratch = [Complex32::new(0.0, 0.0); N];
+    let mut out = [0.0; N / 2 + 1];
+    assert_eq!(
+        fft.envelope_spectrum(&signal, (1.0, 3.0), 8.0, &mut scratch, &mut out),
+        Err(FftError::NonFinite)
+    );
+
+    let fft = fft.with_hygiene(Hygiene::Scrub);
+    fft.envelope_spectrum(&signal, (1.0, 3.0), 8.0, &mut scratch, &mut out)
+        .unwrap();
+    assert!(out.iter().all(|x| x.is_finite()));
+}
+
+#[test]
+fn test_slide_checks_the_incoming_hop() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_hygiene(Hygiene::Detect);
+
+    let mut spectrum = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    fft.process(&mut spectrum, false).unwrap();
+    let before = spectrum;
+    assert_eq!(
+        fft.slide(&mut spectrum, &[1.0], &[f32::NAN]),
+        Err(FftError::NonFinite)
+    );
+    assert_eq!(spectrum, before);
+
+    // Scrub treats the NaN as the zero it is replaced by
+    let fft = fft.with_hygiene(Hygiene::Scrub);
+    fft.slide(&mut spectrum, &[1.0], &[f32::NAN]).unwrap();
+    let mut expected = before;
+    fft.slide(&mut expected, &[1.0], &[0.0]).unwrap();
+    assert_eq!(spectrum, expected);
+}
+
+#[test]
+fn test_scratch_path_matches_process() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_hygiene(Hygiene::Detect);
+    let mut storage = [Complex32::new(0.0, 0.0); N / 2];
+    let mut scratch = Scratch::new(&mut storage);
+
+    let input = [
+        1.0,
+        f32::NAN,
+        3.0,
+        4.0,
+        5.0,
+        6.0,
+        f32::MIN_POSITIVE / 8.0,
+        8.0,
+    ];
+    let mut buffer = input;
+    assert_eq!(
+        fft.process_with_scratch(&mut buffer, &mut scratch, false),
+        Err(FftError::NonFinite)
+    );
+    assert_eq!(buffer[2..], input[2..]);
+
+    let fft = fft.with_hygiene(Hygiene::Scrub);
+    fft.process_with_scratch(&mut buffer, &mut scratch, false)
+        .unwrap();
+    let mut expected = input;
+    fft.process(&mut expected, false).unwrap();
+    assert_eq!(buffer, expected);
+}
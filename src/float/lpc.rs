@@ -0,0 +1,34 @@
+use super::types::Complex32;
+use crate::common::{FftError, RealFft};
+use crate::solvers::levinson_durbin;
+
+impl<'a> RealFft<'a, Complex32> {
+    /// Linear prediction of order p = `a.len()` for `frame`: the FFT
+    /// autocorrelation followed by [`levinson_durbin`], whose conventions
+    /// `a` and `k` follow. Returns the prediction error power.
+    ///
+    /// The frame is zero-padded to N in `scratch` (N samples); N must be at
+    /// least the frame length plus p so that the lags used are not wrapped.
+    /// Window the frame beforehand as usual for LPC.
+    pub fn lpc(
+        &self,
+        frame: &[f32],
+        a: &mut [f32],
+        k: &mut [f32],
+        scratch: &mut [f32],
+    ) -> Result<f32, FftError> {
+        let n = self.n;
+        if frame.len() + a.len() > n {
+            return Err(FftError::SizeMismatch);
+        }
+        let r = scratch.get_mut(..n).ok_or(FftError::BufferTooSmall)?;
+        r[..frame.len()].copy_from_slice(frame);
+        r[frame.len()..].fill(0.0);
+        self.autocorrelate(r)?;
+        levinson_durbin(&r[..=a.len()], a, k)
+    }
+}
+
+#[cfg(test)]
+#[path = "lpc_tests.rs"]
+mod tests;
@@ -0,0 +1,44 @@
+use super::*;
+
+/// 2000 samples of x[n] = 1.3 x[n-1] - 0.6 x[n-2] + e[n], with
+/// deterministic white e.
+fn ar2() -> Vec<f32> {
+    let mut seed = 12345u32;
+    let mut x = vec![0.0f32; 2000];
+    for n in 0..x.len() {
+        seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        let e = (seed >> 8) as f32 / (1 << 24) as f32 - 0.5;
+        let past = |d: usize| if n >= d { x[n - d] } else { 0.0 };
+        x[n] = 1.3 * past(1) - 0.6 * past(2) + e;
+    }
+    x
+}
+
+#[test]
+fn test_recovers_ar2_coefficients() {
+    const N: usize = 4096;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    let frame = ar2();
+    let mut a = [0.0f32; 4];
+    let mut k = [0.0f32; 4];
+    let mut scratch = vec![0.0f32; N];
+    let err = fft.lpc(&frame, &mut a, &mut k, &mut scratch).unwrap();
+    assert!(
+        (a[0] + 1.3).abs() < 0.05 && (a[1] - 0.6).abs() < 0.05,
+        "{a:?}"
+    );
+    assert!(a[2].abs() < 0.05 && a[3].abs() < 0.05, "{a:?}");
+    // The innovation has variance 1/12
+    assert!(
+        (err / frame.len() as f32 - 1.0 / 12.0).abs() < 0.02,
+        "{err}"
+    );
+
+    assert_eq!(
+        fft.lpc(&vec![0.0; N - 2], &mut a, &mut k, &mut scratch),
+        Err(FftError::SizeMismatch)
+    );
+}
@@ -0,0 +1,58 @@
+use super::types::Complex32;
+use crate::common::{CplxFft, FftError, Scratch};
+
+impl<'a> CplxFft<'a, Complex32> {
+    /// Matched filter (pulse compression) of `rx` against `template`.
+    ///
+    /// Writes into `out` the circular cross-correlation
+    /// `out[d] = sum_n rx[n + d] * conj(w[n] * template[n])`, so a copy of
+    /// the template delayed by `d` samples shows up as a peak at `out[d]`
+    /// whose height is its energy. It is computed as conjugate-spectrum
+    /// multiplication followed by an inverse transform, with the normalization
+    /// fixed up whatever `self.scaling` says.
+    ///
+    /// `rx` and `out` must have N samples; `template` may be shorter and is
+    /// zero-padded to N in `scratch` (`self.scratch_len()` samples).
+    /// `window`, if given, must match `template` in length and tapers it to
+    /// lower the range sidelobes at the cost of a wider main lobe.
+    pub fn matched_filter(
+        &self,
+        rx: &[Complex32],
+        template: &[Complex32],
+        window: Option<&[f32]>,
+        out: &mut [Complex32],
+        scratch: &mut Scratch<'_, Complex32>,
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if rx.len() != n || out.len() != n || template.is_empty() || template.len() > n {
+            return Err(FftError::SizeMismatch);
+        }
+        if window.is_some_and(|w| w.len() != template.len()) {
+            return Err(FftError::SizeMismatch);
+        }
+        let reference = scratch.take(self.scratch_len())?;
+
+        out.copy_from_slice(rx);
+        for (i, r) in reference.iter_mut().enumerate() {
+            *r = match template.get(i) {
+                Some(&t) => t * window.map_or(1.0, |w| w[i]),
+                None => Complex32::new(0.0, 0.0),
+            };
+        }
+
+        // The pointwise product does not care about bin order
+        self.process_unordered(out, false)?;
+        self.process_unordered(reference, false)?;
+
+        let norm = self.scaling.correlation_norm(n);
+        for (o, r) in out.iter_mut().zip(reference.iter()) {
+            *o = *o * r.conj() * norm;
+        }
+
+        self.process_unordered(out, true)
+    }
+}
+
+#[cfg(test)]
+#[path = "matched_tests.rs"]
+mod tests;
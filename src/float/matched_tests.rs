@@ -0,0 +1,97 @@
+use super::*;
+use crate::common::{Scaling, Scratch};
+use core::f32::consts::PI;
+
+const N: usize = 128;
+const LEN: usize = 32;
+
+/// Unit-amplitude linear chirp of LEN samples.
+fn chirp() -> Vec<Complex32> {
+    (0..LEN)
+        .map(|i| {
+            let t = i as f32;
+            let phase = PI * 0.5 * t * t / LEN as f32;
+            Complex32::new(phase.cos(), phase.sin())
+        })
+        .collect()
+}
+
+#[test]
+fn test_compresses_delayed_pulse() {
+    let template = chirp();
+    let mut rx = vec![Complex32::new(0.0, 0.0); N];
+    for (i, &t) in template.iter().enumerate() {
+        rx[40 + i] = t * 0.5;
+    }
+
+    for scaling in [Scaling::Inverse, Scaling::Forward, Scaling::None] {
+        let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+        let mut bitrev = vec![0; N];
+        let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+            .unwrap()
+            .with_scaling(scaling);
+        let (mut out, mut storage) = (
+            vec![Complex32::new(0.0, 0.0); N],
+            vec![Complex32::new(0.0, 0.0); fft.scratch_len()],
+        );
+        let mut scratch = Scratch::new(&mut storage);
+        fft.matched_filter(&rx, &template, None, &mut out, &mut scratch)
+            .unwrap();
+
+        // Peak at the delay, with the energy of the echo against the template
+        let peak = (0..N)
+            .max_by(|&a, &b| out[a].norm_sqr().total_cmp(&out[b].norm_sqr()))
+            .unwrap();
+        assert_eq!(peak, 40);
+        assert!(
+            (out[40].re - 0.5 * LEN as f32).abs() < 1e-3,
+            "{:?}",
+            out[40]
+        );
+        assert!(out[40].im.abs() < 1e-3);
+    }
+}
+
+#[test]
+fn test_window_lowers_sidelobes() {
+    let template = chirp();
+    let mut rx = vec![Complex32::new(0.0, 0.0); N];
+    rx[..LEN].copy_from_slice(&template);
+    let hann: Vec<f32> = (0..LEN)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / LEN as f32).cos())
+        .collect();
+
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut storage = vec![Complex32::new(0.0, 0.0); fft.scratch_len()];
+    let mut scratch = Scratch::new(&mut storage);
+
+    // Largest power away from the main lobe, relative to the peak
+    let mut sidelobe = |window: Option<&[f32]>| {
+        let mut out = vec![Complex32::new(0.0, 0.0); N];
+        fft.matched_filter(&rx, &template, window, &mut out, &mut scratch)
+            .unwrap();
+        let far = (4..N - 4).map(|d| out[d].norm_sqr()).fold(0.0, f32::max);
+        far / out[0].norm_sqr()
+    };
+    let plain = sidelobe(None);
+    let tapered = sidelobe(Some(&hann));
+    assert!(tapered < 0.25 * plain, "{tapered} vs {plain}");
+
+    let mut out = vec![Complex32::new(0.0, 0.0); N];
+    assert_eq!(
+        fft.matched_filter(&rx, &template, Some(&hann[1..]), &mut out, &mut scratch),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        fft.matched_filter(
+            &rx,
+            &template,
+            None,
+            &mut out,
+            &mut Scratch::new(&mut [Complex32::new(0.0, 0.0); 4])
+        ),
+        Err(FftError::BufferTooSmall)
+    );
+}
@@ -1,5 +1,39 @@
+mod ambiguity;
+mod autocorr;
+mod batch;
+mod cepstrum;
+mod compensated;
 pub mod complex;
-mod core;
+mod convolve2d;
+pub(crate) mod core;
+mod divide;
+mod envelope;
+#[cfg(feature = "std")]
+mod external;
+mod fft2d;
+mod fft3d;
+#[cfg(feature = "half")]
+mod half;
+pub(crate) mod hygiene;
+mod lpc;
+mod matched;
+mod pair;
+mod precise;
 pub mod real;
+mod staged;
+mod sweep;
+pub mod types;
+mod zero_phase;
 
 pub use crate::common::{FftError, FftProcess};
+pub use batch::{Lanes, load_lane, store_lane};
+pub use cepstrum::lifter;
+pub use convolve2d::EdgeMode;
+pub use divide::spectral_divide;
+#[cfg(feature = "std")]
+pub use external::{BlockStorage, FourStep, StreamStorage};
+pub use fft2d::RealFft2d;
+pub use fft3d::Fft3d;
+pub use precise::PreciseFft;
+pub use sweep::ExpSweep;
+pub use types::{Complex32, PlainComplex32, Polar};
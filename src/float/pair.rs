@@ -0,0 +1,105 @@
+// src/float/pair.rs
+
+use super::types::Complex32;
+use crate::common::{CplxFft, FftError, Scratch};
+
+impl<'a> CplxFft<'a, Complex32> {
+    /// Transforms two real signals of size N at once with a single N-point complex FFT.
+    ///
+    /// `a` and `b` are packed in-place into the Real FFT format (DC, Nyquist,
+    /// then positive frequencies), exactly as `RealFft::process` would produce.
+    /// `scratch` must hold at least `self.scratch_len()` (N) complex values.
+    pub fn process_pair(
+        &self,
+        a: &mut [f32],
+        b: &mut [f32],
+        scratch: &mut Scratch<'_, Complex32>,
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if a.len() != n || b.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+        if n < 2 {
+            return Err(FftError::BufferTooSmall);
+        }
+        let z = scratch.take(n)?;
+
+        // z = a + i*b
+        for (i, val) in z.iter_mut().enumerate() {
+            *val = Complex32::new(a[i], b[i]);
+        }
+
+        self.process(z, false)?;
+
+        // DC and Nyquist of both signals are purely real
+        a[0] = z[0].re;
+        b[0] = z[0].im;
+        a[1] = z[n / 2].re;
+        b[1] = z[n / 2].im;
+
+        for k in 1..n / 2 {
+            let zk = z[k];
+            let zn = z[n - k].conj();
+
+            // A[k] = (Z[k] + conj(Z[N-k])) / 2
+            let fa = (zk + zn).scale(0.5);
+            // B[k] = (Z[k] - conj(Z[N-k])) / 2i
+            let d = (zk - zn).scale(0.5);
+            let fb = Complex32::new(d.im, -d.re);
+
+            a[2 * k] = fa.re;
+            a[2 * k + 1] = fa.im;
+            b[2 * k] = fb.re;
+            b[2 * k + 1] = fb.im;
+        }
+
+        Ok(())
+    }
+
+    /// Synthesizes two real signals of size N from two packed spectra with a single
+    /// N-point complex inverse FFT. This is the inverse of [`Self::process_pair`].
+    ///
+    /// `scratch` must hold at least `self.scratch_len()` (N) complex values.
+    pub fn process_pair_inverse(
+        &self,
+        a: &mut [f32],
+        b: &mut [f32],
+        scratch: &mut Scratch<'_, Complex32>,
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if a.len() != n || b.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+        if n < 2 {
+            return Err(FftError::BufferTooSmall);
+        }
+        let z = scratch.take(n)?;
+
+        // Z[k] = A[k] + i*B[k], using the Hermitian symmetry of both spectra
+        z[0] = Complex32::new(a[0], b[0]);
+        z[n / 2] = Complex32::new(a[1], b[1]);
+
+        for k in 1..n / 2 {
+            let fa = Complex32::new(a[2 * k], a[2 * k + 1]);
+            let fb = Complex32::new(b[2 * k], b[2 * k + 1]);
+            let i_fb = Complex32::new(-fb.im, fb.re);
+            let i_fb_conj = Complex32::new(fb.im, fb.re);
+
+            z[k] = fa + i_fb;
+            z[n - k] = fa.conj() + i_fb_conj;
+        }
+
+        self.process(z, true)?;
+
+        for (i, val) in z.iter().enumerate() {
+            a[i] = val.re;
+            b[i] = val.im;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "pair_tests.rs"]
+mod tests;
@@ -0,0 +1,149 @@
+use crate::common::{CplxFft, FftError, RealFft, Scaling, Scratch};
+use crate::float::types::Complex32;
+
+fn assert_float_close(val: f32, expected: f32) {
+    let tolerance = 1e-4;
+    let diff = (val - expected).abs();
+    assert!(
+        diff < tolerance,
+        "Error. Expected: {}, Got: {}",
+        expected,
+        val
+    );
+}
+
+fn signals(n: usize) -> (Vec<f32>, Vec<f32>) {
+    let a = (0..n).map(|i| (i as f32 * 0.9).sin() + 0.5).collect();
+    let b = (0..n)
+        .map(|i| (i as f32 * 0.3).cos() - i as f32 * 0.1)
+        .collect();
+    (a, b)
+}
+
+#[test]
+fn test_process_pair_matches_real_fft() {
+    for n in [4, 8, 32] {
+        let (mut a, mut b) = signals(n);
+
+        let mut rtw = vec![Complex32::new(0., 0.); n / 2];
+        let mut rbr = vec![0; n / 2];
+        let rfft = RealFft::<Complex32>::new(&mut rtw, &mut rbr, n).unwrap();
+        let mut expected_a = a.clone();
+        let mut expected_b = b.clone();
+        rfft.process(&mut expected_a, false).unwrap();
+        rfft.process(&mut expected_b, false).unwrap();
+
+        let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+        let mut bitrev = vec![0; n];
+        let mut scratch_buf = vec![Complex32::new(0., 0.); n];
+        let mut scratch = Scratch::new(&mut scratch_buf);
+        let fft = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+        fft.process_pair(&mut a, &mut b, &mut scratch).unwrap();
+
+        for i in 0..n {
+            assert_float_close(a[i], expected_a[i]);
+            assert_float_close(b[i], expected_b[i]);
+        }
+    }
+}
+
+#[test]
+fn test_process_pair_inverse_round_trip() {
+    for scaling in [Scaling::Forward, Scaling::Inverse] {
+        let n = 16;
+        let (orig_a, orig_b) = signals(n);
+        let (mut a, mut b) = (orig_a.clone(), orig_b.clone());
+
+        let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+        let mut bitrev = vec![0; n];
+        let mut scratch_buf = vec![Complex32::new(0., 0.); n];
+        let mut scratch = Scratch::new(&mut scratch_buf);
+        let fft = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_scaling(scaling);
+
+        fft.process_pair(&mut a, &mut b, &mut scratch).unwrap();
+        fft.process_pair_inverse(&mut a, &mut b, &mut scratch)
+            .unwrap();
+
+        for i in 0..n {
+            assert_float_close(a[i], orig_a[i]);
+            assert_float_close(b[i], orig_b[i]);
+        }
+    }
+}
+
+#[test]
+fn test_process_pair_inverse_matches_real_ifft() {
+    let n = 8;
+    // Two arbitrary packed spectra
+    let spec_a = [4.0f32, -1.0, 0.5, 2.0, -3.0, 1.0, 0.25, -0.75];
+    let spec_b = [-2.0f32, 3.0, 1.5, -0.5, 0.0, 2.5, -1.0, 1.0];
+
+    let mut rtw = vec![Complex32::new(0., 0.); n / 2];
+    let mut rbr = vec![0; n / 2];
+    let rfft = RealFft::<Complex32>::new(&mut rtw, &mut rbr, n).unwrap();
+    let mut expected_a = spec_a.to_vec();
+    let mut expected_b = spec_b.to_vec();
+    rfft.process(&mut expected_a, true).unwrap();
+    rfft.process(&mut expected_b, true).unwrap();
+
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n];
+    let mut scratch_buf = vec![Complex32::new(0., 0.); n];
+    let mut scratch = Scratch::new(&mut scratch_buf);
+    let fft = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    let (mut a, mut b) = (spec_a, spec_b);
+    fft.process_pair_inverse(&mut a, &mut b, &mut scratch)
+        .unwrap();
+
+    for i in 0..n {
+        assert_float_close(a[i], expected_a[i]);
+        assert_float_close(b[i], expected_b[i]);
+    }
+}
+
+#[test]
+fn test_process_pair_errors() {
+    let n = 8;
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    let mut a = [0.0f32; 8];
+    let mut b = [0.0f32; 4];
+    let mut scratch_buf = [Complex32::new(0., 0.); 8];
+    let mut scratch = Scratch::new(&mut scratch_buf);
+    assert_eq!(
+        fft.process_pair(&mut a, &mut b, &mut scratch),
+        Err(FftError::SizeMismatch)
+    );
+
+    let mut b = [0.0f32; 8];
+    let mut small_buf = [Complex32::new(0., 0.); 4];
+    let mut small = Scratch::new(&mut small_buf);
+    assert_eq!(
+        fft.process_pair_inverse(&mut a, &mut b, &mut small),
+        Err(FftError::BufferTooSmall)
+    );
+}
+
+#[test]
+fn test_scratch_sized_from_plan() {
+    let n = 16;
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<'_, Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    assert_eq!(fft.scratch_len(), n);
+
+    // A larger scratch is fine; only the first scratch_len() elements are used
+    let mut scratch_buf = vec![Complex32::new(0., 0.); fft.scratch_len() + 3];
+    let mut scratch = Scratch::new(&mut scratch_buf);
+    assert_eq!(scratch.len(), n + 3);
+    assert_eq!(scratch.take(n).unwrap().len(), n);
+    assert_eq!(scratch.take(n + 4).err(), Some(FftError::BufferTooSmall));
+
+    let (mut a, mut b) = signals(n);
+    fft.process_pair(&mut a, &mut b, &mut scratch).unwrap();
+}
@@ -0,0 +1,106 @@
+use super::core::{precompute_swaps, swap_table_len};
+use super::types::Complex32;
+use crate::common::{FftError, Scaling};
+
+/// Extended-precision complex FFT on f32 buffers.
+///
+/// Twiddles are computed and stored in f64 and every butterfly runs in f64
+/// in a caller-provided work buffer; the data is only rounded to f32 once,
+/// on the way out. The error is then that single rounding, some 140 dB
+/// below full scale whatever N, instead of growing with `log2(N)` as in
+/// [`CplxFft`](crate::CplxFft). Useful for metrology, at roughly twice the
+/// memory traffic.
+pub struct PreciseFft<'a> {
+    /// `N/2` twiddles as `[re, im]`.
+    pub twiddles: &'a mut [[f64; 2]],
+    pub bitrev: &'a mut [usize],
+    pub n: usize,
+    pub scaling: Scaling,
+}
+
+impl<'a> PreciseFft<'a> {
+    pub fn new(
+        twiddles: &'a mut [[f64; 2]],
+        bitrev: &'a mut [usize],
+        n: usize,
+    ) -> Result<Self, FftError> {
+        if !n.is_power_of_two() {
+            return Err(FftError::NotPowerOfTwo);
+        }
+        if twiddles.len() < n / 2 || bitrev.len() < n {
+            return Err(FftError::BufferTooSmall);
+        }
+        precompute_swaps(bitrev, n);
+        for (j, t) in twiddles.iter_mut().enumerate().take(n / 2) {
+            let angle = -2.0 * core::f64::consts::PI * j as f64 / n as f64;
+            let (sin, cos) = libm::sincos(angle);
+            *t = [cos, sin];
+        }
+        Ok(Self {
+            twiddles,
+            bitrev,
+            n,
+            scaling: Scaling::default(),
+        })
+    }
+
+    /// Selects the normalization applied by `process`.
+    pub fn with_scaling(mut self, scaling: Scaling) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    /// Transforms `buffer` in place, with all arithmetic in `work` (N
+    /// samples of f64 `[re, im]`).
+    pub fn process(
+        &self,
+        buffer: &mut [Complex32],
+        work: &mut [[f64; 2]],
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if buffer.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+        let work = work.get_mut(..n).ok_or(FftError::BufferTooSmall)?;
+        for (w, x) in work.iter_mut().zip(buffer.iter()) {
+            *w = [x.re as f64, x.im as f64];
+        }
+        for pair in self.bitrev[..swap_table_len(n)].chunks_exact(2) {
+            work.swap(pair[0], pair[1]);
+        }
+
+        let mut stride = 1;
+        let mut tw_index = n >> 1;
+        while stride < n {
+            for i in 0..stride {
+                let [wr, wi] = self.twiddles[i * tw_index];
+                let wi = if inverse { -wi } else { wi };
+                for j in (i..n).step_by(stride << 1) {
+                    let [ar, ai] = work[j];
+                    let [br, bi] = work[j + stride];
+                    let (tr, ti) = (br * wr - bi * wi, br * wi + bi * wr);
+                    work[j] = [ar + tr, ai + ti];
+                    work[j + stride] = [ar - tr, ai - ti];
+                }
+            }
+            stride <<= 1;
+            tw_index >>= 1;
+        }
+
+        // The scale is exact in f64, so it is applied once at the end
+        let scale = if self.scaling.is_scaled(inverse) {
+            1.0 / n as f64
+        } else {
+            1.0
+        };
+        for (x, w) in buffer.iter_mut().zip(work.iter()) {
+            *x = Complex32::new((w[0] * scale) as f32, (w[1] * scale) as f32);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "precise_tests.rs"]
+mod tests;
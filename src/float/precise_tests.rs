@@ -0,0 +1,71 @@
+use super::*;
+use crate::common::CplxFft;
+
+const N: usize = 1024;
+
+fn input() -> Vec<Complex32> {
+    let mut seed = 11u32;
+    (0..N)
+        .map(|_| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let re = (seed >> 8) as f32 / (1 << 24) as f32 - 0.5;
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let im = (seed >> 8) as f32 / (1 << 24) as f32 - 0.5;
+            Complex32::new(re, im)
+        })
+        .collect()
+}
+
+/// Largest error against an f64 DFT of the same input.
+fn max_error(x: &[Complex32], spectrum: &[Complex32]) -> f64 {
+    let n = x.len();
+    let mut worst: f64 = 0.0;
+    for (k, s) in spectrum.iter().enumerate() {
+        let (mut re, mut im) = (0.0f64, 0.0f64);
+        for (j, v) in x.iter().enumerate() {
+            let angle = -2.0 * core::f64::consts::PI * ((j * k) % n) as f64 / n as f64;
+            let (sin, cos) = angle.sin_cos();
+            re += v.re as f64 * cos - v.im as f64 * sin;
+            im += v.re as f64 * sin + v.im as f64 * cos;
+        }
+        worst = worst
+            .max((s.re as f64 - re).abs())
+            .max((s.im as f64 - im).abs());
+    }
+    worst
+}
+
+#[test]
+fn test_error_is_a_single_rounding() {
+    let x = input();
+
+    let mut tw = vec![[0.0; 2]; N / 2];
+    let mut br = vec![0; N];
+    let precise = PreciseFft::new(&mut tw, &mut br, N).unwrap();
+    let mut work = vec![[0.0; 2]; N];
+    let mut a = x.clone();
+    precise.process(&mut a, &mut work, false).unwrap();
+
+    let mut tw32 = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut br32 = vec![0; N];
+    let standard = CplxFft::<Complex32>::new(&mut tw32, &mut br32, N).unwrap();
+    let mut b = x.clone();
+    standard.process(&mut b, false).unwrap();
+
+    let (precise_err, standard_err) = (max_error(&x, &a), max_error(&x, &b));
+    // Bins reach about sqrt(N) * 0.4; half an f32 ulp there is ~1e-6
+    assert!(precise_err < 2e-6, "{precise_err}");
+    assert!(
+        precise_err * 4.0 < standard_err,
+        "{precise_err} vs {standard_err}"
+    );
+
+    precise.process(&mut a, &mut work, true).unwrap();
+    for (u, v) in a.iter().zip(&x) {
+        assert!((u.re - v.re).abs() < 1e-7 && (u.im - v.im).abs() < 1e-7);
+    }
+    assert_eq!(
+        precise.process(&mut a, &mut work[1..], false),
+        Err(FftError::BufferTooSmall)
+    );
+}
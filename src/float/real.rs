@@ -1,7 +1,9 @@
-use super::core::{precompute_bitrev, precompute_twiddles, radix_2_dit_fft_core};
-use crate::common::{FftError, FftProcess, RealFft};
+use super::core::{cmul, precompute_swaps, precompute_twiddles, radix_2_fft};
+use super::types::Complex32;
+use crate::common::{
+    Algorithm, FftError, FftProcess, Hygiene, RealFft, Scaling, Scratch, pack_rfft_spectrum_half,
+};
 use core::slice;
-use num_complex::Complex32;
 
 #[cfg(not(feature = "std"))]
 use libm::Libm;
@@ -22,7 +24,8 @@ impl<'a> RealFft<'a, Complex32> {
         bitrev: &'a mut [usize],
         n: usize,
     ) -> Result<Self, FftError> {
-        if !n.is_power_of_two() {
+        // The packed format needs at least the DC and Nyquist slots
+        if !n.is_power_of_two() || n < 2 {
             return Err(FftError::NotPowerOfTwo);
         }
         // For an N-point RFFT, we need auxiliary tables
@@ -33,18 +36,27 @@ impl<'a> RealFft<'a, Complex32> {
             return Err(FftError::BufferTooSmall);
         }
 
-        let mut fft: RealFft<'a, num_complex::Complex<f32>> = Self {
+        let mut fft: RealFft<'a, Complex32> = Self {
             twiddles,
             bitrev,
             n,
+            scaling: Scaling::default(),
+            algorithm: Algorithm::default(),
+            hygiene: Hygiene::default(),
         };
         fft.precompute();
+        event!(
+            debug,
+            "real f32 plan: n = {}, fused multiply-add {}",
+            n,
+            super::core::FUSED
+        );
         Ok(fft)
     }
 
     fn precompute(&mut self) {
         // 1. Bitrev is generated for N/2 (size of the internal FFT)
-        precompute_bitrev(self.bitrev, self.n / 2);
+        precompute_swaps(self.bitrev, self.n / 2);
 
         // 2. Twiddles are generated for N (full circle)
         // This is what allows the post-processing to work
@@ -56,21 +68,49 @@ impl<'a> RealFft<'a, Complex32> {
     /// - buffer[0].re = DC (Frequency 0)
     /// - buffer[0].im = Nyquist (Frequency N/2)
     /// - buffer[1..N/2] = Normal positive frequencies.
-    fn rfft(&self, buffer: &mut [f32]) -> Result<(), FftError> {
+    ///
+    /// If `scale` is set, the spectrum is scaled by 1/N.
+    fn rfft(&self, buffer: &mut [f32], scale: bool) -> Result<(), FftError> {
+        self.validate()?;
         if buffer.len() != self.n {
             return Err(FftError::SizeMismatch);
         }
 
+        // The core scales by 1/(N/2); the unweaving adds the last 1/2 to reach 1/N
+        let post = if scale { 0.5 } else { 1.0 };
+
+        // N = 2: the spectrum is only DC and Nyquist, no complex FFT is needed
+        if self.n == 2 {
+            let (x0, x1) = (buffer[0], buffer[1]);
+            buffer[0] = (x0 + x1) * post;
+            buffer[1] = (x0 - x1) * post;
+            return Ok(());
+        }
+
         // C TRICK: Reinterpret float array as Complex array
         // Safety: Complex32 is repr(C) of two f32s, and alignment is compatible.
         let cbuffer =
             unsafe { slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut Complex32, self.n / 2) };
 
+        self.rfft_complex(cbuffer, scale, post);
+        Ok(())
+    }
+
+    /// Body of `rfft` on the N/2 complex view of the buffer.
+    fn rfft_complex(&self, cbuffer: &mut [Complex32], scale: bool, post: f32) {
         // FFT of the complex sequence of N/2 points, interleaved from real input
         // This basically creates a complex FFT of the even and odd indexed samples
         // where the odd indexed samples are multiplied by j (the imaginary unit).
 
-        radix_2_dit_fft_core::<false>(cbuffer, self.twiddles, self.bitrev, 2);
+        radix_2_fft(
+            cbuffer,
+            self.twiddles,
+            self.bitrev,
+            2,
+            self.algorithm,
+            false,
+            scale,
+        );
 
         // Unweaving
         let n_half = self.n / 2;
@@ -95,10 +135,12 @@ impl<'a> RealFft<'a, Complex32> {
             // DC component = even.real + odd.imag = c[0].re + c[0].im
             // Nyquist component = even.real - odd.imag = c[0].re - c[0].im
 
-            cbuffer[0] = Complex32::new(val.re + val.im, val.re - val.im);
+            cbuffer[0] = Complex32::new(val.re + val.im, val.re - val.im).scale(post);
         }
 
-        cbuffer[n_quarter] = cbuffer[n_quarter].conj();
+        // Bin N/4 pairs with itself and its twiddle is -i, which reduces to a conjugate.
+        // For N = 4 it is the only bin left and the loop below is empty.
+        cbuffer[n_quarter] = cbuffer[n_quarter].conj().scale(post);
         // Main unweaving loop
         for i in 1..n_quarter {
             let idx_a = i;
@@ -109,17 +151,17 @@ impl<'a> RealFft<'a, Complex32> {
             let val_b_conj = val_b.conj();
 
             // even = (cdata[i] + conj(cdata[n/2-i])) / 2
-            let even = (val_a + val_b_conj).scale(0.5);
+            let even = (val_a + val_b_conj).scale(0.5 * post);
 
             // odd = (cdata[i] - conj(cdata[n/2-i])) / 2
-            let odd = (val_a - val_b_conj).scale(0.5);
+            let odd = (val_a - val_b_conj).scale(0.5 * post);
 
             // Twiddle calculation
             // C: w = twd[i]; (Note que twd aqui é a tabela completa de tamanho N/2)
             let w = self.twiddles[i];
 
             // tmp1 = odd * w
-            let tmp1 = odd * w;
+            let tmp1 = cmul(odd, w);
 
             // tmp = I * tmp1 (re: -tmp1.im, im: tmp1.re)
             let tmp = Complex32::new(-tmp1.im, tmp1.re);
@@ -134,28 +176,49 @@ impl<'a> RealFft<'a, Complex32> {
             let val_b_res = (even + tmp).conj();
             cbuffer[idx_b] = val_b_res;
         }
-
-        Ok(())
     }
 
-    fn irfft(&self, buffer: &mut [f32]) -> Result<(), FftError> {
+    /// Executes the Real FFT Inverse on a packed spectrum.
+    ///
+    /// If `scale` is set, the output is scaled by 1/N, otherwise it is N times the signal.
+    fn irfft(&self, buffer: &mut [f32], scale: bool) -> Result<(), FftError> {
+        self.validate()?;
         if buffer.len() != self.n {
             return Err(FftError::SizeMismatch);
         }
 
+        // The reweaving halves every bin; the unscaled inverse drops that 1/2
+        let pre = if scale { 0.5 } else { 1.0 };
+
+        // N = 2: recover the two samples directly from DC and Nyquist
+        if self.n == 2 {
+            let (dc, nyquist) = (buffer[0], buffer[1]);
+            buffer[0] = (dc + nyquist) * pre;
+            buffer[1] = (dc - nyquist) * pre;
+            return Ok(());
+        }
+
         let cbuffer =
             unsafe { slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut Complex32, self.n / 2) };
 
+        self.irfft_complex(cbuffer, scale, pre);
+        Ok(())
+    }
+
+    /// Body of `irfft` on the N/2 complex view of the packed spectrum.
+    fn irfft_complex(&self, cbuffer: &mut [Complex32], scale: bool, pre: f32) {
         let n_half = self.n / 2;
         let n_quarter = n_half / 2;
 
         // 1. Reweaving
 
         cbuffer[0] = Complex32::new(
-            (cbuffer[0].re + cbuffer[0].im) * 0.5,
-            (cbuffer[0].re - cbuffer[0].im) * 0.5,
+            (cbuffer[0].re + cbuffer[0].im) * pre,
+            (cbuffer[0].re - cbuffer[0].im) * pre,
         );
-        cbuffer[n_quarter] = cbuffer[n_quarter].conj();
+        // Bin N/4 pairs with itself and its twiddle is -i, which reduces to a conjugate.
+        // For N = 4 it is the only bin left and the loop below is empty.
+        cbuffer[n_quarter] = cbuffer[n_quarter].conj().scale(2.0 * pre);
 
         for i in 1..n_quarter {
             let idx_a = i;
@@ -165,15 +228,15 @@ impl<'a> RealFft<'a, Complex32> {
             let val_b = cbuffer[idx_b];
 
             // even = (cdata[i] + conj(cdata[n/2-i])) / 2
-            let even = (val_a + val_b.conj()).scale(0.5);
+            let even = (val_a + val_b.conj()).scale(pre);
 
             // odd = (cdata[i] - conj(cdata[n/2-i])) / 2
-            let odd = (val_a - val_b.conj()).scale(0.5);
+            let odd = (val_a - val_b.conj()).scale(pre);
 
             // w = conj(twd[i])
             let w = self.twiddles[i].conj();
 
-            let tmp1 = odd * w;
+            let tmp1 = cmul(odd, w);
             // tmp = I * odd * w
             let tmp = Complex32::new(-tmp1.im, tmp1.re);
 
@@ -183,24 +246,97 @@ impl<'a> RealFft<'a, Complex32> {
         }
 
         // 2. Inverse FFT of the complex sequence of N/2 points
-        radix_2_dit_fft_core::<true>(cbuffer, self.twiddles, self.bitrev, 2);
+        radix_2_fft(
+            cbuffer,
+            self.twiddles,
+            self.bitrev,
+            2,
+            self.algorithm,
+            true,
+            scale,
+        );
+    }
+
+    /// Executes the Real FFT in-place. The normalization follows `self.scaling`.
+    pub fn process(&self, buffer: &mut [f32], inverse: bool) -> Result<(), FftError> {
+        if buffer.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+        super::hygiene::check(self.hygiene, buffer)?;
+
+        let scale = self.scaling.is_scaled(inverse);
+        if inverse {
+            self.irfft(buffer, scale)?;
+        } else {
+            self.rfft(buffer, scale)?;
+        }
 
         Ok(())
     }
 
-    pub fn process(&self, buffer: &mut [f32], inverse: bool) -> Result<(), FftError> {
+    /// Same as [`Self::process`], but the N/2-point complex stage runs on a
+    /// separate `scratch` of at least `self.scratch_len()` (N/2) elements
+    /// instead of reinterpreting `buffer` as `Complex32`.
+    ///
+    /// The samples are copied in and the result copied back out, which costs
+    /// two extra passes but involves no pointer casts, so it suits platforms
+    /// with stricter alignment than `f32` and runs under MIRI or sanitizers
+    /// that reject the aliased view. The output is identical, and the plan's
+    /// `hygiene` check runs on `buffer` first, as in `process`.
+    pub fn process_with_scratch(
+        &self,
+        buffer: &mut [f32],
+        scratch: &mut Scratch<'_, Complex32>,
+        inverse: bool,
+    ) -> Result<(), FftError> {
+        self.validate()?;
         if buffer.len() != self.n {
             return Err(FftError::SizeMismatch);
         }
+        // N = 2 never takes the complex view
+        if self.n == 2 {
+            return self.process(buffer, inverse);
+        }
+        super::hygiene::check(self.hygiene, buffer)?;
+        let cbuffer = scratch.take(self.scratch_len())?;
 
+        for (c, pair) in cbuffer.iter_mut().zip(buffer.chunks_exact(2)) {
+            *c = Complex32::new(pair[0], pair[1]);
+        }
+        let scale = self.scaling.is_scaled(inverse);
+        // The `pre`/`post` factor of `irfft`/`rfft`
+        let half = if scale { 0.5 } else { 1.0 };
         if inverse {
-            self.irfft(buffer)?;
+            self.irfft_complex(cbuffer, scale, half);
         } else {
-            self.rfft(buffer)?;
+            self.rfft_complex(cbuffer, scale, half);
+        }
+        for (pair, c) in buffer.chunks_exact_mut(2).zip(cbuffer.iter()) {
+            pair[0] = c.re;
+            pair[1] = c.im;
         }
 
         Ok(())
     }
+
+    /// Inverse Real FFT from a standard one-sided spectrum of N/2 + 1 bins.
+    ///
+    /// The spectrum is packed into `out` (DC and Nyquist share the first
+    /// complex slot) and transformed in place, so no separate repacking pass
+    /// is needed. The imaginary parts of the DC and Nyquist bins are ignored,
+    /// as they must be zero for a real signal. Follows `self.scaling`.
+    pub fn process_inverse_from(
+        &self,
+        spectrum: &[Complex32],
+        out: &mut [f32],
+    ) -> Result<(), FftError> {
+        if spectrum.len() != self.n / 2 + 1 || out.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+
+        pack_rfft_spectrum_half::<f32>(spectrum, out);
+        self.process(out, true)
+    }
 }
 
 // Implementação da trait FftProcess para RealFft
@@ -1,6 +1,10 @@
 use super::RealFft;
-use crate::common::{pack_rfft_spectrum, unpack_rfft_spectrum};
-use num_complex::Complex32;
+use crate::common::{Algorithm, FftError, Scaling, Scratch};
+use crate::common::{
+    enforce_hermitian, pack_rfft_spectrum, pack_rfft_spectrum_half, pack_rfft_spectrum_in_place,
+    unpack_rfft_spectrum, unpack_rfft_spectrum_half, unpack_rfft_spectrum_in_place,
+};
+use crate::float::types::Complex32;
 
 fn assert_float_close(val: f32, expected: f32) {
     let tolerance = 1e-4;
@@ -23,19 +27,19 @@ fn test_fft_forward() {
         0.0,
         -8.0,
         9.0,
-        -45.24605542913263,
+        -45.246_056,
         -8.0,
-        19.31370849898476,
+        19.313_71,
         9.0,
-        -13.469451863989402,
+        -13.469_452,
         -8.0,
         8.0,
         9.0,
-        -6.013607741273688,
+        -6.013_608,
         -8.0,
-        3.313708498984761,
+        3.313_708_5,
         9.0,
-        -1.7902113064169214,
+        -1.790_211_3,
     ];
 
     let mut buffer = input.to_vec();
@@ -61,19 +65,19 @@ fn test_fft_reverse() {
         0.0,
         -8.0,
         9.0,
-        -45.24605542913263,
+        -45.246_056,
         -8.0,
-        19.31370849898476,
+        19.313_71,
         9.0,
-        -13.469451863989402,
+        -13.469_452,
         -8.0,
         8.0,
         9.0,
-        -6.013607741273688,
+        -6.013_608,
         -8.0,
-        3.313708498984761,
+        3.313_708_5,
         9.0,
-        -1.7902113064169214,
+        -1.790_211_3,
     ];
 
     let mut twiddles = vec![Complex32::new(0., 0.); n];
@@ -150,3 +154,245 @@ fn test_unpack_pack_spectrum_float() {
         assert_float_close(packed_back[i], packed[i]);
     }
 }
+
+#[test]
+fn test_unpack_pack_spectrum_half_float() {
+    let packed = [10.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+
+    let mut half = [Complex32::new(0.0, 0.0); 5];
+    unpack_rfft_spectrum_half(&packed, &mut half);
+
+    assert_eq!(half[0], Complex32::new(10.0, 0.0));
+    assert_eq!(half[1], Complex32::new(3.0, 4.0));
+    assert_eq!(half[2], Complex32::new(5.0, 6.0));
+    assert_eq!(half[3], Complex32::new(7.0, 8.0));
+    assert_eq!(half[4], Complex32::new(2.0, 0.0));
+
+    let mut packed_back = [0.0f32; 8];
+    pack_rfft_spectrum_half(&half, &mut packed_back);
+    assert_eq!(packed_back, packed);
+}
+
+#[test]
+fn test_unpack_pack_spectrum_in_place_float() {
+    let packed = [10.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+
+    // N + 2 slots: packed spectrum followed by room for the Nyquist bin
+    let mut buffer = [0.0f32; 10];
+    buffer[..8].copy_from_slice(&packed);
+
+    unpack_rfft_spectrum_in_place(&mut buffer);
+    assert_eq!(buffer, [10.0, 0.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 2.0, 0.0]);
+
+    pack_rfft_spectrum_in_place(&mut buffer);
+    assert_eq!(buffer[..8], packed);
+}
+
+#[test]
+fn test_enforce_hermitian_float() {
+    let mut spectrum = [
+        Complex32::new(4.0, 1.0),
+        Complex32::new(1.0, 2.0),
+        Complex32::new(3.0, -1.0),
+        Complex32::new(5.0, 0.0),
+        Complex32::new(-2.0, 3.0),
+        Complex32::new(2.0, 5.0),
+        Complex32::new(3.0, 1.0),
+        Complex32::new(3.0, 0.0),
+    ];
+
+    enforce_hermitian::<f32>(&mut spectrum);
+
+    assert_eq!(spectrum[0], Complex32::new(4.0, 0.0));
+    assert_eq!(spectrum[4], Complex32::new(-2.0, 0.0));
+    // avg(1 + 2i, conj(3 + 0i)) = 2 + 1i
+    assert_eq!(spectrum[1], Complex32::new(2.0, 1.0));
+    // avg(3 - 1i, conj(3 + 1i)) = 3 - 1i (already symmetric)
+    assert_eq!(spectrum[2], Complex32::new(3.0, -1.0));
+    // avg(5 + 0i, conj(2 + 5i)) = 3.5 - 2.5i
+    assert_eq!(spectrum[3], Complex32::new(3.5, -2.5));
+
+    for k in 1..4 {
+        assert_eq!(spectrum[8 - k], spectrum[k].conj());
+    }
+
+    // A symmetrized spectrum survives the packed round trip through the inverse RFFT
+    let n = 8;
+    let mut packed = [0.0f32; 8];
+    pack_rfft_spectrum(&spectrum, &mut packed);
+
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    fft.process(&mut packed, true).unwrap();
+    fft.process(&mut packed, false).unwrap();
+
+    let mut round_trip = [Complex32::new(0.0, 0.0); 8];
+    unpack_rfft_spectrum(&packed, &mut round_trip);
+    for (val, expected) in round_trip.iter().zip(spectrum.iter()) {
+        assert_float_close(val.re, expected.re);
+        assert_float_close(val.im, expected.im);
+    }
+}
+
+#[test]
+fn test_small_sizes() {
+    // (input, packed spectrum) for N = 2 and N = 4
+    let cases: [(&[f32], &[f32]); 2] = [
+        (&[3.0, 5.0], &[8.0, -2.0]),
+        (&[1.0, 2.0, 3.0, 4.0], &[10.0, -2.0, -2.0, 2.0]),
+    ];
+
+    for (input, expected) in cases {
+        let n = input.len();
+        let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+        let mut bitrev = vec![0; n / 2];
+        let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+        let mut buffer = input.to_vec();
+        fft.process(&mut buffer, false).unwrap();
+        for (&val, &exp) in buffer.iter().zip(expected) {
+            assert_float_close(val, exp);
+        }
+
+        fft.process(&mut buffer, true).unwrap();
+        for (&val, &exp) in buffer.iter().zip(input) {
+            assert_float_close(val, exp);
+        }
+    }
+}
+
+#[test]
+fn test_rejects_size_one() {
+    let mut twiddles = vec![Complex32::new(0., 0.); 1];
+    let mut bitrev = vec![0; 1];
+    assert!(RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, 1).is_err());
+}
+
+#[test]
+fn test_scaling_contract() {
+    // (scaling, forward gain, round trip gain), checked at several sizes
+    for n in [2, 4, 16] {
+        let input: Vec<f32> = (0..n).map(|i| (i as f32 * 0.7).sin() + 0.25).collect();
+
+        let mut reference = input.clone();
+        let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+        let mut bitrev = vec![0; n / 2];
+        let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+        fft.process(&mut reference, false).unwrap();
+
+        let cases = [
+            (Scaling::None, 1.0, n as f32),
+            (Scaling::Forward, 1.0 / n as f32, 1.0),
+            (Scaling::Inverse, 1.0, 1.0),
+        ];
+
+        for (scaling, forward_gain, round_trip_gain) in cases {
+            let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+            let mut bitrev = vec![0; n / 2];
+            let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+                .unwrap()
+                .with_scaling(scaling);
+
+            let mut buffer = input.clone();
+            fft.process(&mut buffer, false).unwrap();
+            for (&val, &expected) in buffer.iter().zip(reference.iter()) {
+                assert_float_close(val, expected * forward_gain);
+            }
+
+            fft.process(&mut buffer, true).unwrap();
+            for (&val, &expected) in buffer.iter().zip(input.iter()) {
+                assert_float_close(val, expected * round_trip_gain);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dif_matches_dit() {
+    let n = 16;
+    let input: Vec<f32> = (0..n).map(|i| (i as f32 * 0.7).sin() + 0.25).collect();
+
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let dit = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let mut expected = input.clone();
+    dit.process(&mut expected, false).unwrap();
+
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let dif = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_algorithm(Algorithm::Dif);
+    let mut buffer = input.clone();
+    dif.process(&mut buffer, false).unwrap();
+    for (&val, &exp) in buffer.iter().zip(expected.iter()) {
+        assert_float_close(val, exp);
+    }
+
+    dif.process(&mut buffer, true).unwrap();
+    for (&val, &exp) in buffer.iter().zip(input.iter()) {
+        assert_float_close(val, exp);
+    }
+}
+
+#[test]
+fn test_process_inverse_from_half_spectrum() {
+    let n = 16;
+    let input: Vec<f32> = (0..n).map(|i| (i as f32 * 0.9).cos() - 0.1).collect();
+
+    let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    let mut packed = input.clone();
+    fft.process(&mut packed, false).unwrap();
+    let mut half = vec![Complex32::new(0., 0.); n / 2 + 1];
+    unpack_rfft_spectrum_half(&packed, &mut half);
+
+    // Spurious DC/Nyquist imaginary parts are ignored
+    half[0].im = 5.0;
+    half[n / 2].im = -5.0;
+
+    let mut out = vec![0.0f32; n];
+    fft.process_inverse_from(&half, &mut out).unwrap();
+    for (&val, &expected) in out.iter().zip(input.iter()) {
+        assert_float_close(val, expected);
+    }
+
+    assert_eq!(
+        fft.process_inverse_from(&half[..n / 2], &mut out),
+        Err(FftError::SizeMismatch)
+    );
+}
+
+#[test]
+fn test_process_with_scratch_matches_in_place() {
+    for n in [2, 4, 32] {
+        let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+        let mut bitrev = vec![0; n / 2];
+        let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+        let input: Vec<f32> = (0..n).map(|i| (i as f32 * 0.7).sin() + 0.25).collect();
+        assert_eq!(fft.scratch_len(), n / 2);
+        let mut storage = vec![Complex32::new(0.0, 0.0); fft.scratch_len()];
+        let mut scratch = Scratch::new(&mut storage);
+
+        for inverse in [false, true] {
+            let mut expected = input.clone();
+            fft.process(&mut expected, inverse).unwrap();
+            let mut copied = input.clone();
+            fft.process_with_scratch(&mut copied, &mut scratch, inverse)
+                .unwrap();
+            assert_eq!(copied, expected);
+        }
+    }
+
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); 8];
+    let mut bitrev = vec![0; 8];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, 16).unwrap();
+    let mut short = vec![Complex32::new(0.0, 0.0); fft.scratch_len() - 1];
+    assert_eq!(
+        fft.process_with_scratch(&mut [0.0; 16], &mut Scratch::new(&mut short), false),
+        Err(FftError::BufferTooSmall)
+    );
+}
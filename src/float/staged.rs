@@ -0,0 +1,74 @@
+use super::core::{bit_reverse_permute, radix_2_dit_stage};
+use super::types::Complex32;
+use crate::common::{CplxFft, FftError, StageState};
+
+impl CplxFft<'_, Complex32> {
+    /// Runs the next stage of the transform tracked by `state`, so a long
+    /// FFT can be spread over several scheduler ticks or interrupts.
+    ///
+    /// Returns `Ok(true)` once the last stage has run; further calls do
+    /// nothing. Stages run in DIT order whatever the plan's `algorithm`.
+    /// The plan's `hygiene` check runs with the first stage.
+    pub fn process_stage(
+        &self,
+        buffer: &mut [Complex32],
+        state: &mut StageState,
+    ) -> Result<bool, FftError> {
+        self.validate()?;
+        if buffer.len() != self.n {
+            return Err(FftError::SizeMismatch);
+        }
+
+        let stages = self.stage_count();
+        let inverse = state.inverse();
+        match state.next_stage() {
+            0 => {
+                super::hygiene::check_complex(self.hygiene, buffer)?;
+                bit_reverse_permute(buffer, self.bitrev);
+            }
+            k if k < stages => {
+                let scale = self.scaling.is_scaled(inverse);
+                radix_2_dit_stage(buffer, self.twiddles, 1 << (k - 1), inverse, scale);
+            }
+            k if k == stages => return Ok(true),
+            // A state from a larger plan
+            _ => return Err(FftError::SizeMismatch),
+        }
+        state.advance();
+        Ok(state.next_stage() == stages)
+    }
+
+    /// Executes the FFT in-place like [`Self::process`], but calls
+    /// `expired` before each of the log2(N) butterfly stages and stops with
+    /// `FftError::DeadlineExceeded` as soon as it returns `true`.
+    ///
+    /// `expired` typically compares a cycle counter or timer against the
+    /// frame deadline; it runs once per stage, not per butterfly. After an
+    /// abort the buffer holds a partly transformed frame and should be
+    /// dropped. The plan's `algorithm` is ignored (stages always run DIT
+    /// order), and the result matches `process` to within rounding.
+    pub fn process_with_watchdog(
+        &self,
+        buffer: &mut [Complex32],
+        inverse: bool,
+        mut expired: impl FnMut() -> bool,
+    ) -> Result<(), FftError> {
+        let mut state = StageState::new(inverse);
+        while !self.process_stage(buffer, &mut state)? {
+            if expired() {
+                event!(
+                    warn,
+                    "n = {} transform stopped before stage {}",
+                    self.n,
+                    state.next_stage()
+                );
+                return Err(FftError::DeadlineExceeded);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "staged_tests.rs"]
+mod tests;
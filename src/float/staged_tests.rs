@@ -0,0 +1,89 @@
+use crate::common::{CplxFft, FftError, Scaling, StageState};
+use crate::float::types::Complex32;
+
+fn signal(n: usize) -> Vec<Complex32> {
+    (0..n)
+        .map(|i| Complex32::new((i as f32 * 0.4).sin(), (i as f32 * 0.9).cos()))
+        .collect()
+}
+
+#[test]
+fn test_watchdog_that_never_fires_matches_process() {
+    let n = 64;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::Forward);
+
+    for inverse in [false, true] {
+        let mut expected = signal(n);
+        fft.process(&mut expected, inverse).unwrap();
+
+        let mut checks = 0;
+        let mut buffer = signal(n);
+        fft.process_with_watchdog(&mut buffer, inverse, || {
+            checks += 1;
+            false
+        })
+        .unwrap();
+
+        assert_eq!(checks, 6);
+        for (a, b) in buffer.iter().zip(expected.iter()) {
+            assert!((*a - *b).norm_sqr() < 1e-10);
+        }
+    }
+}
+
+#[test]
+fn test_expired_watchdog_stops_between_stages() {
+    let n = 64;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    // Budget of three stages out of six
+    let mut budget = 3;
+    let mut buffer = signal(n);
+    let result = fft.process_with_watchdog(&mut buffer, false, || {
+        if budget == 0 {
+            return true;
+        }
+        budget -= 1;
+        false
+    });
+    assert_eq!(result, Err(FftError::DeadlineExceeded));
+    assert_eq!(budget, 0);
+}
+
+#[test]
+fn test_process_stage_spreads_the_transform() {
+    let n = 16;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    assert_eq!(fft.stage_count(), 5);
+
+    let mut expected = signal(n);
+    fft.process(&mut expected, true).unwrap();
+
+    let mut state = StageState::new(true);
+    let mut buffer = signal(n);
+    let mut calls = 0;
+    while !fft.process_stage(&mut buffer, &mut state).unwrap() {
+        calls += 1;
+    }
+    assert_eq!(calls + 1, fft.stage_count());
+    for (a, b) in buffer.iter().zip(expected.iter()) {
+        assert!((*a - *b).norm_sqr() < 1e-10);
+    }
+
+    // A finished state leaves the buffer alone
+    let before = buffer.clone();
+    assert!(fft.process_stage(&mut buffer, &mut state).unwrap());
+    assert_eq!(buffer, before);
+    assert_eq!(
+        fft.process_stage(&mut buffer[..8], &mut StageState::new(false)),
+        Err(FftError::SizeMismatch)
+    );
+}
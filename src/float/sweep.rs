@@ -0,0 +1,111 @@
+use super::divide::spectral_divide;
+use super::types::Complex32;
+use crate::common::{FftError, RealFft};
+use core::f64::consts::PI;
+
+/// Exponential (logarithmic) sine sweep, the usual stimulus for impulse
+/// response measurements.
+///
+/// The frequency rises from `f_start` to `f_end` at a constant number of
+/// octaves per second, so harmonic distortion of the device under test
+/// shows up before the linear response after deconvolution and can be
+/// windowed away.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExpSweep {
+    f_start: f32,
+    f_end: f32,
+    sample_rate: f32,
+}
+
+impl ExpSweep {
+    /// Requires `0 < f_start < f_end <= sample_rate / 2`.
+    pub fn new(f_start: f32, f_end: f32, sample_rate: f32) -> Result<Self, FftError> {
+        if !(f_start > 0.0 && f_start < f_end && f_end <= sample_rate / 2.0) {
+            return Err(FftError::OutOfRange);
+        }
+        Ok(Self {
+            f_start,
+            f_end,
+            sample_rate,
+        })
+    }
+
+    /// Writes a unit-amplitude sweep spanning the whole of `out`.
+    ///
+    /// The phase is accumulated in `f64`, as long sweeps reach phases where
+    /// `f32` no longer resolves a sample step. No fade is applied; taper the
+    /// ends if the device reacts to the abrupt start.
+    pub fn generate(&self, out: &mut [f32]) {
+        let duration = out.len() as f64 / self.sample_rate as f64;
+        let ratio = libm::log(self.f_end as f64 / self.f_start as f64);
+        // Time constant of the exponential frequency rise
+        let l = duration / ratio;
+        let k = 2.0 * PI * self.f_start as f64 * l;
+        for (i, o) in out.iter_mut().enumerate() {
+            let t = i as f64 / self.sample_rate as f64;
+            *o = libm::sin(k * (libm::exp(t / l) - 1.0)) as f32;
+        }
+    }
+}
+
+impl<'a> RealFft<'a, Complex32> {
+    /// Impulse response of a system from its `recorded` response to
+    /// `stimulus` (e.g. an [`ExpSweep`]), written to `out` (N samples).
+    ///
+    /// Divides the spectra with [`spectral_divide`] and transforms back, with
+    /// `epsilon` set to `regularization` times the peak of `|X|^2`. It keeps
+    /// the bins outside the swept band from amplifying noise; 1e-3 to 1e-6
+    /// is typical, 0 gives plain division.
+    ///
+    /// Both inputs are zero-padded to N in `out` and `scratch` (N samples).
+    /// The deconvolution is circular: with N at least the recording length
+    /// plus the stimulus length, the linear response starts at `out[0]` and
+    /// the distortion products of a sweep land at the end of `out`.
+    pub fn impulse_response(
+        &self,
+        recorded: &[f32],
+        stimulus: &[f32],
+        regularization: f32,
+        out: &mut [f32],
+        scratch: &mut [f32],
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        if out.len() != n || recorded.len() > n || stimulus.is_empty() || stimulus.len() > n {
+            return Err(FftError::SizeMismatch);
+        }
+        let x = scratch.get_mut(..n).ok_or(FftError::BufferTooSmall)?;
+
+        out[..recorded.len()].copy_from_slice(recorded);
+        out[recorded.len()..].fill(0.0);
+        x[..stimulus.len()].copy_from_slice(stimulus);
+        x[stimulus.len()..].fill(0.0);
+        self.process(out, false)?;
+        self.process(x, false)?;
+
+        let peak = x[..2]
+            .iter()
+            .map(|v| v * v)
+            .chain(x[2..].chunks_exact(2).map(|b| b[0] * b[0] + b[1] * b[1]))
+            .fold(0.0f32, f32::max);
+        if peak == 0.0 {
+            return Err(FftError::OutOfRange);
+        }
+        // The forward factors cancel in the ratio; only the inverse one is left
+        let norm = if self.scaling.is_scaled(true) {
+            1.0
+        } else {
+            1.0 / n as f32
+        };
+
+        spectral_divide(out, x, regularization * peak)?;
+        if norm != 1.0 {
+            out.iter_mut().for_each(|y| *y *= norm);
+        }
+
+        self.process(out, true)
+    }
+}
+
+#[cfg(test)]
+#[path = "sweep_tests.rs"]
+mod tests;
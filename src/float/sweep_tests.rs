@@ -0,0 +1,59 @@
+use super::*;
+
+const FS: f32 = 8000.0;
+
+#[test]
+fn test_sweep_parameters_are_checked() {
+    assert!(ExpSweep::new(50.0, 3000.0, FS).is_ok());
+    assert_eq!(ExpSweep::new(0.0, 3000.0, FS), Err(FftError::OutOfRange));
+    assert_eq!(ExpSweep::new(3000.0, 50.0, FS), Err(FftError::OutOfRange));
+    assert_eq!(ExpSweep::new(50.0, 5000.0, FS), Err(FftError::OutOfRange));
+}
+
+#[test]
+fn test_sweep_recovers_impulse_response() {
+    const N: usize = 8192;
+    let mut stimulus = vec![0.0f32; 2048];
+    ExpSweep::new(50.0, 3500.0, FS)
+        .unwrap()
+        .generate(&mut stimulus);
+    assert!(stimulus.iter().all(|s| s.abs() <= 1.0));
+
+    // System: 10-sample delay at half gain, echo at 30 samples
+    let taps = [(10, 0.5f32), (30, 0.25)];
+    let mut recorded = vec![0.0f32; stimulus.len() + 40];
+    for (d, g) in taps {
+        for (i, &s) in stimulus.iter().enumerate() {
+            recorded[i + d] += g * s;
+        }
+    }
+
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut ir = vec![0.0f32; N];
+    let mut scratch = vec![0.0f32; N];
+    fft.impulse_response(&recorded, &stimulus, 1e-4, &mut ir, &mut scratch)
+        .unwrap();
+
+    for (d, g) in taps {
+        assert!((ir[d] - g).abs() < 0.05, "tap {d}: {}", ir[d]);
+    }
+    // Nothing comparable anywhere else
+    let stray = ir
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| !(8..=12).contains(&i) && !(28..=32).contains(&i))
+        .map(|(_, v)| v.abs())
+        .fold(0.0f32, f32::max);
+    assert!(stray < 0.1, "{stray}");
+
+    assert_eq!(
+        fft.impulse_response(&recorded, &[], 1e-4, &mut ir, &mut scratch),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        fft.impulse_response(&recorded, &stimulus, 1e-4, &mut ir, &mut scratch[..N / 2]),
+        Err(FftError::BufferTooSmall)
+    );
+}
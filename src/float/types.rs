@@ -0,0 +1,241 @@
+// src/float/types.rs
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Complex type used by the float path.
+///
+/// With the `num-complex` feature (enabled by default) this is
+/// `num_complex::Complex32`. Without it, the crate falls back to
+/// [`PlainComplex32`] and has no dependency on `num-complex` at all.
+#[cfg(feature = "num-complex")]
+pub use num_complex::Complex32;
+
+#[cfg(not(feature = "num-complex"))]
+pub use PlainComplex32 as Complex32;
+
+/// Minimal single-precision complex number with the same `repr(C)` layout as
+/// `num_complex::Complex32`.
+///
+/// It only provides the operations the FFT needs. When the `num-complex`
+/// feature is enabled, lossless `From` conversions to and from
+/// `num_complex::Complex32` are available.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[repr(C)]
+pub struct PlainComplex32 {
+    pub re: f32,
+    pub im: f32,
+}
+
+impl PlainComplex32 {
+    #[inline]
+    pub const fn new(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the complex conjugate (a - bi)
+    #[inline]
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Multiplies both parts by a real factor.
+    #[inline]
+    pub fn scale(self, t: f32) -> Self {
+        Self::new(self.re * t, self.im * t)
+    }
+
+    /// Returns |re| + |im|.
+    #[inline]
+    pub fn l1_norm(self) -> f32 {
+        libm::fabsf(self.re) + libm::fabsf(self.im)
+    }
+
+    /// Returns re² + im².
+    #[inline]
+    pub fn norm_sqr(self) -> f32 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Returns the magnitude sqrt(re² + im²).
+    #[inline]
+    pub fn norm(self) -> f32 {
+        libm::hypotf(self.re, self.im)
+    }
+
+    /// Same as [`Self::norm`].
+    #[inline]
+    pub fn abs(self) -> f32 {
+        self.norm()
+    }
+
+    /// Returns the phase atan2(im, re) in (-pi, pi].
+    #[inline]
+    pub fn arg(self) -> f32 {
+        libm::atan2f(self.im, self.re)
+    }
+
+    /// Returns `(norm, arg)`.
+    #[inline]
+    pub fn to_polar(self) -> (f32, f32) {
+        (self.norm(), self.arg())
+    }
+
+    /// Builds `r * (cos(theta) + i sin(theta))`.
+    #[inline]
+    pub fn from_polar(r: f32, theta: f32) -> Self {
+        let (sin, cos) = libm::sincosf(theta);
+        Self::new(r * cos, r * sin)
+    }
+}
+
+impl Add for PlainComplex32 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl AddAssign for PlainComplex32 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for PlainComplex32 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl SubAssign for PlainComplex32 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for PlainComplex32 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl MulAssign for PlainComplex32 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Mul<f32> for PlainComplex32 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        self.scale(rhs)
+    }
+}
+
+impl Neg for PlainComplex32 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl fmt::Display for PlainComplex32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < 0.0 {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<PlainComplex32> for num_complex::Complex32 {
+    #[inline]
+    fn from(c: PlainComplex32) -> Self {
+        num_complex::Complex32::new(c.re, c.im)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl From<num_complex::Complex32> for PlainComplex32 {
+    #[inline]
+    fn from(c: num_complex::Complex32) -> Self {
+        PlainComplex32::new(c.re, c.im)
+    }
+}
+
+/// Polar-form helpers available on [`Complex32`] whichever type it resolves
+/// to (`num_complex` only provides them with its `std` feature).
+pub trait Polar: Sized {
+    /// Magnitude sqrt(re² + im²).
+    fn abs(self) -> f32;
+    /// Phase atan2(im, re) in (-pi, pi].
+    fn arg(self) -> f32;
+    /// Returns `(abs, arg)`.
+    fn to_polar(self) -> (f32, f32);
+    /// Builds `r * (cos(theta) + i sin(theta))`.
+    fn from_polar(r: f32, theta: f32) -> Self;
+}
+
+impl Polar for PlainComplex32 {
+    #[inline]
+    fn abs(self) -> f32 {
+        PlainComplex32::abs(self)
+    }
+    #[inline]
+    fn arg(self) -> f32 {
+        PlainComplex32::arg(self)
+    }
+    #[inline]
+    fn to_polar(self) -> (f32, f32) {
+        PlainComplex32::to_polar(self)
+    }
+    #[inline]
+    fn from_polar(r: f32, theta: f32) -> Self {
+        PlainComplex32::from_polar(r, theta)
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl Polar for num_complex::Complex32 {
+    #[inline]
+    fn abs(self) -> f32 {
+        PlainComplex32::from(self).abs()
+    }
+    #[inline]
+    fn arg(self) -> f32 {
+        PlainComplex32::from(self).arg()
+    }
+    #[inline]
+    fn to_polar(self) -> (f32, f32) {
+        PlainComplex32::from(self).to_polar()
+    }
+    #[inline]
+    fn from_polar(r: f32, theta: f32) -> Self {
+        PlainComplex32::from_polar(r, theta).into()
+    }
+}
+
+#[cfg(test)]
+#[path = "types_tests.rs"]
+mod tests;
@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn test_plain_complex_arithmetic() {
+    let a = PlainComplex32::new(1.0, 2.0);
+    let b = PlainComplex32::new(3.0, 4.0);
+
+    assert_eq!(a + b, PlainComplex32::new(4.0, 6.0));
+    assert_eq!(a - b, PlainComplex32::new(-2.0, -2.0));
+    // (1 + 2i) * (3 + 4i) = -5 + 10i
+    assert_eq!(a * b, PlainComplex32::new(-5.0, 10.0));
+    assert_eq!(a * 2.0, PlainComplex32::new(2.0, 4.0));
+    assert_eq!(-a, PlainComplex32::new(-1.0, -2.0));
+    assert_eq!(a.conj(), PlainComplex32::new(1.0, -2.0));
+    assert_eq!(b.norm(), 5.0);
+    assert_eq!(b.norm_sqr(), 25.0);
+    assert_eq!(b.l1_norm(), 7.0);
+}
+
+#[test]
+fn test_plain_complex_polar() {
+    let z = PlainComplex32::new(-3.0, 4.0);
+    assert_eq!(z.abs(), 5.0);
+    assert_eq!(z.arg(), 4f32.atan2(-3.0));
+
+    let (r, theta) = z.to_polar();
+    let back = PlainComplex32::from_polar(r, theta);
+    assert!((back - z).norm() < 1e-5);
+    assert_eq!(PlainComplex32::new(0.0, 0.0).arg(), 0.0);
+
+    // Same results through the trait, whatever Complex32 resolves to
+    let c = Complex32::new(-3.0, 4.0);
+    assert_eq!(Polar::to_polar(c), (r, theta));
+    assert_eq!(Polar::abs(<Complex32 as Polar>::from_polar(2.0, 0.0)), 2.0);
+}
+
+#[test]
+fn test_plain_complex_layout() {
+    assert_eq!(
+        core::mem::size_of::<PlainComplex32>(),
+        core::mem::size_of::<[f32; 2]>()
+    );
+    assert_eq!(core::mem::offset_of!(PlainComplex32, im), 4);
+}
+
+#[cfg(feature = "num-complex")]
+#[test]
+fn test_num_complex_conversions() {
+    let plain = PlainComplex32::new(0.5, -1.5);
+    let nc: num_complex::Complex32 = plain.into();
+    assert_eq!(nc, num_complex::Complex32::new(0.5, -1.5));
+    assert_eq!(PlainComplex32::from(nc), plain);
+}
@@ -0,0 +1,69 @@
+use super::types::Complex32;
+use crate::common::{FftError, RealFft, Scaling};
+
+impl<'a> RealFft<'a, Complex32> {
+    /// Zero-phase filtering of `signal` in place, the frequency-domain
+    /// counterpart of `scipy.signal.filtfilt`.
+    ///
+    /// `freq_response(f)` gives the real gain at `f` cycles per sample
+    /// (0 to 0.5); it is applied with no phase, so features stay aligned
+    /// with the input. `filtfilt` with a filter `H` corresponds to
+    /// `|H(f)|^2`.
+    ///
+    /// The signal (L <= N samples) is laid out in `scratch` (N samples)
+    /// between odd reflections about its end points, as `filtfilt` pads,
+    /// so the circular transform does not smear one edge into the other.
+    /// Each reflection is `min((N - L) / 2, L - 1)` samples long; choose
+    /// N comfortably above L for long filter responses. The result does not
+    /// depend on `self.scaling`.
+    pub fn zero_phase_filter(
+        &self,
+        signal: &mut [f32],
+        freq_response: impl Fn(f32) -> f32,
+        scratch: &mut [f32],
+    ) -> Result<(), FftError> {
+        let n = self.n;
+        let len = signal.len();
+        if len == 0 || len > n {
+            return Err(FftError::SizeMismatch);
+        }
+        let buf = scratch.get_mut(..n).ok_or(FftError::BufferTooSmall)?;
+
+        let pad = ((n - len) / 2).min(len - 1);
+        let (first, last) = (signal[0], signal[len - 1]);
+        let (left, rest) = buf.split_at_mut(pad);
+        let (middle, rest) = rest.split_at_mut(len);
+        let (right, tail) = rest.split_at_mut(pad);
+        for (i, x) in left.iter_mut().enumerate() {
+            *x = 2.0 * first - signal[pad - i];
+        }
+        middle.copy_from_slice(signal);
+        for (i, x) in right.iter_mut().enumerate() {
+            *x = 2.0 * last - signal[len - 2 - i];
+        }
+        tail.fill(0.0);
+
+        self.process(buf, false)?;
+        // Undo the round trip's factor N when neither direction scales
+        let norm = if matches!(self.scaling, Scaling::None) {
+            1.0 / n as f32
+        } else {
+            1.0
+        };
+        buf[0] *= freq_response(0.0) * norm;
+        buf[1] *= freq_response(0.5) * norm;
+        for (k, bin) in buf.chunks_exact_mut(2).enumerate().skip(1) {
+            let gain = freq_response(k as f32 / n as f32) * norm;
+            bin[0] *= gain;
+            bin[1] *= gain;
+        }
+        self.process(buf, true)?;
+
+        signal.copy_from_slice(&buf[pad..pad + len]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "zero_phase_tests.rs"]
+mod tests;
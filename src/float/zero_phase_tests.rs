@@ -0,0 +1,77 @@
+use crate::common::{FftError, RealFft, Scaling};
+use crate::float::types::Complex32;
+use core::f32::consts::PI;
+
+fn tone(len: usize, freq: f32) -> Vec<f32> {
+    (0..len)
+        .map(|i| (2.0 * PI * freq * i as f32).sin())
+        .collect()
+}
+
+#[test]
+fn test_unit_response_returns_the_signal() {
+    let n = 64;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    for scaling in [Scaling::None, Scaling::Forward, Scaling::Inverse] {
+        let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_scaling(scaling);
+        let input: Vec<f32> = (0..40).map(|i| (i as f32 * 0.3).cos() + 0.1).collect();
+        let mut signal = input.clone();
+        let mut scratch = vec![0.0; n];
+        fft.zero_phase_filter(&mut signal, |_| 1.0, &mut scratch)
+            .unwrap();
+        for (a, b) in signal.iter().zip(input.iter()) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn test_lowpass_keeps_the_low_tone_in_phase() {
+    let n = 512;
+    let len = 200;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+    let low = tone(len, 0.01);
+    let high = tone(len, 0.3);
+    let mut signal: Vec<f32> = low.iter().zip(&high).map(|(a, b)| a + b).collect();
+    let mut scratch = vec![0.0; n];
+    // Raised-cosine transition from 0.05 to 0.15 cycles/sample
+    let lowpass = |f: f32| {
+        if f < 0.05 {
+            1.0
+        } else if f > 0.15 {
+            0.0
+        } else {
+            0.5 + 0.5 * (PI * (f - 0.05) / 0.1).cos()
+        }
+    };
+    fft.zero_phase_filter(&mut signal, lowpass, &mut scratch)
+        .unwrap();
+
+    // Away from the edges the output is the low tone, without delay
+    for (a, b) in signal[20..len - 20].iter().zip(&low[20..len - 20]) {
+        assert!((a - b).abs() < 0.05, "{a} vs {b}");
+    }
+}
+
+#[test]
+fn test_signal_longer_than_the_plan_is_rejected() {
+    let n = 16;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let mut scratch = vec![0.0; n];
+    assert_eq!(
+        fft.zero_phase_filter(&mut [0.0; 17], |_| 1.0, &mut scratch),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        fft.zero_phase_filter(&mut [0.0; 8], |_| 1.0, &mut scratch[..8]),
+        Err(FftError::BufferTooSmall)
+    );
+}
@@ -4,18 +4,37 @@
 #[cfg(test)]
 extern crate std;
 
+#[macro_use]
+mod events;
+
+pub mod analysis;
+#[cfg(feature = "std")]
+pub mod codegen;
 pub mod common;
+pub mod diagnostics;
 pub mod fixed;
 pub mod float;
+pub mod plan_io;
+mod shared;
+pub mod solvers;
+pub mod stream;
+pub mod transforms;
+pub mod transpose;
+pub mod validation;
 
 // Re-exporta o erro para ficar acessível globalmente
+use crate::float::types::Complex32;
+pub use common::Algorithm;
 pub use common::CplxFft;
 pub use common::FftError;
 pub use common::FftProcess;
+pub use common::Hygiene;
 pub use common::RealFft;
+pub use common::Scaling;
+pub use common::Scratch;
+pub use common::StageState;
 pub use fixed::ComplexFixed;
 pub use fixed::Fixed;
-use num_complex::Complex32;
 
 pub type ComplexQ23 = ComplexFixed<23>;
 pub type ComplexQ16 = ComplexFixed<16>;
@@ -0,0 +1,317 @@
+// src/plan_io.rs
+
+//! Binary export and import of plan tables.
+//!
+//! Planning fills the twiddle and bit-reversal tables with trigonometry,
+//! which is slow on targets without an FPU. A plan exported once (on the
+//! host, or by a build script) is restored on the target with a plain copy
+//! into the caller's tables.
+//!
+//! The format is little-endian: a 24-byte header (magic `SFFT`, version,
+//! plan kind, table type, scaling, algorithm, hygiene, 2 reserved bytes,
+//! then `n`, the twiddle count and the bit-reversal count as `u32`), the
+//! twiddles, then the bit-reversal table as `u32`.
+
+use crate::common::{Algorithm, CplxFft, FftError, Hygiene, RealFft, Scaling, swaps_are_exact};
+use crate::fixed::core::TWIDDLE_FRAC;
+use crate::fixed::{ComplexFixed, Fixed};
+use crate::float::types::Complex32;
+
+const MAGIC: [u8; 4] = *b"SFFT";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 24;
+
+const KIND_COMPLEX: u8 = 0;
+const KIND_REAL: u8 = 1;
+
+/// A twiddle table element that can be exported.
+pub trait TableEntry: Copy {
+    /// Identifies the element type in the header.
+    const TAG: u8;
+    /// Encoded size in bytes.
+    const BYTES: usize;
+    fn encode(&self, out: &mut [u8]);
+    fn decode(bytes: &[u8]) -> Self;
+}
+
+impl TableEntry for Complex32 {
+    const TAG: u8 = 0;
+    const BYTES: usize = 8;
+
+    fn encode(&self, out: &mut [u8]) {
+        out[..4].copy_from_slice(&self.re.to_le_bytes());
+        out[4..8].copy_from_slice(&self.im.to_le_bytes());
+    }
+
+    fn decode(bytes: &[u8]) -> Self {
+        Complex32::new(
+            f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            f32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        )
+    }
+}
+
+impl TableEntry for ComplexFixed<TWIDDLE_FRAC> {
+    const TAG: u8 = 1;
+    const BYTES: usize = 8;
+
+    fn encode(&self, out: &mut [u8]) {
+        out[..4].copy_from_slice(&self.re.to_bits().to_le_bytes());
+        out[4..8].copy_from_slice(&self.im.to_bits().to_le_bytes());
+    }
+
+    fn decode(bytes: &[u8]) -> Self {
+        ComplexFixed {
+            re: Fixed::from_bits(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+            im: Fixed::from_bits(i32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]])),
+        }
+    }
+}
+
+/// Table sizes and settings shared by both plan kinds.
+struct Layout {
+    kind: u8,
+    n: usize,
+    twiddles: usize,
+    bitrev: usize,
+    scaling: Scaling,
+    algorithm: Algorithm,
+    hygiene: Hygiene,
+}
+
+impl Layout {
+    /// Total size of the export, or `None` if it does not fit a `usize`.
+    /// The counts may come from an untrusted header, so a 32-bit target
+    /// must not wrap them into a small, plausible length.
+    fn byte_len<T: TableEntry>(&self) -> Option<usize> {
+        let twiddles = self.twiddles.checked_mul(T::BYTES)?;
+        let bitrev = self.bitrev.checked_mul(4)?;
+        HEADER_LEN.checked_add(twiddles)?.checked_add(bitrev)
+    }
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> usize {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]) as usize
+}
+
+fn export<T: TableEntry>(
+    layout: &Layout,
+    twiddles: &[T],
+    bitrev: &[usize],
+    out: &mut [u8],
+) -> Result<usize, FftError> {
+    let len = layout.byte_len::<T>().ok_or(FftError::OutOfRange)?;
+    let out = out.get_mut(..len).ok_or(FftError::BufferTooSmall)?;
+    out[..4].copy_from_slice(&MAGIC);
+    out[4] = VERSION;
+    out[5] = layout.kind;
+    out[6] = T::TAG;
+    out[7] = match layout.scaling {
+        Scaling::None => 0,
+        Scaling::Forward => 1,
+        Scaling::Inverse => 2,
+    };
+    out[8] = match layout.algorithm {
+        Algorithm::Dit => 0,
+        Algorithm::Dif => 1,
+    };
+    out[9] = match layout.hygiene {
+        Hygiene::Off => 0,
+        Hygiene::Detect => 1,
+        Hygiene::Scrub => 2,
+    };
+    out[10..12].fill(0);
+    for (i, v) in [layout.n, layout.twiddles, layout.bitrev]
+        .iter()
+        .enumerate()
+    {
+        let v = u32::try_from(*v).map_err(|_| FftError::OutOfRange)?;
+        out[12 + 4 * i..16 + 4 * i].copy_from_slice(&v.to_le_bytes());
+    }
+
+    let (tw_bytes, br_bytes) = out[HEADER_LEN..].split_at_mut(layout.twiddles * T::BYTES);
+    for (chunk, t) in tw_bytes.chunks_exact_mut(T::BYTES).zip(twiddles) {
+        t.encode(chunk);
+    }
+    for (chunk, &b) in br_bytes.chunks_exact_mut(4).zip(bitrev) {
+        chunk.copy_from_slice(&(b as u32).to_le_bytes());
+    }
+    Ok(len)
+}
+
+/// Validates the header and copies the tables. Any inconsistency in the
+/// data, including a swap list that is not the exact bit-reversal, is
+/// reported as `InvalidFormat`; tables that are too short as
+/// `BufferTooSmall`.
+fn import<T: TableEntry>(
+    kind: u8,
+    bytes: &[u8],
+    twiddles: &mut [T],
+    bitrev: &mut [usize],
+) -> Result<Layout, FftError> {
+    if bytes.len() < HEADER_LEN
+        || bytes[..4] != MAGIC
+        || bytes[4] != VERSION
+        || bytes[5] != kind
+        || bytes[6] != T::TAG
+    {
+        return Err(FftError::InvalidFormat);
+    }
+    let scaling = match bytes[7] {
+        0 => Scaling::None,
+        1 => Scaling::Forward,
+        2 => Scaling::Inverse,
+        _ => return Err(FftError::InvalidFormat),
+    };
+    let algorithm = match bytes[8] {
+        0 => Algorithm::Dit,
+        1 => Algorithm::Dif,
+        _ => return Err(FftError::InvalidFormat),
+    };
+    // Formerly reserved and zeroed, so older exports read as `Off`
+    let hygiene = match bytes[9] {
+        0 => Hygiene::Off,
+        1 => Hygiene::Detect,
+        2 => Hygiene::Scrub,
+        _ => return Err(FftError::InvalidFormat),
+    };
+    let layout = Layout {
+        kind,
+        n: read_u32(bytes, 12),
+        twiddles: read_u32(bytes, 16),
+        bitrev: read_u32(bytes, 20),
+        scaling,
+        algorithm,
+        hygiene,
+    };
+    let (min_twiddles, min_bitrev) = required(kind, layout.n);
+    if !layout.n.is_power_of_two()
+        || layout.twiddles < min_twiddles
+        || layout.bitrev < min_bitrev
+        || layout.byte_len::<T>() != Some(bytes.len())
+    {
+        return Err(FftError::InvalidFormat);
+    }
+    if twiddles.len() < layout.twiddles || bitrev.len() < layout.bitrev {
+        return Err(FftError::BufferTooSmall);
+    }
+
+    let (tw_bytes, br_bytes) = bytes[HEADER_LEN..].split_at(layout.twiddles * T::BYTES);
+    for (t, chunk) in twiddles.iter_mut().zip(tw_bytes.chunks_exact(T::BYTES)) {
+        *t = T::decode(chunk);
+    }
+    for (b, chunk) in bitrev.iter_mut().zip(br_bytes.chunks_exact(4)) {
+        *b = read_u32(chunk, 0);
+    }
+    let core_n = if kind == KIND_COMPLEX {
+        layout.n
+    } else {
+        layout.n / 2
+    };
+    if !swaps_are_exact(bitrev, core_n) {
+        return Err(FftError::InvalidFormat);
+    }
+    Ok(layout)
+}
+
+/// Table lengths a plan of this kind and size uses.
+fn required(kind: u8, n: usize) -> (usize, usize) {
+    match kind {
+        KIND_COMPLEX => (n / 2, n),
+        _ => (n / 2, n / 2),
+    }
+}
+
+impl<'a, T: TableEntry> CplxFft<'a, T> {
+    fn layout(&self) -> Layout {
+        let (twiddles, bitrev) = required(KIND_COMPLEX, self.n);
+        Layout {
+            kind: KIND_COMPLEX,
+            n: self.n,
+            twiddles,
+            bitrev,
+            scaling: self.scaling,
+            algorithm: self.algorithm,
+            hygiene: self.hygiene,
+        }
+    }
+
+    /// Size in bytes of [`Self::export`]'s output.
+    pub fn exported_len(&self) -> usize {
+        // Tables already held in memory cannot overflow the count
+        self.layout().byte_len::<T>().unwrap_or(usize::MAX)
+    }
+
+    /// Writes the plan's tables and settings into `out` and returns the
+    /// number of bytes used.
+    pub fn export(&self, out: &mut [u8]) -> Result<usize, FftError> {
+        export(&self.layout(), self.twiddles, self.bitrev, out)
+    }
+
+    /// Restores a plan exported by [`Self::export`] into the caller's
+    /// tables, without recomputing them.
+    pub fn import(
+        twiddles: &'a mut [T],
+        bitrev: &'a mut [usize],
+        bytes: &[u8],
+    ) -> Result<Self, FftError> {
+        let layout = import(KIND_COMPLEX, bytes, twiddles, bitrev)?;
+        Ok(Self {
+            twiddles,
+            bitrev,
+            n: layout.n,
+            scaling: layout.scaling,
+            algorithm: layout.algorithm,
+            hygiene: layout.hygiene,
+        })
+    }
+}
+
+impl<'a, T: TableEntry> RealFft<'a, T> {
+    fn layout(&self) -> Layout {
+        let (twiddles, bitrev) = required(KIND_REAL, self.n);
+        Layout {
+            kind: KIND_REAL,
+            n: self.n,
+            twiddles,
+            bitrev,
+            scaling: self.scaling,
+            algorithm: self.algorithm,
+            hygiene: self.hygiene,
+        }
+    }
+
+    /// Size in bytes of [`Self::export`]'s output.
+    pub fn exported_len(&self) -> usize {
+        // Tables already held in memory cannot overflow the count
+        self.layout().byte_len::<T>().unwrap_or(usize::MAX)
+    }
+
+    /// Writes the plan's tables and settings into `out` and returns the
+    /// number of bytes used.
+    pub fn export(&self, out: &mut [u8]) -> Result<usize, FftError> {
+        export(&self.layout(), self.twiddles, self.bitrev, out)
+    }
+
+    /// Restores a plan exported by [`Self::export`] into the caller's
+    /// tables, without recomputing them.
+    pub fn import(
+        twiddles: &'a mut [T],
+        bitrev: &'a mut [usize],
+        bytes: &[u8],
+    ) -> Result<Self, FftError> {
+        let layout = import(KIND_REAL, bytes, twiddles, bitrev)?;
+        Ok(Self {
+            twiddles,
+            bitrev,
+            n: layout.n,
+            scaling: layout.scaling,
+            algorithm: layout.algorithm,
+            hygiene: layout.hygiene,
+        })
+    }
+}
+
+#[cfg(test)]
+#[path = "plan_io_tests.rs"]
+mod tests;
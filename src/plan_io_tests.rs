@@ -0,0 +1,95 @@
+use super::*;
+
+#[test]
+fn test_complex_round_trip() {
+    let n = 64;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let plan = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::Forward)
+        .with_algorithm(Algorithm::Dif)
+        .with_hygiene(Hygiene::Scrub);
+    let mut bytes = vec![0u8; plan.exported_len()];
+    assert_eq!(plan.export(&mut bytes).unwrap(), bytes.len());
+
+    let mut tw2 = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut br2 = vec![0; n];
+    let restored = CplxFft::<Complex32>::import(&mut tw2, &mut br2, &bytes).unwrap();
+    assert_eq!(restored.n, n);
+    assert_eq!(restored.scaling, Scaling::Forward);
+    assert_eq!(restored.algorithm, Algorithm::Dif);
+    assert_eq!(restored.hygiene, Hygiene::Scrub);
+
+    let input: Vec<Complex32> = (0..n)
+        .map(|i| Complex32::new(i as f32, -(i as f32) * 0.5))
+        .collect();
+    let (mut a, mut b) = (input.clone(), input);
+    plan.process(&mut a, false).unwrap();
+    restored.process(&mut b, false).unwrap();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_fixed_real_round_trip() {
+    let n = 32;
+    let zero = ComplexFixed::<TWIDDLE_FRAC>::default();
+    let mut twiddles = vec![zero; n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let plan = RealFft::<ComplexFixed<TWIDDLE_FRAC>>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let mut bytes = vec![0u8; plan.exported_len()];
+    plan.export(&mut bytes).unwrap();
+
+    let mut tw2 = vec![zero; n / 2];
+    let mut br2 = vec![0; n / 2];
+    let restored =
+        RealFft::<ComplexFixed<TWIDDLE_FRAC>>::import(&mut tw2, &mut br2, &bytes).unwrap();
+    assert_eq!(restored.twiddles, plan.twiddles);
+    assert_eq!(restored.bitrev, plan.bitrev);
+}
+
+#[test]
+fn test_rejects_mismatched_data() {
+    let n = 16;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let plan = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let mut bytes = vec![0u8; plan.exported_len()];
+    assert_eq!(plan.export(&mut bytes[..10]), Err(FftError::BufferTooSmall));
+    plan.export(&mut bytes).unwrap();
+
+    let mut tw2 = vec![Complex32::new(0.0, 0.0); n];
+    let mut br2 = vec![0; n];
+    // A real plan is not a complex plan
+    assert_eq!(
+        CplxFft::<Complex32>::import(&mut tw2, &mut br2, &bytes).err(),
+        Some(FftError::InvalidFormat)
+    );
+    assert_eq!(
+        RealFft::<Complex32>::import(&mut tw2, &mut br2, &bytes[..bytes.len() - 1]).err(),
+        Some(FftError::InvalidFormat)
+    );
+    assert_eq!(
+        RealFft::<Complex32>::import(&mut tw2[..2], &mut br2, &bytes).err(),
+        Some(FftError::BufferTooSmall)
+    );
+    // A swap entry past the first pair is still checked on import
+    let mut edited = bytes.clone();
+    edited[HEADER_LEN + (n / 2) * 8 + 8] = 5;
+    assert_eq!(
+        RealFft::<Complex32>::import(&mut tw2, &mut br2, &edited).err(),
+        Some(FftError::InvalidFormat)
+    );
+    // Header counts whose byte size overflows are rejected, not wrapped
+    let mut edited = bytes.clone();
+    edited[16..24].fill(0xff);
+    assert_eq!(
+        RealFft::<Complex32>::import(&mut tw2, &mut br2, &edited).err(),
+        Some(FftError::InvalidFormat)
+    );
+    bytes[0] = b'X';
+    assert_eq!(
+        RealFft::<Complex32>::import(&mut tw2, &mut br2, &bytes).err(),
+        Some(FftError::InvalidFormat)
+    );
+}
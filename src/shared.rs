@@ -0,0 +1,49 @@
+// src/shared.rs
+//
+// `FftProcess` for plans behind a reference or a lock, so a plan kept in a
+// global or shared between tasks can be handed to generic code directly.
+
+use crate::common::{FftError, FftProcess};
+use core::cell::RefCell;
+
+impl<T, P: FftProcess<T> + ?Sized> FftProcess<T> for &P {
+    fn process(&self, buffer: &mut [T], inverse: bool) -> Result<(), FftError> {
+        (**self).process(buffer, inverse)
+    }
+}
+
+/// Processing only needs a shared borrow. A plan that is mutably borrowed
+/// at the time (e.g. being re-planned) gives `FftError::Busy` instead of a
+/// panic.
+impl<T, P: FftProcess<T>> FftProcess<T> for RefCell<P> {
+    fn process(&self, buffer: &mut [T], inverse: bool) -> Result<(), FftError> {
+        self.try_borrow()
+            .map_err(|_| FftError::Busy)?
+            .process(buffer, inverse)
+    }
+}
+
+/// Holds the lock for the duration of the transform. A poisoned lock is
+/// still used: `process` never leaves a plan half-updated.
+#[cfg(feature = "std")]
+impl<T, P: FftProcess<T>> FftProcess<T> for std::sync::Mutex<P> {
+    fn process(&self, buffer: &mut [T], inverse: bool) -> Result<(), FftError> {
+        self.lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .process(buffer, inverse)
+    }
+}
+
+/// The usual embedded singleton. The whole transform runs inside the
+/// critical section, so interrupts stay masked for its duration on
+/// single-core targets.
+#[cfg(feature = "critical-section")]
+impl<T, P: FftProcess<T>> FftProcess<T> for critical_section::Mutex<RefCell<P>> {
+    fn process(&self, buffer: &mut [T], inverse: bool) -> Result<(), FftError> {
+        critical_section::with(|cs| self.borrow(cs).process(buffer, inverse))
+    }
+}
+
+#[cfg(test)]
+#[path = "shared_tests.rs"]
+mod tests;
@@ -0,0 +1,67 @@
+use crate::common::{CplxFft, FftError, FftProcess};
+use crate::float::types::Complex32;
+use core::cell::RefCell;
+
+fn impulse(n: usize) -> Vec<Complex32> {
+    let mut buffer = vec![Complex32::new(0.0, 0.0); n];
+    buffer[0] = Complex32::new(1.0, 0.0);
+    buffer
+}
+
+/// Generic caller, as user code would write it.
+fn forward<P: FftProcess<Complex32>>(plan: &P, buffer: &mut [Complex32]) -> Result<(), FftError> {
+    plan.process(buffer, false)
+}
+
+#[test]
+fn test_refcell_plan() {
+    let n = 8;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let plan = RefCell::new(CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap());
+
+    let mut buffer = impulse(n);
+    forward(&plan, &mut buffer).unwrap();
+    assert!(
+        buffer
+            .iter()
+            .all(|c| (c.re - 1.0).abs() < 1e-6 && c.im.abs() < 1e-6)
+    );
+    // Through a reference as well
+    forward(&&plan, &mut buffer).unwrap();
+
+    let _guard = plan.borrow_mut();
+    assert_eq!(forward(&plan, &mut impulse(n)), Err(FftError::Busy));
+}
+
+#[test]
+fn test_mutex_plan() {
+    let n = 8;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let plan =
+        std::sync::Mutex::new(CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap());
+
+    let mut buffer = impulse(n);
+    forward(&plan, &mut buffer).unwrap();
+    assert!(buffer.iter().all(|c| (c.re - 1.0).abs() < 1e-6));
+    assert_eq!(
+        forward(&plan, &mut impulse(n / 2)),
+        Err(FftError::SizeMismatch)
+    );
+}
+
+#[cfg(feature = "critical-section")]
+#[test]
+fn test_critical_section_plan() {
+    let n = 8;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let plan = critical_section::Mutex::new(RefCell::new(
+        CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap(),
+    ));
+
+    let mut buffer = impulse(n);
+    forward(&plan, &mut buffer).unwrap();
+    assert!(buffer.iter().all(|c| (c.re - 1.0).abs() < 1e-6));
+}
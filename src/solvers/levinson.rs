@@ -0,0 +1,129 @@
+// src/solvers/levinson.rs
+
+//! Levinson-Durbin recursion: solves the Toeplitz normal equations of
+//! linear prediction from an autocorrelation sequence in O(p²).
+//!
+//! Coefficients follow the error-filter convention
+//! `A(z) = 1 + a[0] z^-1 + ... + a[p-1] z^-p`, so the prediction is
+//! `x[n] ~ -(a[0] x[n-1] + ... + a[p-1] x[n-p])`. Reflection coefficients
+//! use the same sign.
+
+use crate::common::FftError;
+use crate::fixed::Fixed;
+
+/// Order-p prediction from `r[0..=p]` (p = `a.len()` = `k.len()`), writing
+/// the prediction coefficients to `a` and the reflection coefficients to
+/// `k`. Returns the prediction error power, in the units of `r[0]`.
+///
+/// Fails with `OutOfRange` when `r[0]` is not positive or a reflection
+/// coefficient reaches magnitude 1, i.e. `r` is not a valid (positive
+/// definite) autocorrelation.
+pub fn levinson_durbin(r: &[f32], a: &mut [f32], k: &mut [f32]) -> Result<f32, FftError> {
+    let p = a.len();
+    if k.len() != p || r.len() <= p {
+        return Err(FftError::SizeMismatch);
+    }
+    if r[0].is_nan() || r[0] <= 0.0 {
+        return Err(FftError::OutOfRange);
+    }
+
+    let mut err = r[0];
+    for i in 0..p {
+        let acc = r[i + 1] + (0..i).map(|j| a[j] * r[i - j]).sum::<f32>();
+        let ki = -acc / err;
+        if ki.is_nan() || libm::fabsf(ki) >= 1.0 {
+            return Err(FftError::OutOfRange);
+        }
+        k[i] = ki;
+
+        // a[j] += k a[i-1-j], both ends of the pair at once
+        let (mut lo, mut hi) = (0, i);
+        while lo + 1 < hi {
+            hi -= 1;
+            let (x, y) = (a[lo], a[hi]);
+            a[lo] = x + ki * y;
+            a[hi] = y + ki * x;
+            lo += 1;
+        }
+        if lo + 1 == hi {
+            a[lo] += ki * a[lo];
+        }
+        a[i] = ki;
+        err *= 1.0 - ki * ki;
+    }
+    Ok(err)
+}
+
+/// Fixed-point [`levinson_durbin`] for MCUs without an FPU.
+///
+/// `r` may carry any common scale (only ratios matter); it is normalized
+/// internally. The recursion runs on the caller's `Q<AF>` coefficients with
+/// 128-bit accumulators: choose AF to leave room for the largest
+/// coefficient (Q27 covers ±16, enough for speech up to order 16 or so).
+/// Reflection coefficients come out in Q31. Returns the prediction error
+/// relative to `r[0]`, in Q31.
+pub fn levinson_durbin_fixed<const FRAC: u32, const AF: u32>(
+    r: &[Fixed<FRAC>],
+    a: &mut [Fixed<AF>],
+    k: &mut [Fixed<31>],
+) -> Result<Fixed<31>, FftError> {
+    let p = a.len();
+    if k.len() != p || r.len() <= p {
+        return Err(FftError::SizeMismatch);
+    }
+    let r0 = r[0].to_bits() as i64;
+    if r0 <= 0 || AF > 30 {
+        return Err(FftError::OutOfRange);
+    }
+    // Brings r[0] to bit 30; |r[t]| <= r[0] for a valid sequence
+    let shift = r0.leading_zeros() as i32 - 33;
+    let rn = |t: usize| -> i128 {
+        let bits = r[t].to_bits() as i128;
+        if shift >= 0 {
+            bits << shift
+        } else {
+            bits >> -shift
+        }
+    };
+    let round = |x: i128, s: u32| (x + (1 << (s - 1))) >> s;
+
+    let mut err = rn(0);
+    for i in 0..p {
+        let mut acc = rn(i + 1) << AF;
+        for (j, aj) in a[..i].iter().enumerate() {
+            acc += aj.to_bits() as i128 * rn(i - j);
+        }
+        // k in Q31: acc / err is in Q<AF>
+        let ki = -(acc << (31 - AF)) / err;
+        if ki.abs() >= 1 << 31 {
+            return Err(FftError::OutOfRange);
+        }
+        k[i] = Fixed::from_bits(ki as i32);
+
+        let update = |x: Fixed<AF>, y: Fixed<AF>| {
+            Fixed::<AF>::from_bits(
+                (x.to_bits() as i128 + round(ki * y.to_bits() as i128, 31)) as i32,
+            )
+        };
+        let (mut lo, mut hi) = (0, i);
+        while lo + 1 < hi {
+            hi -= 1;
+            let (x, y) = (a[lo], a[hi]);
+            a[lo] = update(x, y);
+            a[hi] = update(y, x);
+            lo += 1;
+        }
+        if lo + 1 == hi {
+            a[lo] = update(a[lo], a[lo]);
+        }
+        a[i] = Fixed::from_bits(round(ki, 31 - AF) as i32);
+        err -= round(err * ki * ki, 62);
+    }
+    Ok(Fixed::from_bits(
+        ((err << 31) / rn(0)).min(i32::MAX as i128) as i32,
+    ))
+}
+
+#[cfg(test)]
+#[path = "levinson_tests.rs"]
+mod tests;
@@ -0,0 +1,83 @@
+use super::*;
+
+#[test]
+fn test_first_order_process() {
+    // Autocorrelation of an AR(1) process with pole 0.5
+    let r = [1.0f32, 0.5, 0.25, 0.125];
+    let mut a = [0.0f32; 3];
+    let mut k = [0.0f32; 3];
+    let err = levinson_durbin(&r, &mut a, &mut k).unwrap();
+    for (x, e) in a.iter().zip([-0.5, 0.0, 0.0]) {
+        assert!((x - e).abs() < 1e-6);
+    }
+    for (x, e) in k.iter().zip([-0.5, 0.0, 0.0]) {
+        assert!((x - e).abs() < 1e-6);
+    }
+    assert!((err - 0.75).abs() < 1e-6);
+
+    let rq: Vec<Fixed<20>> = r.iter().map(|&v| Fixed::from_f64(v as f64)).collect();
+    let mut aq = [Fixed::<27>::ZERO; 3];
+    let mut kq = [Fixed::<31>::ZERO; 3];
+    let errq = levinson_durbin_fixed(&rq, &mut aq, &mut kq).unwrap();
+    for (x, e) in aq.iter().zip(&a) {
+        assert!((x.to_bits() as f32 / (1 << 27) as f32 - e).abs() < 1e-6);
+    }
+    for (x, e) in kq.iter().zip(&k) {
+        assert!((x.to_bits() as f64 / 2f64.powi(31) - *e as f64).abs() < 1e-6);
+    }
+    assert!((errq.to_bits() as f64 / 2f64.powi(31) - 0.75).abs() < 1e-6);
+}
+
+#[test]
+fn test_second_order_matches_float() {
+    // AR(2) x[n] = 1.3 x[n-1] - 0.6 x[n-2] + e[n]: rho1 = 1.3 / 1.6,
+    // rho2 = 1.3 rho1 - 0.6, and the recursion continues for higher lags
+    let mut r = [1.0f32, 1.3 / 1.6, 0.0, 0.0, 0.0];
+    for t in 2..r.len() {
+        r[t] = 1.3 * r[t - 1] - 0.6 * r[t - 2];
+    }
+    let mut a = [0.0f32; 4];
+    let mut k = [0.0f32; 4];
+    levinson_durbin(&r, &mut a, &mut k).unwrap();
+    for (x, e) in a.iter().zip([-1.3, 0.6, 0.0, 0.0]) {
+        assert!((x - e).abs() < 1e-4, "{a:?}");
+    }
+
+    // Scaled up to show that only ratios matter
+    let rq: Vec<Fixed<8>> = r.iter().map(|&v| Fixed::from_f64(v as f64 * 1e5)).collect();
+    let mut aq = [Fixed::<27>::ZERO; 4];
+    let mut kq = [Fixed::<31>::ZERO; 4];
+    levinson_durbin_fixed(&rq, &mut aq, &mut kq).unwrap();
+    for (x, e) in aq.iter().zip(&a) {
+        assert!(
+            (x.to_bits() as f32 / (1 << 27) as f32 - e).abs() < 1e-4,
+            "{aq:?}"
+        );
+    }
+}
+
+#[test]
+fn test_invalid_sequences() {
+    let mut a = [0.0f32; 2];
+    let mut k = [0.0f32; 2];
+    assert_eq!(
+        levinson_durbin(&[0.0, 0.0, 0.0], &mut a, &mut k),
+        Err(FftError::OutOfRange)
+    );
+    // |r[1]| > r[0] cannot come from a real signal
+    assert_eq!(
+        levinson_durbin(&[1.0, 1.5, 0.0], &mut a, &mut k),
+        Err(FftError::OutOfRange)
+    );
+    assert_eq!(
+        levinson_durbin(&[1.0, 0.5], &mut a, &mut k),
+        Err(FftError::SizeMismatch)
+    );
+    let mut aq = [Fixed::<27>::ZERO; 2];
+    let mut kq = [Fixed::<31>::ZERO; 2];
+    let one = Fixed::<20>::from_f64(1.0);
+    assert_eq!(
+        levinson_durbin_fixed(&[one, one + one, Fixed::ZERO], &mut aq, &mut kq),
+        Err(FftError::OutOfRange)
+    );
+}
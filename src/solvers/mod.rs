@@ -0,0 +1,7 @@
+pub mod levinson;
+pub mod poisson;
+
+pub use levinson::{levinson_durbin, levinson_durbin_fixed};
+pub use poisson::{
+    poisson_1d_dirichlet, poisson_1d_periodic, poisson_2d_dirichlet, poisson_2d_periodic,
+};
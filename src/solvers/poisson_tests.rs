@@ -0,0 +1,125 @@
+use super::*;
+
+fn real_plan<'a>(
+    twiddles: &'a mut [Complex32],
+    bitrev: &'a mut [usize],
+    n: usize,
+) -> RealFft<'a, Complex32> {
+    RealFft::<Complex32>::new(twiddles, bitrev, n).unwrap()
+}
+
+fn source(i: usize) -> f32 {
+    ((i * 7) % 11) as f32 * 0.3 - 1.2
+}
+
+#[test]
+fn test_1d_dirichlet_satisfies_stencil() {
+    let m = 15;
+    let h = 0.1;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); m + 1];
+    let mut bitrev = vec![0; m + 1];
+    let fft = real_plan(&mut twiddles, &mut bitrev, 2 * (m + 1));
+    let f: Vec<f32> = (0..m).map(source).collect();
+    let mut u = f.clone();
+    let mut scratch = vec![0.0; 2 * (m + 1)];
+    poisson_1d_dirichlet(&fft, &mut u, h, &mut scratch).unwrap();
+
+    for j in 0..m {
+        let left = if j == 0 { 0.0 } else { u[j - 1] };
+        let right = if j == m - 1 { 0.0 } else { u[j + 1] };
+        let lap = (left - 2.0 * u[j] + right) / (h * h);
+        assert!((lap - f[j]).abs() < 1e-3, "{j}: {lap} vs {}", f[j]);
+    }
+}
+
+#[test]
+fn test_1d_periodic_removes_mean() {
+    let n = 32;
+    let h = 0.5;
+    for scaling in [Scaling::Inverse, Scaling::None] {
+        let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+        let mut bitrev = vec![0; n / 2];
+        let fft = real_plan(&mut twiddles, &mut bitrev, n).with_scaling(scaling);
+        let f: Vec<f32> = (0..n).map(source).collect();
+        let mean = f.iter().sum::<f32>() / n as f32;
+        let mut u = f.clone();
+        poisson_1d_periodic(&fft, &mut u, h).unwrap();
+
+        assert!(u.iter().sum::<f32>().abs() < 1e-3);
+        for j in 0..n {
+            let lap = (u[(j + n - 1) % n] - 2.0 * u[j] + u[(j + 1) % n]) / (h * h);
+            assert!((lap - (f[j] - mean)).abs() < 1e-3);
+        }
+    }
+}
+
+#[test]
+fn test_2d_dirichlet_satisfies_stencil() {
+    let (nx, ny) = (7, 15);
+    let (hx, hy) = (0.2, 0.1);
+    let mut tx = vec![Complex32::new(0.0, 0.0); nx + 1];
+    let mut bx = vec![0; nx + 1];
+    let mut ty = vec![Complex32::new(0.0, 0.0); ny + 1];
+    let mut by = vec![0; ny + 1];
+    let fft_x = real_plan(&mut tx, &mut bx, 2 * (nx + 1));
+    let fft_y = real_plan(&mut ty, &mut by, 2 * (ny + 1));
+
+    let f: Vec<f32> = (0..nx * ny).map(source).collect();
+    let mut u = f.clone();
+    let mut scratch = vec![0.0; ny + 2 * (ny + 1)];
+    poisson_2d_dirichlet(&fft_x, &fft_y, &mut u, nx, (hx, hy), &mut scratch).unwrap();
+
+    let at = |x: isize, y: isize| {
+        if x < 0 || y < 0 || x >= nx as isize || y >= ny as isize {
+            0.0
+        } else {
+            u[y as usize * nx + x as usize]
+        }
+    };
+    for y in 0..ny as isize {
+        for x in 0..nx as isize {
+            let c = at(x, y);
+            let lap = (at(x - 1, y) - 2.0 * c + at(x + 1, y)) / (hx * hx)
+                + (at(x, y - 1) - 2.0 * c + at(x, y + 1)) / (hy * hy);
+            assert!((lap - f[y as usize * nx + x as usize]).abs() < 1e-3);
+        }
+    }
+
+    assert_eq!(
+        poisson_2d_dirichlet(&fft_x, &fft_y, &mut u, nx, (hx, hy), &mut scratch[..4]),
+        Err(FftError::BufferTooSmall)
+    );
+}
+
+#[test]
+fn test_2d_periodic_solves_two_problems() {
+    let (nx, ny) = (8, 16);
+    let mut tx = vec![Complex32::new(0.0, 0.0); nx / 2];
+    let mut bx = vec![0; nx];
+    let mut ty = vec![Complex32::new(0.0, 0.0); ny / 2];
+    let mut by = vec![0; ny];
+    let fft_x = CplxFft::<Complex32>::new(&mut tx, &mut bx, nx).unwrap();
+    let fft_y = CplxFft::<Complex32>::new(&mut ty, &mut by, ny)
+        .unwrap()
+        .with_scaling(Scaling::None);
+
+    let f: Vec<Complex32> = (0..nx * ny)
+        .map(|i| Complex32::new(source(i), source(i + 3)))
+        .collect();
+    let mean = f
+        .iter()
+        .fold(Complex32::new(0.0, 0.0), |a, &b| a + b)
+        .scale(1.0 / (nx * ny) as f32);
+    let mut u = f.clone();
+    let mut column = vec![Complex32::new(0.0, 0.0); ny];
+    poisson_2d_periodic(&fft_x, &fft_y, &mut u, (1.0, 1.0), &mut column).unwrap();
+
+    for y in 0..ny {
+        for x in 0..nx {
+            let at = |dx: usize, dy: usize| u[((y + dy) % ny) * nx + (x + dx) % nx];
+            let lap = at(nx - 1, 0) + at(1, 0) + at(0, ny - 1) + at(0, 1) - at(0, 0).scale(4.0);
+            let err = lap - (f[y * nx + x] - mean);
+            assert!(err.norm_sqr() < 1e-8);
+        }
+    }
+}
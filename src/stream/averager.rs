@@ -0,0 +1,189 @@
+// src/stream/averager.rs
+
+use super::frame_assembler::FrameAssembler;
+use crate::analysis::WindowCorrection;
+use crate::common::{FftError, RealFft};
+use crate::float::types::Complex32;
+
+/// How successive spectra are combined by an [`Averager`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AverageMode {
+    /// Mean of the power in each bin (power or "RMS" averaging). Reduces
+    /// the variance of the noise floor without lowering it.
+    #[default]
+    Rms,
+    /// Largest magnitude seen in each bin (peak hold).
+    Peak,
+    /// Mean of the complex spectrum (vector averaging). Lowers the noise
+    /// floor for signals that are synchronous with the frames.
+    Linear,
+}
+
+/// Averaged spectrum analyzer over overlapping, windowed frames.
+///
+/// Samples are pushed in arbitrary chunks; every complete frame is
+/// windowed, transformed and folded into the running average selected by
+/// [`AverageMode`]. The overlap is set through the hop: `N/2` for 50 %,
+/// `N/4` for 75 %.
+///
+/// Results are corrected for the window: [`Self::amplitude`] reads the peak
+/// amplitude of a sinusoid centred on a bin, and [`Self::enbw`] converts
+/// power readings into noise density.
+pub struct Averager<'a> {
+    fft: RealFft<'a, Complex32>,
+    assembler: FrameAssembler<'a>,
+    frame: &'a mut [f32],
+    acc: &'a mut [f32],
+    mode: AverageMode,
+    count: u32,
+    correction: WindowCorrection,
+}
+
+impl<'a> Averager<'a> {
+    /// Builds an averager around an N-point plan.
+    ///
+    /// `window`, `frame` and `acc` must have N elements and `ring` at least
+    /// N (see [`FrameAssembler::new`]).
+    pub fn new(
+        fft: RealFft<'a, Complex32>,
+        window: &'a [f32],
+        hop: usize,
+        ring: &'a mut [f32],
+        frame: &'a mut [f32],
+        acc: &'a mut [f32],
+        mode: AverageMode,
+    ) -> Result<Self, FftError> {
+        let n = fft.n;
+        if frame.len() != n || acc.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+        let assembler = FrameAssembler::new(ring, n, hop, Some(window))?;
+
+        let correction = WindowCorrection::from_coefficients(window)?;
+        acc.fill(0.0);
+
+        Ok(Self {
+            fft,
+            assembler,
+            frame,
+            acc,
+            mode,
+            count: 0,
+            correction,
+        })
+    }
+
+    /// Feeds samples and returns the number of frames averaged by this
+    /// call. Incomplete frames stay buffered.
+    pub fn push(&mut self, samples: &[f32]) -> Result<usize, FftError> {
+        let mut frames = 0;
+        let mut rest = samples;
+        while !rest.is_empty() {
+            let taken = self.assembler.push(rest);
+            rest = &rest[taken..];
+            while self.assembler.next_frame(self.frame)? {
+                self.accumulate()?;
+                frames += 1;
+            }
+        }
+        Ok(frames)
+    }
+
+    /// Number of frames in the current average.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn mode(&self) -> AverageMode {
+        self.mode
+    }
+
+    /// Mean of the window, the factor by which it attenuates a tone.
+    pub fn coherent_gain(&self) -> f32 {
+        self.correction.coherent_gain
+    }
+
+    /// Equivalent noise bandwidth of the window, in bins.
+    pub fn enbw(&self) -> f32 {
+        self.correction.enbw
+    }
+
+    /// Writes the averaged amplitude spectrum (bins 0..=N/2) into `out`,
+    /// in peak units of the input signal and corrected for the window's
+    /// coherent gain. Before the first frame, `out` is zeroed.
+    pub fn amplitude(&self, out: &mut [f32]) -> Result<(), FftError> {
+        let n = self.acc.len();
+        if out.len() != n / 2 + 1 {
+            return Err(FftError::SizeMismatch);
+        }
+        if self.count == 0 {
+            out.fill(0.0);
+            return Ok(());
+        }
+
+        // Single-sided: every bin but DC and Nyquist holds half the tone
+        let scale = 2.0 / (n as f32 * self.correction.coherent_gain);
+        let count = self.count as f32;
+        for (k, o) in out.iter_mut().enumerate() {
+            let mag = match self.mode {
+                AverageMode::Rms => libm::sqrtf(self.acc[k] / count),
+                AverageMode::Peak => self.acc[k],
+                AverageMode::Linear => packed_magnitude(self.acc, k) / count,
+            };
+            let edge = k == 0 || k == n / 2;
+            *o = mag * if edge { scale / 2.0 } else { scale };
+        }
+        Ok(())
+    }
+
+    /// Forgets the average and any buffered samples.
+    pub fn reset(&mut self) {
+        self.assembler.reset();
+        self.acc.fill(0.0);
+        self.count = 0;
+    }
+
+    fn accumulate(&mut self) -> Result<(), FftError> {
+        self.fft.process(self.frame, false)?;
+        let n = self.frame.len();
+        // Bring a scaled forward transform back to the unscaled convention
+        if self.fft.scaling.is_scaled(false) {
+            self.frame.iter_mut().for_each(|x| *x *= n as f32);
+        }
+
+        match self.mode {
+            AverageMode::Rms => {
+                for k in 0..=n / 2 {
+                    let m = packed_magnitude(self.frame, k);
+                    self.acc[k] += m * m;
+                }
+            }
+            AverageMode::Peak => {
+                for k in 0..=n / 2 {
+                    self.acc[k] = self.acc[k].max(packed_magnitude(self.frame, k));
+                }
+            }
+            AverageMode::Linear => {
+                for (a, &x) in self.acc.iter_mut().zip(self.frame.iter()) {
+                    *a += x;
+                }
+            }
+        }
+        self.count += 1;
+        Ok(())
+    }
+}
+
+/// Magnitude of bin `k` (0..=N/2) of a packed real spectrum.
+fn packed_magnitude(packed: &[f32], k: usize) -> f32 {
+    let n_half = packed.len() / 2;
+    match k {
+        0 => libm::fabsf(packed[0]),
+        _ if k == n_half => libm::fabsf(packed[1]),
+        _ => libm::hypotf(packed[2 * k], packed[2 * k + 1]),
+    }
+}
+
+#[cfg(test)]
+#[path = "averager_tests.rs"]
+mod tests;
@@ -0,0 +1,109 @@
+use super::*;
+use crate::common::Scaling;
+use core::f32::consts::PI;
+
+const N: usize = 64;
+
+fn hann() -> Vec<f32> {
+    (0..N)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / N as f32).cos())
+        .collect()
+}
+
+/// Tone of amplitude 0.5 on bin 8 plus a 0.25 DC offset and pseudo-random
+/// noise of the given peak level.
+fn signal(len: usize, noise: f32) -> Vec<f32> {
+    let mut seed = 3u32;
+    (0..len)
+        .map(|i| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let r = (seed >> 8) as f32 / (1 << 24) as f32 - 0.5;
+            0.25 + 0.5 * (2.0 * PI * 8.0 * i as f32 / N as f32).cos() + noise * r
+        })
+        .collect()
+}
+
+/// Averages `input` with a Hann window and returns (frames, amplitudes).
+fn run(input: &[f32], hop: usize, mode: AverageMode, scaling: Scaling) -> (usize, Vec<f32>) {
+    let window = hann();
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_scaling(scaling);
+    let (mut ring, mut frame, mut acc) = (vec![0.0; N], vec![0.0; N], vec![0.0; N]);
+    let mut avg = Averager::new(fft, &window, hop, &mut ring, &mut frame, &mut acc, mode).unwrap();
+
+    assert!((avg.coherent_gain() - 0.5).abs() < 1e-6);
+    assert!((avg.enbw() - 1.5).abs() < 1e-5);
+
+    // Odd chunk sizes must not matter
+    let mut frames = 0;
+    for chunk in input.chunks(37) {
+        frames += avg.push(chunk).unwrap();
+    }
+    assert_eq!(frames, avg.count() as usize);
+
+    let mut out = vec![0.0; N / 2 + 1];
+    avg.amplitude(&mut out).unwrap();
+    (frames, out)
+}
+
+#[test]
+fn test_window_corrected_amplitude() {
+    let input = signal(16 * N, 0.0);
+    for mode in [AverageMode::Rms, AverageMode::Peak, AverageMode::Linear] {
+        for scaling in [Scaling::Inverse, Scaling::Forward] {
+            let (frames, amp) = run(&input, N / 2, mode, scaling);
+            assert_eq!(frames, 31);
+            assert!((amp[8] - 0.5).abs() < 1e-4, "{mode:?}: {}", amp[8]);
+            assert!((amp[0] - 0.25).abs() < 1e-4, "{mode:?}: {}", amp[0]);
+            assert!(amp[12] < 1e-4);
+        }
+    }
+}
+
+#[test]
+fn test_overlap_and_noise_floor() {
+    let input = signal(32 * N, 0.2);
+    let (frames, rms) = run(&input, N / 4, AverageMode::Rms, Scaling::Inverse);
+    assert_eq!(frames, (32 * N - N) / (N / 4) + 1);
+
+    // Noise is not synchronous with the frames: vector averaging removes
+    // it, power averaging keeps its level, peak hold sits above both
+    let (_, linear) = run(&input, N / 4, AverageMode::Linear, Scaling::Inverse);
+    let (_, peak) = run(&input, N / 4, AverageMode::Peak, Scaling::Inverse);
+    let floor = |a: &[f32]| a[20..30].iter().sum::<f32>() / 10.0;
+    assert!(floor(&linear) < 0.5 * floor(&rms));
+    assert!(floor(&peak) > floor(&rms));
+    assert!((rms[8] - 0.5).abs() < 0.02);
+}
+
+#[test]
+fn test_empty_and_reset() {
+    let window = hann();
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let (mut ring, mut frame, mut acc) = (vec![0.0; N], vec![0.0; N], vec![0.0; N]);
+    let mut avg = Averager::new(
+        fft,
+        &window,
+        N / 2,
+        &mut ring,
+        &mut frame,
+        &mut acc,
+        AverageMode::Peak,
+    )
+    .unwrap();
+
+    let mut out = vec![1.0; N / 2 + 1];
+    avg.amplitude(&mut out).unwrap();
+    assert!(out.iter().all(|&x| x == 0.0));
+
+    avg.push(&signal(2 * N, 0.0)).unwrap();
+    assert_eq!(avg.count(), 3);
+    avg.reset();
+    assert_eq!(avg.count(), 0);
+    assert_eq!(avg.amplitude(&mut [0.0; 4]), Err(FftError::SizeMismatch));
+}
@@ -0,0 +1,144 @@
+// src/stream/block_processor.rs
+
+use super::frame_assembler::FrameAssembler;
+use super::latency::Latency;
+use crate::common::{FftError, RealFft, Scaling};
+use crate::float::types::Complex32;
+use core::slice;
+
+/// Short-time FFT processing pipeline: frames the input, applies a
+/// user-supplied operation to each packed spectrum and resynthesizes the
+/// output by weighted overlap-add.
+///
+/// The same window is used for analysis and synthesis, and the result is
+/// normalized by `hop / sum(w²)`, which is exact whenever the squared window
+/// overlap-adds to a constant at that hop (e.g. a periodic Hann window at
+/// N/4, or its square root at N/2). The plan must use `Scaling::Forward` or
+/// `Scaling::Inverse` so that an unmodified spectrum round-trips.
+///
+/// Output stays sample-aligned with the input: the first `N - hop` output
+/// samples are a fade-in where not all overlapping frames have arrived yet.
+pub struct BlockProcessor<'a> {
+    fft: RealFft<'a, Complex32>,
+    assembler: FrameAssembler<'a>,
+    window: &'a [f32],
+    frame: &'a mut [f32],
+    overlap: &'a mut [f32],
+    hop: usize,
+    norm: f32,
+}
+
+impl<'a> BlockProcessor<'a> {
+    /// Builds the pipeline around an N-point plan.
+    ///
+    /// `window`, `frame` and `overlap` must have N elements and `ring` at
+    /// least N (see [`FrameAssembler::new`]). An unscaled plan, which would
+    /// return N times the input, or an all-zero window fail with
+    /// `FftError::InvalidStride`.
+    pub fn new(
+        fft: RealFft<'a, Complex32>,
+        window: &'a [f32],
+        hop: usize,
+        ring: &'a mut [f32],
+        frame: &'a mut [f32],
+        overlap: &'a mut [f32],
+    ) -> Result<Self, FftError> {
+        let n = fft.n;
+        if frame.len() != n || overlap.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+        if fft.scaling == Scaling::None {
+            return Err(FftError::InvalidStride);
+        }
+        let assembler = FrameAssembler::new(ring, n, hop, Some(window))?;
+
+        let energy: f32 = window.iter().map(|w| w * w).sum();
+        if energy <= 0.0 {
+            return Err(FftError::InvalidStride);
+        }
+        overlap.fill(0.0);
+
+        Ok(Self {
+            fft,
+            assembler,
+            window,
+            frame,
+            overlap,
+            hop,
+            norm: hop as f32 / energy,
+        })
+    }
+
+    /// Pulls samples from `input` until it is exhausted.
+    ///
+    /// Each complete frame is transformed, passed to `process` as a packed
+    /// spectrum (see `RealFft::process`) to be modified in place, and
+    /// transformed back; every finished hop of output is handed to `output`.
+    /// A sample source closure can be adapted with `core::iter::from_fn`.
+    ///
+    /// Samples that do not complete a frame stay buffered for the next call.
+    pub fn run<I, F, O>(&mut self, input: I, mut process: F, mut output: O) -> Result<(), FftError>
+    where
+        I: IntoIterator<Item = f32>,
+        F: FnMut(&mut [f32]),
+        O: FnMut(&[f32]),
+    {
+        for sample in input {
+            self.assembler.push(slice::from_ref(&sample));
+            while self.assembler.next_frame(self.frame)? {
+                self.process_frame(&mut process, &mut output)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Clears the buffered input and the overlap-add state.
+    pub fn reset(&mut self) {
+        self.assembler.reset();
+        self.overlap.fill(0.0);
+    }
+
+    fn process_frame<F, O>(&mut self, process: &mut F, output: &mut O) -> Result<(), FftError>
+    where
+        F: FnMut(&mut [f32]),
+        O: FnMut(&[f32]),
+    {
+        self.fft.process(self.frame, false)?;
+        process(self.frame);
+        self.fft.process(self.frame, true)?;
+
+        for ((acc, &x), &w) in self
+            .overlap
+            .iter_mut()
+            .zip(self.frame.iter())
+            .zip(self.window)
+        {
+            *acc += x * w * self.norm;
+        }
+
+        // The first hop has received all its contributions
+        let hop = self.hop;
+        output(&self.overlap[..hop]);
+        self.overlap.copy_within(hop.., 0);
+        let n = self.overlap.len();
+        self.overlap[n - hop..].fill(0.0);
+
+        Ok(())
+    }
+}
+
+/// Each hop is emitted as soon as the frame that completes it has been
+/// processed, so the framing is the only delay: `N - 1` samples.
+impl Latency for BlockProcessor<'_> {
+    fn latency(&self) -> usize {
+        self.assembler.latency()
+    }
+
+    fn group_delay(&self, freq: f32) -> f32 {
+        self.assembler.group_delay(freq)
+    }
+}
+
+#[cfg(test)]
+#[path = "block_processor_tests.rs"]
+mod tests;
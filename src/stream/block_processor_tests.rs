@@ -0,0 +1,104 @@
+use super::*;
+use crate::common::Scaling;
+use core::f32::consts::PI;
+
+const N: usize = 32;
+
+fn hann() -> Vec<f32> {
+    (0..N)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f32 / N as f32).cos())
+        .collect()
+}
+
+fn signal(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| (i as f32 * 0.21).sin() + 0.3 * (i as f32 * 1.3).cos())
+        .collect()
+}
+
+/// Runs `input` through a processor with a periodic Hann window at N/4 hop.
+fn run_pipeline(input: &[f32], process: impl FnMut(&mut [f32])) -> Vec<f32> {
+    let window = hann();
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let (mut ring, mut frame, mut overlap) = (vec![0.0; N], vec![0.0; N], vec![0.0; N]);
+    let mut bp =
+        BlockProcessor::new(fft, &window, N / 4, &mut ring, &mut frame, &mut overlap).unwrap();
+
+    let mut out = Vec::new();
+    bp.run(input.iter().copied(), process, |hop| {
+        out.extend_from_slice(hop)
+    })
+    .unwrap();
+    out
+}
+
+#[test]
+fn test_identity_reconstructs_input() {
+    let input = signal(256);
+    let out = run_pipeline(&input, |_| {});
+
+    // One hop per frame
+    assert_eq!(out.len(), ((input.len() - N) / (N / 4) + 1) * (N / 4));
+    // Past the fade-in, the output matches the input sample by sample
+    for t in N - N / 4..out.len() {
+        assert!((out[t] - input[t]).abs() < 1e-4, "t = {}", t);
+    }
+}
+
+#[test]
+fn test_spectral_operations() {
+    let input = signal(192);
+
+    let half = run_pipeline(&input, |spec| spec.iter_mut().for_each(|x| *x *= 0.5));
+    for t in N - N / 4..half.len() {
+        assert!((half[t] - 0.5 * input[t]).abs() < 1e-4);
+    }
+
+    let silent = run_pipeline(&input, |spec| spec.fill(0.0));
+    assert!(silent.iter().all(|&x| x == 0.0));
+}
+
+#[test]
+fn test_closure_source_and_errors() {
+    let window = hann();
+    let mut twiddles = vec![Complex32::new(0., 0.); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let (mut ring, mut frame, mut overlap) = (vec![0.0; N], vec![0.0; N], vec![0.0; N - 1]);
+    assert_eq!(
+        BlockProcessor::new(fft, &window, N / 4, &mut ring, &mut frame, &mut overlap).err(),
+        Some(FftError::SizeMismatch)
+    );
+
+    let mut overlap = vec![0.0; N];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N)
+        .unwrap()
+        .with_scaling(Scaling::None);
+    assert_eq!(
+        BlockProcessor::new(fft, &window, N / 4, &mut ring, &mut frame, &mut overlap).err(),
+        Some(FftError::InvalidStride)
+    );
+
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let mut bp =
+        BlockProcessor::new(fft, &window, N / 2, &mut ring, &mut frame, &mut overlap).unwrap();
+
+    let mut t = 0;
+    let source = core::iter::from_fn(|| {
+        t += 1;
+        (t <= 3 * N / 2).then_some(1.0)
+    });
+    let mut hops = 0;
+    bp.run(
+        source,
+        |_| {},
+        |hop| {
+            assert_eq!(hop.len(), N / 2);
+            hops += 1;
+        },
+    )
+    .unwrap();
+    assert_eq!(hops, 2);
+}
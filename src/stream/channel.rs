@@ -0,0 +1,37 @@
+// src/stream/channel.rs
+
+use crate::common::{FftError, FftProcess};
+use core::convert::Infallible;
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::channel::{Receiver, Sender};
+
+/// Async frame processor for embassy (or RTIC with embassy-sync) tasks.
+///
+/// Awaits each `N`-sample frame on `frames`, transforms it in place with
+/// `plan` and forwards the result on `spectra`. The task only yields while
+/// waiting for a frame or for room in the output channel, so a slow consumer
+/// applies back-pressure instead of frames being dropped; the transform
+/// itself runs to completion between awaits.
+///
+/// Runs forever unless a frame fails to process (e.g. `N` does not match
+/// the plan), in which case that error is returned.
+pub async fn process_stream<T, P, M, const N: usize, const IN: usize, const OUT: usize>(
+    plan: &P,
+    frames: Receiver<'_, M, [T; N], IN>,
+    spectra: Sender<'_, M, [T; N], OUT>,
+    inverse: bool,
+) -> Result<Infallible, FftError>
+where
+    P: FftProcess<T>,
+    M: RawMutex,
+{
+    loop {
+        let mut frame = frames.receive().await;
+        plan.process(&mut frame, inverse)?;
+        spectra.send(frame).await;
+    }
+}
+
+#[cfg(test)]
+#[path = "channel_tests.rs"]
+mod tests;
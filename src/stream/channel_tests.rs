@@ -0,0 +1,52 @@
+use super::process_stream;
+use crate::common::{CplxFft, FftError};
+use crate::float::types::Complex32;
+use embassy_futures::block_on;
+use embassy_futures::select::{Either, select};
+use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+use embassy_sync::channel::Channel;
+
+const N: usize = 8;
+
+#[test]
+fn test_frames_are_transformed_in_order() {
+    let mut twiddles = [Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = [0; N];
+    let plan = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let frames = Channel::<NoopRawMutex, [Complex32; N], 2>::new();
+    let spectra = Channel::<NoopRawMutex, [Complex32; N], 1>::new();
+
+    let consumer = async {
+        let mut dc = [0.0; 4];
+        for (k, out) in dc.iter_mut().enumerate() {
+            frames.send([Complex32::new(k as f32, 0.0); N]).await;
+            *out = spectra.receive().await[0].re;
+        }
+        dc
+    };
+    let processor = process_stream(&plan, frames.receiver(), spectra.sender(), false);
+
+    match block_on(select(processor, consumer)) {
+        // A constant frame k has DC bin N * k
+        Either::Second(dc) => assert_eq!(dc, [0.0, 8.0, 16.0, 24.0]),
+        Either::First(result) => panic!("processor stopped: {result:?}"),
+    }
+}
+
+#[test]
+fn test_mismatched_plan_stops_the_stream() {
+    let mut twiddles = [Complex32::new(0.0, 0.0); N];
+    let mut bitrev = [0; 2 * N];
+    let plan = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, 2 * N).unwrap();
+    let frames = Channel::<NoopRawMutex, [Complex32; N], 1>::new();
+    let spectra = Channel::<NoopRawMutex, [Complex32; N], 1>::new();
+
+    frames.try_send([Complex32::new(1.0, 0.0); N]).unwrap();
+    let result = block_on(process_stream(
+        &plan,
+        frames.receiver(),
+        spectra.sender(),
+        false,
+    ));
+    assert_eq!(result.err(), Some(FftError::SizeMismatch));
+}
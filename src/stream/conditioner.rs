@@ -0,0 +1,162 @@
+// src/stream/conditioner.rs
+
+use super::latency::{Latency, first_order_delay};
+use crate::common::FftError;
+use crate::fixed::Fixed;
+
+/// Per-sample conditioning applied before framing: a one-pole DC blocker
+/// followed by a pre-emphasis filter, each optional.
+///
+/// - DC blocker: `y[n] = x[n] - x[n-1] + pole * y[n-1]`, a high-pass whose
+///   corner is about `(1 - pole) * fs / (2 pi)`; 0.995 is a common choice.
+/// - Pre-emphasis: `y[n] = x[n] - alpha * x[n-1]`, the usual 0.95 to 0.97
+///   tilt for speech features.
+///
+/// The filter state runs across calls, so a stream can be conditioned in
+/// chunks of any size. Works on `f32` and on `Fixed<FRAC>` samples; give it
+/// to [`FrameAssembler::with_conditioner`](super::FrameAssembler::with_conditioner)
+/// or call [`Self::process`] on blocks before the FFT.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conditioner<T> {
+    dc_pole: Option<f32>,
+    emphasis: Option<f32>,
+    // Previous DC-blocker input and output
+    dc_x1: T,
+    dc_y1: T,
+    // Previous pre-emphasis input
+    pe_x1: T,
+}
+
+impl<T: Copy + Default> Default for Conditioner<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Default> Conditioner<T> {
+    /// A pass-through conditioner; enable stages with the builders.
+    pub fn new() -> Self {
+        Self {
+            dc_pole: None,
+            emphasis: None,
+            dc_x1: T::default(),
+            dc_y1: T::default(),
+            pe_x1: T::default(),
+        }
+    }
+
+    /// Enables the DC blocker with `pole` in `[0, 1)`.
+    pub fn with_dc_blocker(mut self, pole: f32) -> Result<Self, FftError> {
+        if !(0.0..1.0).contains(&pole) {
+            return Err(FftError::OutOfRange);
+        }
+        self.dc_pole = Some(pole);
+        Ok(self)
+    }
+
+    /// Enables pre-emphasis with `alpha` in `[0, 1)`.
+    pub fn with_pre_emphasis(mut self, alpha: f32) -> Result<Self, FftError> {
+        if !(0.0..1.0).contains(&alpha) {
+            return Err(FftError::OutOfRange);
+        }
+        self.emphasis = Some(alpha);
+        Ok(self)
+    }
+
+    /// Clears the filter state, e.g. after a gap in the input.
+    pub fn reset(&mut self) {
+        self.dc_x1 = T::default();
+        self.dc_y1 = T::default();
+        self.pe_x1 = T::default();
+    }
+}
+
+/// Both stages are causal and run sample by sample, so there is no
+/// algorithmic latency; their phase response still delays each frequency
+/// by a fraction of a sample.
+impl<T> Latency for Conditioner<T> {
+    fn latency(&self) -> usize {
+        0
+    }
+
+    fn group_delay(&self, freq: f32) -> f32 {
+        let mut delay = 0.0;
+        if let Some(pole) = self.dc_pole {
+            delay += first_order_delay(1.0, freq) - first_order_delay(pole, freq);
+        }
+        if let Some(alpha) = self.emphasis {
+            delay += first_order_delay(alpha, freq);
+        }
+        delay
+    }
+}
+
+impl Conditioner<f32> {
+    /// Filters one sample.
+    pub fn step(&mut self, x: f32) -> f32 {
+        let mut y = x;
+        if let Some(pole) = self.dc_pole {
+            y = x - self.dc_x1 + pole * self.dc_y1;
+            self.dc_x1 = x;
+            self.dc_y1 = y;
+        }
+        if let Some(alpha) = self.emphasis {
+            let e = y - alpha * self.pe_x1;
+            self.pe_x1 = y;
+            y = e;
+        }
+        y
+    }
+
+    /// Filters a block in place.
+    pub fn process(&mut self, samples: &mut [f32]) {
+        samples.iter_mut().for_each(|x| *x = self.step(*x));
+    }
+}
+
+impl<const FRAC: u32> Conditioner<Fixed<FRAC>> {
+    /// Filters one sample. Coefficients are applied in Q31 with rounding;
+    /// like the FFT core, the arithmetic wraps, so keep a bit of headroom
+    /// for the DC blocker's response to steps.
+    pub fn step(&mut self, x: Fixed<FRAC>) -> Fixed<FRAC> {
+        let (pole, alpha) = self.coefficients();
+        self.step_with(x, pole, alpha)
+    }
+
+    /// Filters a block in place.
+    pub fn process(&mut self, samples: &mut [Fixed<FRAC>]) {
+        let (pole, alpha) = self.coefficients();
+        samples
+            .iter_mut()
+            .for_each(|x| *x = self.step_with(*x, pole, alpha));
+    }
+
+    fn coefficients(&self) -> (Option<Fixed<31>>, Option<Fixed<31>>) {
+        let q31 = |c: f32| Fixed::<31>::from_f64(c as f64);
+        (self.dc_pole.map(q31), self.emphasis.map(q31))
+    }
+
+    fn step_with(
+        &mut self,
+        x: Fixed<FRAC>,
+        pole: Option<Fixed<31>>,
+        alpha: Option<Fixed<31>>,
+    ) -> Fixed<FRAC> {
+        let mut y = x;
+        if let Some(pole) = pole {
+            y = x - self.dc_x1 + self.dc_y1 * pole;
+            self.dc_x1 = x;
+            self.dc_y1 = y;
+        }
+        if let Some(alpha) = alpha {
+            let e = y - self.pe_x1 * alpha;
+            self.pe_x1 = y;
+            y = e;
+        }
+        y
+    }
+}
+
+#[cfg(test)]
+#[path = "conditioner_tests.rs"]
+mod tests;
@@ -0,0 +1,71 @@
+use super::*;
+use crate::stream::FrameAssembler;
+
+#[test]
+fn test_dc_blocker_removes_offset() {
+    let mut c = Conditioner::<f32>::new().with_dc_blocker(0.99).unwrap();
+    let mut x: Vec<f32> = (0..2000)
+        .map(|i| 1.5 + 0.5 * (i as f32 * 0.3).sin())
+        .collect();
+    c.process(&mut x);
+    let tail = &x[1000..];
+    let mean = tail.iter().sum::<f32>() / tail.len() as f32;
+    assert!(mean.abs() < 0.01, "{mean}");
+    // The tone well above the corner passes nearly unchanged
+    let peak = tail.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+    assert!((peak - 0.5).abs() < 0.02, "{peak}");
+
+    assert_eq!(
+        Conditioner::<f32>::new().with_dc_blocker(1.0),
+        Err(FftError::OutOfRange)
+    );
+    assert_eq!(
+        Conditioner::<f32>::new().with_pre_emphasis(-0.1),
+        Err(FftError::OutOfRange)
+    );
+}
+
+#[test]
+fn test_pre_emphasis_and_chunking() {
+    let x = [1.0f32, 2.0, 4.0, 3.0];
+    let mut c = Conditioner::<f32>::new().with_pre_emphasis(0.5).unwrap();
+    let mut y = x;
+    c.process(&mut y[..1]);
+    c.process(&mut y[1..]);
+    assert_eq!(y, [1.0, 1.5, 3.0, 1.0]);
+
+    // Same result through the frame assembler
+    let mut ring = [0.0f32; 8];
+    let mut asm = FrameAssembler::new(&mut ring, 4, 4, None)
+        .unwrap()
+        .with_conditioner(Conditioner::new().with_pre_emphasis(0.5).unwrap());
+    asm.push(&x[..3]);
+    asm.push(&x[3..]);
+    let mut frame = [0.0f32; 4];
+    assert!(asm.next_frame(&mut frame).unwrap());
+    assert_eq!(frame, y);
+}
+
+#[test]
+fn test_fixed_matches_float() {
+    let pole = 0.995;
+    let alpha = 0.97;
+    let mut cf = Conditioner::<f32>::new()
+        .with_dc_blocker(pole)
+        .unwrap()
+        .with_pre_emphasis(alpha)
+        .unwrap();
+    let mut cq = Conditioner::<Fixed<20>>::new()
+        .with_dc_blocker(pole)
+        .unwrap()
+        .with_pre_emphasis(alpha)
+        .unwrap();
+
+    for i in 0..500 {
+        let x = 0.25 + 0.2 * (i as f32 * 0.1).sin();
+        let yf = cf.step(x);
+        let yq = cq.step(Fixed::from_f64(x as f64));
+        let err = (yq.to_bits() as f32 / (1 << 20) as f32 - yf).abs();
+        assert!(err < 1e-4, "{i}: {err}");
+    }
+}
@@ -0,0 +1,86 @@
+// src/stream/correlogram.rs
+
+use super::frame_assembler::FrameAssembler;
+use crate::common::{FftError, RealFft};
+use crate::float::types::Complex32;
+
+/// Short-time autocorrelation: the time-lag counterpart of a spectrogram.
+///
+/// The input is cut into hop-spaced frames of `frame_len` samples
+/// (optionally windowed), each frame is zero-padded to the plan size N and
+/// autocorrelated through the FFT (see `RealFft::autocorrelate`), and lags
+/// `0..max_lag` are handed out as one correlogram row per frame. Lags are
+/// free of circular wrap-around as long as `max_lag <= N - frame_len + 1`,
+/// which holds for any lag when `N >= 2 * frame_len`.
+///
+/// Rows are raw sums; dividing a row by its lag-0 value gives the
+/// normalized autocorrelation used for pitch picking.
+pub struct Correlogram<'a> {
+    fft: RealFft<'a, Complex32>,
+    assembler: FrameAssembler<'a>,
+    frame: &'a mut [f32],
+    frame_len: usize,
+    max_lag: usize,
+}
+
+impl<'a> Correlogram<'a> {
+    /// `frame` is the N-sample work buffer; `window`, if given, has
+    /// `frame_len` coefficients; `ring` holds at least `frame_len` samples
+    /// (see [`FrameAssembler::new`]).
+    pub fn new(
+        fft: RealFft<'a, Complex32>,
+        frame_len: usize,
+        hop: usize,
+        max_lag: usize,
+        window: Option<&'a [f32]>,
+        ring: &'a mut [f32],
+        frame: &'a mut [f32],
+    ) -> Result<Self, FftError> {
+        let n = fft.n;
+        if frame.len() != n || frame_len > n || max_lag == 0 || max_lag + frame_len > n + 1 {
+            return Err(FftError::SizeMismatch);
+        }
+        let assembler = FrameAssembler::new(ring, frame_len, hop, window)?;
+        Ok(Self {
+            fft,
+            assembler,
+            frame,
+            frame_len,
+            max_lag,
+        })
+    }
+
+    /// Feeds samples; for every completed frame, `row` receives lags
+    /// `0..max_lag` of its autocorrelation. Returns the number of rows
+    /// produced by this call.
+    pub fn push<F>(&mut self, samples: &[f32], mut row: F) -> Result<usize, FftError>
+    where
+        F: FnMut(&[f32]),
+    {
+        let mut rows = 0;
+        let mut rest = samples;
+        while !rest.is_empty() {
+            let taken = self.assembler.push(rest);
+            rest = &rest[taken..];
+            while self
+                .assembler
+                .next_frame(&mut self.frame[..self.frame_len])?
+            {
+                self.frame[self.frame_len..].fill(0.0);
+                self.fft.autocorrelate(self.frame)?;
+                row(&self.frame[..self.max_lag]);
+                rows += 1;
+            }
+        }
+        Ok(rows)
+    }
+
+    /// Discards buffered samples.
+    pub fn reset(&mut self) {
+        self.assembler.reset();
+    }
+}
+
+#[cfg(test)]
+#[path = "correlogram_tests.rs"]
+mod tests;
@@ -0,0 +1,59 @@
+use super::*;
+use core::f32::consts::PI;
+
+const N: usize = 128;
+const FRAME: usize = 64;
+
+#[test]
+fn test_rows_reveal_period() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let (mut ring, mut frame) = (vec![0.0; FRAME], vec![0.0; N]);
+    let mut cg = Correlogram::new(fft, FRAME, FRAME / 2, 40, None, &mut ring, &mut frame).unwrap();
+
+    // Period of 16 samples, then 10 samples
+    let input: Vec<f32> = (0..256)
+        .map(|i| {
+            let period = if i < 128 { 16.0 } else { 10.0 };
+            (2.0 * PI * i as f32 / period).sin()
+        })
+        .collect();
+
+    let mut rows: Vec<Vec<f32>> = Vec::new();
+    let mut produced = 0;
+    for chunk in input.chunks(50) {
+        produced += cg.push(chunk, |r| rows.push(r.to_vec())).unwrap();
+    }
+    assert_eq!(produced, (256 - FRAME) / (FRAME / 2) + 1);
+    assert_eq!(rows.len(), produced);
+    assert!(rows.iter().all(|r| r.len() == 40));
+
+    // Strongest non-zero lag (past the central lobe) is the period
+    let pitch = |r: &[f32]| (5..r.len()).max_by(|&a, &b| r[a].total_cmp(&r[b])).unwrap();
+    assert_eq!(pitch(&rows[0]), 16);
+    assert_eq!(pitch(rows.last().unwrap()), 10);
+
+    // Lag 0 holds the frame energy: 32 for a unit sine over 64 samples
+    assert!((rows[0][0] - 32.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_rejects_wrapping_lags() {
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let (mut ring, mut frame) = (vec![0.0; FRAME], vec![0.0; N]);
+    assert!(matches!(
+        Correlogram::new(
+            fft,
+            FRAME,
+            FRAME,
+            N - FRAME + 2,
+            None,
+            &mut ring,
+            &mut frame
+        ),
+        Err(FftError::SizeMismatch)
+    ));
+}
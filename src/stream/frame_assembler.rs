@@ -0,0 +1,165 @@
+// src/stream/frame_assembler.rs
+
+use super::conditioner::Conditioner;
+use super::latency::Latency;
+use crate::common::FftError;
+
+/// Collects arbitrarily sized chunks of samples into hop-spaced, optionally
+/// windowed frames ready for `RealFft::process`.
+///
+/// Samples are kept in a caller-provided ring buffer, so chunks coming from
+/// an ISR, a DMA callback or a socket can be pushed as they arrive and
+/// frames pulled whenever enough data is available. Consecutive frames
+/// start `hop` samples apart and overlap by `frame_len - hop`.
+pub struct FrameAssembler<'a> {
+    ring: &'a mut [f32],
+    window: Option<&'a [f32]>,
+    conditioner: Option<Conditioner<f32>>,
+    frame_len: usize,
+    hop: usize,
+    // Ring index of the oldest retained sample
+    start: usize,
+    // Number of retained samples
+    len: usize,
+}
+
+impl<'a> FrameAssembler<'a> {
+    /// Creates an assembler producing frames of `frame_len` samples every
+    /// `hop` samples.
+    ///
+    /// `ring` must hold at least `frame_len` samples; extra capacity absorbs
+    /// bursts between two calls to [`Self::next_frame`]. `window`, if given,
+    /// must have `frame_len` coefficients and is applied to every frame.
+    pub fn new(
+        ring: &'a mut [f32],
+        frame_len: usize,
+        hop: usize,
+        window: Option<&'a [f32]>,
+    ) -> Result<Self, FftError> {
+        if hop == 0 || hop > frame_len {
+            return Err(FftError::InvalidStride);
+        }
+        if ring.len() < frame_len {
+            return Err(FftError::BufferTooSmall);
+        }
+        if window.is_some_and(|w| w.len() != frame_len) {
+            return Err(FftError::SizeMismatch);
+        }
+
+        Ok(Self {
+            ring,
+            window,
+            conditioner: None,
+            frame_len,
+            hop,
+            start: 0,
+            len: 0,
+        })
+    }
+
+    /// Runs every accepted sample through `conditioner` (DC blocker,
+    /// pre-emphasis) as it enters the ring, so overlapping frames share one
+    /// continuous filter state.
+    pub fn with_conditioner(mut self, conditioner: Conditioner<f32>) -> Self {
+        self.conditioner = Some(conditioner);
+        self
+    }
+
+    /// Appends samples and returns how many were accepted.
+    ///
+    /// When the ring is full the remaining samples are dropped, so a return
+    /// value smaller than `chunk.len()` signals an overrun.
+    pub fn push(&mut self, chunk: &[f32]) -> usize {
+        let capacity = self.ring.len();
+        let accepted = chunk.len().min(capacity - self.len);
+
+        let mut write = (self.start + self.len) % capacity;
+        for &sample in &chunk[..accepted] {
+            self.ring[write] = match &mut self.conditioner {
+                Some(c) => c.step(sample),
+                None => sample,
+            };
+            write += 1;
+            if write == capacity {
+                write = 0;
+            }
+        }
+        self.len += accepted;
+
+        accepted
+    }
+
+    /// Number of complete frames that can be extracted right now.
+    pub fn frames_ready(&self) -> usize {
+        if self.len < self.frame_len {
+            0
+        } else {
+            (self.len - self.frame_len) / self.hop + 1
+        }
+    }
+
+    /// Number of samples that can still be pushed without an overrun.
+    pub fn free_space(&self) -> usize {
+        self.ring.len() - self.len
+    }
+
+    /// Copies the next frame (windowed, if a window was given) into `out`
+    /// and advances by one hop.
+    ///
+    /// Returns `Ok(false)` without touching `out` when fewer than
+    /// `frame_len` samples are buffered.
+    pub fn next_frame(&mut self, out: &mut [f32]) -> Result<bool, FftError> {
+        if out.len() != self.frame_len {
+            return Err(FftError::SizeMismatch);
+        }
+        if self.len < self.frame_len {
+            return Ok(false);
+        }
+
+        // The frame may wrap around the end of the ring
+        let capacity = self.ring.len();
+        let first = (capacity - self.start).min(self.frame_len);
+        out[..first].copy_from_slice(&self.ring[self.start..self.start + first]);
+        out[first..].copy_from_slice(&self.ring[..self.frame_len - first]);
+
+        if let Some(window) = self.window {
+            for (x, &w) in out.iter_mut().zip(window) {
+                *x *= w;
+            }
+        }
+
+        self.start = (self.start + self.hop) % capacity;
+        self.len -= self.hop;
+
+        Ok(true)
+    }
+
+    /// Discards all buffered samples and the conditioner state.
+    pub fn reset(&mut self) {
+        self.start = 0;
+        self.len = 0;
+        if let Some(c) = &mut self.conditioner {
+            c.reset();
+        }
+    }
+}
+
+/// A frame is complete only when its last sample arrives, `frame_len - 1`
+/// samples after its first.
+impl Latency for FrameAssembler<'_> {
+    fn latency(&self) -> usize {
+        self.frame_len - 1
+    }
+
+    fn group_delay(&self, freq: f32) -> f32 {
+        let conditioning = self
+            .conditioner
+            .as_ref()
+            .map_or(0.0, |c| c.group_delay(freq));
+        self.latency() as f32 + conditioning
+    }
+}
+
+#[cfg(test)]
+#[path = "frame_assembler_tests.rs"]
+mod tests;
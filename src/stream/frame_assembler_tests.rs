@@ -0,0 +1,95 @@
+use super::*;
+
+fn ramp(len: usize) -> Vec<f32> {
+    (0..len).map(|i| i as f32).collect()
+}
+
+#[test]
+fn test_frames_from_irregular_chunks() {
+    let (frame_len, hop) = (8, 3);
+    let signal = ramp(40);
+    let mut ring = [0.0f32; 13];
+    let mut asm = FrameAssembler::new(&mut ring, frame_len, hop, None).unwrap();
+
+    let mut frame = [0.0f32; 8];
+    let mut next_start = 0;
+    let mut pos = 0;
+    // Chunk sizes that do not line up with the frame or hop size
+    for size in [1, 5, 2, 7, 3, 6, 4, 1, 5, 6].iter().cycle() {
+        if pos >= signal.len() {
+            break;
+        }
+        let end = (pos + size).min(signal.len());
+        pos += asm.push(&signal[pos..end]);
+
+        while asm.next_frame(&mut frame).unwrap() {
+            assert_eq!(&frame[..], &signal[next_start..next_start + frame_len]);
+            next_start += hop;
+        }
+    }
+
+    // Every full frame of the signal was produced
+    assert_eq!(next_start, (signal.len() - frame_len) / hop * hop + hop);
+}
+
+#[test]
+fn test_window_and_frame_count() {
+    let window: Vec<f32> = (0..4).map(|i| 0.5 * (i + 1) as f32).collect();
+    let mut ring = [0.0f32; 8];
+    let mut asm = FrameAssembler::new(&mut ring, 4, 2, Some(&window)).unwrap();
+
+    assert_eq!(asm.push(&[1.0; 3]), 3);
+    assert_eq!(asm.frames_ready(), 0);
+    let mut frame = [0.0f32; 4];
+    assert!(!asm.next_frame(&mut frame).unwrap());
+
+    assert_eq!(asm.push(&[1.0; 5]), 5);
+    assert_eq!(asm.frames_ready(), 3);
+    assert!(asm.next_frame(&mut frame).unwrap());
+    assert_eq!(frame, [0.5, 1.0, 1.5, 2.0]);
+    assert_eq!(asm.frames_ready(), 2);
+}
+
+#[test]
+fn test_overrun_drops_samples() {
+    let mut ring = [0.0f32; 6];
+    let mut asm = FrameAssembler::new(&mut ring, 4, 4, None).unwrap();
+
+    assert_eq!(asm.push(&ramp(10)), 6);
+    assert_eq!(asm.free_space(), 0);
+
+    let mut frame = [0.0f32; 4];
+    assert!(asm.next_frame(&mut frame).unwrap());
+    assert_eq!(frame, [0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(asm.free_space(), 4);
+
+    asm.reset();
+    assert_eq!(asm.frames_ready(), 0);
+    assert_eq!(asm.free_space(), 6);
+}
+
+#[test]
+fn test_invalid_configuration() {
+    let mut ring = [0.0f32; 8];
+    assert_eq!(
+        FrameAssembler::new(&mut ring, 4, 0, None).err(),
+        Some(FftError::InvalidStride)
+    );
+    assert_eq!(
+        FrameAssembler::new(&mut ring, 4, 5, None).err(),
+        Some(FftError::InvalidStride)
+    );
+    assert_eq!(
+        FrameAssembler::new(&mut ring, 16, 4, None).err(),
+        Some(FftError::BufferTooSmall)
+    );
+    let window = [1.0f32; 3];
+    assert_eq!(
+        FrameAssembler::new(&mut ring, 4, 2, Some(&window)).err(),
+        Some(FftError::SizeMismatch)
+    );
+
+    let mut asm = FrameAssembler::new(&mut ring, 4, 2, None).unwrap();
+    let mut wrong = [0.0f32; 3];
+    assert_eq!(asm.next_frame(&mut wrong), Err(FftError::SizeMismatch));
+}
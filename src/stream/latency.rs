@@ -0,0 +1,76 @@
+// src/stream/latency.rs
+
+/// Delay a processing stage adds to a live stream.
+///
+/// `latency` is the algorithmic latency in samples: output sample `t` can
+/// only be produced once input sample `t + latency` has arrived, however
+/// fast the host is. `group_delay` is the delay of the stage's response at
+/// one frequency, which also covers the frequency-dependent delay of
+/// recursive filters; for block stages it defaults to the latency.
+///
+/// Stages compose through tuples, so an assembled pipeline can state its
+/// total delay: `(conditioner, processor).latency()`.
+pub trait Latency {
+    /// Algorithmic latency in samples.
+    fn latency(&self) -> usize;
+
+    /// Group delay in samples at `freq`, in cycles per sample (0 to 0.5).
+    fn group_delay(&self, freq: f32) -> f32 {
+        let _ = freq;
+        self.latency() as f32
+    }
+
+    /// [`Self::latency`] in seconds.
+    fn latency_seconds(&self, sample_rate: f32) -> f32 {
+        self.latency() as f32 / sample_rate
+    }
+}
+
+impl<L: Latency + ?Sized> Latency for &L {
+    fn latency(&self) -> usize {
+        (**self).latency()
+    }
+
+    fn group_delay(&self, freq: f32) -> f32 {
+        (**self).group_delay(freq)
+    }
+}
+
+macro_rules! impl_latency_for_tuple {
+    ($($stage:ident),+) => {
+        impl<$($stage: Latency),+> Latency for ($($stage,)+) {
+            fn latency(&self) -> usize {
+                #[allow(non_snake_case)]
+                let ($($stage,)+) = self;
+                0 $(+ $stage.latency())+
+            }
+
+            fn group_delay(&self, freq: f32) -> f32 {
+                #[allow(non_snake_case)]
+                let ($($stage,)+) = self;
+                0.0 $(+ $stage.group_delay(freq))+
+            }
+        }
+    };
+}
+
+impl_latency_for_tuple!(A, B);
+impl_latency_for_tuple!(A, B, C);
+impl_latency_for_tuple!(A, B, C, D);
+
+/// Group delay in samples of the first-order section `1 - a z^-1` at
+/// `freq` cycles per sample; negate it for the pole `1 / (1 - a z^-1)`.
+pub(crate) fn first_order_delay(a: f32, freq: f32) -> f32 {
+    let c = libm::cosf(2.0 * core::f32::consts::PI * freq);
+    let denom = 1.0 - 2.0 * a * c + a * a;
+    if denom <= f32::EPSILON {
+        // Zero on the unit circle at this frequency; the limit is 1/2
+        0.5
+    } else {
+        (a * a - a * c) / denom
+    }
+}
+
+#[cfg(test)]
+#[path = "latency_tests.rs"]
+mod tests;
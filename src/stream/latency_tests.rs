@@ -0,0 +1,77 @@
+use super::*;
+use crate::common::RealFft;
+use crate::float::Complex32;
+use crate::stream::{BlockProcessor, Conditioner};
+use core::f32::consts::PI;
+
+/// Group delay of `b0 + b1 z^-1` over `1 + a1 z^-1`, from the derivative
+/// of the phase.
+fn measured_delay(b: [f32; 2], a1: f32, freq: f32) -> f32 {
+    let phase = |f: f64| {
+        let w = 2.0 * core::f64::consts::PI * f;
+        let (b0, b1, a1) = (b[0] as f64, b[1] as f64, a1 as f64);
+        let num = (b0 + b1 * w.cos(), -b1 * w.sin());
+        let den = (1.0 + a1 * w.cos(), -a1 * w.sin());
+        num.1.atan2(num.0) - den.1.atan2(den.0)
+    };
+    let h = 1e-5;
+    let dw = 2.0 * core::f64::consts::PI * 2.0 * h;
+    (-(phase(freq as f64 + h) - phase(freq as f64 - h)) / dw) as f32
+}
+
+#[test]
+fn test_conditioner_group_delay() {
+    let plain = Conditioner::<f32>::new();
+    assert_eq!(plain.latency(), 0);
+    assert_eq!(plain.group_delay(0.1), 0.0);
+
+    let dc = Conditioner::<f32>::new().with_dc_blocker(0.9).unwrap();
+    let emphasis = Conditioner::<f32>::new().with_pre_emphasis(0.95).unwrap();
+    for freq in [0.01, 0.1, 0.3] {
+        let expected = measured_delay([1.0, -1.0], -0.9, freq);
+        assert!((dc.group_delay(freq) - expected).abs() < 1e-3, "{freq}");
+        let expected = measured_delay([1.0, -0.95], 0.0, freq);
+        assert!(
+            (emphasis.group_delay(freq) - expected).abs() < 1e-3,
+            "{freq}"
+        );
+    }
+    // Cascaded stages add up
+    let both = dc.with_pre_emphasis(0.95).unwrap();
+    let sum = dc.group_delay(0.05) + emphasis.group_delay(0.05);
+    assert!((both.group_delay(0.05) - sum).abs() < 1e-5);
+    assert!(((dc, emphasis).group_delay(0.05) - sum).abs() < 1e-5);
+}
+
+#[test]
+fn test_block_processor_latency_matches_its_output_timing() {
+    const N: usize = 32;
+    const HOP: usize = 8;
+    let window: Vec<f32> = (0..N)
+        .map(|i| 0.5 - 0.5 * libm::cosf(2.0 * PI * i as f32 / N as f32))
+        .collect();
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+    let (mut ring, mut frame, mut overlap) = (vec![0.0; N], vec![0.0; N], vec![0.0; N]);
+    let mut bp =
+        BlockProcessor::new(fft, &window, HOP, &mut ring, &mut frame, &mut overlap).unwrap();
+
+    // Worst case over all samples of how far the input had to run ahead
+    // before output sample t was emitted
+    let mut produced = 0;
+    let mut worst = 0;
+    for pushed in 0..100 {
+        let before = produced;
+        bp.run([0.0], |_| {}, |hop| produced += hop.len()).unwrap();
+        for t in before..produced {
+            worst = worst.max(pushed - t);
+        }
+    }
+    assert_eq!(worst, bp.latency());
+    assert_eq!(bp.latency(), N - 1);
+
+    let conditioner = Conditioner::<f32>::new();
+    assert_eq!((&conditioner, &bp).latency(), N - 1);
+    assert_eq!(bp.latency_seconds(8000.0), (N - 1) as f32 / 8000.0);
+}
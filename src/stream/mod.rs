@@ -0,0 +1,22 @@
+pub mod averager;
+pub mod block_processor;
+#[cfg(feature = "async")]
+pub mod channel;
+pub mod conditioner;
+pub mod correlogram;
+pub mod frame_assembler;
+pub mod latency;
+pub mod output_ring;
+pub mod ping_pong;
+mod sliding;
+
+pub use averager::{AverageMode, Averager};
+pub use block_processor::BlockProcessor;
+#[cfg(feature = "async")]
+pub use channel::process_stream;
+pub use conditioner::Conditioner;
+pub use correlogram::Correlogram;
+pub use frame_assembler::FrameAssembler;
+pub use latency::Latency;
+pub use output_ring::{OutputRing, OverlapAddWriter, RingReader};
+pub use ping_pong::PingPongBuffers;
@@ -0,0 +1,217 @@
+// src/stream/output_ring.rs
+
+use core::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+use super::latency::Latency;
+use crate::common::FftError;
+
+/// Single-producer, single-consumer sample ring between a synthesis loop
+/// and an audio callback.
+///
+/// Samples are stored as `f32` bits in caller-provided `AtomicU32` slots
+/// and the two positions are atomics that each side only ever stores to, so
+/// neither side takes a lock, allocates or waits: the callback reads what
+/// is there and pads the rest with silence. Only plain loads and stores are
+/// used, which keeps it usable on cores without compare-and-swap.
+///
+/// [`Self::split`] hands out the [`OverlapAddWriter`] and [`RingReader`]
+/// halves; the ring itself is `Sync`, so they can live on different threads
+/// or in an interrupt handler.
+pub struct OutputRing<'a> {
+    samples: &'a [AtomicU32],
+    // Positions modulo twice the capacity, so a full ring is told apart from
+    // an empty one without the counts ever wrapping, whatever the capacity.
+    // Only the writer stores `write`, only the reader stores `read`
+    write: AtomicUsize,
+    read: AtomicUsize,
+}
+
+impl<'a> OutputRing<'a> {
+    /// Creates an empty ring over `samples`.
+    pub fn new(samples: &'a [AtomicU32]) -> Result<Self, FftError> {
+        if samples.is_empty() {
+            return Err(FftError::BufferTooSmall);
+        }
+        Ok(Self {
+            samples,
+            write: AtomicUsize::new(0),
+            read: AtomicUsize::new(0),
+        })
+    }
+
+    /// Number of samples written and not yet read.
+    pub fn available(&self) -> usize {
+        let span = self.span();
+        let write = self.write.load(Ordering::Acquire);
+        (write + span - self.read.load(Ordering::Acquire)) % span
+    }
+
+    /// Range of the stored positions: twice the capacity.
+    fn span(&self) -> usize {
+        2 * self.samples.len()
+    }
+
+    /// Splits the ring into its writing and reading sides.
+    ///
+    /// The writer overlap-adds frames of `frame_len` samples spaced `hop`
+    /// apart, keeping the part still waiting for the next frames in `tail`
+    /// (`frame_len` samples). `window`, if given, is the synthesis window
+    /// applied to every frame before it is added.
+    pub fn split<'r>(
+        &'r mut self,
+        tail: &'r mut [f32],
+        hop: usize,
+        window: Option<&'r [f32]>,
+    ) -> Result<(OverlapAddWriter<'r, 'a>, RingReader<'r, 'a>), FftError> {
+        let frame_len = tail.len();
+        if hop == 0 || hop > frame_len {
+            return Err(FftError::InvalidStride);
+        }
+        if self.samples.len() < hop {
+            return Err(FftError::BufferTooSmall);
+        }
+        if window.is_some_and(|w| w.len() != frame_len) {
+            return Err(FftError::SizeMismatch);
+        }
+        tail.fill(0.0);
+
+        let ring = &*self;
+        Ok((
+            OverlapAddWriter {
+                ring,
+                tail,
+                window,
+                hop,
+            },
+            RingReader { ring, underruns: 0 },
+        ))
+    }
+}
+
+/// Producer side of an [`OutputRing`]: overlap-adds synthesized (e.g.
+/// inverse FFT) frames and publishes every completed hop.
+pub struct OverlapAddWriter<'r, 'a> {
+    ring: &'r OutputRing<'a>,
+    tail: &'r mut [f32],
+    window: Option<&'r [f32]>,
+    hop: usize,
+}
+
+impl OverlapAddWriter<'_, '_> {
+    /// Number of samples that can still be published without overwriting
+    /// unread ones.
+    pub fn free_space(&self) -> usize {
+        self.ring.samples.len() - self.ring.available()
+    }
+
+    /// Adds the next frame and publishes the `hop` samples it completes.
+    ///
+    /// Returns `Ok(false)` without touching anything when the ring has no
+    /// room for a hop; the caller retries once the reader has caught up.
+    pub fn write_frame(&mut self, frame: &[f32]) -> Result<bool, FftError> {
+        if frame.len() != self.tail.len() {
+            return Err(FftError::SizeMismatch);
+        }
+        if self.free_space() < self.hop {
+            return Ok(false);
+        }
+
+        match self.window {
+            Some(window) => {
+                for ((t, &x), &w) in self.tail.iter_mut().zip(frame).zip(window) {
+                    *t += x * w;
+                }
+            }
+            None => {
+                for (t, &x) in self.tail.iter_mut().zip(frame) {
+                    *t += x;
+                }
+            }
+        }
+
+        self.publish(self.hop);
+        Ok(true)
+    }
+
+    /// Publishes the overlap still held in the tail, e.g. at the end of a
+    /// stream, as if zero frames followed. Returns `false` if the ring has no
+    /// room for it.
+    pub fn flush(&mut self) -> bool {
+        let pending = self.tail.len() - self.hop;
+        if self.free_space() < pending {
+            return false;
+        }
+        self.publish(pending);
+        true
+    }
+
+    /// Stores the first `count` tail samples into the ring, then shifts the
+    /// tail left by `count`.
+    fn publish(&mut self, count: usize) {
+        let capacity = self.ring.samples.len();
+        let write = self.ring.write.load(Ordering::Relaxed);
+        for (i, &x) in self.tail[..count].iter().enumerate() {
+            let slot = (write + i) % capacity;
+            self.ring.samples[slot].store(x.to_bits(), Ordering::Relaxed);
+        }
+        // Makes the samples visible to the reader
+        self.ring
+            .write
+            .store((write + count) % self.ring.span(), Ordering::Release);
+
+        self.tail.copy_within(count.., 0);
+        let len = self.tail.len();
+        self.tail[len - count..].fill(0.0);
+    }
+}
+
+/// A hop is published by the frame that completes it, so overlap-add adds
+/// nothing to the latency of the framing that fed it. Samples waiting in the
+/// ring ([`OutputRing::available`]) come on top, as buffering rather than
+/// algorithmic delay.
+impl Latency for OverlapAddWriter<'_, '_> {
+    fn latency(&self) -> usize {
+        0
+    }
+}
+
+/// Consumer side of an [`OutputRing`], meant to be called from the audio
+/// callback.
+pub struct RingReader<'r, 'a> {
+    ring: &'r OutputRing<'a>,
+    underruns: usize,
+}
+
+impl RingReader<'_, '_> {
+    /// Fills `out` with the next samples and returns how many came from the
+    /// ring. Any shortfall is padded with silence and counted as an
+    /// underrun.
+    pub fn read(&mut self, out: &mut [f32]) -> usize {
+        let capacity = self.ring.samples.len();
+        let read = self.ring.read.load(Ordering::Relaxed);
+        let count = self.ring.available().min(out.len());
+        for (i, o) in out[..count].iter_mut().enumerate() {
+            let slot = (read + i) % capacity;
+            *o = f32::from_bits(self.ring.samples[slot].load(Ordering::Relaxed));
+        }
+        // Hands the slots back to the writer
+        self.ring
+            .read
+            .store((read + count) % self.ring.span(), Ordering::Release);
+
+        if count < out.len() {
+            out[count..].fill(0.0);
+            self.underruns += 1;
+        }
+        count
+    }
+
+    /// Number of [`Self::read`] calls that had to pad with silence.
+    pub fn underruns(&self) -> usize {
+        self.underruns
+    }
+}
+
+#[cfg(test)]
+#[path = "output_ring_tests.rs"]
+mod tests;
@@ -0,0 +1,158 @@
+use super::*;
+use crate::analysis::Window;
+use crate::common::RealFft;
+use crate::float::Complex32;
+use crate::stream::FrameAssembler;
+
+fn slots(len: usize) -> Vec<AtomicU32> {
+    (0..len).map(|_| AtomicU32::new(0)).collect()
+}
+
+#[test]
+fn test_stft_round_trip_through_the_ring() {
+    const N: usize = 32;
+    const HOP: usize = N / 2;
+    let signal: Vec<f32> = (0..400)
+        .map(|i| libm::sinf(i as f32 * 0.21) + 0.3)
+        .collect();
+
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); N / 2];
+    let mut bitrev = vec![0; N / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, N).unwrap();
+
+    // Periodic Hann at 50 % overlap sums to one, so no synthesis window
+    let mut window = [0.0; N];
+    Window::Hann.fill(&mut window);
+    let mut input_ring = [0.0; N];
+    let mut assembler = FrameAssembler::new(&mut input_ring, N, HOP, Some(&window)).unwrap();
+
+    let storage = slots(3 * HOP);
+    let mut ring = OutputRing::new(&storage).unwrap();
+    let mut tail = [0.0; N];
+    let (mut writer, mut reader) = ring.split(&mut tail, HOP, None).unwrap();
+
+    let mut output = Vec::new();
+    let mut frame = [0.0; N];
+    let mut chunk = [0.0; 7];
+    for block in signal.chunks(HOP) {
+        assembler.push(block);
+        while assembler.next_frame(&mut frame).unwrap() {
+            fft.process(&mut frame, false).unwrap();
+            fft.process(&mut frame, true).unwrap();
+            while !writer.write_frame(&frame).unwrap() {
+                let count = reader.read(&mut chunk);
+                output.extend_from_slice(&chunk[..count]);
+            }
+        }
+    }
+    while !writer.flush() {
+        let count = reader.read(&mut chunk);
+        output.extend_from_slice(&chunk[..count]);
+    }
+    loop {
+        let count = reader.read(&mut chunk);
+        output.extend_from_slice(&chunk[..count]);
+        if count < chunk.len() {
+            break;
+        }
+    }
+
+    // The first hop only saw one window; everything after it is restored
+    assert_eq!(output.len(), signal.len());
+    for (i, (a, b)) in output.iter().zip(&signal).enumerate().skip(HOP) {
+        if i < signal.len() - HOP {
+            assert!((a - b).abs() < 1e-5, "sample {i}: {a} vs {b}");
+        }
+    }
+    assert_eq!(reader.underruns(), 1);
+}
+
+#[test]
+fn test_full_ring_and_underrun() {
+    let storage = slots(6);
+    let mut ring = OutputRing::new(&storage).unwrap();
+    let mut tail = [0.0; 4];
+    let window = [0.5; 4];
+    let (mut writer, mut reader) = ring.split(&mut tail, 2, Some(&window)).unwrap();
+
+    assert!(writer.write_frame(&[2.0; 4]).unwrap());
+    assert!(writer.write_frame(&[2.0; 4]).unwrap());
+    assert!(writer.write_frame(&[2.0; 4]).unwrap());
+    // Six samples published, no room for another hop
+    assert_eq!(writer.free_space(), 0);
+    assert!(!writer.write_frame(&[2.0; 4]).unwrap());
+    assert!(!writer.flush());
+    assert_eq!(writer.write_frame(&[2.0; 3]), Err(FftError::SizeMismatch));
+
+    let mut out = [9.0; 8];
+    assert_eq!(reader.read(&mut out), 6);
+    assert_eq!(out, [1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 0.0, 0.0]);
+    assert_eq!(reader.underruns(), 1);
+
+    assert!(writer.flush());
+    assert_eq!(reader.read(&mut out[..2]), 2);
+    assert_eq!(out[..2], [1.0, 1.0]);
+}
+
+#[test]
+fn test_reader_on_another_thread() {
+    let storage = slots(64);
+    let mut ring = OutputRing::new(&storage).unwrap();
+    let mut tail = [0.0; 8];
+    let (mut writer, mut reader) = ring.split(&mut tail, 8, None).unwrap();
+
+    let received = std::thread::scope(|s| {
+        let consumer = s.spawn(move || {
+            let mut received = Vec::new();
+            let mut chunk = [0.0; 5];
+            while received.len() < 800 {
+                let count = reader.read(&mut chunk);
+                received.extend_from_slice(&chunk[..count]);
+            }
+            received
+        });
+        for f in 0..100 {
+            let frame: Vec<f32> = (0..8).map(|i| (f * 8 + i) as f32).collect();
+            while !writer.write_frame(&frame).unwrap() {
+                std::thread::yield_now();
+            }
+        }
+        consumer.join().unwrap()
+    });
+
+    let expected: Vec<f32> = (0..800).map(|i| i as f32).collect();
+    assert_eq!(received, expected);
+}
+
+#[test]
+fn test_positions_stay_reduced_for_any_capacity() {
+    // Seven slots, not a power of two: many laps through the ring must keep
+    // the sample order and never let the stored positions run free
+    let storage = slots(7);
+    let mut ring = OutputRing::new(&storage).unwrap();
+    let mut tail = [0.0; 3];
+    let (mut writer, mut reader) = ring.split(&mut tail, 3, None).unwrap();
+
+    let mut received = Vec::new();
+    let mut chunk = [0.0; 4];
+    for f in 0..200 {
+        let frame: Vec<f32> = (0..3).map(|i| (f * 3 + i) as f32).collect();
+        while !writer.write_frame(&frame).unwrap() {
+            let count = reader.read(&mut chunk);
+            received.extend_from_slice(&chunk[..count]);
+        }
+        assert!(writer.ring.write.load(Ordering::Relaxed) < 14);
+        assert!(writer.ring.read.load(Ordering::Relaxed) < 14);
+        assert!(writer.ring.available() <= 7);
+    }
+    loop {
+        let count = reader.read(&mut chunk);
+        received.extend_from_slice(&chunk[..count]);
+        if count < chunk.len() {
+            break;
+        }
+    }
+
+    let expected: Vec<f32> = (0..600).map(|i| i as f32).collect();
+    assert_eq!(received, expected);
+}
@@ -0,0 +1,108 @@
+// src/stream/ping_pong.rs
+
+use crate::common::{FftError, FftProcess};
+
+/// Double buffer for circular DMA capture.
+///
+/// Both halves live in one contiguous `2 * N` region, which is what a
+/// circular DMA channel expects: the peripheral fills the first half, raises
+/// a half-transfer interrupt, fills the second half, raises a
+/// transfer-complete interrupt and wraps around. The interrupt handlers call
+/// [`Self::on_half_complete`] and [`Self::on_complete`], and the main loop
+/// transforms whichever half has just been released while the other fills.
+///
+/// `N` is checked at compile time to be a power of two usable as an FFT
+/// length; the plan still rejects a mismatched `n` with `SizeMismatch`.
+/// Sharing the struct between the ISR and the main loop (critical section,
+/// `Mutex<RefCell<_>>`, ...) is left to the caller.
+pub struct PingPongBuffers<T, const N: usize> {
+    buffers: [[T; N]; 2],
+    // Half released by the DMA and not yet consumed
+    ready: Option<usize>,
+    overruns: usize,
+}
+
+impl<T: Copy, const N: usize> PingPongBuffers<T, N> {
+    const VALID_LEN: () = assert!(
+        N.is_power_of_two() && N >= 2,
+        "PingPongBuffers length must be a power of two >= 2"
+    );
+
+    /// Creates both halves filled with `value`.
+    pub const fn new(value: T) -> Self {
+        let () = Self::VALID_LEN;
+        Self {
+            buffers: [[value; N]; 2],
+            ready: None,
+            overruns: 0,
+        }
+    }
+
+    /// Length of each half, i.e. the FFT size.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Always `false`: `N` is at least 2.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// The whole `2 * N` region to hand to the DMA controller.
+    pub fn dma_region(&mut self) -> &mut [T] {
+        self.buffers.as_flattened_mut()
+    }
+
+    /// Half-transfer interrupt hook: the first half is complete.
+    ///
+    /// Returns `false` if the previously released half had not been consumed
+    /// yet; it is dropped and counted as an overrun.
+    pub fn on_half_complete(&mut self) -> bool {
+        self.release(0)
+    }
+
+    /// Transfer-complete interrupt hook: the second half is complete.
+    pub fn on_complete(&mut self) -> bool {
+        self.release(1)
+    }
+
+    /// Number of halves dropped because the consumer fell behind.
+    pub fn overruns(&self) -> usize {
+        self.overruns
+    }
+
+    /// Takes the half released by the DMA, if any.
+    pub fn take_ready(&mut self) -> Option<&mut [T; N]> {
+        let half = self.ready.take()?;
+        Some(&mut self.buffers[half])
+    }
+
+    /// Transforms the released half in place with `plan` and returns it.
+    ///
+    /// Returns `Ok(None)` when no half is ready.
+    pub fn process<P: FftProcess<T>>(
+        &mut self,
+        plan: &P,
+        inverse: bool,
+    ) -> Result<Option<&mut [T; N]>, FftError> {
+        match self.take_ready() {
+            Some(half) => {
+                plan.process(half, inverse)?;
+                Ok(Some(half))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn release(&mut self, half: usize) -> bool {
+        let overrun = self.ready.replace(half).is_some();
+        if overrun {
+            self.overruns += 1;
+        }
+        !overrun
+    }
+}
+
+#[cfg(test)]
+#[path = "ping_pong_tests.rs"]
+mod tests;
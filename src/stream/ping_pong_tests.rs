@@ -0,0 +1,56 @@
+use super::*;
+use crate::common::RealFft;
+use crate::float::types::Complex32;
+
+#[test]
+fn test_halves_alternate() {
+    let mut pp = PingPongBuffers::<f32, 4>::new(0.0);
+    assert_eq!(pp.len(), 4);
+    assert!(pp.take_ready().is_none());
+
+    // Simulated DMA writes into the contiguous region
+    pp.dma_region()[..4].copy_from_slice(&[1.0; 4]);
+    assert!(pp.on_half_complete());
+    pp.dma_region()[4..].copy_from_slice(&[2.0; 4]);
+
+    assert_eq!(pp.take_ready(), Some(&mut [1.0; 4]));
+    assert!(pp.take_ready().is_none());
+
+    assert!(pp.on_complete());
+    assert_eq!(pp.take_ready(), Some(&mut [2.0; 4]));
+    assert_eq!(pp.overruns(), 0);
+}
+
+#[test]
+fn test_overrun_keeps_latest_half() {
+    let mut pp = PingPongBuffers::<i16, 2>::new(0);
+    pp.dma_region().copy_from_slice(&[1, 1, 2, 2]);
+
+    assert!(pp.on_half_complete());
+    assert!(!pp.on_complete());
+    assert_eq!(pp.overruns(), 1);
+    assert_eq!(pp.take_ready(), Some(&mut [2, 2]));
+}
+
+#[test]
+fn test_process_with_plan() {
+    let mut twiddles = vec![Complex32::new(0., 0.); 4];
+    let mut bitrev = vec![0; 4];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, 8).unwrap();
+
+    let mut pp = PingPongBuffers::<f32, 8>::new(1.0);
+    assert_eq!(pp.process(&fft, false), Ok(None));
+
+    pp.on_complete();
+    let spectrum = pp.process(&fft, false).unwrap().unwrap();
+    // Constant input: everything lands in DC, Nyquist is zero
+    assert_eq!(spectrum[0], 8.0);
+    assert!(spectrum[1..].iter().all(|x| x.abs() < 1e-6));
+
+    // A plan of a different size is rejected at run time
+    let mut twiddles = vec![Complex32::new(0., 0.); 8];
+    let mut bitrev = vec![0; 8];
+    let big = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, 16).unwrap();
+    pp.on_half_complete();
+    assert_eq!(pp.process(&big, false), Err(FftError::SizeMismatch));
+}
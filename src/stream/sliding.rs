@@ -0,0 +1,141 @@
+// src/stream/sliding.rs
+
+use crate::common::{CplxFft, FftError, RealFft};
+use crate::float::hygiene::{check_read_only, filter};
+use crate::float::types::Complex32;
+
+/// `W_N^j = e^(-2 pi i j / N)` from a table holding its first N/2 powers.
+#[inline]
+fn root(twiddles: &[Complex32], n: usize, j: usize) -> Complex32 {
+    let j = j % n;
+    if j < n / 2 {
+        twiddles[j]
+    } else {
+        -twiddles[j - n / 2]
+    }
+}
+
+/// `W^(-H k) (X[k] + c * sum_m delta[m] W^(m k))`, the bin of the window
+/// advanced by H = `delta.len()` samples.
+fn slide_bin(
+    twiddles: &[Complex32],
+    n: usize,
+    k: usize,
+    bin: Complex32,
+    delta: impl Iterator<Item = Complex32>,
+    c: f32,
+) -> Complex32 {
+    let mut acc = Complex32::new(0.0, 0.0);
+    let mut hop = 0;
+    for d in delta {
+        acc += d * root(twiddles, n, hop * k % n);
+        hop += 1;
+    }
+    (bin + acc.scale(c)) * root(twiddles, n, hop * k % n).conj()
+}
+
+impl CplxFft<'_, Complex32> {
+    /// Warm update of a spectrum for a window that advanced by H samples.
+    ///
+    /// `spectrum` holds the forward transform (as produced by `process`)
+    /// of the previous N-sample window, `outgoing` its first H samples and
+    /// `incoming` the H samples appended after it. On return `spectrum` is
+    /// the transform of the new window, at O(N H) cost instead of
+    /// O(N log N), which pays off while H is below about log2(N).
+    ///
+    /// The update assumes a rectangular window; a Hann-windowed view can be
+    /// formed afterwards by combining neighboring bins as
+    /// `-X[k-1]/4 + X[k]/2 - X[k+1]/4`. Rounding errors accumulate over
+    /// successive updates, so recompute the spectrum with a full transform
+    /// every few hundred hops.
+    pub fn slide(
+        &self,
+        spectrum: &mut [Complex32],
+        outgoing: &[Complex32],
+        incoming: &[Complex32],
+    ) -> Result<(), FftError> {
+        self.validate()?;
+        let n = self.n;
+        if spectrum.len() != n || outgoing.len() != incoming.len() || incoming.len() > n {
+            return Err(FftError::SizeMismatch);
+        }
+        // The outgoing samples were incoming once and passed the check then
+        let hygiene = self.hygiene;
+        check_read_only(hygiene, incoming.iter().flat_map(|x| [x.re, x.im]))?;
+        let c = if self.scaling.is_scaled(false) {
+            1.0 / n as f32
+        } else {
+            1.0
+        };
+        let clean = |x: &Complex32| Complex32::new(filter(hygiene, x.re), filter(hygiene, x.im));
+
+        for (k, bin) in spectrum.iter_mut().enumerate() {
+            let delta = incoming
+                .iter()
+                .zip(outgoing)
+                .map(|(a, b)| clean(a) - clean(b));
+            *bin = slide_bin(self.twiddles, n, k, *bin, delta, c);
+        }
+        Ok(())
+    }
+}
+
+impl RealFft<'_, Complex32> {
+    /// Real-signal counterpart of [`CplxFft::slide`], updating a packed
+    /// spectrum (`[DC, Nyquist, re1, im1, ...]`) in place.
+    pub fn slide(
+        &self,
+        spectrum: &mut [f32],
+        outgoing: &[f32],
+        incoming: &[f32],
+    ) -> Result<(), FftError> {
+        self.validate()?;
+        let n = self.n;
+        if spectrum.len() != n || outgoing.len() != incoming.len() || incoming.len() > n {
+            return Err(FftError::SizeMismatch);
+        }
+        let hygiene = self.hygiene;
+        check_read_only(hygiene, incoming.iter().copied())?;
+        let c = if self.scaling.is_scaled(false) {
+            1.0 / n as f32
+        } else {
+            1.0
+        };
+        let delta = || {
+            incoming
+                .iter()
+                .zip(outgoing)
+                .map(|(&a, &b)| Complex32::new(filter(hygiene, a) - filter(hygiene, b), 0.0))
+        };
+
+        // DC and Nyquist stay real: their rotations are 1 and (-1)^H
+        let (dc, nyquist) = (spectrum[0], spectrum[1]);
+        spectrum[0] = slide_bin(self.twiddles, n, 0, Complex32::new(dc, 0.0), delta(), c).re;
+        spectrum[1] = slide_bin(
+            self.twiddles,
+            n,
+            n / 2,
+            Complex32::new(nyquist, 0.0),
+            delta(),
+            c,
+        )
+        .re;
+        for (k, pair) in spectrum.chunks_exact_mut(2).enumerate().skip(1) {
+            let bin = slide_bin(
+                self.twiddles,
+                n,
+                k,
+                Complex32::new(pair[0], pair[1]),
+                delta(),
+                c,
+            );
+            pair[0] = bin.re;
+            pair[1] = bin.im;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "sliding_tests.rs"]
+mod tests;
@@ -0,0 +1,71 @@
+use crate::common::{CplxFft, FftError, RealFft, Scaling};
+use crate::float::types::Complex32;
+
+fn sample(i: usize) -> f32 {
+    (i as f32 * 0.37).sin() + 0.5 * (i as f32 * 1.3).cos()
+}
+
+#[test]
+fn test_complex_slide_matches_full_transform() {
+    let n = 32;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n];
+    let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+        .unwrap()
+        .with_scaling(Scaling::Forward);
+    let signal: Vec<Complex32> = (0..n + 20)
+        .map(|i| Complex32::new(sample(i), sample(i + 100)))
+        .collect();
+
+    let mut spectrum = signal[..n].to_vec();
+    fft.process(&mut spectrum, false).unwrap();
+    let mut start = 0;
+    for hop in [1, 3, 2, 5, 1, 8] {
+        fft.slide(
+            &mut spectrum,
+            &signal[start..start + hop],
+            &signal[start + n..start + n + hop],
+        )
+        .unwrap();
+        start += hop;
+    }
+
+    let mut expected = signal[start..start + n].to_vec();
+    fft.process(&mut expected, false).unwrap();
+    for (a, b) in spectrum.iter().zip(expected.iter()) {
+        assert!((*a - *b).norm_sqr() < 1e-9, "{a:?} vs {b:?}");
+    }
+    assert_eq!(
+        fft.slide(&mut spectrum, &signal[..2], &signal[..3]),
+        Err(FftError::SizeMismatch)
+    );
+}
+
+#[test]
+fn test_real_slide_matches_full_transform() {
+    let n = 64;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+    let mut bitrev = vec![0; n / 2];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+    let signal: Vec<f32> = (0..n + 16).map(sample).collect();
+
+    let mut spectrum = signal[..n].to_vec();
+    fft.process(&mut spectrum, false).unwrap();
+    // Odd and even hops flip the sign of the Nyquist rotation
+    let mut start = 0;
+    for hop in [1, 2, 3, 4, 6] {
+        fft.slide(
+            &mut spectrum,
+            &signal[start..start + hop],
+            &signal[start + n..start + n + hop],
+        )
+        .unwrap();
+        start += hop;
+    }
+
+    let mut expected = signal[start..start + n].to_vec();
+    fft.process(&mut expected, false).unwrap();
+    for (a, b) in spectrum.iter().zip(expected.iter()) {
+        assert!((a - b).abs() < 1e-3, "{a} vs {b}");
+    }
+}
@@ -0,0 +1,119 @@
+// src/transforms/dst.rs
+
+use crate::common::{FftError, RealFft};
+use crate::float::types::Complex32;
+use core::f32::consts::PI;
+
+/// Discrete sine transform variants, with the unnormalized definitions of
+/// `scipy.fft.dst(x, type)`.
+///
+/// - I: `y[k] = 2 sum x[n] sin(pi (k+1)(n+1) / (M+1))`
+/// - II: `y[k] = 2 sum x[n] sin(pi (k+1)(2n+1) / 2M)`
+/// - III: `y[k] = (-1)^k x[M-1] + 2 sum_{n<M-1} x[n] sin(pi (n+1)(2k+1) / 2M)`
+///
+/// DST-I is its own inverse up to `2(M+1)`; DST-II and DST-III invert each
+/// other up to `2M`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DstType {
+    I,
+    II,
+    III,
+}
+
+impl DstType {
+    /// Real FFT size needed for an M-point transform: `2(M+1)` for type I,
+    /// `2M` otherwise. It must be a power of two.
+    pub fn fft_len(self, m: usize) -> usize {
+        match self {
+            DstType::I => 2 * (m + 1),
+            DstType::II | DstType::III => 2 * m,
+        }
+    }
+}
+
+/// M-point discrete sine transform of `data`, in place, through a real FFT
+/// of the odd extension of the data.
+///
+/// `fft` must have size [`DstType::fft_len`] and `scratch` at least that
+/// many samples. The result does not depend on the plan's scaling.
+pub fn dst(
+    fft: &RealFft<'_, Complex32>,
+    data: &mut [f32],
+    scratch: &mut [f32],
+    kind: DstType,
+) -> Result<(), FftError> {
+    let m = data.len();
+    let n = fft.n;
+    if m == 0 || kind.fft_len(m) != n {
+        return Err(FftError::SizeMismatch);
+    }
+    let buf = scratch.get_mut(..n).ok_or(FftError::BufferTooSmall)?;
+    // Factors that undo the plan's normalization in each direction
+    let forward_norm = if fft.scaling.is_scaled(false) {
+        n as f32
+    } else {
+        1.0
+    };
+    let inverse_norm = if fft.scaling.is_scaled(true) {
+        n as f32
+    } else {
+        1.0
+    };
+
+    match kind {
+        DstType::I => {
+            // [0, x, 0, -reverse(x)]: bin k is -2i * sum x[n] sin(...)
+            buf[0] = 0.0;
+            buf[1..=m].copy_from_slice(data);
+            buf[m + 1] = 0.0;
+            for (dst, &x) in buf[m + 2..].iter_mut().zip(data.iter().rev()) {
+                *dst = -x;
+            }
+            fft.process(buf, false)?;
+            for (k, y) in data.iter_mut().enumerate() {
+                *y = -buf[2 * (k + 1) + 1] * forward_norm;
+            }
+        }
+        DstType::II => {
+            // [x, -reverse(x)]: e^(-i pi k / 2M) * bin k is -2i * sum ...
+            buf[..m].copy_from_slice(data);
+            for (dst, &x) in buf[m..].iter_mut().zip(data.iter().rev()) {
+                *dst = -x;
+            }
+            fft.process(buf, false)?;
+            for (j, y) in data.iter_mut().enumerate() {
+                let k = j + 1;
+                *y = if k == m {
+                    // Nyquist bin, rotated by e^(-i pi / 2)
+                    buf[1] * forward_norm
+                } else {
+                    let (re, im) = (buf[2 * k], buf[2 * k + 1]);
+                    let (s, c) = libm::sincosf(PI * k as f32 / (2 * m) as f32);
+                    // -Im(e^(-i theta) (re + i im))
+                    -(im * c - re * s) * forward_norm
+                };
+            }
+        }
+        DstType::III => {
+            // Spectrum i e^(i pi k / 2M) x[k-1], whose inverse is -y / N
+            buf[0] = 0.0;
+            buf[1] = -data[m - 1];
+            for k in 1..m {
+                let (s, c) = libm::sincosf(PI * k as f32 / (2 * m) as f32);
+                let x = data[k - 1];
+                buf[2 * k] = -s * x;
+                buf[2 * k + 1] = c * x;
+            }
+            fft.process(buf, true)?;
+            let scale = -inverse_norm;
+            for (y, &v) in data.iter_mut().zip(buf.iter()) {
+                *y = v * scale;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "dst_tests.rs"]
+mod tests;
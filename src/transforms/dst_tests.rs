@@ -0,0 +1,86 @@
+use super::*;
+use crate::common::Scaling;
+
+/// Direct evaluation of the scipy definitions, in f64.
+fn reference(x: &[f32], kind: DstType) -> Vec<f32> {
+    let m = x.len();
+    let pi = core::f64::consts::PI;
+    (0..m)
+        .map(|k| {
+            let sum: f64 = match kind {
+                DstType::I => (0..m)
+                    .map(|n| {
+                        2.0 * x[n] as f64 * (pi * ((k + 1) * (n + 1)) as f64 / (m + 1) as f64).sin()
+                    })
+                    .sum(),
+                DstType::II => (0..m)
+                    .map(|n| {
+                        2.0 * x[n] as f64
+                            * (pi * ((k + 1) * (2 * n + 1)) as f64 / (2 * m) as f64).sin()
+                    })
+                    .sum(),
+                DstType::III => {
+                    let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+                    sign * x[m - 1] as f64
+                        + (0..m - 1)
+                            .map(|n| {
+                                2.0 * x[n] as f64
+                                    * (pi * ((n + 1) * (2 * k + 1)) as f64 / (2 * m) as f64).sin()
+                            })
+                            .sum::<f64>()
+                }
+            };
+            sum as f32
+        })
+        .collect()
+}
+
+fn input(m: usize) -> Vec<f32> {
+    (0..m).map(|i| ((i * 5) % 7) as f32 * 0.25 - 0.6).collect()
+}
+
+#[test]
+fn test_matches_definitions() {
+    for (kind, m) in [(DstType::I, 15), (DstType::II, 16), (DstType::III, 16)] {
+        for scaling in [Scaling::Inverse, Scaling::Forward, Scaling::None] {
+            let n = kind.fft_len(m);
+            let mut twiddles = vec![Complex32::new(0.0, 0.0); n / 2];
+            let mut bitrev = vec![0; n / 2];
+            let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+                .unwrap()
+                .with_scaling(scaling);
+
+            let x = input(m);
+            let expected = reference(&x, kind);
+            let mut data = x.clone();
+            let mut scratch = vec![0.0; n];
+            dst(&fft, &mut data, &mut scratch, kind).unwrap();
+            for (a, b) in data.iter().zip(&expected) {
+                assert!((a - b).abs() < 1e-4, "{kind:?} {scaling:?}: {a} vs {b}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_inverse_pairs() {
+    let m = 8;
+    let mut twiddles = vec![Complex32::new(0.0, 0.0); m];
+    let mut bitrev = vec![0; m];
+    let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, 2 * m).unwrap();
+    let mut scratch = vec![0.0; 2 * m];
+
+    let x = input(m);
+    let mut y = x.clone();
+    dst(&fft, &mut y, &mut scratch, DstType::II).unwrap();
+    dst(&fft, &mut y, &mut scratch, DstType::III).unwrap();
+    for (a, b) in y.iter().zip(&x) {
+        assert!((a / (2 * m) as f32 - b).abs() < 1e-5);
+    }
+
+    let mut short = input(m - 1);
+    assert_eq!(
+        dst(&fft, &mut short, &mut scratch, DstType::II),
+        Err(FftError::SizeMismatch)
+    );
+}
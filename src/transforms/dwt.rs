@@ -0,0 +1,199 @@
+// src/transforms/dwt.rs
+
+use crate::common::FftError;
+use crate::fixed::Fixed;
+
+/// Orthonormal wavelets supported by [`dwt`] and [`idwt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wavelet {
+    /// 2-tap Haar wavelet.
+    Haar,
+    /// 8-tap Daubechies wavelet with four vanishing moments (`db4` in
+    /// PyWavelets and MATLAB).
+    Db4,
+}
+
+// Scaling (low-pass) filters, as f32 and as Q31 for the fixed-point path
+const HAAR: [f32; 2] = [core::f32::consts::FRAC_1_SQRT_2; 2];
+const HAAR_Q31: [i32; 2] = [1_518_500_250; 2];
+const DB4: [f32; 8] = [
+    0.230_377_81,
+    0.714_846_57,
+    0.630_880_8,
+    -0.027_983_77,
+    -0.187_034_81,
+    0.030_841_38,
+    0.032_883_01,
+    -0.010_597_4,
+];
+const DB4_Q31: [i32; 8] = [
+    494_732_587,
+    1_535_121_321,
+    1_354_806_133,
+    -60_094_687,
+    -401_654_200,
+    66_231_363,
+    70_615_730,
+    -22_757_747,
+];
+
+impl Wavelet {
+    fn low_pass(self) -> (&'static [f32], &'static [i32]) {
+        match self {
+            Wavelet::Haar => (&HAAR, &HAAR_Q31),
+            Wavelet::Db4 => (&DB4, &DB4_Q31),
+        }
+    }
+}
+
+/// Sample types the DWT can run on. The arithmetic is done in `Acc`, wide
+/// enough to sum a full filter without intermediate rounding.
+pub trait DwtNum: Copy {
+    type Acc: Copy + Default + core::ops::Add<Output = Self::Acc>;
+
+    /// Product of a sample with a filter tap, given both as f32 and as Q31.
+    fn mul_tap(self, tap: f32, tap_q31: i32) -> Self::Acc;
+
+    /// Rounds an accumulated sum back to a sample.
+    fn from_acc(acc: Self::Acc) -> Self;
+}
+
+impl DwtNum for f32 {
+    type Acc = f32;
+
+    #[inline]
+    fn mul_tap(self, tap: f32, _: i32) -> f32 {
+        self * tap
+    }
+
+    #[inline]
+    fn from_acc(acc: f32) -> f32 {
+        acc
+    }
+}
+
+impl<const FRAC: u32> DwtNum for Fixed<FRAC> {
+    // Q(FRAC + 31)
+    type Acc = i64;
+
+    #[inline]
+    fn mul_tap(self, _: f32, tap_q31: i32) -> i64 {
+        self.to_bits() as i64 * tap_q31 as i64
+    }
+
+    #[inline]
+    fn from_acc(acc: i64) -> Self {
+        let rounded = (acc + (1 << 30)) >> 31;
+        Fixed::from_bits(rounded.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+}
+
+/// Largest number of levels for an N-point transform: the approximation
+/// is halved at every level down to a single sample.
+pub fn max_levels(n: usize) -> usize {
+    if n.is_power_of_two() {
+        n.trailing_zeros() as usize
+    } else {
+        0
+    }
+}
+
+/// Tap `k` of the high-pass filter, `g[k] = (-1)^k h[L-1-k]`.
+#[inline]
+fn high_tap(h: &[f32], hq: &[i32], k: usize) -> (f32, i32) {
+    let j = h.len() - 1 - k;
+    if k.is_multiple_of(2) {
+        (h[j], hq[j])
+    } else {
+        (-h[j], -hq[j])
+    }
+}
+
+fn check<T>(buffer: &[T], scratch: &[T], levels: usize) -> Result<(), FftError> {
+    let n = buffer.len();
+    if !n.is_power_of_two() {
+        return Err(FftError::NotPowerOfTwo);
+    }
+    if levels > max_levels(n) {
+        return Err(FftError::SizeMismatch);
+    }
+    if scratch.len() < n {
+        return Err(FftError::BufferTooSmall);
+    }
+    Ok(())
+}
+
+/// Forward periodized discrete wavelet transform, in place.
+///
+/// After `levels` levels the buffer holds the Mallat layout
+/// `[a_L | d_L | d_L-1 | ... | d_1]`, where each `d_j` has `N / 2^j`
+/// coefficients. The transform is orthonormal, so [`idwt`] inverts it
+/// exactly (up to rounding) and energy is preserved.
+///
+/// In fixed point the approximation band can grow by up to sqrt(2) per
+/// level, so the input needs `levels / 2` bits of headroom (rounded up);
+/// results saturate otherwise. `scratch` must hold N samples.
+pub fn dwt<T: DwtNum>(
+    buffer: &mut [T],
+    scratch: &mut [T],
+    wavelet: Wavelet,
+    levels: usize,
+) -> Result<(), FftError> {
+    check(buffer, scratch, levels)?;
+    let (h, hq) = wavelet.low_pass();
+
+    for level in 0..levels {
+        let m = buffer.len() >> level;
+        let half = m / 2;
+        let x = &buffer[..m];
+        for i in 0..half {
+            let (mut a, mut d) = (T::Acc::default(), T::Acc::default());
+            for k in 0..h.len() {
+                let s = x[(2 * i + k) % m];
+                let (g, gq) = high_tap(h, hq, k);
+                a = a + s.mul_tap(h[k], hq[k]);
+                d = d + s.mul_tap(g, gq);
+            }
+            scratch[i] = T::from_acc(a);
+            scratch[half + i] = T::from_acc(d);
+        }
+        buffer[..m].copy_from_slice(&scratch[..m]);
+    }
+    Ok(())
+}
+
+/// Inverse of [`dwt`] with the same wavelet and number of levels.
+pub fn idwt<T: DwtNum>(
+    buffer: &mut [T],
+    scratch: &mut [T],
+    wavelet: Wavelet,
+    levels: usize,
+) -> Result<(), FftError> {
+    check(buffer, scratch, levels)?;
+    let (h, hq) = wavelet.low_pass();
+
+    for level in (0..levels).rev() {
+        let m = buffer.len() >> level;
+        let half = m / 2;
+        let (a, d) = buffer[..m].split_at(half);
+        // Sample j collects every (i, k) with 2i + k = j (mod m)
+        for (j, out) in scratch[..m].iter_mut().enumerate() {
+            let mut acc = T::Acc::default();
+            for k in 0..h.len() {
+                if !(j + k).is_multiple_of(2) {
+                    continue;
+                }
+                let i = ((j + m * h.len() - k) / 2) % half;
+                let (g, gq) = high_tap(h, hq, k);
+                acc = acc + a[i].mul_tap(h[k], hq[k]) + d[i].mul_tap(g, gq);
+            }
+            *out = T::from_acc(acc);
+        }
+        buffer[..m].copy_from_slice(&scratch[..m]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "dwt_tests.rs"]
+mod tests;
@@ -0,0 +1,95 @@
+use super::*;
+
+const N: usize = 64;
+
+fn signal() -> Vec<f32> {
+    (0..N)
+        .map(|i| 0.4 * (i as f32 * 0.3).sin() + 0.2 * ((i * 7) % 11) as f32 / 11.0)
+        .collect()
+}
+
+#[test]
+fn test_haar_single_level() {
+    let mut x = [1.0f32, 3.0, 2.0, 2.0];
+    let mut scratch = [0.0; 4];
+    dwt(&mut x, &mut scratch, Wavelet::Haar, 1).unwrap();
+    let s = core::f32::consts::SQRT_2;
+    let expected = [4.0 / s, 4.0 / s, -2.0 / s, 0.0];
+    for (a, b) in x.iter().zip(&expected) {
+        assert!((a - b).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_float_round_trip_and_energy() {
+    for wavelet in [Wavelet::Haar, Wavelet::Db4] {
+        for levels in [1, 3, max_levels(N)] {
+            let input = signal();
+            let mut x = input.clone();
+            let mut scratch = vec![0.0; N];
+            dwt(&mut x, &mut scratch, wavelet, levels).unwrap();
+
+            let e_in: f32 = input.iter().map(|v| v * v).sum();
+            let e_out: f32 = x.iter().map(|v| v * v).sum();
+            assert!((e_in - e_out).abs() < 1e-4 * e_in, "{wavelet:?}");
+
+            idwt(&mut x, &mut scratch, wavelet, levels).unwrap();
+            for (a, b) in x.iter().zip(&input) {
+                assert!((a - b).abs() < 1e-5, "{wavelet:?}, {levels} levels");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_db4_annihilates_cubics() {
+    // Four vanishing moments: details of a smooth polynomial vanish away
+    // from the periodic wrap
+    let mut x: Vec<f32> = (0..N)
+        .map(|i| {
+            let t = i as f32 / N as f32;
+            1.0 + t - 2.0 * t * t + t * t * t
+        })
+        .collect();
+    let mut scratch = vec![0.0; N];
+    dwt(&mut x, &mut scratch, Wavelet::Db4, 1).unwrap();
+    let details = &x[N / 2..];
+    assert!(details[..N / 2 - 4].iter().all(|d| d.abs() < 1e-5));
+}
+
+#[test]
+fn test_fixed_matches_float() {
+    let input = signal();
+    let mut fixed: Vec<Fixed<20>> = input.iter().map(|&v| Fixed::from_f64(v as f64)).collect();
+    let mut float = input.clone();
+    let mut fscratch = vec![Fixed::ZERO; N];
+    let mut scratch = vec![0.0; N];
+
+    dwt(&mut fixed, &mut fscratch, Wavelet::Db4, 4).unwrap();
+    dwt(&mut float, &mut scratch, Wavelet::Db4, 4).unwrap();
+    for (q, f) in fixed.iter().zip(&float) {
+        assert!((q.to_bits() as f32 / (1 << 20) as f32 - f).abs() < 1e-5);
+    }
+
+    idwt(&mut fixed, &mut fscratch, Wavelet::Db4, 4).unwrap();
+    for (q, f) in fixed.iter().zip(&input) {
+        assert!((q.to_bits() as f32 / (1 << 20) as f32 - f).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_argument_checks() {
+    let mut scratch = vec![0.0; N];
+    assert_eq!(
+        dwt(&mut [0.0f32; 6], &mut scratch, Wavelet::Haar, 1),
+        Err(FftError::NotPowerOfTwo)
+    );
+    assert_eq!(
+        dwt(&mut [0.0f32; 8], &mut scratch, Wavelet::Haar, 4),
+        Err(FftError::SizeMismatch)
+    );
+    assert_eq!(
+        idwt(&mut [0.0f32; 8], &mut [0.0; 4], Wavelet::Haar, 1),
+        Err(FftError::BufferTooSmall)
+    );
+}
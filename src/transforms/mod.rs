@@ -0,0 +1,9 @@
+pub mod dst;
+pub mod dwt;
+pub mod ntt;
+pub mod wht;
+
+pub use dst::{DstType, dst};
+pub use dwt::{DwtNum, Wavelet, dwt, idwt, max_levels};
+pub use ntt::{GOLDILOCKS, Ntt, NttWord, P998244353};
+pub use wht::{WhtOrder, hadamard_to_sequency, sequency_to_hadamard, wht};
@@ -0,0 +1,246 @@
+// src/transforms/ntt.rs
+
+use crate::common::{FftError, Scaling};
+use crate::float::core::{precompute_swaps, swap_table_len};
+use core::fmt::Debug;
+
+/// Machine word an [`Ntt`] computes in. Products are formed in the next
+/// wider type, so any prime below the word's maximum is usable.
+pub trait NttWord: Copy + Eq + Debug {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn add_mod(self, rhs: Self, modulus: Self) -> Self;
+    fn sub_mod(self, rhs: Self, modulus: Self) -> Self;
+    fn mul_mod(self, rhs: Self, modulus: Self) -> Self;
+    fn to_u64(self) -> u64;
+    /// Truncating conversion; only called with values below the modulus.
+    fn from_u64(v: u64) -> Self;
+}
+
+impl NttWord for u32 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    #[inline]
+    fn add_mod(self, rhs: u32, modulus: u32) -> u32 {
+        ((self as u64 + rhs as u64) % modulus as u64) as u32
+    }
+    #[inline]
+    fn sub_mod(self, rhs: u32, modulus: u32) -> u32 {
+        if self >= rhs {
+            self - rhs
+        } else {
+            modulus - (rhs - self)
+        }
+    }
+    #[inline]
+    fn mul_mod(self, rhs: u32, modulus: u32) -> u32 {
+        ((self as u64 * rhs as u64) % modulus as u64) as u32
+    }
+    #[inline]
+    fn to_u64(self) -> u64 {
+        self as u64
+    }
+    #[inline]
+    fn from_u64(v: u64) -> u32 {
+        v as u32
+    }
+}
+
+impl NttWord for u64 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    #[inline]
+    fn add_mod(self, rhs: u64, modulus: u64) -> u64 {
+        ((self as u128 + rhs as u128) % modulus as u128) as u64
+    }
+    #[inline]
+    fn sub_mod(self, rhs: u64, modulus: u64) -> u64 {
+        if self >= rhs {
+            self - rhs
+        } else {
+            modulus - (rhs - self)
+        }
+    }
+    #[inline]
+    fn mul_mod(self, rhs: u64, modulus: u64) -> u64 {
+        ((self as u128 * rhs as u128) % modulus as u128) as u64
+    }
+    #[inline]
+    fn to_u64(self) -> u64 {
+        self
+    }
+    #[inline]
+    fn from_u64(v: u64) -> u64 {
+        v
+    }
+}
+
+/// 998244353 = 119 * 2^23 + 1, primitive root 3: sizes up to 2^23.
+pub const P998244353: (u32, u32) = (998_244_353, 3);
+/// 2^64 - 2^32 + 1 ("Goldilocks"), primitive root 7: sizes up to 2^32.
+pub const GOLDILOCKS: (u64, u64) = (0xFFFF_FFFF_0000_0001, 7);
+
+fn pow_mod<W: NttWord>(mut base: W, mut exp: u64, modulus: W) -> W {
+    let mut acc = W::ONE;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = acc.mul_mod(base, modulus);
+        }
+        base = base.mul_mod(base, modulus);
+        exp >>= 1;
+    }
+    acc
+}
+
+/// Number-theoretic transform: an FFT over the integers modulo a prime.
+///
+/// Results are exact, which makes it the tool for convolutions that must
+/// not round, such as big-integer or polynomial multiplication. The plan
+/// mirrors `CplxFft`: caller-provided `roots` (N/2 powers of the root of
+/// unity) and `bitrev` (N entries, the same swap list as the float path)
+/// tables, and a `scaling` that decides which direction multiplies by
+/// N^-1 mod p.
+pub struct Ntt<'a, W: NttWord> {
+    pub roots: &'a mut [W],
+    pub bitrev: &'a mut [usize],
+    pub n: usize,
+    pub modulus: W,
+    pub scaling: Scaling,
+    n_inv: W,
+}
+
+impl<'a, W: NttWord> Ntt<'a, W> {
+    /// Builds an N-point plan modulo the prime `modulus`, whose
+    /// multiplicative group is generated by `generator` (see
+    /// [`P998244353`] and [`GOLDILOCKS`]).
+    ///
+    /// Fails with `OutOfRange` if N does not divide `modulus - 1` or if
+    /// `generator` does not yield a root of unity of order exactly N.
+    pub fn new(
+        roots: &'a mut [W],
+        bitrev: &'a mut [usize],
+        n: usize,
+        modulus: W,
+        generator: W,
+    ) -> Result<Self, FftError> {
+        if !n.is_power_of_two() {
+            return Err(FftError::NotPowerOfTwo);
+        }
+        if roots.len() < n / 2 || bitrev.len() < n {
+            return Err(FftError::BufferTooSmall);
+        }
+        let p = modulus.to_u64();
+        if p < 3 || !(p - 1).is_multiple_of(n as u64) {
+            return Err(FftError::OutOfRange);
+        }
+
+        let omega = pow_mod(generator, (p - 1) / n as u64, modulus);
+        // Order exactly N: omega^(N/2) must be -1
+        let minus_one = W::from_u64(p - 1);
+        if n > 1 && pow_mod(omega, n as u64 / 2, modulus) != minus_one {
+            return Err(FftError::OutOfRange);
+        }
+
+        let mut w = W::ONE;
+        for r in roots[..n / 2].iter_mut() {
+            *r = w;
+            w = w.mul_mod(omega, modulus);
+        }
+        precompute_swaps(bitrev, n);
+
+        Ok(Self {
+            roots,
+            bitrev,
+            n,
+            modulus,
+            scaling: Scaling::default(),
+            // Fermat: n^(p-2) = n^-1
+            n_inv: pow_mod(W::from_u64(n as u64 % p), p - 2, modulus),
+        })
+    }
+
+    /// Selects which direction multiplies by N^-1.
+    pub fn with_scaling(mut self, scaling: Scaling) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    /// Transforms `buffer` in place. Values must already be reduced below
+    /// the modulus.
+    pub fn process(&self, buffer: &mut [W], inverse: bool) -> Result<(), FftError> {
+        let n = self.n;
+        if buffer.len() != n {
+            return Err(FftError::SizeMismatch);
+        }
+        let m = self.modulus;
+
+        for pair in self.bitrev[..swap_table_len(n)].chunks_exact(2) {
+            buffer.swap(pair[0], pair[1]);
+        }
+
+        let mut half = 1;
+        while half < n {
+            let stride = n / (2 * half);
+            for block in buffer.chunks_exact_mut(2 * half) {
+                let (lo, hi) = block.split_at_mut(half);
+                for (j, (a, b)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+                    let v = b.mul_mod(self.twiddle(j * stride, inverse), m);
+                    let u = *a;
+                    *a = u.add_mod(v, m);
+                    *b = u.sub_mod(v, m);
+                }
+            }
+            half *= 2;
+        }
+
+        if self.scaling.is_scaled(inverse) {
+            for x in buffer.iter_mut() {
+                *x = x.mul_mod(self.n_inv, m);
+            }
+        }
+        Ok(())
+    }
+
+    /// Cyclic convolution of `a` and `b` modulo p, written into `a`; `b`
+    /// is left holding its transform. Zero-pad both to N >= len(a) +
+    /// len(b) - 1 for a linear (acyclic) product.
+    pub fn convolve(&self, a: &mut [W], b: &mut [W]) -> Result<(), FftError> {
+        self.process(a, false)?;
+        self.process(b, false)?;
+        for (x, &y) in a.iter_mut().zip(b.iter()) {
+            *x = x.mul_mod(y, self.modulus);
+        }
+        self.process(a, true)?;
+
+        // The product must carry exactly one N^-1: a scaled forward
+        // transform applied it twice, an unscaled plan not at all
+        let fix = match self.scaling {
+            Scaling::Inverse => return Ok(()),
+            Scaling::Forward => W::from_u64(self.n as u64 % self.modulus.to_u64()),
+            Scaling::None => self.n_inv,
+        };
+        for x in a.iter_mut() {
+            *x = x.mul_mod(fix, self.modulus);
+        }
+        Ok(())
+    }
+
+    /// omega^k for the forward transform, omega^-k for the inverse one,
+    /// with `k < N/2`.
+    #[inline]
+    fn twiddle(&self, k: usize, inverse: bool) -> W {
+        if !inverse || k == 0 {
+            self.roots[k]
+        } else {
+            // omega^-k = omega^(N/2) * omega^(N/2 - k) = -omega^(N/2 - k)
+            W::ZERO.sub_mod(self.roots[self.n / 2 - k], self.modulus)
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "ntt_tests.rs"]
+mod tests;
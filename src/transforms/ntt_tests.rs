@@ -0,0 +1,97 @@
+use super::*;
+
+const N: usize = 64;
+
+/// Schoolbook product of two polynomials, reduced mod p.
+fn schoolbook(a: &[u64], b: &[u64], p: u64) -> Vec<u64> {
+    let mut out = vec![0u64; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            out[i + j] = ((out[i + j] as u128 + x as u128 * y as u128) % p as u128) as u64;
+        }
+    }
+    out
+}
+
+#[test]
+fn test_u32_polynomial_product() {
+    let (p, g) = P998244353;
+    let mut roots = vec![0u32; N / 2];
+    let mut bitrev = vec![0; N];
+    let ntt = Ntt::new(&mut roots, &mut bitrev, N, p, g).unwrap();
+
+    let a: Vec<u32> = (0..30).map(|i| (i * 7919 + 13) % p).collect();
+    let b: Vec<u32> = (0..30).map(|i| (i * i * 104_729 + 5) % p).collect();
+    let expected = schoolbook(
+        &a.iter().map(|&x| x as u64).collect::<Vec<_>>(),
+        &b.iter().map(|&x| x as u64).collect::<Vec<_>>(),
+        p as u64,
+    );
+
+    let mut fa = vec![0u32; N];
+    let mut fb = vec![0u32; N];
+    fa[..30].copy_from_slice(&a);
+    fb[..30].copy_from_slice(&b);
+    ntt.convolve(&mut fa, &mut fb).unwrap();
+
+    for (k, &e) in expected.iter().enumerate() {
+        assert_eq!(fa[k] as u64, e, "coefficient {k}");
+    }
+    assert!(fa[expected.len()..].iter().all(|&x| x == 0));
+}
+
+#[test]
+fn test_u64_round_trip_all_scalings() {
+    let (p, g) = GOLDILOCKS;
+    for scaling in [Scaling::Inverse, Scaling::Forward, Scaling::None] {
+        let mut roots = vec![0u64; N / 2];
+        let mut bitrev = vec![0; N];
+        let ntt = Ntt::new(&mut roots, &mut bitrev, N, p, g)
+            .unwrap()
+            .with_scaling(scaling);
+
+        let input: Vec<u64> = (0..N as u64)
+            .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15) % p)
+            .collect();
+        let mut x = input.clone();
+        ntt.process(&mut x, false).unwrap();
+        ntt.process(&mut x, true).unwrap();
+        if scaling == Scaling::None {
+            // Round trip yields N * x
+            for (a, &b) in x.iter().zip(&input) {
+                assert_eq!(*a, b.mul_mod(N as u64, p));
+            }
+        } else {
+            assert_eq!(x, input);
+        }
+
+        // Convolution is exact whatever the scaling
+        let mut a = vec![0u64; N];
+        let mut b = vec![0u64; N];
+        a[..3].copy_from_slice(&[p - 1, 2, 3]);
+        b[..2].copy_from_slice(&[4, 5]);
+        ntt.convolve(&mut a, &mut b).unwrap();
+        assert_eq!(&a[..4], &[p - 4, 3, 22, 15]);
+    }
+}
+
+#[test]
+fn test_rejects_unsupported_sizes() {
+    let mut roots = vec![0u32; 16];
+    let mut bitrev = vec![0; 32];
+    // 17 - 1 = 16: no 32nd root of unity
+    assert!(matches!(
+        Ntt::new(&mut roots, &mut bitrev, 32, 17u32, 3),
+        Err(FftError::OutOfRange)
+    ));
+    // 2 is not a generator mod 17 (its order is 8)
+    assert!(matches!(
+        Ntt::new(&mut roots, &mut bitrev, 16, 17u32, 2),
+        Err(FftError::OutOfRange)
+    ));
+    assert!(Ntt::new(&mut roots, &mut bitrev, 16, 17u32, 3).is_ok());
+    assert!(matches!(
+        Ntt::new(&mut roots, &mut bitrev, 24, 17u32, 3),
+        Err(FftError::NotPowerOfTwo)
+    ));
+}
@@ -0,0 +1,122 @@
+// src/transforms/wht.rs
+
+use crate::common::FftError;
+use core::ops::{Add, Sub};
+
+/// Output ordering of [`wht`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhtOrder {
+    /// Natural (Hadamard) order, as produced by the butterflies.
+    #[default]
+    Hadamard,
+    /// Sequency (Walsh) order: coefficient `k` belongs to the basis
+    /// function with `k` sign changes, the analogue of frequency ordering.
+    Sequency,
+}
+
+/// Fast Walsh-Hadamard transform, in place and unnormalized.
+///
+/// Uses only additions and subtractions, so it runs on integers and
+/// fixed-point values as well as floats; fixed-point data needs log2(N)
+/// bits of headroom. Applying the Hadamard-ordered transform twice gives
+/// `N * x`. For `Sequency`, the natural-order result is reordered in place
+/// by [`hadamard_to_sequency`].
+pub fn wht<T>(buffer: &mut [T], order: WhtOrder) -> Result<(), FftError>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+{
+    let n = buffer.len();
+    if !n.is_power_of_two() {
+        return Err(FftError::NotPowerOfTwo);
+    }
+
+    let mut half = 1;
+    while half < n {
+        for block in buffer.chunks_exact_mut(2 * half) {
+            let (lo, hi) = block.split_at_mut(half);
+            for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+                let (x, y) = (*a, *b);
+                *a = x + y;
+                *b = x - y;
+            }
+        }
+        half *= 2;
+    }
+
+    if order == WhtOrder::Sequency {
+        hadamard_to_sequency(buffer)?;
+    }
+    Ok(())
+}
+
+/// Reorders Hadamard-ordered coefficients into sequency order, in place.
+pub fn hadamard_to_sequency<T: Copy>(buffer: &mut [T]) -> Result<(), FftError> {
+    let bits = index_bits(buffer.len())?;
+    // Sequency k sits at the bit-reversed Gray code of k
+    permute(buffer, |k| bit_reverse(k ^ (k >> 1), bits));
+    Ok(())
+}
+
+/// Reorders sequency-ordered coefficients back into Hadamard order, in
+/// place. Inverse of [`hadamard_to_sequency`].
+pub fn sequency_to_hadamard<T: Copy>(buffer: &mut [T]) -> Result<(), FftError> {
+    let bits = index_bits(buffer.len())?;
+    permute(buffer, |h| gray_decode(bit_reverse(h, bits)));
+    Ok(())
+}
+
+fn index_bits(n: usize) -> Result<u32, FftError> {
+    if n.is_power_of_two() {
+        Ok(n.trailing_zeros())
+    } else {
+        Err(FftError::NotPowerOfTwo)
+    }
+}
+
+fn bit_reverse(x: usize, bits: u32) -> usize {
+    if bits == 0 {
+        0
+    } else {
+        x.reverse_bits() >> (usize::BITS - bits)
+    }
+}
+
+fn gray_decode(mut g: usize) -> usize {
+    let mut x = 0;
+    while g != 0 {
+        x ^= g;
+        g >>= 1;
+    }
+    x
+}
+
+/// Sets `buffer[k] = old[source(k)]` for a permutation `source`, following
+/// each cycle once from its smallest index. No extra memory is needed.
+fn permute<T: Copy>(buffer: &mut [T], source: impl Fn(usize) -> usize) {
+    for start in 0..buffer.len() {
+        // Only the smallest index of a cycle moves it
+        let mut k = source(start);
+        while k > start {
+            k = source(k);
+        }
+        if k < start {
+            continue;
+        }
+
+        let first = buffer[start];
+        let mut k = start;
+        loop {
+            let next = source(k);
+            if next == start {
+                buffer[k] = first;
+                break;
+            }
+            buffer[k] = buffer[next];
+            k = next;
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "wht_tests.rs"]
+mod tests;
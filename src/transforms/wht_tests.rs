@@ -0,0 +1,67 @@
+use super::*;
+use crate::fixed::Fixed;
+
+const N: usize = 16;
+
+/// Number of sign changes of Walsh function `index` in the given ordering.
+fn sign_changes(index: usize, order: WhtOrder) -> usize {
+    // Entry `index` of the transform of unit vector `h` is sample `h` of
+    // that basis function
+    let mut row = [0i32; N];
+    for (h, r) in row.iter_mut().enumerate() {
+        let mut unit = [0i32; N];
+        unit[h] = 1;
+        wht(&mut unit, order).unwrap();
+        *r = unit[index];
+    }
+    row.windows(2).filter(|w| w[0] != w[1]).count()
+}
+
+#[test]
+fn test_known_transform() {
+    let mut x = [1, 0, 1, 0, 0, 1, 1, 0];
+    wht(&mut x, WhtOrder::Hadamard).unwrap();
+    assert_eq!(x, [4, 2, 0, -2, 0, 2, 0, 2]);
+
+    // Self-inverse up to N
+    wht(&mut x, WhtOrder::Hadamard).unwrap();
+    assert_eq!(x, [8, 0, 8, 0, 0, 8, 8, 0]);
+}
+
+#[test]
+fn test_sequency_order() {
+    for k in 0..N {
+        assert_eq!(sign_changes(k, WhtOrder::Sequency), k);
+    }
+    // Natural order is not monotonic in sequency
+    assert_ne!(sign_changes(1, WhtOrder::Hadamard), 1);
+}
+
+#[test]
+fn test_reordering_round_trip() {
+    let original: Vec<usize> = (0..64).collect();
+    let mut x = original.clone();
+    hadamard_to_sequency(&mut x).unwrap();
+    assert_ne!(x, original);
+    sequency_to_hadamard(&mut x).unwrap();
+    assert_eq!(x, original);
+
+    assert_eq!(
+        hadamard_to_sequency(&mut [0; 6]),
+        Err(FftError::NotPowerOfTwo)
+    );
+    let mut one = [7];
+    hadamard_to_sequency(&mut one).unwrap();
+    assert_eq!(one, [7]);
+}
+
+#[test]
+fn test_fixed_point() {
+    let q = |v: f64| Fixed::<20>::from_f64(v);
+    let mut x = [q(0.5), q(-0.25), q(0.125), q(0.0)];
+    wht(&mut x, WhtOrder::Sequency).unwrap();
+    let expected = [0.375, 0.125, 0.625, 0.875];
+    for (a, b) in x.iter().zip(&expected) {
+        assert_eq!(*a, q(*b));
+    }
+}
@@ -0,0 +1,110 @@
+// src/transpose.rs
+
+//! Cache-blocked matrix transposes, as used by the multidimensional
+//! transforms to turn strided pencils into contiguous ones.
+//!
+//! Matrices are row-major. A naive transpose walks one side with a stride
+//! of a full row and misses the cache on nearly every access once the rows
+//! are large; working in `BLOCK` x `BLOCK` tiles keeps both sides of a tile
+//! in cache.
+
+use crate::common::FftError;
+
+/// Tile edge, in elements. 16 complex `f32` values are two cache lines.
+pub const BLOCK: usize = 16;
+
+/// Writes the transpose of the `rows` x `cols` matrix `src` into `dst`
+/// (`cols` x `rows`).
+pub fn transpose<T: Copy>(
+    src: &[T],
+    dst: &mut [T],
+    rows: usize,
+    cols: usize,
+) -> Result<(), FftError> {
+    if src.len() != rows * cols || dst.len() != rows * cols {
+        return Err(FftError::SizeMismatch);
+    }
+    for r0 in (0..rows).step_by(BLOCK) {
+        for c0 in (0..cols).step_by(BLOCK) {
+            for r in r0..(r0 + BLOCK).min(rows) {
+                for c in c0..(c0 + BLOCK).min(cols) {
+                    dst[c * rows + r] = src[r * cols + c];
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Transposes the `n` x `n` matrix `data` in place.
+///
+/// Off-diagonal tiles are swapped with their mirror, diagonal tiles are
+/// transposed on themselves.
+pub fn transpose_square_in_place<T: Copy>(data: &mut [T], n: usize) -> Result<(), FftError> {
+    if data.len() != n * n {
+        return Err(FftError::SizeMismatch);
+    }
+    for r0 in (0..n).step_by(BLOCK) {
+        for c0 in (r0..n).step_by(BLOCK) {
+            for r in r0..(r0 + BLOCK).min(n) {
+                // On a diagonal tile only the upper triangle is swapped
+                let start = if c0 == r0 { r + 1 } else { c0 };
+                for c in start..(c0 + BLOCK).min(n) {
+                    data.swap(r * n + c, c * n + r);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Transposes the `rows` x `cols` matrix `data` in place, without extra
+/// memory, by following the cycles of the index permutation.
+///
+/// Square matrices take the blocked path of [`transpose_square_in_place`].
+/// Other shapes cost O(N) moves but touch memory in cycle order, so prefer
+/// [`transpose`] with a second buffer when one is available.
+pub fn transpose_in_place<T: Copy>(
+    data: &mut [T],
+    rows: usize,
+    cols: usize,
+) -> Result<(), FftError> {
+    let len = rows * cols;
+    if data.len() != len {
+        return Err(FftError::SizeMismatch);
+    }
+    if rows == cols {
+        return transpose_square_in_place(data, rows);
+    }
+    if len < 2 {
+        return Ok(());
+    }
+    // Element i of the result comes from (i * cols) mod (len - 1); the
+    // first and last elements stay put.
+    let last = len - 1;
+    let source = |i: usize| i * cols % last;
+    for start in 1..last {
+        // Visit each cycle once, from its smallest index
+        let mut i = source(start);
+        while i > start {
+            i = source(i);
+        }
+        if i < start {
+            continue;
+        }
+        let first = data[start];
+        let mut dst = start;
+        let mut src = source(start);
+        while src != start {
+            data[dst] = data[src];
+            dst = src;
+            src = source(src);
+        }
+        data[dst] = first;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "transpose_tests.rs"]
+mod tests;
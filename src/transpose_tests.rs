@@ -0,0 +1,35 @@
+use super::*;
+
+fn naive(src: &[u32], rows: usize, cols: usize) -> Vec<u32> {
+    (0..rows * cols)
+        .map(|i| src[(i % rows) * cols + i / rows])
+        .collect()
+}
+
+#[test]
+fn test_out_of_place_matches_naive() {
+    for (rows, cols) in [(1, 1), (3, 40), (33, 17), (16, 16)] {
+        let src: Vec<u32> = (0..(rows * cols) as u32).collect();
+        let mut dst = vec![0; rows * cols];
+        transpose(&src, &mut dst, rows, cols).unwrap();
+        assert_eq!(dst, naive(&src, rows, cols), "{rows}x{cols}");
+    }
+    assert_eq!(
+        transpose(&[0u32; 6], &mut [0; 5], 2, 3),
+        Err(FftError::SizeMismatch)
+    );
+}
+
+#[test]
+fn test_in_place_matches_naive() {
+    for (rows, cols) in [(1, 5), (2, 3), (7, 12), (35, 35), (40, 9)] {
+        let src: Vec<u32> = (0..(rows * cols) as u32).collect();
+        let mut data = src.clone();
+        transpose_in_place(&mut data, rows, cols).unwrap();
+        assert_eq!(data, naive(&src, rows, cols), "{rows}x{cols}");
+    }
+    assert_eq!(
+        transpose_square_in_place(&mut [0u32; 8], 3),
+        Err(FftError::SizeMismatch)
+    );
+}
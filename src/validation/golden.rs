@@ -0,0 +1,284 @@
+// src/validation/golden.rs
+
+use crate::common::{Algorithm, CplxFft, FftError, RealFft};
+use crate::fixed::{ComplexFixed, Fixed};
+use crate::float::types::Complex32;
+use core::fmt;
+
+/// Q31 twiddle type of the fixed-point plans.
+type Twiddle = ComplexFixed<31>;
+
+/// Largest transform size in the corpus. Buffers sized for it fit every vector.
+pub const GOLDEN_MAX_N: usize = 512;
+
+/// Families of test signals in the corpus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignalClass {
+    /// Unit impulse at sample 1 (a pure linear phase ramp in frequency).
+    Impulse,
+    /// Constant 0.5 (all energy in the DC bin).
+    Dc,
+    /// Off-bin sinusoid, 3.3 cycles per frame, with leakage into every bin.
+    Sine,
+    /// Linear chirp sweeping from DC to Nyquist.
+    Chirp,
+    /// Uniform pseudo-random noise in [-0.9, 0.9) from a fixed-seed LCG.
+    Noise,
+}
+
+/// One entry of the golden corpus: a real input and its reference spectrum,
+/// computed by an f64 DFT on the host and rounded to f32.
+#[derive(Debug, Clone, Copy)]
+pub struct GoldenVector {
+    pub class: SignalClass,
+    pub n: usize,
+    /// Real input signal, N samples with magnitude at most 1.
+    pub input: &'static [f32],
+    /// One-sided reference spectrum, N/2 + 1 bins stored as `[re, im]`.
+    pub spectrum: &'static [[f32; 2]],
+}
+
+// Checked in; regenerate with `cargo run --example gen_golden`
+include!("golden_vectors.rs");
+
+/// Returns the whole corpus, ordered by size and then by signal class.
+pub fn golden_vectors() -> &'static [GoldenVector] {
+    &GOLDEN
+}
+
+impl GoldenVector {
+    /// Reference value of bin `k` of the full N-point spectrum. Bins above
+    /// N/2 follow from Hermitian symmetry.
+    pub fn bin(&self, k: usize) -> (f32, f32) {
+        if k <= self.n / 2 {
+            let [re, im] = self.spectrum[k];
+            (re, im)
+        } else {
+            let [re, im] = self.spectrum[self.n - k];
+            (re, -im)
+        }
+    }
+
+    /// Compares `k -> (re, im)` against the reference over bins `0..bins`.
+    ///
+    /// The error of a bin is the larger of its real and imaginary deviations
+    /// divided by N, so one tolerance applies to every size. Returns the
+    /// worst error, or the first bin exceeding `tolerance`.
+    fn check(
+        &self,
+        algorithm: Algorithm,
+        bins: usize,
+        tolerance: f32,
+        actual: impl Fn(usize) -> (f32, f32),
+    ) -> Result<f32, GoldenMismatch> {
+        let mut worst = 0.0f32;
+        for k in 0..bins {
+            let (re, im) = actual(k);
+            let (ref_re, ref_im) = self.bin(k);
+            let error =
+                libm::fmaxf(libm::fabsf(re - ref_re), libm::fabsf(im - ref_im)) / self.n as f32;
+            if error.is_nan() || error > tolerance {
+                return Err(GoldenMismatch {
+                    class: self.class,
+                    n: self.n,
+                    algorithm,
+                    bin: k,
+                    error,
+                });
+            }
+            worst = libm::fmaxf(worst, error);
+        }
+        Ok(worst)
+    }
+}
+
+/// Location and size of the first deviation beyond tolerance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoldenMismatch {
+    pub class: SignalClass,
+    pub n: usize,
+    pub algorithm: Algorithm,
+    pub bin: usize,
+    /// Deviation of the bin, normalized by N.
+    pub error: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValidationError {
+    /// A plan could not be built, usually because the buffers are smaller
+    /// than `GOLDEN_MAX_N` requires.
+    Fft(FftError),
+    /// A transform output deviated from its golden reference.
+    Mismatch(GoldenMismatch),
+}
+
+impl From<FftError> for ValidationError {
+    fn from(e: FftError) -> Self {
+        ValidationError::Fft(e)
+    }
+}
+
+impl From<GoldenMismatch> for ValidationError {
+    fn from(m: GoldenMismatch) -> Self {
+        ValidationError::Mismatch(m)
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Fft(e) => write!(f, "{}", e),
+            ValidationError::Mismatch(m) => write!(
+                f,
+                "{:?} N={} ({:?}): bin {} off by {} (normalized)",
+                m.class, m.n, m.algorithm, m.bin, m.error
+            ),
+        }
+    }
+}
+
+const ALGORITHMS: [Algorithm; 2] = [Algorithm::Dit, Algorithm::Dif];
+
+#[inline]
+fn q15_to_f32(x: Fixed<15>) -> f32 {
+    x.to_bits() as f32 / (1 << 15) as f32
+}
+
+/// Runs every golden vector through the float `RealFft` with both
+/// algorithms and returns the worst normalized error.
+///
+/// Requires `twiddles` and `bitrev` of at least `GOLDEN_MAX_N / 2` entries
+/// and `buffer` of at least `GOLDEN_MAX_N`.
+pub fn validate_real_f32(
+    twiddles: &mut [Complex32],
+    bitrev: &mut [usize],
+    buffer: &mut [f32],
+    tolerance: f32,
+) -> Result<f32, ValidationError> {
+    if buffer.len() < GOLDEN_MAX_N {
+        return Err(FftError::BufferTooSmall.into());
+    }
+
+    let mut worst = 0.0f32;
+    for vector in golden_vectors() {
+        let n = vector.n;
+        for algorithm in ALGORITHMS {
+            let fft = RealFft::<Complex32>::new(twiddles, bitrev, n)?.with_algorithm(algorithm);
+            let out = &mut buffer[..n];
+            out.copy_from_slice(vector.input);
+            fft.process(out, false)?;
+
+            let error = vector.check(algorithm, n / 2 + 1, tolerance, |k| match k {
+                0 => (out[0], 0.0),
+                k if k == n / 2 => (out[1], 0.0),
+                k => (out[2 * k], out[2 * k + 1]),
+            })?;
+            worst = libm::fmaxf(worst, error);
+        }
+    }
+    Ok(worst)
+}
+
+/// Runs every golden vector through the float `CplxFft` (with a zero
+/// imaginary part) using both algorithms, and returns the worst normalized
+/// error over all N bins.
+///
+/// Requires `twiddles` of at least `GOLDEN_MAX_N / 2` entries and `bitrev`
+/// and `buffer` of at least `GOLDEN_MAX_N`.
+pub fn validate_complex_f32(
+    twiddles: &mut [Complex32],
+    bitrev: &mut [usize],
+    buffer: &mut [Complex32],
+    tolerance: f32,
+) -> Result<f32, ValidationError> {
+    if buffer.len() < GOLDEN_MAX_N {
+        return Err(FftError::BufferTooSmall.into());
+    }
+
+    let mut worst = 0.0f32;
+    for vector in golden_vectors() {
+        let n = vector.n;
+        for algorithm in ALGORITHMS {
+            let fft = CplxFft::<Complex32>::new(twiddles, bitrev, n)?.with_algorithm(algorithm);
+            let out = &mut buffer[..n];
+            for (dst, &x) in out.iter_mut().zip(vector.input) {
+                *dst = Complex32::new(x, 0.0);
+            }
+            fft.process(out, false)?;
+
+            let error = vector.check(algorithm, n, tolerance, |k| (out[k].re, out[k].im))?;
+            worst = libm::fmaxf(worst, error);
+        }
+    }
+    Ok(worst)
+}
+
+/// Fixed-point counterpart of [`validate_real_f32`]: inputs are quantized
+/// to Q15 and transformed unscaled, which the 16 integer bits of a
+/// `Fixed<15>` accommodate up to `GOLDEN_MAX_N`.
+pub fn validate_real_q15(
+    twiddles: &mut [ComplexFixed<31>],
+    bitrev: &mut [usize],
+    buffer: &mut [Fixed<15>],
+    tolerance: f32,
+) -> Result<f32, ValidationError> {
+    if buffer.len() < GOLDEN_MAX_N {
+        return Err(FftError::BufferTooSmall.into());
+    }
+
+    let mut worst = 0.0f32;
+    for vector in golden_vectors() {
+        let n = vector.n;
+        for algorithm in ALGORITHMS {
+            let fft = RealFft::<Twiddle>::new(twiddles, bitrev, n)?.with_algorithm(algorithm);
+            let out = &mut buffer[..n];
+            for (dst, &x) in out.iter_mut().zip(vector.input) {
+                *dst = Fixed::from_f64(x as f64);
+            }
+            fft.process(out, false)?;
+
+            let error = vector.check(algorithm, n / 2 + 1, tolerance, |k| match k {
+                0 => (q15_to_f32(out[0]), 0.0),
+                k if k == n / 2 => (q15_to_f32(out[1]), 0.0),
+                k => (q15_to_f32(out[2 * k]), q15_to_f32(out[2 * k + 1])),
+            })?;
+            worst = libm::fmaxf(worst, error);
+        }
+    }
+    Ok(worst)
+}
+
+/// Fixed-point counterpart of [`validate_complex_f32`], on Q15 data.
+pub fn validate_complex_q15(
+    twiddles: &mut [ComplexFixed<31>],
+    bitrev: &mut [usize],
+    buffer: &mut [ComplexFixed<15>],
+    tolerance: f32,
+) -> Result<f32, ValidationError> {
+    if buffer.len() < GOLDEN_MAX_N {
+        return Err(FftError::BufferTooSmall.into());
+    }
+
+    let mut worst = 0.0f32;
+    for vector in golden_vectors() {
+        let n = vector.n;
+        for algorithm in ALGORITHMS {
+            let fft = CplxFft::<Twiddle>::new(twiddles, bitrev, n)?.with_algorithm(algorithm);
+            let out = &mut buffer[..n];
+            for (dst, &x) in out.iter_mut().zip(vector.input) {
+                *dst = ComplexFixed::new(Fixed::from_f64(x as f64), Fixed::from_bits(0));
+            }
+            fft.process(out, false)?;
+
+            let error = vector.check(algorithm, n, tolerance, |k| {
+                (q15_to_f32(out[k].re), q15_to_f32(out[k].im))
+            })?;
+            worst = libm::fmaxf(worst, error);
+        }
+    }
+    Ok(worst)
+}
+
+#[cfg(test)]
+#[path = "golden_tests.rs"]
+mod tests;
@@ -0,0 +1,97 @@
+use super::*;
+
+#[test]
+fn test_corpus_shape() {
+    let vectors = golden_vectors();
+    assert_eq!(vectors.len(), 25);
+    assert!(vectors.iter().all(|v| v.n <= GOLDEN_MAX_N));
+    assert!(vectors.iter().any(|v| v.n == GOLDEN_MAX_N));
+    for v in vectors {
+        assert_eq!(v.input.len(), v.n);
+        assert_eq!(v.spectrum.len(), v.n / 2 + 1);
+    }
+
+    // Spot-check the host-side reference: a constant 0.5 is N/2 at DC only
+    let dc = vectors
+        .iter()
+        .find(|v| v.class == SignalClass::Dc && v.n == 32)
+        .unwrap();
+    assert_eq!(dc.spectrum[0], [16.0, 0.0]);
+    assert!(
+        dc.spectrum[1..]
+            .iter()
+            .all(|b| b[0].abs() < 1e-5 && b[1].abs() < 1e-5)
+    );
+    let (re, im) = dc.bin(1);
+    assert_eq!(dc.bin(31), (re, -im));
+}
+
+#[test]
+fn test_checked_in_corpus_matches_reference() {
+    // Guards the generated file against hand edits and a stale grid
+    for v in golden_vectors() {
+        let n = v.n;
+        for (k, &[re, im]) in v.spectrum.iter().enumerate() {
+            let (mut acc_re, mut acc_im) = (0.0f64, 0.0f64);
+            for (t, &x) in v.input.iter().enumerate() {
+                let angle = -2.0 * core::f64::consts::PI * ((k * t) % n) as f64 / n as f64;
+                acc_re += x as f64 * angle.cos();
+                acc_im += x as f64 * angle.sin();
+            }
+            let tol = 1e-6 * n as f64;
+            assert!(
+                (re as f64 - acc_re).abs() < tol && (im as f64 - acc_im).abs() < tol,
+                "{:?}, n = {}, bin {}",
+                v.class,
+                n,
+                k
+            );
+        }
+    }
+}
+
+#[test]
+fn test_float_paths_match_golden() {
+    let mut twiddles = vec![Complex32::new(0., 0.); GOLDEN_MAX_N / 2];
+    let mut bitrev = vec![0; GOLDEN_MAX_N];
+    let mut real = vec![0.0f32; GOLDEN_MAX_N];
+    let mut cplx = vec![Complex32::new(0., 0.); GOLDEN_MAX_N];
+
+    let worst = validate_real_f32(&mut twiddles, &mut bitrev, &mut real, 1e-5).unwrap();
+    assert!(worst > 0.0);
+    validate_complex_f32(&mut twiddles, &mut bitrev, &mut cplx, 1e-5).unwrap();
+}
+
+#[test]
+fn test_fixed_paths_match_golden() {
+    let zero = ComplexFixed::ZERO;
+    let mut twiddles = vec![zero; GOLDEN_MAX_N / 2];
+    let mut bitrev = vec![0; GOLDEN_MAX_N];
+    let mut real = vec![Fixed::<15>::from_bits(0); GOLDEN_MAX_N];
+    let mut cplx = vec![ComplexFixed::<15>::ZERO; GOLDEN_MAX_N];
+
+    validate_real_q15(&mut twiddles, &mut bitrev, &mut real, 1e-4).unwrap();
+    validate_complex_q15(&mut twiddles, &mut bitrev, &mut cplx, 1e-4).unwrap();
+}
+
+#[test]
+fn test_reports_mismatch_and_small_buffers() {
+    let mut twiddles = vec![Complex32::new(0., 0.); GOLDEN_MAX_N / 2];
+    let mut bitrev = vec![0; GOLDEN_MAX_N];
+    let mut real = vec![0.0f32; GOLDEN_MAX_N];
+
+    // Rounding makes some bin deviate from the reference by more than zero
+    match validate_real_f32(&mut twiddles, &mut bitrev, &mut real, 0.0) {
+        Err(ValidationError::Mismatch(m)) => {
+            assert!(m.error > 0.0);
+            assert!(m.bin <= m.n / 2);
+        }
+        other => panic!("expected a mismatch, got {:?}", other),
+    }
+
+    let mut small = vec![0.0f32; GOLDEN_MAX_N / 2];
+    assert_eq!(
+        validate_real_f32(&mut twiddles, &mut bitrev, &mut small, 1.0),
+        Err(ValidationError::Fft(FftError::BufferTooSmall))
+    );
+}
@@ -0,0 +1,154 @@
+// Generated by examples/gen_golden.rs. Do not edit.
+
+pub(super) static GOLDEN: [GoldenVector; 25] = [
+    GoldenVector {
+        class: SignalClass::Impulse,
+        n: 2,
+        input: &[0.0, 1.0, ],
+        spectrum: &[[1.0, 0.0], [-1.0, -1.2246469e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Dc,
+        n: 2,
+        input: &[0.5, 0.5, ],
+        spectrum: &[[1.0, 0.0], [0.0, -6.123234e-17], ],
+    },
+    GoldenVector {
+        class: SignalClass::Sine,
+        n: 2,
+        input: &[0.22266357, -0.8363583, ],
+        spectrum: &[[-0.6136947, 0.0], [1.0590218, 1.02424356e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Chirp,
+        n: 2,
+        input: &[0.8, 0.56568545, ],
+        spectrum: &[[1.3656855, 0.0], [0.23431456, -6.927649e-17], ],
+    },
+    GoldenVector {
+        class: SignalClass::Noise,
+        n: 2,
+        input: &[-0.075499356, 0.5427347, ],
+        spectrum: &[[0.46723533, 0.0], [-0.61823404, -6.646583e-17], ],
+    },
+    GoldenVector {
+        class: SignalClass::Impulse,
+        n: 8,
+        input: &[0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, ],
+        spectrum: &[[1.0, 0.0], [0.70710677, -0.70710677], [6.123234e-17, -1.0], [-0.70710677, -0.70710677], [-1.0, -1.2246469e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Dc,
+        n: 8,
+        input: &[0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, ],
+        spectrum: &[[4.0, 0.0], [-2.7755576e-16, 1.110223e-16], [-1.4735966e-16, -2.220446e-16], [-1.110223e-16, 0.0], [0.0, -2.4492937e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Sine,
+        n: 8,
+        input: &[0.22266357, 0.26577792, -0.67588943, 0.88680303, -0.8363583, 0.5394224, -0.08350804, -0.39701775, ],
+        spectrum: &[[-0.07810662, 0.0], [-0.042272367, -0.121921115], [0.14570272, -0.31541502], [2.1603162, -1.3066839], [-2.6680777, -1.5858999e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Chirp,
+        n: 8,
+        input: &[0.8, 0.7846282, 0.56568545, -0.15607226, -0.8, 0.15607226, 0.56568545, -0.7846282, ],
+        spectrum: &[[1.1313709, 0.0], [1.6, -0.8889124], [-1.1313709, -1.881401], [1.6, -0.8889124], [1.1313709, 0.0], ],
+    },
+    GoldenVector {
+        class: SignalClass::Noise,
+        n: 8,
+        input: &[-0.075499356, 0.5427347, -0.63380194, -0.3701134, 0.034223914, 0.693744, 0.7457383, 0.80728966, ],
+        spectrum: &[[1.7443159, 0.0], [0.6160467, 2.3188696], [-0.15321183, -0.79930246], [-0.83549327, -0.44021085], [-1.602994, -2.0496362e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Impulse,
+        n: 32,
+        input: &[0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, ],
+        spectrum: &[[1.0, 0.0], [0.98078525, -0.19509032], [0.9238795, -0.38268343], [0.8314696, -0.55557024], [0.70710677, -0.70710677], [0.55557024, -0.8314696], [0.38268343, -0.9238795], [0.19509032, -0.98078525], [6.123234e-17, -1.0], [-0.19509032, -0.98078525], [-0.38268343, -0.9238795], [-0.55557024, -0.8314696], [-0.70710677, -0.70710677], [-0.8314696, -0.55557024], [-0.9238795, -0.38268343], [-0.98078525, -0.19509032], [-1.0, -1.2246469e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Dc,
+        n: 32,
+        input: &[0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, ],
+        spectrum: &[[16.0, 0.0], [-1.1657342e-15, -1.1241008e-15], [-7.2164497e-16, 5.551115e-17], [-4.9960036e-16, 5.551115e-17], [-9.436896e-16, 1.110223e-16], [-8.3266727e-16, -1.6653345e-16], [-9.15934e-16, -1.6653345e-16], [-7.771561e-16, -2.220446e-16], [-4.8042655e-16, -8.881784e-16], [-9.020562e-16, -1.6653345e-16], [-9.15934e-16, 2.220446e-16], [-7.2164497e-16, 5.551115e-17], [-4.440892e-16, 0.0], [-7.771561e-16, -5.551115e-17], [-7.2164497e-16, 8.326673e-17], [-7.771561e-16, -5.551115e-17], [0.0, -9.797175e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Sine,
+        n: 32,
+        input: &[0.22266357, 0.7038478, 0.89972126, 0.73088485, 0.26577792, -0.3070644, -0.75543547, -0.89758426, -0.67588943, -0.18021694, 0.38850805, 0.7997479, 0.88680303, 0.61438483, 0.09292038, -0.46621013, -0.8363583, -0.8674814, -0.54696333, -0.0047289412, 0.5394224, 0.8649141, 0.8398054, 0.4742743, -0.08350804, -0.6074397, -0.88514036, -0.8040417, -0.39701775, 0.17094079, 0.669607, 0.8968422, ],
+        spectrum: &[[1.7459856, 0.0], [1.9570135, -0.28597733], [2.957886, -0.81319594], [11.689933, -4.3753185], [-4.795766, 2.1079478], [-1.9582103, 0.9249692], [-1.2538517, 0.59923226], [-0.9470529, 0.43798515], [-0.78112954, 0.3372991], [-0.6804415, 0.26578516], [-0.6150214, 0.21056204], [-0.57079494, 0.16528428], [-0.5403896, 0.12640436], [-0.5196633, 0.09173221], [-0.5062019, 0.0597939], [-0.49860853, 0.02950712], [-0.496153, -1.3729139e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Chirp,
+        n: 32,
+        input: &[0.8, 0.7990364, 0.7846282, 0.72319144, 0.56568545, 0.26951188, -0.15607226, -0.5927609, -0.8, -0.5372472, 0.15607226, 0.7532353, 0.56568545, -0.3420441, -0.7846282, 0.03925414, 0.8, -0.03925414, -0.7846282, 0.3420441, 0.56568545, -0.7532353, 0.15607226, 0.5372472, -0.8, 0.5927609, -0.15607226, -0.26951188, 0.56568545, -0.72319144, 0.7846282, -0.7990364, ],
+        spectrum: &[[2.2627418, 0.0], [2.6607027, -0.23476873], [3.2, -1.6135181], [1.7778248, -3.9572968], [-2.2627418, -3.3435614], [-1.7778248, 1.7190459], [3.2, -0.8562484], [-2.6607027, -2.5878642], [2.2627418, 1.4673259], [-2.6607027, -2.5878642], [3.2, -0.8562484], [-1.7778248, 1.7190459], [-2.2627418, -3.3435614], [1.7778248, -3.9572968], [3.2, -1.6135181], [2.6607027, -0.23476873], [2.2627418, 1.2325952e-32], ],
+    },
+    GoldenVector {
+        class: SignalClass::Noise,
+        n: 32,
+        input: &[-0.075499356, 0.5427347, -0.63380194, -0.3701134, 0.034223914, 0.693744, 0.7457383, 0.80728966, 0.26851842, 0.0127315875, -0.67154765, -0.1734576, -0.42593297, -0.53436875, 0.35172912, -0.12056036, -0.36871266, 0.6924774, 0.17837366, -0.3043964, -0.09116367, -0.06528808, -0.0046508433, 0.79488844, 0.53862256, -0.07090688, 0.1783404, 0.095449194, -0.68554926, 0.30883083, 0.5502619, 0.6431001, ],
+        spectrum: &[[2.8411045, 0.0], [2.049536, 0.65801615], [-1.4684654, -2.1134899], [-1.9350024, 1.7365003], [4.113249, 4.0788426], [1.8212619, 2.802178], [0.030038308, -0.95224714], [0.7652233, 0.3673943], [-1.499936, -0.20775516], [-0.45955986, -0.5942878], [-2.8137238, -3.2354565], [2.3815813, -0.07390051], [-1.0505468, -1.1045856], [-0.71533155, 1.0297937], [-0.7532611, -0.1785294], [-1.5620023, -0.29512712], [-3.0632045, -3.6153465e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Impulse,
+        n: 128,
+        input: &[0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, ],
+        spectrum: &[[1.0, 0.0], [0.99879545, -0.049067676], [0.9951847, -0.09801714], [0.9891765, -0.14673047], [0.98078525, -0.19509032], [0.97003126, -0.24298018], [0.95694035, -0.29028466], [0.94154406, -0.33688986], [0.9238795, -0.38268343], [0.9039893, -0.42755508], [0.8819213, -0.47139674], [0.8577286, -0.51410276], [0.8314696, -0.55557024], [0.8032075, -0.5956993], [0.77301043, -0.6343933], [0.7409511, -0.671559], [0.70710677, -0.70710677], [0.671559, -0.7409511], [0.6343933, -0.77301043], [0.5956993, -0.8032075], [0.55557024, -0.8314696], [0.51410276, -0.8577286], [0.47139674, -0.8819213], [0.42755508, -0.9039893], [0.38268343, -0.9238795], [0.33688986, -0.94154406], [0.29028466, -0.95694035], [0.24298018, -0.97003126], [0.19509032, -0.98078525], [0.14673047, -0.9891765], [0.09801714, -0.9951847], [0.049067676, -0.99879545], [6.123234e-17, -1.0], [-0.049067676, -0.99879545], [-0.09801714, -0.9951847], [-0.14673047, -0.9891765], [-0.19509032, -0.98078525], [-0.24298018, -0.97003126], [-0.29028466, -0.95694035], [-0.33688986, -0.94154406], [-0.38268343, -0.9238795], [-0.42755508, -0.9039893], [-0.47139674, -0.8819213], [-0.51410276, -0.8577286], [-0.55557024, -0.8314696], [-0.5956993, -0.8032075], [-0.6343933, -0.77301043], [-0.671559, -0.7409511], [-0.70710677, -0.70710677], [-0.7409511, -0.671559], [-0.77301043, -0.6343933], [-0.8032075, -0.5956993], [-0.8314696, -0.55557024], [-0.8577286, -0.51410276], [-0.8819213, -0.47139674], [-0.9039893, -0.42755508], [-0.9238795, -0.38268343], [-0.94154406, -0.33688986], [-0.95694035, -0.29028466], [-0.97003126, -0.24298018], [-0.98078525, -0.19509032], [-0.9891765, -0.14673047], [-0.9951847, -0.09801714], [-0.99879545, -0.049067676], [-1.0, -1.2246469e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Dc,
+        n: 128,
+        input: &[0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, ],
+        spectrum: &[[64.0, 0.0], [-1.6653345e-15, -3.7296555e-15], [-1.1657342e-15, 3.5735304e-15], [-2.220446e-15, 1.4571677e-15], [-5.162537e-15, -1.1241008e-15], [-3.4416914e-15, -4.8572257e-16], [-2.164935e-15, -1.3322676e-15], [-9.436896e-16, 3.8857806e-16], [-3.3861802e-15, 5.551115e-17], [-1.7763568e-15, 1.110223e-15], [-1.6653345e-15, -4.9960036e-16], [-1.3322676e-15, -9.992007e-16], [-3.1641356e-15, 1.3877788e-15], [-2.1094237e-15, -2.7755576e-16], [-3.1641356e-15, -1.6653345e-15], [-2.7755576e-15, -5.551115e-16], [-3.608225e-15, 1.110223e-16], [-2.7755576e-15, 5.551115e-17], [-2.553513e-15, -1.6653345e-16], [-2.2759572e-15, -1.110223e-16], [-3.4972025e-15, -8.3266727e-16], [-2.8865799e-15, -3.8857806e-16], [-2.6645353e-15, -8.881784e-16], [-2.1371793e-15, -1.6653345e-16], [-3.5804693e-15, -1.6653345e-16], [-2.8033131e-15, -4.440892e-16], [-2.6645353e-15, -6.1062266e-16], [-2.414735e-15, -3.8857806e-16], [-3.4416914e-15, -8.881784e-16], [-2.7061686e-15, -3.330669e-16], [-2.6714742e-15, -4.440892e-16], [-2.6610658e-15, -4.9960036e-16], [-1.8126941e-15, -3.5527137e-15], [-2.5743296e-15, -1.110223e-16], [-2.7824965e-15, -8.881784e-16], [-2.5257574e-15, 5.551115e-17], [-3.5665915e-15, -1.6653345e-16], [-2.9282132e-15, -4.9960036e-16], [-3.0808689e-15, -4.440892e-16], [-2.4702462e-15, -4.9960036e-16], [-3.5804693e-15, 8.881784e-16], [-2.9143354e-15, -2.7755576e-16], [-3.0808689e-15, -6.661338e-16], [-2.220446e-15, -3.330669e-16], [-3.0531133e-15, 5.551115e-17], [-2.3314684e-15, -2.220446e-16], [-2.7755576e-15, -6.661338e-16], [-2.553513e-15, -2.7755576e-16], [-1.7763568e-15, 0.0], [-2.6090241e-15, -2.7755576e-16], [-2.6645353e-15, -8.3266727e-16], [-2.4980018e-15, -1.110223e-16], [-2.7755576e-15, -5.551115e-17], [-2.6645353e-15, -3.330669e-16], [-2.942091e-15, -6.938894e-16], [-2.4980018e-15, -2.4980018e-16], [-2.7200464e-15, 8.326673e-17], [-2.4424907e-15, -3.8857806e-16], [-2.7755576e-15, -9.15934e-16], [-2.3869795e-15, -4.718448e-16], [-3.1086245e-15, -2.220446e-16], [-2.3869795e-15, -3.8857806e-16], [-2.8865799e-15, -7.5633944e-16], [-2.2759572e-15, -2.9837244e-16], [0.0, -3.91887e-15], ],
+    },
+    GoldenVector {
+        class: SignalClass::Sine,
+        n: 128,
+        input: &[0.22266357, 0.3603889, 0.48867822, 0.6041725, 0.7038478, 0.7850943, 0.84578484, 0.8843302, 0.89972126, 0.89155495, 0.8600452, 0.806017, 0.73088485, 0.63661605, 0.5256788, 0.40097773, 0.26577792, 0.123619266, -0.0217761, -0.1666013, -0.3070644, -0.43948767, -0.5604039, -0.6666471, -0.75543547, -0.8244444, -0.87186706, -0.8964616, -0.89758426, -0.8752055, -0.82991135, -0.76288766, -0.67588943, -0.5711944, -0.45154387, -0.3200706, -0.18021694, -0.03564469, 0.109860845, 0.2524899, 0.38850805, 0.51435393, 0.6267325, 0.72270143, 0.7997479, 0.8558547, 0.8895527, 0.8999596, 0.88680303, 0.8504273, 0.79178494, 0.7124114, 0.61438483, 0.50027186, 0.37306032, 0.23608099, 0.09292038, -0.05267316, -0.19688757, -0.3359469, -0.46621013, -0.58426666, -0.68702537, -0.77179575, -0.8363583, -0.8790226, -0.89867145, -0.89479053, -0.8674814, -0.81745905, -0.74603325, -0.6550742, -0.54696333, -0.4245314, -0.290984, -0.14981781, -0.0047289412, 0.14048374, 0.28201815, 0.4161685, 0.5394224, 0.6485526, 0.7407018, 0.8134573, 0.8649141, 0.893725, 0.89913553, 0.8810041, 0.8398054, 0.7766182, 0.6930969, 0.5914282, 0.4742743, 0.34470248, 0.20610535, 0.062111795, -0.08350804, -0.22694139, -0.36443275, -0.4923822, -0.6074397, -0.7065926, -0.78724486, -0.8472848, -0.88514036, -0.8998203, -0.8909404, -0.8587331, -0.8040417, -0.7282981, -0.63348556, -0.5220865, -0.39701775, -0.26155388, -0.119241804, 0.026192391, 0.17094079, 0.31121346, 0.44333768, 0.563854, 0.669607, 0.75782776, 0.8262063, 0.87295246, 0.8968422, 0.89724994, 0.8741651, 0.8281921, ],
+        spectrum: &[[8.066822, 0.0], [8.911514, -1.1101323], [12.9167595, -3.1850798], [47.84791, -17.39934], [-18.090656, 8.568418], [-6.734851, 3.871829], [-3.910366, 2.6050222], [-2.6744995, 1.9971933], [-2.0003152, 1.6328539], [-1.5850009, 1.3867075], [-1.3083676, 1.2075301], [-1.1137041, 1.0702937], [-0.9709936, 0.9612301], [-0.8629832, 0.87209475], [-0.77911854, 0.7976271], [-0.7126186, 0.73429805], [-0.6589513, 0.6796459], [-0.6149869, 0.6318958], [-0.5785028, 0.58973336], [-0.5478838, 0.5521625], [-0.5219321, 0.5184138], [-0.499743, 0.48788244], [-0.48062238, 0.4600862], [-0.46403095, 0.43463528], [-0.44954228, 0.41121116], [-0.43681854, 0.38954982], [-0.42558718, 0.36943218], [-0.41562605, 0.35067347], [-0.40675417, 0.33311677], [-0.39882132, 0.31662893], [-0.39170328, 0.30109483], [-0.38529548, 0.28641504], [-0.3795103, 0.27250314], [-0.37427306, 0.25928348], [-0.3695204, 0.24669015], [-0.36519808, 0.2346643], [-0.3612597, 0.22315456], [-0.35766447, 0.21211325], [-0.35437846, 0.20150061], [-0.3513704, 0.19127871], [-0.3486141, 0.18141481], [-0.34608662, 0.17187798], [-0.34376702, 0.16264096], [-0.34163803, 0.15367854], [-0.3396835, 0.14496927], [-0.33788952, 0.13649061], [-0.33624393, 0.12822421], [-0.33473557, 0.12015191], [-0.3333547, 0.11225738], [-0.33209306, 0.10452566], [-0.33094242, 0.09694232], [-0.32989645, 0.089493945], [-0.3289491, 0.08216813], [-0.32809466, 0.074952655], [-0.32732877, 0.06783671], [-0.3266475, 0.060809694], [-0.32604668, 0.053861104], [-0.32552338, 0.046981588], [-0.3250752, 0.040161494], [-0.32469988, 0.033391934], [-0.3243949, 0.026664145], [-0.32415935, 0.019969424], [-0.32399192, 0.013299289], [-0.32389197, 0.006645404], [-0.32385862, -5.13781e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Chirp,
+        n: 128,
+        input: &[0.8, 0.79993975, 0.7990364, 0.7951256, 0.7846282, 0.7626448, 0.72319144, 0.6596714, 0.56568545, 0.43625998, 0.26951188, 0.06863785, -0.15607226, -0.38574702, -0.5927609, -0.7428049, -0.8, -0.73529106, -0.5372472, -0.22281575, 0.15607226, 0.51506525, 0.7532353, 0.78265387, 0.56568545, 0.14643191, -0.3420441, -0.7101117, -0.7846282, -0.4998876, 0.03925414, 0.5725847, 0.8, 0.558701, -0.03925414, -0.6245898, -0.7846282, -0.36843097, 0.3420441, 0.78648436, 0.56568545, -0.1656891, -0.7532353, -0.6121338, 0.15607226, 0.7683444, 0.5372472, -0.31519362, -0.8, -0.29705375, 0.5927609, 0.7008561, -0.15607226, -0.7970501, -0.26951188, 0.6705798, 0.56568545, -0.45258546, -0.72319144, 0.24160476, 0.7846282, -0.08817776, -0.7990364, 0.0098172305, 0.8, -0.0098172305, -0.7990364, 0.08817776, 0.7846282, -0.24160476, -0.72319144, 0.45258546, 0.56568545, -0.6705798, -0.26951188, 0.7970501, -0.15607226, -0.7008561, 0.5927609, 0.29705375, -0.8, 0.31519362, 0.5372472, -0.7683444, 0.15607226, 0.6121338, -0.7532353, 0.1656891, 0.56568545, -0.78648436, 0.3420441, 0.36843097, -0.7846282, 0.6245898, -0.03925414, -0.558701, 0.8, -0.5725847, 0.03925414, 0.4998876, -0.7846282, 0.7101117, -0.3420441, -0.14643191, 0.56568545, -0.78265387, 0.7532353, -0.51506525, 0.15607226, 0.22281575, -0.5372472, 0.73529106, -0.8, 0.7428049, -0.5927609, 0.38574702, -0.15607226, -0.06863785, 0.26951188, -0.43625998, 0.56568545, -0.6596714, 0.72319144, -0.7626448, 0.7846282, -0.7951256, 0.7990364, -0.79993975, ],
+        spectrum: &[[4.5254836, 0.0], [4.7420874, -0.05889151], [5.3214054, -0.43013954], [6.0258822, -1.402113], [6.4, -3.1469028], [5.7855315, -5.51793], [3.5556495, -7.790936], [-0.31403306, -8.600158], [-4.5254836, -6.5154495], [-6.392291, -1.4945258], [-3.5556495, 3.6643968], [2.7363527, 4.255816], [6.4, -1.4221299], [2.156095, -7.3565063], [-5.3214054, -4.8079767], [-4.2979774, 3.5571184], [4.5254836, 3.3987334], [4.2979774, -5.818327], [-5.3214054, -4.5879416], [-2.156095, 5.0318966], [6.4, -0.96057105], [-2.7363527, -6.7169743], [-3.5556495, 4.4153023], [6.392291, -1.1981785], [-4.5254836, -5.3907185], [0.31403306, 5.5431924], [3.5556495, -6.156917], [-5.7855315, 1.9120601], [6.4, -0.81529725], [-6.0258822, -2.964507], [5.3214054, 2.7520967], [-4.7420874, -5.0986376], [4.5254836, 3.7257836], [-4.7420874, -5.0986376], [5.3214054, 2.7520967], [-6.0258822, -2.964507], [6.4, -0.81529725], [-5.7855315, 1.9120601], [3.5556495, -6.156917], [0.31403306, 5.5431924], [-4.5254836, -5.3907185], [6.392291, -1.1981785], [-3.5556495, 4.4153023], [-2.7363527, -6.7169743], [6.4, -0.96057105], [-2.156095, 5.0318966], [-5.3214054, -4.5879416], [4.2979774, -5.818327], [4.5254836, 3.3987334], [-4.2979774, 3.5571184], [-5.3214054, -4.8079767], [2.156095, -7.3565063], [6.4, -1.4221299], [2.7363527, 4.255816], [-3.5556495, 3.6643968], [-6.392291, -1.4945258], [-4.5254836, -6.5154495], [-0.31403306, -8.600158], [3.5556495, -7.790936], [5.7855315, -5.51793], [6.4, -3.1469028], [6.0258822, -1.402113], [5.3214054, -0.43013954], [4.7420874, -0.05889151], [4.5254836, 6.162976e-32], ],
+    },
+    GoldenVector {
+        class: SignalClass::Noise,
+        n: 128,
+        input: &[-0.075499356, 0.5427347, -0.63380194, -0.3701134, 0.034223914, 0.693744, 0.7457383, 0.80728966, 0.26851842, 0.0127315875, -0.67154765, -0.1734576, -0.42593297, -0.53436875, 0.35172912, -0.12056036, -0.36871266, 0.6924774, 0.17837366, -0.3043964, -0.09116367, -0.06528808, -0.0046508433, 0.79488844, 0.53862256, -0.07090688, 0.1783404, 0.095449194, -0.68554926, 0.30883083, 0.5502619, 0.6431001, 0.16742553, -0.4974266, -0.1881633, -0.12241441, 0.52123606, 0.17187059, 0.47237006, -0.09860734, -0.8195498, -0.12226067, 0.8588436, 0.06672478, 0.2549402, -0.48304546, -0.5685446, -0.7537332, -0.12643236, -0.4813078, -0.7162708, -0.39663744, 0.49024934, -0.7578504, -0.6446801, 0.5272328, 0.8355328, -0.7342342, 0.73563915, 0.64885616, 0.016803075, -0.49133006, -0.71357405, -0.6968603, 0.720249, -0.8250199, -0.738612, 0.05300324, 0.5177103, -0.43842036, 0.25103748, -0.45899698, 0.33153456, 0.41999424, 0.23084894, -0.6158808, 0.16056487, 0.8129961, 0.34086028, 0.07733281, -0.5796124, -0.34344035, -0.72515464, -0.6783842, -0.09903906, 0.58779323, -0.19281767, -0.35803822, 0.7947525, -0.75052685, 0.035540663, 0.011079025, 0.8985089, -0.06819602, -0.032484878, 0.6443739, 0.14178446, -0.83796984, 0.52932924, 0.18184744, 0.24535088, 0.28252438, -0.4752662, -0.8774683, -0.63738364, -0.6920224, 0.32222697, 0.42708987, -0.59282357, -0.19768427, 0.898718, 0.45420507, 0.21962324, -0.2340326, -0.2822377, -0.1536159, 0.17673783, 0.17410852, -0.4295079, -0.36920083, -0.103811145, 0.65650916, -0.04263382, 0.4507947, 0.7398662, 0.45960522, -0.3611337, -0.10631762, ],
+        spectrum: &[[-0.85332686, 0.0], [5.5873833, -0.7839043], [-0.93594253, -3.1940553], [0.65561914, 3.529059], [5.538055, 2.033995], [0.24242824, -0.0024017175], [-3.582759, -1.4383622], [7.9232006, -7.1506095], [-5.414691, 4.1375737], [0.5946142, 2.1821787], [-8.17343, 1.7914984], [-7.5932193, 3.4472005], [-4.536004, -3.4766212], [-1.1878432, 3.5057812], [-4.249818, 2.398616], [2.706319, 6.7232194], [-2.7859588, 4.446878], [1.3850138, -1.8127115], [8.125984, -6.1372705], [-6.2840757, 2.0526125], [-0.3330407, 2.5474327], [7.696913, -2.3724544], [3.2246118, -8.336759], [3.3780751, -1.1299691], [4.422442, 5.040639], [-2.6005845, -2.4351203], [-3.1565664, 1.2143569], [-4.003926, -4.439382], [6.035996, -3.3201482], [-2.577615, -2.4353607], [9.583712, -3.4242156], [-5.3712726, -4.2843666], [4.2099485, 2.0379956], [-2.3371477, -0.7624666], [1.3020535, -2.181573], [7.069268, -7.72648], [1.1046329, 9.038761], [2.0989158, -0.3291842], [1.3780546, -4.585891], [0.38763326, 4.17204], [-1.7194961, -4.842189], [-9.436798, -1.2756418], [4.551985, 0.6746946], [-0.9110582, 7.006231], [5.8459024, -0.00033217127], [-5.4171405, 1.0975072], [0.19642031, -4.678797], [-0.60538304, -5.1597075], [1.0766759, 2.2122092], [-3.5397704, 0.099652335], [-1.2532912, -3.5572455], [-2.0577064, 0.3923949], [-0.5700513, 3.595536], [0.08816717, -2.0581431], [0.5547334, -2.4842954], [0.9833985, 1.1318718], [-1.7258182, -0.029540796], [3.190007, -1.445268], [-0.34491792, 2.8458092], [-4.612798, -1.9877472], [1.38726, 0.637009], [-2.2391322, 2.9287724], [-1.8521935, -2.6583376], [-8.675432, -1.6546175], [6.3083286, 4.3852492e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Impulse,
+        n: 512,
+        input: &[0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, ],
+        spectrum: &[[1.0, 0.0], [0.9999247, -0.012271538], [0.9996988, -0.024541229], [0.99932235, -0.036807224], [0.99879545, -0.049067676], [0.9981181, -0.061320737], [0.99729043, -0.07356457], [0.9963126, -0.08579731], [0.9951847, -0.09801714], [0.993907, -0.110222206], [0.99247956, -0.12241068], [0.99090266, -0.1345807], [0.9891765, -0.14673047], [0.9873014, -0.15885815], [0.98527765, -0.17096189], [0.9831055, -0.18303989], [0.98078525, -0.19509032], [0.9783174, -0.20711137], [0.9757021, -0.21910124], [0.97293997, -0.2310581], [0.97003126, -0.24298018], [0.96697646, -0.25486565], [0.96377605, -0.26671275], [0.9604305, -0.2785197], [0.95694035, -0.29028466], [0.953306, -0.30200595], [0.94952816, -0.31368175], [0.9456073, -0.3253103], [0.94154406, -0.33688986], [0.937339, -0.34841868], [0.9329928, -0.35989505], [0.9285061, -0.3713172], [0.9238795, -0.38268343], [0.9191139, -0.39399204], [0.9142098, -0.4052413], [0.909168, -0.41642955], [0.9039893, -0.42755508], [0.8986745, -0.43861625], [0.8932243, -0.44961134], [0.88763964, -0.46053872], [0.8819213, -0.47139674], [0.8760701, -0.48218378], [0.87008697, -0.4928982], [0.86397284, -0.50353837], [0.8577286, -0.51410276], [0.8513552, -0.52458966], [0.8448536, -0.53499764], [0.8382247, -0.545325], [0.8314696, -0.55557024], [0.8245893, -0.5657318], [0.8175848, -0.57580817], [0.81045717, -0.58579785], [0.8032075, -0.5956993], [0.7958369, -0.60551107], [0.7883464, -0.6152316], [0.7807372, -0.6248595], [0.77301043, -0.6343933], [0.76516724, -0.64383155], [0.7572088, -0.65317285], [0.7491364, -0.6624158], [0.7409511, -0.671559], [0.7326543, -0.680601], [0.7242471, -0.68954057], [0.71573085, -0.69837624], [0.70710677, -0.70710677], [0.69837624, -0.71573085], [0.68954057, -0.7242471], [0.680601, -0.7326543], [0.671559, -0.7409511], [0.6624158, -0.7491364], [0.65317285, -0.7572088], [0.64383155, -0.76516724], [0.6343933, -0.77301043], [0.6248595, -0.7807372], [0.6152316, -0.7883464], [0.60551107, -0.7958369], [0.5956993, -0.8032075], [0.58579785, -0.81045717], [0.57580817, -0.8175848], [0.5657318, -0.8245893], [0.55557024, -0.8314696], [0.545325, -0.8382247], [0.53499764, -0.8448536], [0.52458966, -0.8513552], [0.51410276, -0.8577286], [0.50353837, -0.86397284], [0.4928982, -0.87008697], [0.48218378, -0.8760701], [0.47139674, -0.8819213], [0.46053872, -0.88763964], [0.44961134, -0.8932243], [0.43861625, -0.8986745], [0.42755508, -0.9039893], [0.41642955, -0.909168], [0.4052413, -0.9142098], [0.39399204, -0.9191139], [0.38268343, -0.9238795], [0.3713172, -0.9285061], [0.35989505, -0.9329928], [0.34841868, -0.937339], [0.33688986, -0.94154406], [0.3253103, -0.9456073], [0.31368175, -0.94952816], [0.30200595, -0.953306], [0.29028466, -0.95694035], [0.2785197, -0.9604305], [0.26671275, -0.96377605], [0.25486565, -0.96697646], [0.24298018, -0.97003126], [0.2310581, -0.97293997], [0.21910124, -0.9757021], [0.20711137, -0.9783174], [0.19509032, -0.98078525], [0.18303989, -0.9831055], [0.17096189, -0.98527765], [0.15885815, -0.9873014], [0.14673047, -0.9891765], [0.1345807, -0.99090266], [0.12241068, -0.99247956], [0.110222206, -0.993907], [0.09801714, -0.9951847], [0.08579731, -0.9963126], [0.07356457, -0.99729043], [0.061320737, -0.9981181], [0.049067676, -0.99879545], [0.036807224, -0.99932235], [0.024541229, -0.9996988], [0.012271538, -0.9999247], [6.123234e-17, -1.0], [-0.012271538, -0.9999247], [-0.024541229, -0.9996988], [-0.036807224, -0.99932235], [-0.049067676, -0.99879545], [-0.061320737, -0.9981181], [-0.07356457, -0.99729043], [-0.08579731, -0.9963126], [-0.09801714, -0.9951847], [-0.110222206, -0.993907], [-0.12241068, -0.99247956], [-0.1345807, -0.99090266], [-0.14673047, -0.9891765], [-0.15885815, -0.9873014], [-0.17096189, -0.98527765], [-0.18303989, -0.9831055], [-0.19509032, -0.98078525], [-0.20711137, -0.9783174], [-0.21910124, -0.9757021], [-0.2310581, -0.97293997], [-0.24298018, -0.97003126], [-0.25486565, -0.96697646], [-0.26671275, -0.96377605], [-0.2785197, -0.9604305], [-0.29028466, -0.95694035], [-0.30200595, -0.953306], [-0.31368175, -0.94952816], [-0.3253103, -0.9456073], [-0.33688986, -0.94154406], [-0.34841868, -0.937339], [-0.35989505, -0.9329928], [-0.3713172, -0.9285061], [-0.38268343, -0.9238795], [-0.39399204, -0.9191139], [-0.4052413, -0.9142098], [-0.41642955, -0.909168], [-0.42755508, -0.9039893], [-0.43861625, -0.8986745], [-0.44961134, -0.8932243], [-0.46053872, -0.88763964], [-0.47139674, -0.8819213], [-0.48218378, -0.8760701], [-0.4928982, -0.87008697], [-0.50353837, -0.86397284], [-0.51410276, -0.8577286], [-0.52458966, -0.8513552], [-0.53499764, -0.8448536], [-0.545325, -0.8382247], [-0.55557024, -0.8314696], [-0.5657318, -0.8245893], [-0.57580817, -0.8175848], [-0.58579785, -0.81045717], [-0.5956993, -0.8032075], [-0.60551107, -0.7958369], [-0.6152316, -0.7883464], [-0.6248595, -0.7807372], [-0.6343933, -0.77301043], [-0.64383155, -0.76516724], [-0.65317285, -0.7572088], [-0.6624158, -0.7491364], [-0.671559, -0.7409511], [-0.680601, -0.7326543], [-0.68954057, -0.7242471], [-0.69837624, -0.71573085], [-0.70710677, -0.70710677], [-0.71573085, -0.69837624], [-0.7242471, -0.68954057], [-0.7326543, -0.680601], [-0.7409511, -0.671559], [-0.7491364, -0.6624158], [-0.7572088, -0.65317285], [-0.76516724, -0.64383155], [-0.77301043, -0.6343933], [-0.7807372, -0.6248595], [-0.7883464, -0.6152316], [-0.7958369, -0.60551107], [-0.8032075, -0.5956993], [-0.81045717, -0.58579785], [-0.8175848, -0.57580817], [-0.8245893, -0.5657318], [-0.8314696, -0.55557024], [-0.8382247, -0.545325], [-0.8448536, -0.53499764], [-0.8513552, -0.52458966], [-0.8577286, -0.51410276], [-0.86397284, -0.50353837], [-0.87008697, -0.4928982], [-0.8760701, -0.48218378], [-0.8819213, -0.47139674], [-0.88763964, -0.46053872], [-0.8932243, -0.44961134], [-0.8986745, -0.43861625], [-0.9039893, -0.42755508], [-0.909168, -0.41642955], [-0.9142098, -0.4052413], [-0.9191139, -0.39399204], [-0.9238795, -0.38268343], [-0.9285061, -0.3713172], [-0.9329928, -0.35989505], [-0.937339, -0.34841868], [-0.94154406, -0.33688986], [-0.9456073, -0.3253103], [-0.94952816, -0.31368175], [-0.953306, -0.30200595], [-0.95694035, -0.29028466], [-0.9604305, -0.2785197], [-0.96377605, -0.26671275], [-0.96697646, -0.25486565], [-0.97003126, -0.24298018], [-0.97293997, -0.2310581], [-0.9757021, -0.21910124], [-0.9783174, -0.20711137], [-0.98078525, -0.19509032], [-0.9831055, -0.18303989], [-0.98527765, -0.17096189], [-0.9873014, -0.15885815], [-0.9891765, -0.14673047], [-0.99090266, -0.1345807], [-0.99247956, -0.12241068], [-0.993907, -0.110222206], [-0.9951847, -0.09801714], [-0.9963126, -0.08579731], [-0.99729043, -0.07356457], [-0.9981181, -0.061320737], [-0.99879545, -0.049067676], [-0.99932235, -0.036807224], [-0.9996988, -0.024541229], [-0.9999247, -0.012271538], [-1.0, -1.2246469e-16], ],
+    },
+    GoldenVector {
+        class: SignalClass::Dc,
+        n: 512,
+        input: &[0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, 0.5, ],
+        spectrum: &[[256.0, 0.0], [-9.992007e-15, -1.4522238e-14], [2.942091e-15, -2.459491e-14], [-7.771561e-16, 3.3931191e-15], [-6.994405e-15, -3.7296555e-15], [-4.6629367e-15, 3.903128e-15], [-3.5527137e-15, -6.3907213e-15], [-8.6042284e-15, -2.5188185e-15], [-1.1657342e-15, 1.4231671e-14], [-7.605028e-15, -1.2004286e-15], [-1.4765966e-14, 4.1633363e-16], [-1.5654145e-14, 4.510281e-15], [-1.0214052e-14, 6.7862382e-15], [-1.37667655e-14, 3.4833247e-15], [-1.4377388e-14, 5.5233595e-15], [-1.15463195e-14, -1.1241008e-15], [-2.1149749e-14, -1.1241008e-15], [-1.1712853e-14, -7.771561e-16], [-1.3933299e-14, 3.8857806e-15], [-9.825474e-15, 1.7069679e-15], [-1.4099832e-14, -4.8572257e-16], [-1.1768364e-14, -4.1633363e-16], [-8.770762e-15, 2.8588243e-15], [-8.9928065e-15, 3.330669e-16], [-7.494005e-15, -6.661338e-15], [-9.7144515e-15, 1.1379786e-15], [-6.217249e-15, 3.330669e-16], [-8.382184e-15, -2.2759572e-15], [-3.608225e-15, 3.8857806e-16], [-8.493206e-15, -4.440892e-16], [-8.104628e-15, -1.7763568e-15], [-7.327472e-15, -2.7755576e-16], [-1.4044321e-14, 5.551115e-17], [-8.881784e-15, 0.0], [-7.494005e-15, -1.6653345e-16], [-9.936496e-15, -1.3600232e-15], [-7.1054274e-15, 5.107026e-15], [-8.826273e-15, 1.3045121e-15], [-8.326673e-15, 1.2490009e-15], [-7.5495166e-15, -1.0547119e-15], [-6.994405e-15, -3.1641356e-15], [-9.547918e-15, 3.6082248e-16], [-8.715251e-15, 7.771561e-16], [-8.937295e-15, -5.551115e-16], [-5.3290705e-15, -4.9960036e-15], [-1.0214052e-14, 1.3322676e-15], [-9.15934e-15, 2.7755576e-15], [-1.0214052e-14, -2.7755576e-16], [-1.3822277e-14, 6.7168493e-15], [-8.9928065e-15, 1.3322676e-15], [-8.104628e-15, 6.661338e-16], [-9.15934e-15, -4.9960036e-16], [-7.438494e-15, -2.7755576e-16], [-1.0214052e-14, 3.330669e-16], [-8.881784e-15, 9.436896e-16], [-1.0325074e-14, 1.4432899e-15], [-1.2490009e-14, -6.994405e-15], [-1.1768364e-14, 3.330669e-16], [-1.060263e-14, 9.992007e-16], [-1.0047518e-14, 1.110223e-16], [-1.1435297e-14, -3.2196468e-15], [-9.492407e-15, 7.771561e-16], [-1.0491608e-14, -5.551115e-17], [-1.1046719e-14, 1.3877788e-15], [-1.4266366e-14, 1.110223e-16], [-1.08246745e-14, 1.6653345e-16], [-1.01030295e-14, 1.831868e-15], [-1.0547119e-14, 3.330669e-16], [-1.1435297e-14, 5.551115e-17], [-9.880985e-15, 3.8857806e-16], [-1.0713652e-14, 6.661338e-16], [-1.1157741e-14, 1.6653345e-16], [-1.0547119e-14, -1.6653345e-16], [-1.0547119e-14, 9.992007e-16], [-1.0658141e-14, -1.6653345e-16], [-9.880985e-15, -4.440892e-16], [-8.937295e-15, -1.110223e-16], [-9.825474e-15, -1.7208457e-15], [-9.270362e-15, -2.220446e-16], [-1.01030295e-14, -2.7755576e-16], [-1.41553436e-14, -3.4972025e-15], [-1.0380585e-14, -1.110223e-16], [-1.0547119e-14, -1.3322676e-15], [-1.0880186e-14, -1.6653345e-16], [-1.15463195e-14, -1.0547119e-15], [-1.0047518e-14, 5.551115e-16], [-1.0741408e-14, -1.0547119e-15], [-9.65894e-15, -6.1062266e-16], [-1.0658141e-14, -3.5527137e-15], [-9.353629e-15, -5.551115e-16], [-1.0713652e-14, -1.9428903e-15], [-1.060263e-14, -5.551115e-16], [-8.132384e-15, -8.3266727e-16], [-9.769963e-15, -4.440892e-16], [-1.0741408e-14, -1.8873791e-15], [-9.797718e-15, -8.3266727e-16], [-1.423861e-14, -1.6653345e-16], [-1.0491608e-14, -1.0547119e-15], [-1.0241807e-14, -8.881784e-16], [-9.742207e-15, 0.0], [-1.1463053e-14, -1.7763568e-15], [-9.880985e-15, -6.661338e-16], [-1.1129986e-14, -1.831868e-15], [-9.742207e-15, -1.5543122e-15], [-1.0658141e-14, -1.9428903e-15], [-9.964252e-15, -4.440892e-16], [-1.0269563e-14, -1.3322676e-15], [-1.02973186e-14, -4.440892e-16], [-9.742207e-15, -1.7208457e-15], [-1.0685897e-14, -4.9960036e-16], [-1.1532442e-14, -9.436896e-16], [-1.0422219e-14, 3.330669e-16], [-1.4099832e-14, -3.5527137e-15], [-1.0935697e-14, -9.436896e-16], [-1.0685897e-14, -1.7763568e-15], [-1.0547119e-14, 5.551115e-17], [-1.0699774e-14, -9.992007e-16], [-1.0783041e-14, -4.9960036e-16], [-9.2495456e-15, -1.110223e-16], [-1.02973186e-14, 1.110223e-16], [-1.066508e-14, -1.7763568e-15], [-1.0283441e-14, 1.110223e-16], [-1.05609965e-14, -4.9960036e-16], [-9.690165e-15, -2.220446e-16], [-1.0654672e-14, -1.831868e-15], [-1.1046719e-14, -5.551115e-16], [-9.4629166e-15, 0.0], [-1.0545384e-14, -2.7755576e-16], [-7.141765e-15, -1.4210855e-14], [-1.0233134e-14, -4.9960036e-16], [-1.0482934e-14, -4.9960036e-16], [-9.842821e-15, 1.110223e-16], [-1.0567935e-14, -7.771561e-16], [-1.0921819e-14, 2.220446e-16], [-1.0942636e-14, -7.2164497e-16], [-1.0054457e-14, -5.551115e-17], [-1.0776102e-14, -3.5527137e-15], [-1.11508025e-14, 5.551115e-17], [-1.0470791e-14, -1.3877788e-15], [-1.0449974e-14, -5.551115e-17], [-1.0519363e-14, 5.551115e-17], [-1.0061396e-14, -2.220446e-16], [-1.0644263e-14, -1.831868e-15], [-1.0005885e-14, 1.110223e-16], [-1.4224733e-14, -1.6653345e-16], [-1.0408341e-14, -3.8857806e-16], [-1.0796919e-14, -8.881784e-16], [-1.0144663e-14, -2.220446e-16], [-1.1587953e-14, -1.831868e-15], [-9.992007e-15, -3.8857806e-16], [-1.0658141e-14, -7.2164497e-16], [-1.0186296e-14, -5.551115e-16], [-1.2406742e-14, -1.7763568e-15], [-9.65894e-15, 0.0], [-9.353629e-15, -6.661338e-16], [-9.5201624e-15, -4.9960036e-16], [-9.797718e-15, -1.831868e-15], [-1.02973186e-14, -5.551115e-16], [-1.0130785e-14, -8.3266727e-16], [-1.035283e-14, -2.220446e-16], [-1.423861e-14, 3.5527137e-15], [-1.02973186e-14, -1.6653345e-16], [-1.02973186e-14, -1.3877788e-15], [-9.992007e-15, -6.661338e-16], [-1.1574075e-14, -9.436896e-16], [-1.0380585e-14, -2.220446e-16], [-1.0158541e-14, -4.440892e-16], [-1.0019763e-14, -8.881784e-16], [-1.2406742e-14, -2.6645353e-15], [-9.769963e-15, -2.220446e-16], [-1.0075274e-14, -1.110223e-15], [-1.0269563e-14, -3.330669e-16], [-8.881784e-15, -1.3322676e-15], [-1.04360964e-14, -2.220446e-16], [-9.880985e-15, -6.661338e-16], [-1.0214052e-14, -8.881784e-16], [-1.2378987e-14, 5.551115e-17], [-1.0713652e-14, -5.551115e-17], [-1.0325074e-14, -6.1062266e-16], [-9.936496e-15, -3.8857806e-16], [-9.65894e-15, -8.881784e-16], [-1.0269563e-14, -3.330669e-16], [-1.0158541e-14, -1.3322676e-15], [-1.0158541e-14, -2.220446e-16], [-1.0769163e-14, -2.6645353e-15], [-1.08246745e-14, -5.551115e-17], [-9.880985e-15, -1.0547119e-15], [-1.0380585e-14, -1.110223e-16], [-9.880985e-15, -9.436896e-16], [-1.04360964e-14, 0.0], [-9.769963e-15, -1.3877788e-15], [-9.547918e-15, 1.6653345e-16], [-7.1054274e-15, 0.0], [-1.04360964e-14, -4.440892e-16], [-9.936496e-15, -4.440892e-16], [-9.936496e-15, -3.8857806e-16], [-9.936496e-15, -9.436896e-16], [-1.0214052e-14, -1.6653345e-16], [-1.0214052e-14, -1.3322676e-15], [-9.547918e-15, -3.330669e-16], [-1.0658141e-14, -3.4972025e-15], [-9.992007e-15, -3.8857806e-16], [-9.65894e-15, -8.881784e-16], [-1.0491608e-14, -4.9960036e-16], [-9.825474e-15, -4.440892e-16], [-1.0214052e-14, -3.8857806e-16], [-9.65894e-15, -7.2164497e-16], [-1.0158541e-14, -2.220446e-16], [-1.0769163e-14, -5.551115e-17], [-9.936496e-15, -3.8857806e-16], [-1.0269563e-14, -6.661338e-16], [-9.65894e-15, -3.330669e-16], [-1.0658141e-14, -1.3322676e-15], [-9.603429e-15, -3.330669e-16], [-9.270362e-15, -7.2164497e-16], [-9.992007e-15, -2.220446e-16], [-1.2267964e-14, -2.6922908e-15], [-1.0325074e-14, -3.330669e-16], [-1.0658141e-14, -9.15934e-16], [-9.936496e-15, -3.6082248e-16], [-1.0491608e-14, -9.15934e-16], [-1.0269563e-14, -1.9428903e-16], [-1.0547119e-14, -8.6042284e-16], [-1.060263e-14, -2.220446e-16], [-1.0713652e-14, 8.326673e-17], [-1.0380585e-14, -2.4980018e-16], [-1.0269563e-14, -9.436896e-16], [-1.01030295e-14, -1.6653345e-16], [-9.769963e-15, -1.3877788e-15], [-1.01030295e-14, -3.6082248e-16], [-1.0214052e-14, -6.661338e-16], [-9.880985e-15, -1.3877788e-16], [-1.0769163e-14, -3.5804693e-15], [-1.0547119e-14, -3.330669e-16], [-1.0214052e-14, -9.15934e-16], [-9.880985e-15, -1.6653345e-16], [-9.7144515e-15, -1.8041124e-15], [-1.0269563e-14, -3.0531133e-16], [-1.110223e-14, -1.179612e-15], [-1.0047518e-14, -4.1633363e-16], [-1.2434498e-14, -8.881784e-16], [-1.0047518e-14, -2.4980018e-16], [-1.0214052e-14, -9.020562e-16], [-1.01030295e-14, -2.4980018e-16], [-9.7144515e-15, -1.3877788e-15], [-1.01030295e-14, -2.7755576e-16], [-1.0047518e-14, -8.951173e-16], [-1.0269563e-14, -3.400058e-16], [-1.15463195e-14, -3.0878078e-15], [-1.0325074e-14, -3.9551695e-16], [-1.0269563e-14, -8.257284e-16], [-1.0214052e-14, -2.9143354e-16], [-9.270362e-15, -1.2975732e-15], [-1.0047518e-14, -3.2265857e-16], [-9.769963e-15, -9.124645e-16], [-1.01030295e-14, -2.654127e-16], [0.0, -1.567548e-14], ],
+    },
+    GoldenVector {
+        class: SignalClass::Sine,
+        n: 512,
+        input: &[0.22266357, 0.25778568, 0.29248506, 0.32670486, 0.3603889, 0.393482, 0.42592984, 0.4576793, 0.48867822, 0.51887584, 0.54822254, 0.57667035, 0.6041725, 0.63068396, 0.65616125, 0.6805625, 0.7038478, 0.725979, 0.7469197, 0.7666356, 0.7850943, 0.80226576, 0.8181216, 0.8326359, 0.84578484, 0.85754687, 0.8679027, 0.87683535, 0.8843302, 0.8903749, 0.8949596, 0.8980768, 0.89972126, 0.89989036, 0.8985839, 0.89580387, 0.89155495, 0.8858441, 0.8786806, 0.8700763, 0.8600452, 0.84860384, 0.83577096, 0.82156754, 0.806017, 0.7891447, 0.77097833, 0.7515478, 0.73088485, 0.7090234, 0.68599933, 0.66185033, 0.63661605, 0.61033785, 0.58305883, 0.5548237, 0.5256788, 0.49567187, 0.46485215, 0.4332702, 0.40097773, 0.36802778, 0.33447433, 0.3003724, 0.26577792, 0.23074763, 0.19533896, 0.15960997, 0.123619266, 0.08742584, 0.051089063, 0.014668508, -0.0217761, -0.058185, -0.09449849, -0.13065702, -0.1666013, -0.2022724, -0.2376118, -0.27256158, -0.3070644, -0.3410637, -0.37450373, -0.40732968, -0.43948767, -0.47092497, -0.5015901, -0.5314327, -0.5604039, -0.5884561, -0.61554337, -0.6416213, -0.6666471, -0.69057965, -0.7133799, -0.7350103, -0.75543547, -0.7746219, -0.7925381, -0.8091547, -0.8244444, -0.8383823, -0.85094535, -0.86211306, -0.87186706, -0.8801914, -0.8870724, -0.89249873, -0.8964616, -0.8989545, -0.8999733, -0.8995163, -0.89758426, -0.89418036, -0.88931024, -0.88298184, -0.8752055, -0.86599404, -0.8553625, -0.84332836, -0.82991135, -0.81513345, -0.79901886, -0.7815941, -0.76288766, -0.7429303, -0.7217546, -0.6993955, -0.67588943, -0.65127504, -0.62559277, -0.5988846, -0.5711944, -0.54256755, -0.51305103, -0.4826932, -0.45154387, -0.41965407, -0.38707614, -0.3538635, -0.3200706, -0.28575283, -0.2509665, -0.21576862, -0.18021694, -0.14436974, -0.1082858, -0.0720243, -0.03564469, 0.0007933679, 0.037230127, 0.073605835, 0.109860845, 0.1459357, 0.18177126, 0.21730874, 0.2524899, 0.28725702, 0.32155308, 0.35532188, 0.38850805, 0.4210571, 0.45291573, 0.48403168, 0.51435393, 0.5438327, 0.5724197, 0.6000681, 0.6267325, 0.6523692, 0.6769361, 0.700393, 0.72270143, 0.7438247, 0.7637283, 0.78237957, 0.7997479, 0.8158048, 0.83052397, 0.8438812, 0.8558547, 0.86642474, 0.87557405, 0.88328755, 0.8895527, 0.8943591, 0.89769906, 0.8995669, 0.8999596, 0.8988766, 0.8963197, 0.8922929, 0.88680303, 0.8798589, 0.87147206, 0.8616561, 0.8504273, 0.83780396, 0.82380676, 0.80845875, 0.79178494, 0.77381283, 0.75457186, 0.7340935, 0.7124114, 0.68956107, 0.66558003, 0.6405076, 0.61438483, 0.5872546, 0.55916137, 0.5301513, 0.50027186, 0.46957207, 0.43810228, 0.4059141, 0.37306032, 0.33959478, 0.3055724, 0.27104893, 0.23608099, 0.20072594, 0.16504173, 0.1290869, 0.09292038, 0.056601495, 0.020189796, -0.01625501, -0.05267316, -0.08900494, -0.12519076, -0.16117132, -0.19688757, -0.23228097, -0.26729348, -0.30186766, -0.3359469, -0.36947522, -0.40239766, -0.4346603, -0.46621013, -0.4969955, -0.5269659, -0.5560722, -0.58426666, -0.611503, -0.6377367, -0.6629245, -0.68702537, -0.7099996, -0.7318096, -0.7524196, -0.77179575, -0.7899064, -0.8067217, -0.8222141, -0.8363583, -0.84913105, -0.8605114, -0.8704807, -0.8790226, -0.88612306, -0.8917704, -0.89595556, -0.89867145, -0.8999137, -0.8996804, -0.8979717, -0.89479053, -0.8901421, -0.88403404, -0.87647635, -0.8674814, -0.85706395, -0.8452411, -0.83203226, -0.81745905, -0.8015454, -0.7843173, -0.76580316, -0.74603325, -0.72504, -0.70285785, -0.67952317, -0.6550742, -0.629551, -0.6029955, -0.57545125, -0.54696333, -0.51757854, -0.487345, -0.45631233, -0.4245314, -0.39205432, -0.35893437, -0.32522583, -0.290984, -0.256265, -0.22112581, -0.185624, -0.14981781, -0.11376594, -0.07752753, -0.041161984, -0.0047289412, 0.031711854, 0.06810065, 0.10437778, 0.14048374, 0.17635934, 0.21194576, 0.24718462, 0.28201815, 0.31638923, 0.3502415, 0.38351944, 0.4161685, 0.44813514, 0.47936693, 0.50981265, 0.5394224, 0.56814754, 0.5959411, 0.62275743, 0.6485526, 0.67328423, 0.6969118, 0.71939665, 0.7407018, 0.7607924, 0.77963537, 0.79719996, 0.8134573, 0.82838076, 0.8419458, 0.85413027, 0.8649141, 0.87427974, 0.8822117, 0.88869697, 0.893725, 0.8972875, 0.89937866, 0.89999497, 0.89913553, 0.8968017, 0.89299726, 0.8877285, 0.8810041, 0.87283504, 0.86323464, 0.85221875, 0.8398054, 0.826015, 0.81087005, 0.79439545, 0.7766182, 0.75756747, 0.7372745, 0.71577257, 0.6930969, 0.66928464, 0.64437497, 0.6184086, 0.5914282, 0.56347805, 0.53460383, 0.504853, 0.4742743, 0.4429179, 0.4108352, 0.37807882, 0.34470248, 0.3107609, 0.27630973, 0.24140547, 0.20610535, 0.17046727, 0.13454966, 0.09841141, 0.062111795, 0.025710322, -0.010733309, -0.04715934, -0.08350804, -0.1197198, -0.15573525, -0.19149533, -0.22694139, -0.2620153, -0.2966596, -0.3308174, -0.36443275, -0.3974505, -0.4298165, -0.46147773, -0.4923822, -0.5224793, -0.5517196, -0.58005524, -0.6074397, -0.63382804, -0.65917706, -0.6834452, -0.7065926, -0.72858137, -0.74937534, -0.76894057, -0.78724486, -0.8042583, -0.81995285, -0.83430284, -0.8472848, -0.85887736, -0.8690616, -0.8778207, -0.88514036, -0.89100856, -0.8954157, -0.8983546, -0.8998203, -0.8998106, -0.8983253, -0.89536697, -0.8909404, -0.8850529, -0.87771416, -0.86893606, -0.8587331, -0.8471221, -0.8341219, -0.8197539, -0.8040417, -0.787011, -0.7686899, -0.7491082, -0.7282981, -0.7062938, -0.6831313, -0.65884864, -0.63348556, -0.60708374, -0.5796864, -0.5513385, -0.5220865, -0.49197844, -0.46106362, -0.42939276, -0.39701775, -0.36399174, -0.33036885, -0.29620424, -0.26155388, -0.22647467, -0.19102407, -0.15526024, -0.119241804, -0.08302784, -0.046677727, -0.010251072, 0.026192391, 0.0625929, 0.09889078, 0.13502648, 0.17094079, 0.20657478, 0.24187003, 0.27676868, 0.31121346, 0.34514794, 0.37851644, 0.41126424, 0.44333768, 0.47468412, 0.5052522, 0.53499174, 0.563854, 0.5917917, 0.6187589, 0.64471155, 0.669607, 0.69340444, 0.7160648, 0.737551, 0.75782776, 0.77686185, 0.794622, 0.8110792, 0.8262063, 0.8399787, 0.8523737, 0.86337095, 0.87295246, 0.88110256, 0.8878078, 0.89305717, 0.8968422, 0.8991566, 0.89999646, 0.8993606, 0.89724994, 0.893668, 0.8886206, 0.88211614, 0.8741651, 0.86478066, 0.85397816, 0.8417753, 0.8281921, 0.81325084, 0.79697603, 0.7793943, ],
+        spectrum: &[[33.142628, 0.0], [36.521408, -4.432266], [52.542416, -12.723792], [192.26706, -69.57256], [-71.48714, 34.306744], [-26.06384, 15.528672], [-14.765805, 10.46974], [-9.822223, 8.046732], [-7.125353, 6.597696], [-5.4639444, 5.6214504], [-4.3572416, 4.9131007], [-3.5783982, 4.3725357], [-3.0073493, 3.9446857], [-2.575081, 3.5965755], [-2.2393768, 3.307164], [-1.9731119, 3.0623388], [-1.7581577, 2.8522544], [-1.5819932, 2.6698134], [-1.4357306, 2.5097616], [-1.3129076, 2.3681166], [-1.2087318, 2.2418036], [-1.1195841, 2.1284068], [-1.0426883, 2.0259986], [-0.97588444, 1.9330226], [-0.9174707, 1.848208], [-0.86609143, 1.7705022], [-0.82065606, 1.6990309], [-0.7802769, 1.6330591], [-0.74422836, 1.5719613], [-0.71190923, 1.5152087], [-0.68282133, 1.4623435], [-0.6565456, 1.4129716], [-0.63273084, 1.3667514], [-0.6110769, 1.3233846], [-0.5913303, 1.2826092], [-0.5732718, 1.2441955], [-0.5567153, 1.2079402], [-0.54149675, 1.17366], [-0.52747715, 1.1411971], [-0.5145323, 1.1104058], [-0.5025554, 1.0811583], [-0.4914523, 1.0533376], [-0.4811386, 1.0268391], [-0.47154295, 1.0015686], [-0.46259922, 0.97744125], [-0.4542496, 0.95437783], [-0.44644225, 0.93230826], [-0.43913183, 0.9111673], [-0.43227634, 0.8908957], [-0.42583945, 0.8714394], [-0.41978726, 0.8527492], [-0.41409016, 0.8347784], [-0.40872037, 0.81748503], [-0.4036533, 0.8008304], [-0.39886695, 0.7847779], [-0.39434162, 0.7692943], [-0.39005715, 0.7543491], [-0.3859973, 0.7399133], [-0.38214737, 0.72595954], [-0.37849233, 0.7124641], [-0.37501955, 0.6994029], [-0.37171733, 0.6867546], [-0.36857447, 0.6744988], [-0.36558053, 0.66261613], [-0.36272696, 0.6510904], [-0.3600048, 0.6399037], [-0.35740578, 0.62904024], [-0.35492337, 0.6184859], [-0.3525502, 0.6082269], [-0.3502805, 0.59825027], [-0.34810683, 0.5885434], [-0.34602582, 0.57909554], [-0.34403124, 0.56989443], [-0.34211913, 0.5609306], [-0.34028354, 0.5521947], [-0.33852196, 0.54367703], [-0.3368296, 0.5353685], [-0.33520305, 0.52726215], [-0.3336397, 0.5193487], [-0.33213595, 0.51162136], [-0.33068797, 0.5040731], [-0.32929397, 0.49669695], [-0.32795045, 0.48948663], [-0.32665664, 0.48243624], [-0.32540882, 0.47554013], [-0.32420558, 0.46879265], [-0.32304382, 0.46218756], [-0.32192245, 0.45572126], [-0.32083932, 0.44938862], [-0.31979355, 0.44318527], [-0.31878185, 0.4371057], [-0.317804, 0.43114716], [-0.3168586, 0.4253043], [-0.31594384, 0.41957527], [-0.31505817, 0.41395408], [-0.31420106, 0.40843907], [-0.31337044, 0.40302497], [-0.3125658, 0.39771098], [-0.3117866, 0.3924935], [-0.31103098, 0.38736764], [-0.31029806, 0.38233167], [-0.30958727, 0.37738353], [-0.30889806, 0.37251967], [-0.30822825, 0.3677387], [-0.30757844, 0.36303723], [-0.3069484, 0.3584127], [-0.30633503, 0.3538639], [-0.3057398, 0.34938794], [-0.30516142, 0.34498283], [-0.304599, 0.3406465], [-0.30405244, 0.33637723], [-0.303521, 0.3321737], [-0.30300346, 0.32803333], [-0.30250058, 0.3239541], [-0.3020111, 0.319935], [-0.30153492, 0.31597486], [-0.3010706, 0.3120705], [-0.30061948, 0.30822277], [-0.30017948, 0.30442828], [-0.29975143, 0.3006851], [-0.29933393, 0.2969945], [-0.29892728, 0.2933536], [-0.2985306, 0.28976032], [-0.2981446, 0.2862154], [-0.2977681, 0.2827158], [-0.29740095, 0.2792609], [-0.297042, 0.27585006], [-0.29669306, 0.2724825], [-0.29635194, 0.269156], [-0.29601938, 0.26587048], [-0.29569492, 0.26262498], [-0.29537788, 0.25941736], [-0.2950687, 0.2562479], [-0.2947665, 0.25311592], [-0.29447165, 0.25001916], [-0.29418373, 0.2469578], [-0.29390296, 0.24393147], [-0.29362783, 0.240938], [-0.2933593, 0.23797709], [-0.2930974, 0.23504858], [-0.29284132, 0.23215137], [-0.2925911, 0.22928463], [-0.29234663, 0.22644831], [-0.29210684, 0.22364017], [-0.29187307, 0.22086093], [-0.29164487, 0.21811017], [-0.2914215, 0.21538624], [-0.29120257, 0.21268903], [-0.29098913, 0.2100182], [-0.29077992, 0.2073721], [-0.29057544, 0.20475136], [-0.29037586, 0.2021552], [-0.29018033, 0.19958307], [-0.2899887, 0.19703367], [-0.2898016, 0.19450817], [-0.28961858, 0.19200374], [-0.28943923, 0.18952157], [-0.28926384, 0.18706119], [-0.2890921, 0.18462203], [-0.28892392, 0.1822025], [-0.28875938, 0.17980345], [-0.28859866, 0.17742424], [-0.2884408, 0.17506371], [-0.2882867, 0.17272249], [-0.28813553, 0.1703995], [-0.28798786, 0.16809416], [-0.28784359, 0.16580701], [-0.28770152, 0.16353689], [-0.28756362, 0.16128357], [-0.28742805, 0.15904726], [-0.28729475, 0.15682662], [-0.28716505, 0.15462159], [-0.2870376, 0.15243284], [-0.28691334, 0.15025856], [-0.28679168, 0.1480998], [-0.28667203, 0.14595512], [-0.28655508, 0.14382471], [-0.28644103, 0.14170831], [-0.2863288, 0.1396058], [-0.28621942, 0.13751675], [-0.28611177, 0.13544045], [-0.28600663, 0.13337721], [-0.2859036, 0.13132614], [-0.28580305, 0.12928733], [-0.28570485, 0.12726068], [-0.28560847, 0.12524606], [-0.28551412, 0.12324233], [-0.28542197, 0.12125037], [-0.28533193, 0.119269066], [-0.28524366, 0.11729938], [-0.2851572, 0.11533931], [-0.28507298, 0.113389865], [-0.28499028, 0.11145021], [-0.28490958, 0.109520674], [-0.2848302, 0.107600726], [-0.2847531, 0.105690345], [-0.284678, 0.10378851], [-0.28460476, 0.10189642], [-0.28453252, 0.1000125], [-0.28446186, 0.09813743], [-0.28439352, 0.096270956], [-0.28432623, 0.09441225], [-0.28426096, 0.09256247], [-0.2841968, 0.090719976], [-0.2841346, 0.08888446], [-0.28407317, 0.08705723], [-0.2840139, 0.08523705], [-0.28395584, 0.0834237], [-0.28389943, 0.08161786], [-0.28384453, 0.079817854], [-0.28379136, 0.07802457], [-0.28373852, 0.07623769], [-0.28368837, 0.07445688], [-0.28363848, 0.07268333], [-0.28359088, 0.07091436], [-0.28354365, 0.069151625], [-0.2834981, 0.06739396], [-0.28345412, 0.06564257], [-0.28341123, 0.06389596], [-0.28336924, 0.062154137], [-0.28332937, 0.060417175], [-0.28328997, 0.058685444], [-0.28325227, 0.056958243], [-0.28321558, 0.055235874], [-0.28318053, 0.053517345], [-0.28314665, 0.051802993], [-0.28311306, 0.05009272], [-0.28308123, 0.048386585], [-0.283051, 0.04668407], [-0.28302142, 0.044985443], [-0.282993, 0.043290623], [-0.28296584, 0.041597918], [-0.28293964, 0.039909106], [-0.2829151, 0.038223952], [-0.28289083, 0.036541328], [-0.28286824, 0.034861136], [-0.28284627, 0.033183992], [-0.28282613, 0.03150886], [-0.28280652, 0.029837057], [-0.28278846, 0.028166693], [-0.2827709, 0.026498377], [-0.28275514, 0.024832834], [-0.28273955, 0.023168927], [-0.2827257, 0.021506457], [-0.28271323, 0.019846188], [-0.28270096, 0.018186808], [-0.28269002, 0.016529104], [-0.28267992, 0.0148727875], [-0.2826708, 0.013217303], [-0.28266373, 0.011563188], [-0.28265673, 0.009910022], [-0.28265086, 0.0082567455], [-0.28264683, 0.006605069], [-0.28264275, 0.004953226], [-0.28264046, 0.0033016384], [-0.28263882, 0.0016510618], [-0.28263786, -2.0467073e-15], ],
+    },
+    GoldenVector {
+        class: SignalClass::Chirp,
+        n: 512,
+        input: &[0.8, 0.79999626, 0.79993975, 0.7996951, 0.7990364, 0.7976481, 0.7951256, 0.79097736, 0.7846282, 0.775425, 0.7626448, 0.7455074, 0.72319144, 0.6948566, 0.6596714, 0.6168484, 0.56568545, 0.505615, 0.43625998, 0.35749507, 0.26951188, 0.17288543, 0.06863785, -0.041705362, -0.15607226, -0.2718215, -0.38574702, -0.49411786, -0.5927609, -0.67719275, -0.7428049, -0.7851034, -0.8, -0.7841457, -0.73529106, -0.65265155, -0.5372472, -0.3921812, -0.22281575, -0.036802545, 0.15607226, 0.3442612, 0.51506525, 0.655478, 0.7532353, 0.79800916, 0.78265387, 0.7043767, 0.56568545, 0.37495106, 0.14643191, -0.100363985, -0.3420441, -0.55340743, -0.7101117, -0.7916976, -0.7846282, -0.68491787, -0.4998876, -0.24861372, 0.03925414, 0.32643533, 0.5725847, 0.7400394, 0.8, 0.7381609, 0.558701, 0.28562477, -0.03925414, -0.36187968, -0.6245898, -0.7766177, -0.7846282, -0.64110094, -0.36843097, -0.017179264, 0.3420441, 0.63218415, 0.78648436, 0.7648362, 0.56568545, 0.22987796, -0.1656891, -0.5243943, -0.7532353, -0.7877988, -0.6121338, -0.26719972, 0.15607226, 0.5390632, 0.7683444, 0.7703626, 0.5372472, 0.13435064, -0.31519362, -0.66653615, -0.8, -0.663809, -0.29705375, 0.17767489, 0.5927609, 0.79428035, 0.7008561, 0.33982375, -0.15607226, -0.59440637, -0.7970501, -0.67456657, -0.26951188, 0.2532747, 0.6705798, 0.79590344, 0.56568545, 0.0759708, -0.45258546, -0.77167183, -0.72319144, -0.32194772, 0.24160476, 0.68744147, 0.7846282, 0.47458583, -0.08817776, -0.60736734, -0.7990364, -0.54985225, 0.0098172305, 0.5674183, 0.8, 0.56394726, -0.0098172305, -0.58108735, -0.7990364, -0.5206773, 0.08817776, 0.6440251, 0.7846282, 0.40917507, -0.24160476, -0.732359, -0.72319144, -0.21100375, 0.45258546, 0.79638463, 0.56568545, -0.08085589, -0.6705798, -0.7588491, -0.26951188, 0.43006966, 0.7970501, 0.5354261, -0.15607226, -0.7242374, -0.7008561, -0.09549217, 0.5927609, 0.7800203, 0.29705375, -0.44649482, -0.8, -0.44241336, 0.31519362, 0.788638, 0.5372472, -0.21573466, -0.7683444, -0.5911099, 0.15607226, 0.75405854, 0.6121338, -0.1391871, -0.7532353, -0.6041611, 0.1656891, 0.7662611, 0.56568545, -0.23457533, -0.78648436, -0.49024805, 0.3420441, 0.79981554, 0.36843097, -0.47852856, -0.7846282, -0.19200242, 0.6245898, 0.71347255, -0.03925414, -0.74727404, -0.558701, 0.30841285, 0.8, 0.30387777, -0.5725847, -0.73036975, 0.03925414, 0.76038885, 0.4998876, -0.41338545, -0.7846282, -0.11495603, 0.7101117, 0.5777026, -0.3420441, -0.7936795, -0.14643191, 0.70669067, 0.56568545, -0.37928018, -0.78265387, -0.05640366, 0.7532353, 0.45863774, -0.51506525, -0.72213864, 0.15607226, 0.79915303, 0.22281575, -0.69727606, -0.5372472, 0.46265104, 0.73529106, -0.15847872, -0.8, -0.15366432, 0.7428049, 0.4259225, -0.5927609, -0.62916416, 0.38574702, 0.75240487, -0.15607226, -0.79891217, -0.06863785, 0.7810958, 0.26951188, -0.7156796, -0.43625998, 0.6199625, 0.56568545, -0.5094095, -0.6596714, 0.39645222, 0.72319144, -0.29020458, -0.7626448, 0.19676404, 0.7846282, -0.119811624, -0.7951256, 0.06129909, 0.7990364, -0.022086516, -0.79993975, 0.0024543654, 0.8, -0.0024543654, -0.79993975, 0.022086516, 0.7990364, -0.06129909, -0.7951256, 0.119811624, 0.7846282, -0.19676404, -0.7626448, 0.29020458, 0.72319144, -0.39645222, -0.6596714, 0.5094095, 0.56568545, -0.6199625, -0.43625998, 0.7156796, 0.26951188, -0.7810958, -0.06863785, 0.79891217, -0.15607226, -0.75240487, 0.38574702, 0.62916416, -0.5927609, -0.4259225, 0.7428049, 0.15366432, -0.8, 0.15847872, 0.73529106, -0.46265104, -0.5372472, 0.69727606, 0.22281575, -0.79915303, 0.15607226, 0.72213864, -0.51506525, -0.45863774, 0.7532353, 0.05640366, -0.78265387, 0.37928018, 0.56568545, -0.70669067, -0.14643191, 0.7936795, -0.3420441, -0.5777026, 0.7101117, 0.11495603, -0.7846282, 0.41338545, 0.4998876, -0.76038885, 0.03925414, 0.73036975, -0.5725847, -0.30387777, 0.8, -0.30841285, -0.558701, 0.74727404, -0.03925414, -0.71347255, 0.6245898, 0.19200242, -0.7846282, 0.47852856, 0.36843097, -0.79981554, 0.3420441, 0.49024805, -0.78648436, 0.23457533, 0.56568545, -0.7662611, 0.1656891, 0.6041611, -0.7532353, 0.1391871, 0.6121338, -0.75405854, 0.15607226, 0.5911099, -0.7683444, 0.21573466, 0.5372472, -0.788638, 0.31519362, 0.44241336, -0.8, 0.44649482, 0.29705375, -0.7800203, 0.5927609, 0.09549217, -0.7008561, 0.7242374, -0.15607226, -0.5354261, 0.7970501, -0.43006966, -0.26951188, 0.7588491, -0.6705798, 0.08085589, 0.56568545, -0.79638463, 0.45258546, 0.21100375, -0.72319144, 0.732359, -0.24160476, -0.40917507, 0.7846282, -0.6440251, 0.08817776, 0.5206773, -0.7990364, 0.58108735, -0.0098172305, -0.56394726, 0.8, -0.5674183, 0.0098172305, 0.54985225, -0.7990364, 0.60736734, -0.08817776, -0.47458583, 0.7846282, -0.68744147, 0.24160476, 0.32194772, -0.72319144, 0.77167183, -0.45258546, -0.0759708, 0.56568545, -0.79590344, 0.6705798, -0.2532747, -0.26951188, 0.67456657, -0.7970501, 0.59440637, -0.15607226, -0.33982375, 0.7008561, -0.79428035, 0.5927609, -0.17767489, -0.29705375, 0.663809, -0.8, 0.66653615, -0.31519362, -0.13435064, 0.5372472, -0.7703626, 0.7683444, -0.5390632, 0.15607226, 0.26719972, -0.6121338, 0.7877988, -0.7532353, 0.5243943, -0.1656891, -0.22987796, 0.56568545, -0.7648362, 0.78648436, -0.63218415, 0.3420441, 0.017179264, -0.36843097, 0.64110094, -0.7846282, 0.7766177, -0.6245898, 0.36187968, -0.03925414, -0.28562477, 0.558701, -0.7381609, 0.8, -0.7400394, 0.5725847, -0.32643533, 0.03925414, 0.24861372, -0.4998876, 0.68491787, -0.7846282, 0.7916976, -0.7101117, 0.55340743, -0.3420441, 0.100363985, 0.14643191, -0.37495106, 0.56568545, -0.7043767, 0.78265387, -0.79800916, 0.7532353, -0.655478, 0.51506525, -0.3442612, 0.15607226, 0.036802545, -0.22281575, 0.3921812, -0.5372472, 0.65265155, -0.73529106, 0.7841457, -0.8, 0.7851034, -0.7428049, 0.67719275, -0.5927609, 0.49411786, -0.38574702, 0.2718215, -0.15607226, 0.041705362, 0.06863785, -0.17288543, 0.26951188, -0.35749507, 0.43625998, -0.505615, 0.56568545, -0.6168484, 0.6596714, -0.6948566, 0.72319144, -0.7455074, 0.7626448, -0.775425, 0.7846282, -0.79097736, 0.7951256, -0.7976481, 0.7990364, -0.7996951, 0.79993975, -0.79999626, ],
+        spectrum: &[[9.050967, 0.0], [9.161354, -0.014726123], [9.484175, -0.107963316], [9.993437, -0.35784605], [10.642811, -0.84061915], [11.361787, -1.6268971], [12.0517645, -2.7746842], [12.58375, -4.3184743], [12.8, -6.25432], [12.522463, -8.521916], [11.571063, -10.986346], [9.794141, -13.424344], [7.111299, -15.522223], [3.565052, -16.894299], [-0.6280661, -17.130405], [-5.043098, -15.877374], [-9.050967, -12.950576], [-11.884877, -8.457541], [-12.784582, -2.8981388], [-11.213697, 2.809379], [-7.111299, 7.4304957], [-1.0982059, 9.678706], [5.4727054, 8.62435], [10.729276, 4.1510673], [12.8, -2.7202737], [10.554743, -9.860767], [4.31219, -14.577475], [-3.865676, -14.640846], [-10.642811, -9.46855], [-12.72201, -0.870498], [-8.595955, 7.273851], [0.1570755, 10.655268], [9.050967, 6.969672], [12.799036, -2.1796384], [8.595955, -11.45144], [-1.4108446, -14.637138], [-10.642811, -8.977203], [-12.202317, 2.0463064], [-4.31219, 10.276442], [7.241367, 8.821161], [12.8, -1.6939812], [6.9801598, -12.385647], [-5.4727054, -13.191675], [-12.752802, -2.6847897], [-7.111299, 9.088642], [6.171952, 9.690432], [12.784582, -2.121843], [4.75286, -13.350493], [-9.050967, -10.489667], [-11.764657, 3.6301396], [0.6280661, 11.39626], [12.29351, 2.2003267], [7.111299, -11.984922], [-8.241044, -11.114568], [-11.571063, 4.1730843], [2.6510255, 11.242817], [12.8, -1.2604603], [2.3429105, -13.825774], [-12.0517645, -5.536488], [-5.8948956, 10.154539], [10.642811, 5.9204583], [7.998202, -11.168485], [-9.484175, -9.755794], [-8.939217, 8.016167], [9.050967, 7.9198985], [8.939217, -10.278813], [-9.484175, -9.700291], [-7.998202, 8.901757], [10.642811, 6.03183], [5.8948956, -12.429476], [-12.0517645, -5.368508], [-2.3429105, 11.538408], [12.8, -1.0347462], [-2.6510255, -13.546977], [-11.571063, 4.4580703], [8.241044, 8.789048], [7.111299, -11.638688], [-12.29351, -4.552028], [0.6280661, 11.806204], [11.764657, -6.0131707], [-9.050967, -10.013018], [-4.75286, 10.930574], [12.784582, -1.5748868], [-6.171952, -12.153291], [-7.111299, 9.710195], [12.752802, 0.17232601], [-5.4727054, -12.49044], [-6.9801598, 9.8161745], [12.8, -0.9070465], [-7.241367, -11.455949], [-4.31219, 11.15619], [12.202317, -4.7558217], [-10.642811, -7.9962134], [1.4108446, 11.8421335], [8.595955, -10.3592005], [-12.799036, -0.7132832], [9.050967, 8.185096], [-0.1570755, -13.660595], [-8.595955, 8.626758], [12.72201, -2.2642868], [-10.642811, -7.960931], [3.865676, 11.356335], [4.31219, -12.894255], [-10.554743, 6.4022145], [12.8, -0.83596724], [-10.729276, -7.8130903], [5.4727054, 10.741023], [1.0982059, -13.580095], [-7.111299, 9.818122], [11.213697, -6.9941764], [-12.784582, -0.19183096], [11.884877, 3.9351537], [-9.050967, -9.866616], [5.043098, 10.9509325], [-0.6280661, -13.596514], [-3.565052, 11.483243], [7.111299, -11.451544], [-9.794141, 7.43372], [11.571063, -6.2787466], [-12.522463, 1.8461416], [12.8, -0.80385065], [-12.58375, -3.1458514], [12.0517645, 3.5100358], [-11.361787, -6.6963844], [10.642811, 6.3103533], [-9.993437, -8.798725], [9.484175, 7.7957325], [-9.161354, -9.739258], [9.050967, 8.250985], [-9.161354, -9.739258], [9.484175, 7.7957325], [-9.993437, -8.798725], [10.642811, 6.3103533], [-11.361787, -6.6963844], [12.0517645, 3.5100358], [-12.58375, -3.1458514], [12.8, -0.80385065], [-12.522463, 1.8461416], [11.571063, -6.2787466], [-9.794141, 7.43372], [7.111299, -11.451544], [-3.565052, 11.483243], [-0.6280661, -13.596514], [5.043098, 10.9509325], [-9.050967, -9.866616], [11.884877, 3.9351537], [-12.784582, -0.19183096], [11.213697, -6.9941764], [-7.111299, 9.818122], [1.0982059, -13.580095], [5.4727054, 10.741023], [-10.729276, -7.8130903], [12.8, -0.83596724], [-10.554743, 6.4022145], [4.31219, -12.894255], [3.865676, 11.356335], [-10.642811, -7.960931], [12.72201, -2.2642868], [-8.595955, 8.626758], [-0.1570755, -13.660595], [9.050967, 8.185096], [-12.799036, -0.7132832], [8.595955, -10.3592005], [1.4108446, 11.8421335], [-10.642811, -7.9962134], [12.202317, -4.7558217], [-4.31219, 11.15619], [-7.241367, -11.455949], [12.8, -0.9070465], [-6.9801598, 9.8161745], [-5.4727054, -12.49044], [12.752802, 0.17232601], [-7.111299, 9.710195], [-6.171952, -12.153291], [12.784582, -1.5748868], [-4.75286, 10.930574], [-9.050967, -10.013018], [11.764657, -6.0131707], [0.6280661, 11.806204], [-12.29351, -4.552028], [7.111299, -11.638688], [8.241044, 8.789048], [-11.571063, 4.4580703], [-2.6510255, -13.546977], [12.8, -1.0347462], [-2.3429105, 11.538408], [-12.0517645, -5.368508], [5.8948956, -12.429476], [10.642811, 6.03183], [-7.998202, 8.901757], [-9.484175, -9.700291], [8.939217, -10.278813], [9.050967, 7.9198985], [-8.939217, 8.016167], [-9.484175, -9.755794], [7.998202, -11.168485], [10.642811, 5.9204583], [-5.8948956, 10.154539], [-12.0517645, -5.536488], [2.3429105, -13.825774], [12.8, -1.2604603], [2.6510255, 11.242817], [-11.571063, 4.1730843], [-8.241044, -11.114568], [7.111299, -11.984922], [12.29351, 2.2003267], [0.6280661, 11.39626], [-11.764657, 3.6301396], [-9.050967, -10.489667], [4.75286, -13.350493], [12.784582, -2.121843], [6.171952, 9.690432], [-7.111299, 9.088642], [-12.752802, -2.6847897], [-5.4727054, -13.191675], [6.9801598, -12.385647], [12.8, -1.6939812], [7.241367, 8.821161], [-4.31219, 10.276442], [-12.202317, 2.0463064], [-10.642811, -8.977203], [-1.4108446, -14.637138], [8.595955, -11.45144], [12.799036, -2.1796384], [9.050967, 6.969672], [0.1570755, 10.655268], [-8.595955, 7.273851], [-12.72201, -0.870498], [-10.642811, -9.46855], [-3.865676, -14.640846], [4.31219, -14.577475], [10.554743, -9.860767], [12.8, -2.7202737], [10.729276, 4.1510673], [5.4727054, 8.62435], [-1.0982059, 9.678706], [-7.111299, 7.4304957], [-11.213697, 2.809379], [-12.784582, -2.8981388], [-11.884877, -8.457541], [-9.050967, -12.950576], [-5.043098, -15.877374], [-0.6280661, -17.130405], [3.565052, -16.894299], [7.111299, -15.522223], [9.794141, -13.424344], [11.571063, -10.986346], [12.522463, -8.521916], [12.8, -6.25432], [12.58375, -4.3184743], [12.0517645, -2.7746842], [11.361787, -1.6268971], [10.642811, -0.84061915], [9.993437, -0.35784605], [9.484175, -0.107963316], [9.161354, -0.014726123], [9.050967, -8.628166e-32], ],
+    },
+    GoldenVector {
+        class: SignalClass::Noise,
+        n: 512,
+        input: &[-0.075499356, 0.5427347, -0.63380194, -0.3701134, 0.034223914, 0.693744, 0.7457383, 0.80728966, 0.26851842, 0.0127315875, -0.67154765, -0.1734576, -0.42593297, -0.53436875, 0.35172912, -0.12056036, -0.36871266, 0.6924774, 0.17837366, -0.3043964, -0.09116367, -0.06528808, -0.0046508433, 0.79488844, 0.53862256, -0.07090688, 0.1783404, 0.095449194, -0.68554926, 0.30883083, 0.5502619, 0.6431001, 0.16742553, -0.4974266, -0.1881633, -0.12241441, 0.52123606, 0.17187059, 0.47237006, -0.09860734, -0.8195498, -0.12226067, 0.8588436, 0.06672478, 0.2549402, -0.48304546, -0.5685446, -0.7537332, -0.12643236, -0.4813078, -0.7162708, -0.39663744, 0.49024934, -0.7578504, -0.6446801, 0.5272328, 0.8355328, -0.7342342, 0.73563915, 0.64885616, 0.016803075, -0.49133006, -0.71357405, -0.6968603, 0.720249, -0.8250199, -0.738612, 0.05300324, 0.5177103, -0.43842036, 0.25103748, -0.45899698, 0.33153456, 0.41999424, 0.23084894, -0.6158808, 0.16056487, 0.8129961, 0.34086028, 0.07733281, -0.5796124, -0.34344035, -0.72515464, -0.6783842, -0.09903906, 0.58779323, -0.19281767, -0.35803822, 0.7947525, -0.75052685, 0.035540663, 0.011079025, 0.8985089, -0.06819602, -0.032484878, 0.6443739, 0.14178446, -0.83796984, 0.52932924, 0.18184744, 0.24535088, 0.28252438, -0.4752662, -0.8774683, -0.63738364, -0.6920224, 0.32222697, 0.42708987, -0.59282357, -0.19768427, 0.898718, 0.45420507, 0.21962324, -0.2340326, -0.2822377, -0.1536159, 0.17673783, 0.17410852, -0.4295079, -0.36920083, -0.103811145, 0.65650916, -0.04263382, 0.4507947, 0.7398662, 0.45960522, -0.3611337, -0.10631762, -0.7310295, -0.118576385, 0.8332631, -0.4695489, 0.8539869, -0.38001698, -0.21042272, -0.46698514, 0.49266535, -0.47420415, -0.68613833, 0.58462584, -0.060864903, 0.49909782, 0.07692812, 0.8490761, -0.7027244, -0.677572, -0.018355, -0.3856873, 0.6814714, 0.86918646, 0.71792984, -0.123906575, 0.79787415, 0.5787926, -0.516438, 0.7773048, 0.14929765, 0.3390705, 0.09894143, 0.06857024, -0.5831294, 0.76648587, 0.7939171, 0.20577243, 0.8215723, -0.27514088, -0.8051892, -0.44988626, 0.31877625, 0.37318057, -0.31023845, -0.8136583, 0.12892628, 0.083151914, -0.199485, 0.52476317, -0.4425295, 0.41707933, -0.7612165, -0.097327605, -0.0928216, -0.39325723, 0.17530124, -0.54918164, 0.33596557, -0.3761324, 0.09969942, -0.05946393, -0.23664883, -0.8171779, 0.6993274, -0.6228328, 0.57867306, 0.26616699, 0.83514386, -0.04460578, -0.44581372, 0.41368073, -0.25719723, -0.55788577, 0.41616875, -0.7345119, -0.76293945, -0.6975235, 0.17716034, -0.30008727, -0.45237193, -0.66529125, 0.78245896, 0.5566839, 0.06459296, -0.57064146, 0.8740004, -0.013881827, 0.521537, 0.7185725, 0.32474536, 0.114902936, 0.39447474, 0.6310404, 0.2466996, 0.56910706, 0.14473629, 0.21822818, -0.3743085, -0.5549579, -0.61607915, -0.6324753, -0.81396705, -0.83139855, 0.089046225, -0.8320722, 0.13818762, 0.7336998, 0.04601748, 0.54389447, 0.31257263, 0.8604398, -0.050458252, -0.47701982, -0.36738303, -0.13637038, -0.5623868, -0.24710849, 0.33020395, -0.3277213, -0.8113073, 0.33357936, 0.35662073, 0.6906927, -0.34029078, 0.27999452, 0.49214083, -0.5582028, 0.09300474, 0.18113312, -0.69263566, 0.18331149, 0.07035059, 0.07099678, -0.82055867, 0.13739948, -0.8578406, 0.17709038, 0.21630256, 0.22442237, -0.48998392, 0.86208504, 0.7920235, 0.04141749, -0.80934453, 0.32426974, 0.44644552, -0.7240709, -0.46049064, -0.12758292, -0.44781926, -0.604185, -0.7380508, 0.04068782, -0.397876, -0.094344124, -0.30965072, 0.0592, -0.5794023, 0.11038996, -0.2449052, -0.75411314, -0.74874485, -0.22319959, -0.87233937, -0.6647316, -0.07513436, -0.266522, -0.3361146, 0.009763027, -0.8398916, 0.29988694, -0.6744348, 0.14916226, 0.1004983, -0.51484436, 0.20849358, -0.236105, 0.8405706, 0.5472199, 0.5550715, -0.17229438, 0.74444735, -0.64705706, 0.4796119, -0.5246286, 0.40991223, -0.17016384, -0.615534, -0.51891613, -0.41888198, 0.050007213, -0.50490665, -0.21688084, -0.43694773, -0.063041136, -0.2077963, 0.87042266, -0.85911506, -0.7641344, -0.73090756, -0.50326794, 0.45732468, -0.16204974, 0.09229857, 0.2378656, 0.3511074, -0.0004113436, -0.106294334, 0.057798278, 0.7097432, 0.8467649, 0.67221457, 0.6991525, -0.16035469, -0.7944966, -0.7668884, -0.2362714, -0.69323224, 0.6489028, 0.14325571, -0.0013023734, -0.049125303, 0.7013959, 0.83021235, -0.64591485, 0.82656926, -0.40913773, -0.296543, 0.42716753, 0.87420326, -0.6107846, -0.5742263, -0.84059143, -0.026203787, -0.5679064, -0.487025, -0.4984408, 0.08508621, -0.84172374, -0.10993409, 0.4125468, 0.42683923, -0.040548243, -0.31802127, -0.7922214, 0.8985414, -0.11903794, -0.6329961, -0.0775167, -0.40240228, 0.6641495, 0.47103977, -0.39428172, -0.46483433, 0.27092844, -0.7692113, -0.28933558, 0.5037446, 0.28121063, -0.09597673, 0.19683595, -0.7003501, 0.4038057, 0.055047404, -0.7901715, 0.35349274, -0.4085766, -0.40652162, 0.05423223, -0.32820508, 0.3004029, -0.60552615, 0.6253332, 0.8428598, 0.285793, 0.778529, 0.31522897, -0.08997299, -0.02759006, -0.42103043, 0.4589839, 0.56543446, -0.10750476, -0.42473552, 0.036447465, -0.8325864, -0.8693985, 0.3202843, -0.40463784, 0.13464186, 0.76632935, -0.56037, -0.1886139, 0.32017872, 0.15554002, -0.4688435, -0.44934672, 0.21850894, 0.7406712, -0.55718267, 0.55049914, -0.49128103, -0.069221914, 0.55695385, -0.71602535, -0.31306925, -0.3580734, -0.24084467, -0.7762255, -0.80888116, -0.6614373, 0.6198142, -0.22879575, -0.7285649, 0.06648413, -0.8334989, -0.43418816, -0.29083365, -0.18196246, 0.07528607, 0.1413081, -0.06255072, 0.62016845, 0.75912976, -0.056599222, -0.033131074, -0.41405356, 0.08146931, -0.22483124, -0.43093538, -0.37980658, 0.6731257, -0.21440087, 0.021468723, 0.3198366, -0.5193446, -0.8330863, 0.5663031, 0.25961497, -0.57572705, -0.6236895, 0.18695845, -0.8962388, -0.106531546, -0.45225713, -0.44511807, -0.7657151, -0.14155412, 0.20936337, 0.2500969, -0.16351658, -0.774494, -0.527743, 0.60848045, 0.23230311, 0.7145, 0.5060882, 0.59892464, -0.8978215, 0.58647895, -0.83271366, -0.21649557, 0.08597134, 0.19966224, 0.2953963, -0.13622048, 0.50363785, 0.62185276, 0.04321768, 0.36635274, -0.21375392, 0.57742167, -0.14364249, -0.12918752, -0.14202866, 0.456988, -0.6321767, -0.5668178, 0.7596917, -0.63207984, 0.5232787, -0.09199698, 0.65481114, -0.34621912, -0.09741096, ],
+        spectrum: &[[-20.350916, 0.0], [5.37712, -13.071211], [-4.7803397, 3.7228508], [13.959121, -4.374053], [13.155708, 3.3614988], [3.7109175, -8.746609], [5.693279, 12.809516], [-2.9228702, -3.1963649], [-4.499214, -11.062118], [-2.839533, 6.2141747], [6.544941, 5.380001], [-19.10857, 5.287555], [2.1898482, 3.0659611], [-8.208506, 11.3835125], [-3.9293206, -14.4753685], [3.486668, -1.669821], [-0.607539, 6.594664], [20.690987, -19.807798], [-0.7875769, 1.3201474], [11.338565, 1.8520039], [-5.906169, 5.320416], [-6.111378, 4.699882], [3.0949802, -24.193554], [2.8730066, 7.0438123], [1.7565813, -4.3758035], [-9.387973, 1.4858967], [4.9872346, 13.882136], [0.98639107, -13.558991], [7.831115, -5.7149377], [13.798625, -21.796015], [-16.163519, 2.9318383], [-6.908571, -20.295633], [8.317892, 11.229865], [3.838478, 5.8080754], [-5.829542, 9.905182], [8.4078245, -3.1661916], [-13.464241, 13.860319], [11.216516, -5.493291], [-1.7983434, -3.5863535], [-14.360426, 1.569163], [-8.102446, -4.3676567], [-2.6981766, 10.865957], [1.677707, -6.0856338], [-14.331203, 11.0736], [-5.373702, 2.523674], [-12.566115, 3.7553895], [-3.756092, 0.5794122], [4.8803697, -2.5021133], [-3.2599888, -15.933932], [-7.762353, -4.4766393], [6.9503717, 17.354856], [-4.160226, 6.1584945], [-8.90442, -2.8133092], [0.86609423, 1.9752607], [4.1713777, 5.8130393], [-1.0752349, -3.241532], [2.4632535, 0.98342526], [-9.461256, 20.75781], [-3.1871715, 5.259714], [5.6088424, 7.99208], [-9.107284, 13.048462], [-2.0105925, 7.7479405], [2.5860333, -2.7256732], [-12.138435, -6.3661504], [-0.20977613, -10.31423], [9.420588, 17.876993], [2.368524, 6.252268], [5.12943, -13.027905], [1.3321996, -4.0047665], [10.004384, 7.5227237], [10.094653, 0.18839255], [3.347376, 11.837007], [9.3177805, -7.155759], [2.0268626, -10.364123], [-5.8526754, -6.222629], [-9.680383, 0.76645845], [-0.49813098, -0.848163], [-10.473954, 5.518294], [-5.1403346, 8.024134], [10.434923, -0.22920872], [-9.1139145, -0.4236807], [-8.139627, 1.7419475], [14.321895, 7.919469], [8.647466, -10.568624], [11.9813795, -4.746949], [11.0083, 5.3058763], [6.8610644, -0.87189597], [0.2787286, -5.6916614], [11.659111, -11.956506], [0.8759298, -1.429083], [-13.737046, 11.630966], [3.7855988, -10.157807], [1.688878, 3.1319664], [-8.385288, 3.245481], [-6.2537684, -12.6876745], [4.510848, -0.4148744], [-1.7763338, 2.1361485], [14.219156, 1.827368], [12.093288, -11.909142], [-0.051327117, -5.306951], [-0.9225652, 6.447193], [1.5066881, 2.8833065], [-9.970848, -3.0302768], [0.70557886, 2.4380245], [-13.395961, -6.94144], [3.7529442, 2.1837888], [8.136511, -2.992177], [3.1210167, -2.5375714], [-3.5534651, -7.8756332], [-11.093337, 6.4246383], [4.211707, 0.067326196], [13.994363, 1.3146269], [-2.3641315, -0.38988584], [12.214839, -6.6474457], [-2.6583683, 1.2402666], [-2.3361628, -0.762518], [-7.347764, -11.144363], [5.4085693, 1.7940071], [7.172217, 15.133417], [2.0376208, 6.57177], [-0.708045, 1.5940039], [6.178929, -14.172246], [-1.8509376, -8.135016], [-8.882351, -13.501554], [-0.813912, -1.8369621], [-1.8868914, 0.54509664], [-13.311261, 8.144001], [-11.812136, 1.0723076], [18.57808, -6.7254014], [-6.6626906, -0.6679549], [5.816363, -3.6403902], [-3.0928795, -9.2184925], [2.7744439, 1.492184], [-5.5539374, 3.724086], [-1.2980032, 3.2326705], [-5.1984634, -6.232303], [4.2242265, -8.002839], [-5.5211353, -6.9444375], [13.065675, -6.3705726], [3.6556268, 2.8905435], [18.836758, -12.937759], [5.947612, -19.948246], [-10.646449, 12.719345], [-1.2765503, 6.47071], [-9.070849, 2.0310223], [20.898987, 9.864158], [-4.5480957, -0.75733066], [8.473199, 1.5037479], [-4.205208, -3.01074], [4.7312717, -5.522077], [-0.70530885, -4.8985667], [24.199377, -6.822565], [-2.3849454, 4.2436633], [-3.725016, -2.7410886], [-5.556614, 4.8739867], [-4.2350082, -2.313121], [-7.124881, -4.4298677], [9.781751, 1.6477041], [10.323234, 3.9292984], [3.3855433, -18.40073], [-5.630905, -5.8820863], [8.1525545, 7.61612], [5.54134, -9.1849], [-3.943377, -3.5608246], [-12.676316, 18.545332], [-10.131765, 8.419338], [-12.87522, 7.8738985], [3.2291057, 1.0499455], [0.0031591489, 10.269882], [0.32142654, -2.868526], [-14.6595125, -7.174916], [-7.789246, 0.014628641], [9.231171, -1.3835857], [4.49735, 13.514677], [2.4318452, 9.933123], [1.3238815, -6.385862], [7.2752466, 3.302135], [-0.9035361, 1.2480686], [-1.8178647, -11.7834], [1.6854783, -16.745552], [-11.5191, 6.6228867], [7.945354, 8.542167], [0.41378236, -2.560798], [8.906751, -3.8884342], [-7.7218084, 10.168491], [-11.568433, -9.873394], [-3.9502404, -13.532033], [12.967487, -9.08556], [0.6011225, 0.5983936], [-6.5848904, -0.55371356], [-9.700195, -4.276399], [-5.9494267, -1.2456113], [2.4994795, -11.148676], [10.040877, 4.29094], [2.426193, -7.128286], [1.6086102, -8.800925], [-4.697837, 8.125851], [3.0633276, -0.7721214], [4.246745, -3.0541213], [-2.3303566, -0.3056204], [12.446923, -6.6770005], [-6.8089952, 5.4539266], [-3.5977552, 4.815978], [-2.6365223, -2.6149433], [-12.065338, -6.2439837], [15.703418, 5.7981234], [-6.506691, 3.1423357], [-12.332215, 6.5211573], [3.2400193, -7.3039026], [6.185226, 3.8660254], [3.5946517, 4.2973776], [4.719787, -2.4716265], [-1.9034362, -6.9595413], [-2.642007, 5.3695774], [7.536477, -7.5407343], [2.033609, 0.8249059], [-0.35789442, -9.090488], [4.629176, 0.6984021], [11.209293, -0.678665], [-1.732797, 1.4341788], [3.5094886, 8.468314], [5.813198, 3.689399], [10.081214, 1.3211569], [-3.178871, -3.610266], [0.7485366, 19.25208], [-0.5837517, 12.04672], [-1.8411431, -1.5419567], [-12.857479, 4.6849914], [5.207966, 6.434835], [5.2035565, -5.225236], [-9.552506, -16.79888], [1.9536552, 23.932327], [-6.950297, 1.2002977], [-1.9288478, -2.4286041], [4.193467, -0.39021367], [-10.759623, -10.99379], [5.7841864, 2.7153752], [-4.3725834, 9.520528], [-7.7785363, -5.6286826], [1.3345455, 15.047159], [-4.690925, 2.8748443], [2.104478, -4.561828], [-8.370861, -1.0137905], [-1.540554, 2.6334188], [-8.968545, 9.426639], [-2.8077562, -2.0434785], [7.1888857, -3.3383658], [-11.949161, 7.588893], [-3.6498663, -6.8187876], [-0.6957608, -6.823097], [-15.385035, 1.9737344], [-2.5933588, -7.2626796], [-22.506945, -16.75841], [4.405148, 3.7485635], [-5.8521805, 0.21177237], [9.122287, 6.547517], [11.736256, 1.9647728e-15], ],
+    },
+];
@@ -0,0 +1,6 @@
+pub mod golden;
+
+pub use golden::{
+    GOLDEN_MAX_N, GoldenMismatch, GoldenVector, SignalClass, ValidationError, golden_vectors,
+    validate_complex_f32, validate_complex_q15, validate_real_f32, validate_real_q15,
+};
@@ -0,0 +1,193 @@
+// Property tests covering every transform path: round-trip identity,
+// linearity and Parseval's theorem, for random sizes and inputs.
+
+use proptest::prelude::*;
+use rs_simple_fft::float::Complex32;
+use rs_simple_fft::{Algorithm, ComplexFixed, CplxFft, Fixed, RealFft};
+
+/// Q31 twiddle type used by the fixed-point plans.
+type Twiddle = ComplexFixed<31>;
+
+const FLOAT_TOL: f64 = 1e-3;
+
+/// Random power-of-two size 2^min_log..=2^max_log with that many samples in [-1, 1].
+fn sized_signal(min_log: u32, max_log: u32) -> impl Strategy<Value = Vec<f32>> {
+    (min_log..=max_log).prop_flat_map(|log| prop::collection::vec(-1.0f32..1.0, 1usize << log))
+}
+
+fn algorithm() -> impl Strategy<Value = Algorithm> {
+    prop_oneof![Just(Algorithm::Dit), Just(Algorithm::Dif)]
+}
+
+fn to_complex(v: &[f32]) -> Vec<Complex32> {
+    v.chunks_exact(2)
+        .map(|c| Complex32::new(c[0], c[1]))
+        .collect()
+}
+
+fn energy(v: &[Complex32]) -> f64 {
+    v.iter().map(|c| c.norm_sqr() as f64).sum()
+}
+
+fn zero_twiddles(n: usize) -> Vec<Twiddle> {
+    vec![Twiddle::ZERO; n]
+}
+
+fn to_f64<const FRAC: u32>(x: Fixed<FRAC>) -> f64 {
+    x.to_bits() as f64 / (1u64 << FRAC) as f64
+}
+
+/// Energy of a packed real spectrum, counting the mirrored bins.
+fn packed_energy(packed: &[f64]) -> f64 {
+    let mirrored: f64 = packed[2..].iter().map(|x| x * x).sum();
+    packed[0] * packed[0] + packed[1] * packed[1] + 2.0 * mirrored
+}
+
+proptest! {
+    #[test]
+    fn float_complex_round_trip(data in sized_signal(2, 11), algorithm in algorithm()) {
+        let input = to_complex(&data);
+        let n = input.len();
+        let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+        let mut bitrev = vec![0; n];
+        let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_algorithm(algorithm);
+
+        let mut buffer = input.clone();
+        fft.process(&mut buffer, false).unwrap();
+        fft.process(&mut buffer, true).unwrap();
+        for (a, b) in buffer.iter().zip(&input) {
+            prop_assert!(((*a - *b).l1_norm() as f64) < FLOAT_TOL);
+        }
+    }
+
+    #[test]
+    fn float_complex_linearity(data in sized_signal(2, 10), alpha in -2.0f32..2.0, beta in -2.0f32..2.0) {
+        let (x, y) = data.split_at(data.len() / 2);
+        let (x, y) = (to_complex(x), to_complex(y));
+        let n = x.len();
+        let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+        let mut bitrev = vec![0; n];
+        let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+        let mut mixed: Vec<Complex32> = x.iter().zip(&y).map(|(&a, &b)| a * alpha + b * beta).collect();
+        let (mut fx, mut fy) = (x.clone(), y.clone());
+        fft.process(&mut mixed, false).unwrap();
+        fft.process(&mut fx, false).unwrap();
+        fft.process(&mut fy, false).unwrap();
+
+        let tol = FLOAT_TOL * n as f64;
+        for k in 0..n {
+            let expected = fx[k] * alpha + fy[k] * beta;
+            prop_assert!(((mixed[k] - expected).l1_norm() as f64) < tol);
+        }
+    }
+
+    #[test]
+    fn float_complex_parseval(data in sized_signal(2, 11)) {
+        let input = to_complex(&data);
+        let n = input.len();
+        let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+        let mut bitrev = vec![0; n];
+        let fft = CplxFft::<Complex32>::new(&mut twiddles, &mut bitrev, n).unwrap();
+
+        let mut spectrum = input.clone();
+        fft.process(&mut spectrum, false).unwrap();
+        let time = energy(&input);
+        let freq = energy(&spectrum) / n as f64;
+        prop_assert!((time - freq).abs() <= FLOAT_TOL * (1.0 + time));
+    }
+
+    #[test]
+    fn float_real_round_trip_and_parseval(input in sized_signal(1, 11), algorithm in algorithm()) {
+        let n = input.len();
+        let mut twiddles = vec![Complex32::new(0., 0.); n / 2];
+        let mut bitrev = vec![0; n / 2];
+        let fft = RealFft::<Complex32>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_algorithm(algorithm);
+
+        let mut buffer = input.clone();
+        fft.process(&mut buffer, false).unwrap();
+
+        let packed: Vec<f64> = buffer.iter().map(|&x| x as f64).collect();
+        let time: f64 = input.iter().map(|&x| (x as f64) * (x as f64)).sum();
+        let freq = packed_energy(&packed) / n as f64;
+        prop_assert!((time - freq).abs() <= FLOAT_TOL * (1.0 + time));
+
+        fft.process(&mut buffer, true).unwrap();
+        for (a, b) in buffer.iter().zip(&input) {
+            prop_assert!(((a - b).abs() as f64) < FLOAT_TOL);
+        }
+    }
+
+    #[test]
+    fn fixed_complex_round_trip_and_linearity(data in sized_signal(2, 10), algorithm in algorithm()) {
+        const FRAC: u32 = 15;
+        let n = data.len() / 2;
+        let q = |v: f32| Fixed::<FRAC>::from_f64(v as f64 * 0.25);
+        let x: Vec<ComplexFixed<FRAC>> = data[..n].chunks_exact(2)
+            .chain(data[n..].chunks_exact(2))
+            .map(|c| ComplexFixed::new(q(c[0]), q(c[1])))
+            .collect();
+        let n = x.len();
+        let mut twiddles = zero_twiddles(n / 2);
+        let mut bitrev = vec![0; n];
+        let fft = CplxFft::<Twiddle>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_algorithm(algorithm);
+
+        // Q15 in an i32 leaves ample headroom for the unscaled forward pass;
+        // each scaled inverse stage loses up to one LSB of precision
+        let lsb = 1.0 / (1u64 << FRAC) as f64;
+        let tol = 4.0 * lsb * (n.trailing_zeros() as f64 + 1.0);
+
+        let mut buffer = x.clone();
+        fft.process(&mut buffer, false).unwrap();
+
+        // Linearity: F(2x) = 2 F(x). Unscaled products round at every stage,
+        // so the error may grow with N.
+        let mut doubled: Vec<ComplexFixed<FRAC>> = x.iter().map(|&c| c + c).collect();
+        fft.process(&mut doubled, false).unwrap();
+        let forward_tol = lsb * n as f64;
+        for (d, s) in doubled.iter().zip(&buffer) {
+            prop_assert!((to_f64(d.re) - 2.0 * to_f64(s.re)).abs() < forward_tol);
+            prop_assert!((to_f64(d.im) - 2.0 * to_f64(s.im)).abs() < forward_tol);
+        }
+
+        fft.process(&mut buffer, true).unwrap();
+        for (a, b) in buffer.iter().zip(&x) {
+            prop_assert!((to_f64(a.re) - to_f64(b.re)).abs() < tol);
+            prop_assert!((to_f64(a.im) - to_f64(b.im)).abs() < tol);
+        }
+    }
+
+    #[test]
+    fn fixed_real_round_trip_and_parseval(data in sized_signal(1, 10), algorithm in algorithm()) {
+        const FRAC: u32 = 15;
+        let input: Vec<Fixed<FRAC>> = data.iter().map(|&v| Fixed::from_f64(v as f64 * 0.5)).collect();
+        let n = input.len();
+        let mut twiddles = zero_twiddles(n / 2);
+        let mut bitrev = vec![0; n / 2];
+        let fft = RealFft::<Twiddle>::new(&mut twiddles, &mut bitrev, n)
+            .unwrap()
+            .with_algorithm(algorithm);
+
+        let lsb = 1.0 / (1u64 << FRAC) as f64;
+        let tol = 4.0 * lsb * (n.trailing_zeros() as f64 + 1.0);
+
+        let mut buffer = input.clone();
+        fft.process(&mut buffer, false).unwrap();
+
+        let packed: Vec<f64> = buffer.iter().map(|&x| to_f64(x)).collect();
+        let time: f64 = input.iter().map(|&x| to_f64(x) * to_f64(x)).sum();
+        let freq = packed_energy(&packed) / n as f64;
+        prop_assert!((time - freq).abs() <= 1e-3 * (1.0 + time));
+
+        fft.process(&mut buffer, true).unwrap();
+        for (a, b) in buffer.iter().zip(&input) {
+            prop_assert!((to_f64(*a) - to_f64(*b)).abs() < tol);
+        }
+    }
+}